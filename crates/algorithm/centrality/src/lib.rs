@@ -0,0 +1,283 @@
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdges, IntoNeighbors, IntoNodeIdentifiers};
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+fn node_indices<G>(graph: G) -> (Vec<G::NodeId>, HashMap<G::NodeId, usize>)
+where
+    G: IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let nodes = graph.node_identifiers().collect::<Vec<_>>();
+    let indices = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &u)| (u, i))
+        .collect::<HashMap<_, _>>();
+    (nodes, indices)
+}
+
+fn adjacency<G>(
+    graph: G,
+    nodes: &[G::NodeId],
+    indices: &HashMap<G::NodeId, usize>,
+) -> Vec<Vec<usize>>
+where
+    G: IntoNeighbors,
+    G::NodeId: Eq + Hash,
+{
+    nodes
+        .iter()
+        .map(|&u| graph.neighbors(u).map(|v| indices[&v]).collect())
+        .collect()
+}
+
+fn bfs(adjacency: &[Vec<usize>], s: usize) -> Vec<Option<usize>> {
+    let mut distance = vec![None; adjacency.len()];
+    distance[s] = Some(0);
+    let mut queue = VecDeque::new();
+    queue.push_back(s);
+    while let Some(u) = queue.pop_front() {
+        let du = distance[u].unwrap();
+        for &v in adjacency[u].iter() {
+            if distance[v].is_none() {
+                distance[v] = Some(du + 1);
+                queue.push_back(v);
+            }
+        }
+    }
+    distance
+}
+
+pub fn betweenness_centrality<G>(graph: G) -> HashMap<G::NodeId, f32>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + GraphProp,
+    G::NodeId: Eq + Hash,
+{
+    let (nodes, indices) = node_indices(graph);
+    let adjacency = adjacency(graph, &nodes, &indices);
+    let n = nodes.len();
+    let mut centrality = vec![0.; n];
+    for s in 0..n {
+        let mut stack = vec![];
+        let mut predecessors = vec![vec![]; n];
+        let mut sigma = vec![0.; n];
+        let mut distance = vec![None; n];
+        sigma[s] = 1.;
+        distance[s] = Some(0);
+        let mut queue = VecDeque::new();
+        queue.push_back(s);
+        while let Some(u) = queue.pop_front() {
+            stack.push(u);
+            let du = distance[u].unwrap();
+            for &v in adjacency[u].iter() {
+                if distance[v].is_none() {
+                    distance[v] = Some(du + 1);
+                    queue.push_back(v);
+                }
+                if distance[v] == Some(du + 1) {
+                    sigma[v] += sigma[u];
+                    predecessors[v].push(u);
+                }
+            }
+        }
+        let mut delta = vec![0.; n];
+        while let Some(v) = stack.pop() {
+            for &u in predecessors[v].iter() {
+                delta[u] += sigma[u] / sigma[v] * (1. + delta[v]);
+            }
+            if v != s {
+                centrality[v] += delta[v];
+            }
+        }
+    }
+    let scale = if graph.is_directed() { 1. } else { 0.5 };
+    nodes
+        .into_iter()
+        .zip(centrality)
+        .map(|(u, c)| (u, c * scale))
+        .collect()
+}
+
+pub fn edge_betweenness_centrality<G>(graph: G) -> HashMap<G::EdgeId, f32>
+where
+    G: IntoEdges + IntoNodeIdentifiers + GraphProp,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+{
+    let (nodes, indices) = node_indices(graph);
+    let mut edges = vec![];
+    let mut edge_indices = HashMap::new();
+    let adjacency = nodes
+        .iter()
+        .map(|&u| {
+            graph
+                .edges(u)
+                .map(|e| {
+                    let k = *edge_indices.entry(e.id()).or_insert_with(|| {
+                        edges.push(e.id());
+                        edges.len() - 1
+                    });
+                    let v = if e.source() == u {
+                        e.target()
+                    } else {
+                        e.source()
+                    };
+                    (indices[&v], k)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let n = nodes.len();
+    let mut centrality = vec![0.; edges.len()];
+    for s in 0..n {
+        let mut stack = vec![];
+        let mut predecessors = vec![vec![]; n];
+        let mut sigma = vec![0.; n];
+        let mut distance = vec![None; n];
+        sigma[s] = 1.;
+        distance[s] = Some(0);
+        let mut queue = VecDeque::new();
+        queue.push_back(s);
+        while let Some(u) = queue.pop_front() {
+            stack.push(u);
+            let du = distance[u].unwrap();
+            for &(v, k) in adjacency[u].iter() {
+                if distance[v].is_none() {
+                    distance[v] = Some(du + 1);
+                    queue.push_back(v);
+                }
+                if distance[v] == Some(du + 1) {
+                    sigma[v] += sigma[u];
+                    predecessors[v].push((u, k));
+                }
+            }
+        }
+        let mut delta = vec![0.; n];
+        while let Some(v) = stack.pop() {
+            for &(u, k) in predecessors[v].iter() {
+                let c = sigma[u] / sigma[v] * (1. + delta[v]);
+                centrality[k] += c;
+                delta[u] += c;
+            }
+        }
+    }
+    let scale = if graph.is_directed() { 1. } else { 0.5 };
+    edges
+        .into_iter()
+        .zip(centrality)
+        .map(|(e, c)| (e, c * scale))
+        .collect()
+}
+
+pub fn closeness_centrality<G>(graph: G) -> HashMap<G::NodeId, f32>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let (nodes, indices) = node_indices(graph);
+    let adjacency = adjacency(graph, &nodes, &indices);
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &u)| {
+            let (count, total) = bfs(&adjacency, i)
+                .into_iter()
+                .flatten()
+                .fold((0, 0), |(c, t), d| (c + 1, t + d));
+            let c = if total > 0 {
+                (count - 1) as f32 / total as f32
+            } else {
+                0.
+            };
+            (u, c)
+        })
+        .collect()
+}
+
+pub fn pagerank<G>(graph: G, damping: f32, iterations: usize) -> HashMap<G::NodeId, f32>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let (nodes, indices) = node_indices(graph);
+    let adjacency = adjacency(graph, &nodes, &indices);
+    let n = nodes.len();
+    let mut rank = vec![1. / n as f32; n];
+    for _ in 0..iterations {
+        let dangling = (0..n)
+            .filter(|&u| adjacency[u].is_empty())
+            .map(|u| rank[u])
+            .sum::<f32>();
+        let mut next = vec![(1. - damping + damping * dangling) / n as f32; n];
+        for u in 0..n {
+            let k = adjacency[u].len() as f32;
+            for &v in adjacency[u].iter() {
+                next[v] += damping * rank[u] / k;
+            }
+        }
+        rank = next;
+    }
+    nodes.into_iter().zip(rank).collect()
+}
+
+pub fn eigenvector_centrality<G>(graph: G, iterations: usize) -> HashMap<G::NodeId, f32>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let (nodes, indices) = node_indices(graph);
+    let adjacency = adjacency(graph, &nodes, &indices);
+    let n = nodes.len();
+    let mut x = vec![1. / (n as f32).sqrt(); n];
+    for _ in 0..iterations {
+        let mut next = x.clone();
+        for u in 0..n {
+            for &v in adjacency[u].iter() {
+                next[v] += x[u];
+            }
+        }
+        let norm = next.iter().map(|v| v * v).sum::<f32>().sqrt();
+        if norm == 0. {
+            break;
+        }
+        x = next.into_iter().map(|v| v / norm).collect();
+    }
+    nodes.into_iter().zip(x).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_centrality() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..5).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..5 {
+            graph.add_edge(nodes[0], nodes[i], ());
+        }
+
+        let betweenness = betweenness_centrality(&graph);
+        assert!((betweenness[&nodes[0]] - 6.).abs() < 1e-6);
+        assert!(betweenness[&nodes[1]].abs() < 1e-6);
+
+        let edge_betweenness = edge_betweenness_centrality(&graph);
+        assert_eq!(edge_betweenness.len(), 4);
+        for e in graph.edge_indices() {
+            assert!((edge_betweenness[&e] - 4.).abs() < 1e-6);
+        }
+
+        let closeness = closeness_centrality(&graph);
+        assert!((closeness[&nodes[0]] - 1.).abs() < 1e-6);
+        assert!((closeness[&nodes[1]] - 4. / 7.).abs() < 1e-6);
+
+        let rank = pagerank(&graph, 0.85, 100);
+        assert!((rank.values().sum::<f32>() - 1.).abs() < 1e-4);
+        assert!(rank[&nodes[0]] > rank[&nodes[1]]);
+
+        let eigenvector = eigenvector_centrality(&graph, 100);
+        assert!(eigenvector[&nodes[0]] > eigenvector[&nodes[1]]);
+        assert!((eigenvector[&nodes[1]] - eigenvector[&nodes[4]]).abs() < 1e-6);
+    }
+}
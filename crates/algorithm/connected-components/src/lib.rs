@@ -1,6 +1,7 @@
-use petgraph::graph::{Graph, IndexType, NodeIndex};
+use petgraph::graph::{EdgeIndex, Graph, IndexType, NodeIndex};
 use petgraph::unionfind::UnionFind;
-use petgraph::EdgeType;
+use petgraph::visit::EdgeRef;
+use petgraph::{EdgeType, Undirected};
 use std::collections::HashMap;
 
 pub fn connected_components<N, E, Ty: EdgeType, Ix: IndexType>(
@@ -23,6 +24,74 @@ pub fn connected_components<N, E, Ty: EdgeType, Ix: IndexType>(
     result
 }
 
+pub type EdgeComponentMap<Ix> = HashMap<EdgeIndex<Ix>, usize>;
+
+pub fn biconnected_component_edges<N, E, Ix: IndexType>(
+    graph: &Graph<N, E, Undirected, Ix>,
+) -> (Vec<Vec<EdgeIndex<Ix>>>, EdgeComponentMap<Ix>) {
+    let mut disc = HashMap::<NodeIndex<Ix>, usize>::new();
+    let mut low = HashMap::<NodeIndex<Ix>, usize>::new();
+    let mut edge_stack = vec![];
+    let mut components = vec![];
+    for s in graph.node_indices() {
+        if disc.contains_key(&s) {
+            continue;
+        }
+        disc.insert(s, disc.len());
+        low.insert(s, disc[&s]);
+        let mut stack = vec![(s, None, graph.edges(s).collect::<Vec<_>>(), 0)];
+        while let Some((u, parent_edge, edges, k)) = stack.last_mut() {
+            let u = *u;
+            if *k < edges.len() {
+                let edge = edges[*k];
+                *k += 1;
+                let e = edge.id();
+                let v = edge.target();
+                if Some(e) == *parent_edge {
+                    continue;
+                }
+                if v == u {
+                    components.push(vec![e]);
+                } else if let Some(&dv) = disc.get(&v) {
+                    if dv < disc[&u] {
+                        edge_stack.push(e);
+                        low.insert(u, low[&u].min(dv));
+                    }
+                } else {
+                    edge_stack.push(e);
+                    disc.insert(v, disc.len());
+                    low.insert(v, disc[&v]);
+                    stack.push((v, Some(e), graph.edges(v).collect::<Vec<_>>(), 0));
+                }
+            } else {
+                let parent_edge = *parent_edge;
+                stack.pop();
+                if let (Some(e), Some((p, _, _, _))) = (parent_edge, stack.last()) {
+                    let p = *p;
+                    low.insert(p, low[&p].min(low[&u]));
+                    if low[&u] >= disc[&p] {
+                        let mut component = vec![];
+                        while let Some(f) = edge_stack.pop() {
+                            component.push(f);
+                            if f == e {
+                                break;
+                            }
+                        }
+                        components.push(component);
+                    }
+                }
+            }
+        }
+    }
+    let mut edge_components = HashMap::new();
+    for (i, component) in components.iter().enumerate() {
+        for &e in component.iter() {
+            edge_components.insert(e, i);
+        }
+    }
+    (components, edge_components)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -45,4 +114,33 @@ mod test {
         assert_ne!(components[&u3], components[&u4]);
         assert_eq!(components[&u4], components[&u5]);
     }
+
+    #[test]
+    fn test_biconnected_component_edges() {
+        let mut graph = Graph::new_undirected();
+        let u = (0..7).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let e01 = graph.add_edge(u[0], u[1], ());
+        let e12 = graph.add_edge(u[1], u[2], ());
+        let e20 = graph.add_edge(u[2], u[0], ());
+        let e23 = graph.add_edge(u[2], u[3], ());
+        let e34 = graph.add_edge(u[3], u[4], ());
+        let e45 = graph.add_edge(u[4], u[5], ());
+        let e53 = graph.add_edge(u[5], u[3], ());
+        let e56 = graph.add_edge(u[5], u[6], ());
+        let (components, edge_components) = biconnected_component_edges(&graph);
+        assert_eq!(components.len(), 4);
+        assert_eq!(edge_components.len(), 8);
+        assert_eq!(edge_components[&e01], edge_components[&e12]);
+        assert_eq!(edge_components[&e01], edge_components[&e20]);
+        assert_eq!(edge_components[&e34], edge_components[&e45]);
+        assert_eq!(edge_components[&e34], edge_components[&e53]);
+        assert_ne!(edge_components[&e01], edge_components[&e23]);
+        assert_ne!(edge_components[&e23], edge_components[&e34]);
+        assert_ne!(edge_components[&e34], edge_components[&e56]);
+        for (i, component) in components.iter().enumerate() {
+            for e in component {
+                assert_eq!(edge_components[e], i);
+            }
+        }
+    }
 }
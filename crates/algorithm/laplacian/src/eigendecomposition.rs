@@ -0,0 +1,115 @@
+use crate::laplacian::LaplacianStructure;
+use ndarray::prelude::*;
+
+fn orthogonalize(x: &mut Array1<f32>, basis: &[Array1<f32>]) {
+    for b in basis {
+        let c = x.dot(b);
+        x.scaled_add(-c, b);
+    }
+}
+
+fn normalize(x: &mut Array1<f32>) {
+    let norm = x.dot(x).sqrt();
+    if norm > 0. {
+        *x /= norm;
+    }
+}
+
+pub struct Convergence {
+    pub eigenvalues: Array1<f32>,
+    pub residuals: Array1<f32>,
+    pub iterations: Vec<usize>,
+}
+
+impl Convergence {
+    pub fn converged(&self, eps: f32) -> bool {
+        self.residuals.iter().all(|&r| r < eps)
+    }
+}
+
+fn smallest_eigenvectors(
+    laplacian: &LaplacianStructure,
+    k: usize,
+    normalized: bool,
+    iterations: usize,
+    eps: f32,
+) -> (Array2<f32>, Convergence) {
+    let n = laplacian.len();
+    let dot = |x: &Array1<f32>| {
+        if normalized {
+            laplacian.normalized_dot(x)
+        } else {
+            laplacian.dot(x)
+        }
+    };
+    let c = if normalized {
+        2.
+    } else {
+        2. * laplacian.degree().fold(0., |m: f32, &d| m.max(d))
+    };
+
+    let mut trivial = if normalized {
+        laplacian.degree().mapv(|d| d.sqrt())
+    } else {
+        Array1::ones(n)
+    };
+    normalize(&mut trivial);
+    let mut basis = vec![trivial];
+    let mut e = Array1::zeros(k);
+    let mut r = Array1::zeros(k);
+    let mut iterations_used = vec![0; k];
+    let mut v = Array2::zeros((n, k));
+    for j in 0..k {
+        let mut x = Array1::from_shape_fn(n, |i| ((i * (j + 2)) as f32).cos());
+        orthogonalize(&mut x, &basis);
+        normalize(&mut x);
+        for _ in 0..iterations {
+            let lx = dot(&x);
+            let residual = &lx - &(&x * x.dot(&lx));
+            if residual.dot(&residual).sqrt() < eps {
+                break;
+            }
+            x = &x * c - lx;
+            orthogonalize(&mut x, &basis);
+            normalize(&mut x);
+            iterations_used[j] += 1;
+        }
+        let lx = dot(&x);
+        e[j] = x.dot(&lx);
+        let residual = &lx - &(&x * e[j]);
+        r[j] = residual.dot(&residual).sqrt();
+        v.slice_mut(s![.., j]).assign(&x);
+        basis.push(x);
+    }
+    (
+        v,
+        Convergence {
+            eigenvalues: e,
+            residuals: r,
+            iterations: iterations_used,
+        },
+    )
+}
+
+/// Embeds the nodes with the `d` smallest non-trivial eigenvectors of the
+/// Laplacian. With `normalized`, the eigenvectors of the normalized Laplacian
+/// are rescaled by the inverse square root of the degrees.
+pub fn spectral_embedding(
+    laplacian: &LaplacianStructure,
+    d: usize,
+    normalized: bool,
+    iterations: usize,
+    eps: f32,
+) -> (Array2<f32>, Convergence) {
+    let (mut v, convergence) = smallest_eigenvectors(laplacian, d, normalized, iterations, eps);
+    if normalized {
+        let degree = laplacian.degree();
+        for i in 0..v.nrows() {
+            if degree[i] > 0. {
+                let s = degree[i].sqrt();
+                v.row_mut(i).mapv_inplace(|x| x / s);
+            }
+        }
+    }
+    (v, convergence)
+}
@@ -0,0 +1,92 @@
+use ndarray::prelude::*;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub struct LaplacianStructure {
+    neighbors: Vec<Vec<(usize, f32)>>,
+    degree: Array1<f32>,
+}
+
+impl LaplacianStructure {
+    pub fn new<G>(graph: G) -> LaplacianStructure
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers,
+        G::NodeId: Eq + Hash,
+    {
+        LaplacianStructure::new_with_weight(graph, |_| 1.)
+    }
+
+    /// Builds the weighted Laplacian. Self loops and edges whose weight is not
+    /// a positive finite number are skipped.
+    pub fn new_with_weight<G, F>(graph: G, mut weight: F) -> LaplacianStructure
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers,
+        G::NodeId: Eq + Hash,
+        F: FnMut(G::EdgeRef) -> f32,
+    {
+        let indices = graph
+            .node_identifiers()
+            .enumerate()
+            .map(|(i, u)| (u, i))
+            .collect::<HashMap<_, _>>();
+        let n = indices.len();
+        let mut neighbors = vec![vec![]; n];
+        let mut degree = Array1::zeros(n);
+        for e in graph.edge_references() {
+            let u = indices[&e.source()];
+            let v = indices[&e.target()];
+            if u == v {
+                continue;
+            }
+            let w = weight(e);
+            if !(w > 0. && w.is_finite()) {
+                continue;
+            }
+            neighbors[u].push((v, w));
+            neighbors[v].push((u, w));
+            degree[u] += w;
+            degree[v] += w;
+        }
+        LaplacianStructure { neighbors, degree }
+    }
+
+    pub fn len(&self) -> usize {
+        self.degree.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.degree.is_empty()
+    }
+
+    pub fn neighbors(&self, i: usize) -> &[(usize, f32)] {
+        &self.neighbors[i]
+    }
+
+    pub fn degree(&self) -> &Array1<f32> {
+        &self.degree
+    }
+
+    pub fn dot(&self, x: &Array1<f32>) -> Array1<f32> {
+        let mut y = &self.degree * x;
+        for (i, neighbors) in self.neighbors.iter().enumerate() {
+            for &(j, w) in neighbors {
+                y[i] -= w * x[j];
+            }
+        }
+        y
+    }
+
+    pub fn normalized_dot(&self, x: &Array1<f32>) -> Array1<f32> {
+        let d = self
+            .degree
+            .mapv(|d| if d > 0. { 1. / d.sqrt() } else { 0. });
+        let mut y = x.clone();
+        for (i, neighbors) in self.neighbors.iter().enumerate() {
+            for &(j, w) in neighbors {
+                y[i] -= d[i] * w * d[j] * x[j];
+            }
+        }
+        y
+    }
+}
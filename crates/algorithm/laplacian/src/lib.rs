@@ -0,0 +1,20 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_algorithm_laplacian::{spectral_embedding, LaplacianStructure};
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 0..4 {
+//!     graph.add_edge(nodes[i], nodes[(i + 1) % 4], ());
+//! }
+//! let laplacian = LaplacianStructure::new(&graph);
+//! let (embedding, convergence) = spectral_embedding(&laplacian, 2, false, 1000, 1e-4);
+//! assert_eq!(embedding.dim(), (4, 2));
+//! assert!(convergence.converged(1e-4));
+//! ```
+
+mod eigendecomposition;
+mod laplacian;
+
+pub use eigendecomposition::{spectral_embedding, Convergence};
+pub use laplacian::LaplacianStructure;
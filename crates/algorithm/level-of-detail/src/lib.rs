@@ -0,0 +1,203 @@
+use ordered_float::OrderedFloat;
+use petgraph::visit::{EdgeRef, IntoEdgesDirected, IntoNeighbors, IntoNodeIdentifiers};
+use petgraph::Direction::{Incoming, Outgoing};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+pub fn core_numbers<G>(graph: G) -> HashMap<G::NodeId, usize>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let nodes = graph.node_identifiers().collect::<Vec<_>>();
+    let indices = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &u)| (u, i))
+        .collect::<HashMap<_, _>>();
+    let neighbors = nodes
+        .iter()
+        .map(|&u| {
+            graph
+                .neighbors(u)
+                .filter(|&v| v != u)
+                .map(|v| indices[&v])
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut degree = neighbors.iter().map(|ns| ns.len()).collect::<Vec<_>>();
+    let mut removed = vec![false; nodes.len()];
+    let mut core = vec![0; nodes.len()];
+    let mut queue = (0..nodes.len())
+        .map(|i| Reverse((degree[i], i)))
+        .collect::<BinaryHeap<_>>();
+    let mut k = 0;
+    while let Some(Reverse((d, i))) = queue.pop() {
+        if removed[i] || d != degree[i] {
+            continue;
+        }
+        removed[i] = true;
+        k = k.max(d);
+        core[i] = k;
+        for &j in neighbors[i].iter() {
+            if !removed[j] {
+                degree[j] -= 1;
+                queue.push(Reverse((degree[j], j)));
+            }
+        }
+    }
+    nodes.into_iter().zip(core).collect()
+}
+
+pub struct LevelOfDetail<N, E> {
+    pub node_ranks: HashMap<N, usize>,
+    pub edge_ranks: HashMap<E, usize>,
+}
+
+pub fn level_of_detail<G, NF, EF>(
+    graph: G,
+    mut node_score: NF,
+    mut edge_weight: EF,
+) -> LevelOfDetail<G::NodeId, G::EdgeId>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+    G::EdgeId: Eq + Hash,
+    NF: FnMut(G::NodeId) -> f32,
+    EF: FnMut(G::EdgeRef) -> f32,
+{
+    let nodes = graph.node_identifiers().collect::<Vec<_>>();
+    let indices = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, &u)| (u, i))
+        .collect::<HashMap<_, _>>();
+    let score = nodes
+        .iter()
+        .map(|&u| OrderedFloat(node_score(u)))
+        .collect::<Vec<_>>();
+    let mut order = (0..nodes.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| (Reverse(score[i]), i));
+
+    let mut node_ranks = vec![None; nodes.len()];
+    let mut rank = 0;
+    let mut frontier = BinaryHeap::new();
+    for &s in order.iter() {
+        if node_ranks[s].is_some() {
+            continue;
+        }
+        frontier.push((score[s], Reverse(s)));
+        while let Some((_, Reverse(i))) = frontier.pop() {
+            if node_ranks[i].is_some() {
+                continue;
+            }
+            node_ranks[i] = Some(rank);
+            rank += 1;
+            let out_neighbors = graph.edges_directed(nodes[i], Outgoing).map(|e| e.target());
+            let in_neighbors = graph.edges_directed(nodes[i], Incoming).map(|e| e.source());
+            for v in out_neighbors.chain(in_neighbors) {
+                let j = indices[&v];
+                if node_ranks[j].is_none() {
+                    frontier.push((score[j], Reverse(j)));
+                }
+            }
+        }
+    }
+    let node_ranks = node_ranks
+        .into_iter()
+        .map(Option::unwrap)
+        .collect::<Vec<_>>();
+
+    let mut edges = vec![];
+    for &u in nodes.iter() {
+        for e in graph.edges_directed(u, Outgoing) {
+            let ri = node_ranks[indices[&e.source()]];
+            let rj = node_ranks[indices[&e.target()]];
+            let key = (
+                ri.max(rj),
+                Reverse(OrderedFloat(edge_weight(e))),
+                ri.min(rj),
+            );
+            edges.push((key, e.id()));
+        }
+    }
+    edges.sort_by_key(|&(key, _)| key);
+    let mut edge_ranks = HashMap::new();
+    for (_, e) in edges {
+        let r = edge_ranks.len();
+        edge_ranks.entry(e).or_insert(r);
+    }
+
+    LevelOfDetail {
+        node_ranks: nodes.into_iter().zip(node_ranks).collect(),
+        edge_ranks,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::prelude::*;
+    use petgraph::unionfind::UnionFind;
+
+    #[test]
+    fn test_level_of_detail() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..8).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..4 {
+            for j in 0..i {
+                graph.add_edge(nodes[j], nodes[i], 1.);
+            }
+        }
+        graph.add_edge(nodes[3], nodes[4], 2.);
+        graph.add_edge(nodes[4], nodes[5], 1.);
+        graph.add_edge(nodes[5], nodes[6], 1.);
+        graph.add_edge(nodes[0], nodes[7], 1.);
+
+        let core = core_numbers(&graph);
+        assert_eq!(core[&nodes[0]], 3);
+        assert_eq!(core[&nodes[4]], 1);
+
+        let lod = level_of_detail(&graph, |u| core[&u] as f32, |e| *e.weight());
+        let mut edges = graph.edge_indices().collect::<Vec<_>>();
+        edges.sort_by_key(|e| lod.edge_ranks[e]);
+        for k in 1..=edges.len() {
+            let mut components = UnionFind::new(graph.node_count());
+            let mut present = vec![false; graph.node_count()];
+            for &e in edges[..k].iter() {
+                let (u, v) = graph.edge_endpoints(e).unwrap();
+                components.union(u.index(), v.index());
+                present[u.index()] = true;
+                present[v.index()] = true;
+            }
+            let roots = (0..graph.node_count())
+                .filter(|&i| present[i])
+                .map(|i| components.find(i))
+                .collect::<std::collections::HashSet<_>>();
+            assert_eq!(roots.len(), 1);
+        }
+        let mut order = nodes.clone();
+        order.sort_by_key(|u| lod.node_ranks[u]);
+        assert!(order[..4].iter().all(|u| core[u] == 3));
+    }
+
+    #[test]
+    fn test_level_of_detail_directed() {
+        let mut graph = Graph::new();
+        let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let e1 = graph.add_edge(nodes[1], nodes[0], ());
+        let e2 = graph.add_edge(nodes[2], nodes[1], ());
+        let e3 = graph.add_edge(nodes[3], nodes[0], ());
+
+        let score = [4., 3., 1., 2.];
+        let lod = level_of_detail(&graph, |u| score[u.index()], |_| 1.);
+        for (&u, r) in nodes.iter().zip([0, 1, 3, 2]) {
+            assert_eq!(lod.node_ranks[&u], r);
+        }
+        assert_eq!(lod.edge_ranks.len(), 3);
+        assert_eq!(lod.edge_ranks[&e1], 0);
+        assert_eq!(lod.edge_ranks[&e3], 1);
+        assert_eq!(lod.edge_ranks[&e2], 2);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::distance_matrix::{DistanceMatrix, FullDistanceMatrix};
+use ndarray::prelude::*;
+use std::hash::Hash;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeMetric {
+    Euclidean,
+    Cosine,
+}
+
+fn attribute_distance<S>(x: &[S], y: &[S], metric: AttributeMetric) -> S
+where
+    S: NdFloat,
+{
+    match metric {
+        AttributeMetric::Euclidean => x
+            .iter()
+            .zip(y.iter())
+            .fold(S::zero(), |s, (&a, &b)| s + (a - b) * (a - b))
+            .sqrt(),
+        AttributeMetric::Cosine => {
+            if x == y {
+                return S::zero();
+            }
+            let mut xy = S::zero();
+            let mut xx = S::zero();
+            let mut yy = S::zero();
+            for (&a, &b) in x.iter().zip(y.iter()) {
+                xy += a * b;
+                xx += a * a;
+                yy += b * b;
+            }
+            if xx == S::zero() || yy == S::zero() {
+                S::one()
+            } else {
+                S::one() - xy / (xx * yy).sqrt()
+            }
+        }
+    }
+}
+
+pub fn blend_attribute_distance<N, S, F>(
+    d: &FullDistanceMatrix<N, S>,
+    mut features: F,
+    metric: AttributeMetric,
+    alpha: S,
+) -> FullDistanceMatrix<N, S>
+where
+    N: Copy + Eq + Hash,
+    S: NdFloat,
+    F: FnMut(N) -> Vec<S>,
+{
+    let n = d.shape().0;
+    let x = d.row_indices().map(&mut features).collect::<Vec<_>>();
+    let mut a = Array2::zeros((n, n));
+    let mut a_max = S::zero();
+    let mut d_max = S::zero();
+    for i in 0..n {
+        for j in 0..n {
+            a[[i, j]] = attribute_distance(&x[i], &x[j], metric);
+            a_max = a_max.max(a[[i, j]]);
+            let dij = d.get_by_index(i, j);
+            if dij.is_finite() {
+                d_max = d_max.max(dij);
+            }
+        }
+    }
+    let scale = if a_max > S::zero() {
+        d_max / a_max
+    } else {
+        S::zero()
+    };
+
+    let mut result = FullDistanceMatrix::from_indices(d.row_indices().collect());
+    for i in 0..n {
+        for j in 0..n {
+            let dij = d.get_by_index(i, j);
+            let dij = if dij.is_finite() { dij } else { d_max };
+            result.set_by_index(i, j, (S::one() - alpha) * dij + alpha * scale * a[[i, j]]);
+        }
+    }
+    result
+}
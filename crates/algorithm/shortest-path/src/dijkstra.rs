@@ -1,9 +1,16 @@
 use ndarray::prelude::*;
 use ordered_float::OrderedFloat;
 use petgraph::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers};
-use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    hash::Hash,
+};
 
-use crate::distance_matrix::{DistanceMatrix, FullDistanceMatrix, SubDistanceMatrix};
+use crate::{
+    bfs::all_sources_bfs,
+    distance_matrix::{DistanceMatrix, FullDistanceMatrix, SubDistanceMatrix},
+};
 
 pub fn dijkstra_with_distance_matrix<G, S, F, D>(
     graph: G,
@@ -70,6 +77,23 @@ where
     distance_matrix
 }
 
+pub fn all_sources_shortest_path<G, S, F>(graph: G, length: F) -> FullDistanceMatrix<G::NodeId, S>
+where
+    G: IntoEdges + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash + Ord,
+    F: FnMut(G::EdgeRef) -> S,
+    S: NdFloat,
+{
+    let mut length = length;
+    let mut lengths = graph.edge_references().map(&mut length);
+    let unit_edge_length = lengths.next();
+    if lengths.all(|l| Some(l) == unit_edge_length) {
+        all_sources_bfs(graph, unit_edge_length.unwrap_or(S::one()))
+    } else {
+        all_sources_dijkstra(graph, length)
+    }
+}
+
 pub fn dijkstra<G, S, F>(graph: G, length: F, s: G::NodeId) -> SubDistanceMatrix<G::NodeId, S>
 where
     G: IntoEdges + IntoNodeIdentifiers,
@@ -79,3 +103,50 @@ where
 {
     multi_source_dijkstra(graph, length, &[s])
 }
+
+/// Distances from `source` to the reachable nodes of `targets`. The search
+/// stops as soon as every target is settled.
+pub fn dijkstra_with_targets<G, S, F>(
+    graph: G,
+    source: G::NodeId,
+    targets: &[G::NodeId],
+    length: F,
+) -> HashMap<G::NodeId, S>
+where
+    G: IntoEdges,
+    G::NodeId: Eq + Hash + Ord,
+    F: FnMut(G::EdgeRef) -> S,
+    S: NdFloat,
+{
+    let mut length = length;
+    let targets = targets.iter().copied().collect::<HashSet<_>>();
+    let mut remaining = targets.clone();
+    let mut tentative = HashMap::new();
+    let mut settled = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    queue.push((Reverse(OrderedFloat(S::zero())), source));
+    tentative.insert(source, S::zero());
+    while let Some((Reverse(OrderedFloat(d)), u)) = queue.pop() {
+        if settled.contains_key(&u) {
+            continue;
+        }
+        settled.insert(u, d);
+        remaining.remove(&u);
+        if remaining.is_empty() {
+            break;
+        }
+        for edge in graph.edges(u) {
+            let v = edge.target();
+            if settled.contains_key(&v) {
+                continue;
+            }
+            let e = d + length(edge);
+            if tentative.get(&v).map_or(true, |&dv| e < dv) {
+                tentative.insert(v, e);
+                queue.push((Reverse(OrderedFloat(e)), v));
+            }
+        }
+    }
+    settled.retain(|u, _| targets.contains(u));
+    settled
+}
@@ -3,6 +3,9 @@ use petgraph::visit::IntoNodeIdentifiers;
 use std::{collections::HashMap, hash::Hash};
 
 pub trait DistanceMatrix<N, S> {
+    /// The same kind of matrix keyed by `M`, as returned by `map_indices`.
+    type Mapped<M>;
+
     fn get(&self, u: N, v: N) -> Option<S>;
 
     fn set(&mut self, u: N, v: N, d: S) -> Option<()>;
@@ -20,6 +23,20 @@ pub trait DistanceMatrix<N, S> {
     fn row_indices(&self) -> IndexIterator<N>;
 
     fn col_indices(&self) -> IndexIterator<N>;
+
+    /// Restricts the matrix to the rows and columns of `nodes`, in that
+    /// order. Nodes that are not in the matrix are skipped.
+    fn submatrix(&self, nodes: &[N]) -> Self
+    where
+        Self: Sized,
+        N: Copy;
+
+    /// Renames every node with `f`, keeping the distances.
+    fn map_indices<M, F>(self, f: F) -> Self::Mapped<M>
+    where
+        Self: Sized,
+        M: Eq + Hash + Copy,
+        F: FnMut(N) -> M;
 }
 
 pub struct IndexIterator<'a, N> {
@@ -54,6 +71,8 @@ where
     N: Eq + Hash,
     S: NdFloat,
 {
+    type Mapped<M> = FullDistanceMatrix<M, S>;
+
     fn get(&self, u: N, v: N) -> Option<S> {
         self.index(u, v).map(|(i, j)| self.d[[i, j]])
     }
@@ -95,6 +114,34 @@ where
             index: 0,
         }
     }
+
+    fn submatrix(&self, nodes: &[N]) -> Self
+    where
+        N: Copy,
+    {
+        let (indices, positions): (Vec<_>, Vec<_>) = nodes
+            .iter()
+            .filter_map(|&u| self.index_map.get(&u).map(|&i| (u, i)))
+            .unzip();
+        let mut matrix = Self::from_indices(indices);
+        for (i, &k) in positions.iter().enumerate() {
+            for (j, &l) in positions.iter().enumerate() {
+                matrix.d[[i, j]] = self.d[[k, l]];
+            }
+        }
+        matrix
+    }
+
+    fn map_indices<M, F>(self, f: F) -> FullDistanceMatrix<M, S>
+    where
+        M: Eq + Hash + Copy,
+        F: FnMut(N) -> M,
+    {
+        let indices = self.indices.into_iter().map(f).collect::<Vec<_>>();
+        let mut matrix = FullDistanceMatrix::from_indices(indices);
+        matrix.d = self.d;
+        matrix
+    }
 }
 
 impl<N, S> FullDistanceMatrix<N, S>
@@ -112,6 +159,13 @@ where
             .node_identifiers()
             .map(|u| u.into())
             .collect::<Vec<_>>();
+        Self::from_indices(indices)
+    }
+
+    pub fn from_indices(indices: Vec<N>) -> Self
+    where
+        N: Copy,
+    {
         let mut index_map = HashMap::new();
         for (i, &u) in indices.iter().enumerate() {
             index_map.insert(u, i);
@@ -132,6 +186,54 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct FullDistanceMatrixData<N, S> {
+    indices: Vec<N>,
+    distances: Vec<Option<S>>,
+}
+
+#[cfg(feature = "serde")]
+impl<N, S> serde::Serialize for FullDistanceMatrix<N, S>
+where
+    N: Eq + Hash + Copy + serde::Serialize,
+    S: NdFloat + serde::Serialize,
+{
+    fn serialize<Se: serde::Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        FullDistanceMatrixData {
+            indices: self.indices.clone(),
+            distances: self
+                .d
+                .iter()
+                .map(|&d| if d.is_finite() { Some(d) } else { None })
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, N, S> serde::Deserialize<'de> for FullDistanceMatrix<N, S>
+where
+    N: Eq + Hash + Copy + serde::Deserialize<'de>,
+    S: NdFloat + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = FullDistanceMatrixData::<N, S>::deserialize(deserializer)?;
+        let n = data.indices.len();
+        if data.distances.len() != n * n {
+            return Err(serde::de::Error::custom(
+                "distances must have indices.len() squared entries",
+            ));
+        }
+        let mut matrix = FullDistanceMatrix::from_indices(data.indices);
+        for (d, value) in matrix.d.iter_mut().zip(data.distances) {
+            *d = value.unwrap_or_else(S::infinity);
+        }
+        Ok(matrix)
+    }
+}
+
 pub struct SubDistanceMatrix<N, S> {
     row_indices: Vec<N>,
     row_index_map: HashMap<N, usize>,
@@ -145,6 +247,8 @@ where
     N: Eq + Hash,
     S: NdFloat,
 {
+    type Mapped<M> = SubDistanceMatrix<M, S>;
+
     fn get(&self, u: N, v: N) -> Option<S> {
         self.index(u, v).map(|(i, j)| self.d[[i, j]])
     }
@@ -186,6 +290,23 @@ where
             index: 0,
         }
     }
+
+    fn submatrix(&self, nodes: &[N]) -> Self
+    where
+        N: Copy,
+    {
+        self.select(nodes, nodes)
+    }
+
+    fn map_indices<M, F>(self, mut f: F) -> SubDistanceMatrix<M, S>
+    where
+        M: Eq + Hash + Copy,
+        F: FnMut(N) -> M,
+    {
+        let row_indices = self.row_indices.into_iter().map(&mut f).collect();
+        let col_indices = self.col_indices.into_iter().map(&mut f).collect();
+        SubDistanceMatrix::from_parts(row_indices, col_indices, self.d)
+    }
 }
 
 impl<N, S> SubDistanceMatrix<N, S>
@@ -245,6 +366,49 @@ where
             .ok();
     }
 
+    /// Copies the entries between `rows` and `cols`. Nodes that are not in
+    /// the matrix are skipped.
+    pub fn select(&self, rows: &[N], cols: &[N]) -> Self
+    where
+        N: Copy,
+    {
+        let (row_indices, row_positions): (Vec<_>, Vec<_>) = rows
+            .iter()
+            .filter_map(|&u| self.row_index_map.get(&u).map(|&i| (u, i)))
+            .unzip();
+        let (col_indices, col_positions): (Vec<_>, Vec<_>) = cols
+            .iter()
+            .filter_map(|&u| self.col_index_map.get(&u).map(|&i| (u, i)))
+            .unzip();
+        let d = Array2::from_shape_fn((row_indices.len(), col_indices.len()), |(i, j)| {
+            self.d[[row_positions[i], col_positions[j]]]
+        });
+        Self::from_parts(row_indices, col_indices, d)
+    }
+
+    fn from_parts(row_indices: Vec<N>, col_indices: Vec<N>, d: Array2<S>) -> Self
+    where
+        N: Copy,
+    {
+        let row_index_map = row_indices
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i))
+            .collect();
+        let col_index_map = col_indices
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i))
+            .collect();
+        Self {
+            row_indices,
+            row_index_map,
+            col_indices,
+            col_index_map,
+            d,
+        }
+    }
+
     fn index(&self, u: N, v: N) -> Option<(usize, usize)> {
         self.row_index_map
             .get(&u)
@@ -0,0 +1,176 @@
+use crate::{
+    dijkstra::multi_source_dijkstra,
+    distance_matrix::{DistanceMatrix, IndexIterator, SubDistanceMatrix},
+};
+use ndarray::prelude::*;
+use petgraph::visit::{GraphProp, IntoEdges, IntoNodeIdentifiers};
+use std::{collections::HashMap, hash::Hash};
+
+pub struct LandmarkDistanceMatrix<N, S> {
+    landmarks: SubDistanceMatrix<N, S>,
+    landmark_rows: HashMap<usize, usize>,
+    overrides: HashMap<(usize, usize), S>,
+}
+
+impl<N, S> LandmarkDistanceMatrix<N, S>
+where
+    N: Eq + Hash + Copy,
+    S: NdFloat,
+{
+    /// Runs Dijkstra from each landmark and estimates every other distance
+    /// through the landmarks.
+    ///
+    /// As with `SubDistanceMatrix::new`, node ids are stored as `N` through
+    /// `G::NodeId: Into<N>`. This lets the matrix be keyed by a type other
+    /// than the graph's own node id. Usually `N` is simply `G::NodeId`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `graph` is directed. The estimates read a landmark row in
+    /// both directions, which only holds for symmetric distances.
+    pub fn new<G, F>(graph: G, length: F, landmarks: &[G::NodeId]) -> Self
+    where
+        G: IntoEdges + IntoNodeIdentifiers + GraphProp,
+        G::NodeId: Eq + Hash + Ord + Into<N>,
+        F: FnMut(G::EdgeRef) -> S,
+    {
+        assert!(
+            !graph.is_directed(),
+            "LandmarkDistanceMatrix requires an undirected graph"
+        );
+        let d = multi_source_dijkstra(graph, length, landmarks);
+        let mut landmarks = SubDistanceMatrix::empty(graph);
+        for u in d.row_indices() {
+            landmarks.push(u.into());
+        }
+        let (h, n) = d.shape();
+        for k in 0..h {
+            for j in 0..n {
+                landmarks.set_by_index(k, j, d.get_by_index(k, j));
+            }
+        }
+        Self::from_sub_distance_matrix(landmarks)
+    }
+
+    /// Wraps precomputed landmark rows. The distances must be symmetric.
+    pub fn from_sub_distance_matrix(landmarks: SubDistanceMatrix<N, S>) -> Self {
+        let landmark_rows = landmarks
+            .row_indices()
+            .enumerate()
+            .filter_map(|(k, u)| landmarks.col_index(u).map(|j| (j, k)))
+            .collect();
+        Self {
+            landmarks,
+            landmark_rows,
+            overrides: HashMap::new(),
+        }
+    }
+
+    pub fn landmarks(&self) -> &SubDistanceMatrix<N, S> {
+        &self.landmarks
+    }
+
+    pub fn lower_bound_by_index(&self, i: usize, j: usize) -> S {
+        let h = self.landmarks.shape().0;
+        (0..h).fold(S::zero(), |d, k| {
+            let dki = self.landmarks.get_by_index(k, i);
+            let dkj = self.landmarks.get_by_index(k, j);
+            if dki.is_finite() && dkj.is_finite() {
+                d.max((dki - dkj).abs())
+            } else {
+                d
+            }
+        })
+    }
+}
+
+impl<N, S> DistanceMatrix<N, S> for LandmarkDistanceMatrix<N, S>
+where
+    N: Eq + Hash + Copy,
+    S: NdFloat,
+{
+    type Mapped<M> = LandmarkDistanceMatrix<M, S>;
+
+    fn get(&self, u: N, v: N) -> Option<S> {
+        let i = self.landmarks.col_index(u)?;
+        let j = self.landmarks.col_index(v)?;
+        Some(self.get_by_index(i, j))
+    }
+
+    fn set(&mut self, u: N, v: N, d: S) -> Option<()> {
+        let i = self.landmarks.col_index(u)?;
+        let j = self.landmarks.col_index(v)?;
+        self.set_by_index(i, j, d);
+        Some(())
+    }
+
+    fn get_by_index(&self, i: usize, j: usize) -> S {
+        if i == j {
+            return S::zero();
+        }
+        if let Some(&d) = self.overrides.get(&(i, j)) {
+            return d;
+        }
+        if let Some(&k) = self.landmark_rows.get(&i) {
+            return self.landmarks.get_by_index(k, j);
+        }
+        if let Some(&k) = self.landmark_rows.get(&j) {
+            return self.landmarks.get_by_index(k, i);
+        }
+        let h = self.landmarks.shape().0;
+        (0..h).fold(S::infinity(), |d, k| {
+            d.min(self.landmarks.get_by_index(k, i) + self.landmarks.get_by_index(k, j))
+        })
+    }
+
+    fn set_by_index(&mut self, i: usize, j: usize, d: S) {
+        self.overrides.insert((i, j), d);
+    }
+
+    fn shape(&self) -> (usize, usize) {
+        let n = self.landmarks.shape().1;
+        (n, n)
+    }
+
+    fn row_index(&self, u: N) -> Option<usize> {
+        self.landmarks.col_index(u)
+    }
+
+    fn col_index(&self, u: N) -> Option<usize> {
+        self.landmarks.col_index(u)
+    }
+
+    fn row_indices(&self) -> IndexIterator<'_, N> {
+        self.landmarks.col_indices()
+    }
+
+    fn col_indices(&self) -> IndexIterator<'_, N> {
+        self.landmarks.col_indices()
+    }
+
+    /// Keeps every landmark row, so the estimates between the remaining
+    /// nodes are unchanged.
+    fn submatrix(&self, nodes: &[N]) -> Self {
+        let rows = self.landmarks.row_indices().collect::<Vec<_>>();
+        let mut matrix = Self::from_sub_distance_matrix(self.landmarks.select(&rows, nodes));
+        let cols = self.landmarks.col_indices().collect::<Vec<_>>();
+        for (&(i, j), &d) in self.overrides.iter() {
+            if let (Some(k), Some(l)) = (matrix.col_index(cols[i]), matrix.col_index(cols[j])) {
+                matrix.overrides.insert((k, l), d);
+            }
+        }
+        matrix
+    }
+
+    fn map_indices<M, F>(self, f: F) -> LandmarkDistanceMatrix<M, S>
+    where
+        M: Eq + Hash + Copy,
+        F: FnMut(N) -> M,
+    {
+        LandmarkDistanceMatrix {
+            landmarks: self.landmarks.map_indices(f),
+            landmark_rows: self.landmark_rows,
+            overrides: self.overrides,
+        }
+    }
+}
@@ -1,9 +1,27 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_algorithm_shortest_path::{all_sources_bfs, warshall_floyd, DistanceMatrix};
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 1..4 {
+//!     graph.add_edge(nodes[i - 1], nodes[i], ());
+//! }
+//! let d = all_sources_bfs(&graph, 1.);
+//! assert_eq!(d.get(nodes[0], nodes[3]), Some(3.));
+//! let d = warshall_floyd(&graph, &mut |_| 2.);
+//! assert_eq!(d.get(nodes[1], nodes[3]), Some(4.));
+//! ```
+mod attribute_distance;
 mod bfs;
 mod dijkstra;
 mod distance_matrix;
+mod landmark_distance_matrix;
 mod warshall_floyd;
 
+pub use attribute_distance::*;
 pub use bfs::*;
 pub use dijkstra::*;
 pub use distance_matrix::*;
+pub use landmark_distance_matrix::*;
 pub use warshall_floyd::*;
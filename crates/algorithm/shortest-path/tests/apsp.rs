@@ -33,7 +33,108 @@ fn test_all_sources_dijkstra() {
     run(|graph| all_sources_dijkstra(graph, &mut |_| 1.));
 }
 
+#[test]
+fn test_all_sources_shortest_path() {
+    run(|graph| all_sources_shortest_path(graph, &mut |_| 1.));
+}
+
 #[test]
 fn test_warshall_floyd() {
     run(|graph| warshall_floyd(graph, &mut |_| 1.));
 }
+
+#[test]
+fn test_blend_attribute_distance() {
+    let graph: UnGraph<(), ()> = dataset_1138_bus();
+    let d = all_sources_bfs(&graph, 1.);
+    let blended = blend_attribute_distance(
+        &d,
+        |u| vec![(u.index() % 7) as f32, (u.index() % 3) as f32],
+        AttributeMetric::Euclidean,
+        0.,
+    );
+    for u in graph.node_indices() {
+        for v in graph.node_indices() {
+            assert_eq!(blended.get(u, v), d.get(u, v));
+        }
+    }
+    let blended = blend_attribute_distance(&d, |_| vec![1., 1.], AttributeMetric::Cosine, 1.);
+    for u in graph.node_indices() {
+        for v in graph.node_indices() {
+            assert!(blended.get(u, v).unwrap().abs() < 1e-3);
+        }
+    }
+}
+
+#[test]
+fn test_blend_attribute_distance_cosine_zero_vector() {
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..3).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    graph.add_edge(nodes[0], nodes[1], ());
+    graph.add_edge(nodes[1], nodes[2], ());
+    let d = all_sources_bfs(&graph, 1.);
+    let blended = blend_attribute_distance(
+        &d,
+        |u| {
+            if u == nodes[2] {
+                vec![1., 0.]
+            } else {
+                vec![0., 0.]
+            }
+        },
+        AttributeMetric::Cosine,
+        1.,
+    );
+    for &u in &nodes {
+        assert_eq!(blended.get(u, u), Some(0.));
+    }
+    assert_eq!(blended.get(nodes[0], nodes[1]), Some(0.));
+    assert_eq!(blended.get(nodes[0], nodes[2]), Some(2.));
+}
+
+#[test]
+fn test_landmark_distance_matrix() {
+    let graph: UnGraph<(), ()> = dataset_1138_bus();
+    let landmarks = graph.node_indices().step_by(100).collect::<Vec<_>>();
+    let d = LandmarkDistanceMatrix::<NodeIndex, f32>::new(&graph, |_| 1., &landmarks);
+    let expected = all_sources_bfs(&graph, 1.);
+    assert_eq!(d.shape(), expected.shape());
+    for u in graph.node_indices().step_by(7) {
+        for v in graph.node_indices().step_by(11) {
+            let i = d.row_index(u).unwrap();
+            let j = d.col_index(v).unwrap();
+            let exact = expected.get(u, v).unwrap();
+            assert!(d.lower_bound_by_index(i, j) <= exact);
+            assert!(exact <= d.get(u, v).unwrap());
+        }
+    }
+    for &l in &landmarks {
+        for v in graph.node_indices() {
+            assert_eq!(d.get(l, v), expected.get(l, v));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_landmark_distance_matrix_directed() {
+    let mut graph = DiGraph::<(), ()>::new();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_edge(a, b, ());
+    LandmarkDistanceMatrix::<NodeIndex, f32>::new(&graph, |_| 1., &[a]);
+}
+
+#[test]
+fn test_dijkstra_with_targets() {
+    let graph: UnGraph<(), ()> = dataset_1138_bus();
+    let source = NodeIndex::new(0);
+    let targets = [NodeIndex::new(10), NodeIndex::new(500)];
+    let expected = petgraph::algo::dijkstra(&graph, source, None, |_| 1.);
+    let actual = dijkstra_with_targets(&graph, source, &targets, |_| 1f32);
+    assert_eq!(actual.len(), targets.len());
+    assert!(actual.keys().all(|u| targets.contains(u)));
+    for &t in targets.iter() {
+        assert_eq!(actual[&t], expected[&t]);
+    }
+}
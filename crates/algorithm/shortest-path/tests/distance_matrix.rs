@@ -0,0 +1,71 @@
+use petgraph::prelude::*;
+use petgraph_algorithm_shortest_path::*;
+
+fn path_graph(n: usize) -> UnGraph<(), ()> {
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for i in 1..n {
+        graph.add_edge(nodes[i - 1], nodes[i], ());
+    }
+    graph
+}
+
+#[test]
+fn test_full_distance_matrix_submatrix() {
+    let graph = path_graph(6);
+    let d = all_sources_bfs(&graph, 1.);
+    let nodes = [NodeIndex::new(4), NodeIndex::new(1), NodeIndex::new(9)];
+    let sub = d.submatrix(&nodes);
+    assert_eq!(sub.shape(), (2, 2));
+    assert_eq!(sub.row_indices().collect::<Vec<_>>(), &nodes[..2]);
+    assert_eq!(sub.get(nodes[0], nodes[1]), Some(3.));
+    assert_eq!(sub.get_by_index(1, 0), 3.);
+    assert_eq!(sub.get(NodeIndex::new(0), nodes[1]), None);
+
+    let renumbered = sub.map_indices(|u| if u == nodes[0] { 0 } else { 1 });
+    assert_eq!(renumbered.get(0, 1), Some(3.));
+}
+
+#[test]
+fn test_sub_distance_matrix_submatrix() {
+    let graph = path_graph(6);
+    let sources = [NodeIndex::new(0), NodeIndex::new(5)];
+    let d = multi_source_dijkstra(&graph, |_| 1., &sources);
+    let cols = [NodeIndex::new(2), NodeIndex::new(3)];
+    let sub = d.select(&sources[1..], &cols);
+    assert_eq!(sub.shape(), (1, 2));
+    assert_eq!(sub.get(sources[1], cols[0]), Some(3.));
+    assert_eq!(sub.get_by_index(0, 1), 2.);
+
+    let renumbered = sub.map_indices(|u| u.index());
+    assert_eq!(renumbered.get(5, 3), Some(2.));
+
+    let sub = d.submatrix(&[cols[0], sources[1]]);
+    assert_eq!(sub.shape(), (1, 2));
+    assert_eq!(sub.row_indices().collect::<Vec<_>>(), &sources[1..]);
+    assert_eq!(sub.get(sources[1], cols[0]), Some(3.));
+}
+
+fn restrict<D>(d: &D, nodes: &[NodeIndex]) -> D
+where
+    D: DistanceMatrix<NodeIndex, f32>,
+{
+    d.submatrix(nodes)
+}
+
+#[test]
+fn test_landmark_distance_matrix_submatrix() {
+    let graph = path_graph(6);
+    let nodes = graph.node_indices().collect::<Vec<_>>();
+    let mut d = LandmarkDistanceMatrix::new(&graph, |_| 1., &[nodes[0], nodes[5]]);
+    d.set(nodes[1], nodes[2], 7.);
+    let sub = restrict(&d, &[nodes[4], nodes[1], nodes[2]]);
+    assert_eq!(sub.shape(), (3, 3));
+    assert_eq!(sub.get(nodes[4], nodes[1]), d.get(nodes[4], nodes[1]));
+    assert_eq!(sub.get(nodes[1], nodes[2]), Some(7.));
+    assert_eq!(sub.get(nodes[0], nodes[1]), None);
+
+    let renumbered = sub.map_indices(|u| u.index());
+    assert_eq!(renumbered.get(4, 1), d.get(nodes[4], nodes[1]));
+    assert_eq!(renumbered.get(1, 2), Some(7.));
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "serde")]
+
+use petgraph::prelude::*;
+use petgraph_algorithm_shortest_path::*;
+
+#[test]
+fn test_full_distance_matrix_serde() {
+    let mut graph = UnGraph::<(), ()>::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    graph.add_node(());
+    graph.add_edge(a, b, ());
+    let expected: FullDistanceMatrix<NodeIndex, f32> = all_sources_bfs(&graph, 1.);
+    let json = serde_json::to_string(&expected).unwrap();
+    let actual: FullDistanceMatrix<NodeIndex, f32> = serde_json::from_str(&json).unwrap();
+    for u in graph.node_indices() {
+        for v in graph.node_indices() {
+            assert_eq!(actual.get(u, v), expected.get(u, v));
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use petgraph::graph::{IndexType, NodeIndex, UnGraph};
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex, DrawingValue};
+use std::{cmp::Ordering, collections::BinaryHeap, collections::HashSet};
+
+#[derive(PartialEq)]
+struct Candidate {
+    distance: f64,
+    index: usize,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+pub struct KdTree {
+    points: Vec<(f64, f64)>,
+    order: Vec<usize>,
+}
+
+fn coordinate((x, y): (f64, f64), axis: usize) -> f64 {
+    if axis == 0 {
+        x
+    } else {
+        y
+    }
+}
+
+impl KdTree {
+    pub fn new(points: &[(f64, f64)]) -> KdTree {
+        let mut order = (0..points.len()).collect::<Vec<_>>();
+        KdTree::build(points, &mut order, 0);
+        KdTree {
+            points: points.to_vec(),
+            order,
+        }
+    }
+
+    fn build(points: &[(f64, f64)], order: &mut [usize], axis: usize) {
+        if order.len() <= 1 {
+            return;
+        }
+        let mid = order.len() / 2;
+        order.select_nth_unstable_by(mid, |&i, &j| {
+            coordinate(points[i], axis).total_cmp(&coordinate(points[j], axis))
+        });
+        let (left, right) = order.split_at_mut(mid);
+        KdTree::build(points, left, 1 - axis);
+        KdTree::build(points, &mut right[1..], 1 - axis);
+    }
+
+    pub fn k_nearest(&self, p: (f64, f64), k: usize) -> Vec<(usize, f64)> {
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            self.search(&self.order, 0, p, k, &mut heap);
+        }
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|c| (c.index, c.distance.sqrt()))
+            .collect()
+    }
+
+    fn search(
+        &self,
+        order: &[usize],
+        axis: usize,
+        p: (f64, f64),
+        k: usize,
+        heap: &mut BinaryHeap<Candidate>,
+    ) {
+        if order.is_empty() {
+            return;
+        }
+        let mid = order.len() / 2;
+        let index = order[mid];
+        let q = self.points[index];
+        let distance = (p.0 - q.0).powi(2) + (p.1 - q.1).powi(2);
+        if heap.len() < k {
+            heap.push(Candidate { distance, index });
+        } else if distance < heap.peek().unwrap().distance {
+            heap.pop();
+            heap.push(Candidate { distance, index });
+        }
+        let diff = coordinate(p, axis) - coordinate(q, axis);
+        let (near, far) = if diff < 0. {
+            (&order[..mid], &order[mid + 1..])
+        } else {
+            (&order[mid + 1..], &order[..mid])
+        };
+        self.search(near, 1 - axis, p, k, heap);
+        if heap.len() < k || diff * diff < heap.peek().unwrap().distance {
+            self.search(far, 1 - axis, p, k, heap);
+        }
+    }
+}
+
+pub fn knn<N, S>(drawing: &DrawingEuclidean2d<N, S>, k: usize) -> Vec<(usize, usize)>
+where
+    N: DrawingIndex,
+    S: DrawingValue,
+{
+    let points = (0..drawing.len())
+        .map(|i| {
+            let p = drawing.raw_entry(i);
+            (p.0.to_f64().unwrap(), p.1.to_f64().unwrap())
+        })
+        .collect::<Vec<_>>();
+    let tree = KdTree::new(&points);
+    let mut edges = HashSet::new();
+    for (i, &p) in points.iter().enumerate() {
+        for (j, _) in tree
+            .k_nearest(p, k + 1)
+            .into_iter()
+            .filter(|&(j, _)| j != i)
+            .take(k)
+        {
+            edges.insert((i.min(j), i.max(j)));
+        }
+    }
+    let mut edges = edges.into_iter().collect::<Vec<_>>();
+    edges.sort();
+    edges
+}
+
+pub fn knn_graph<N, S, Ix>(drawing: &DrawingEuclidean2d<N, S>, k: usize) -> UnGraph<N, (), Ix>
+where
+    N: DrawingIndex + Copy,
+    S: DrawingValue,
+    Ix: IndexType,
+{
+    let mut graph = UnGraph::default();
+    for i in 0..drawing.len() {
+        graph.add_node(*drawing.node_id(i));
+    }
+    for (i, j) in knn(drawing, k) {
+        graph.add_edge(NodeIndex::new(i), NodeIndex::new(j), ());
+    }
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k_nearest() {
+        let points = (0..100)
+            .map(|i| (((i * 37) % 100) as f64, ((i * 61) % 100) as f64 * 0.5))
+            .collect::<Vec<_>>();
+        let tree = KdTree::new(&points);
+        for &p in points.iter().step_by(7) {
+            let mut expected = points
+                .iter()
+                .map(|q| ((p.0 - q.0).powi(2) + (p.1 - q.1).powi(2)).sqrt())
+                .collect::<Vec<_>>();
+            expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let result = tree.k_nearest(p, 5);
+            assert_eq!(result.len(), 5);
+            for (&(_, d), &e) in result.iter().zip(expected.iter()) {
+                assert!((d - e).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_knn_graph() {
+        let mut drawing = DrawingEuclidean2d::<usize, f32>::from_node_indices(&[0, 1, 2, 3]);
+        for (i, x) in [0., 1., 10., 12.].into_iter().enumerate() {
+            drawing.set_x(i, x);
+        }
+        assert_eq!(knn(&drawing, 1), vec![(0, 1), (2, 3)]);
+        let graph = knn_graph::<_, _, u32>(&drawing, 2);
+        assert_eq!(graph.edge_count(), 5);
+    }
+}
@@ -0,0 +1,185 @@
+use petgraph::graph::{IndexType, NodeIndex, UnGraph};
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex, DrawingValue};
+use std::collections::{HashMap, HashSet};
+
+mod kd_tree;
+mod voronoi;
+
+pub use kd_tree::{knn, knn_graph, KdTree};
+pub use voronoi::voronoi_cells;
+
+struct Triangle {
+    vertices: [usize; 3],
+    cx: f64,
+    cy: f64,
+    r2: f64,
+}
+
+impl Triangle {
+    fn new(points: &[(f64, f64)], a: usize, b: usize, c: usize) -> Option<Triangle> {
+        let (ax, ay) = points[a];
+        let (bx, by) = points[b];
+        let (cx, cy) = points[c];
+        let d = 2. * (ax * (by - cy) + bx * (cy - ay) + cx * (ay - by));
+        if d == 0. {
+            return None;
+        }
+        let a2 = ax * ax + ay * ay;
+        let b2 = bx * bx + by * by;
+        let c2 = cx * cx + cy * cy;
+        let ux = (a2 * (by - cy) + b2 * (cy - ay) + c2 * (ay - by)) / d;
+        let uy = (a2 * (cx - bx) + b2 * (ax - cx) + c2 * (bx - ax)) / d;
+        Some(Triangle {
+            vertices: [a, b, c],
+            cx: ux,
+            cy: uy,
+            r2: (ax - ux).powi(2) + (ay - uy).powi(2),
+        })
+    }
+
+    fn in_circumcircle(&self, (x, y): (f64, f64)) -> bool {
+        (x - self.cx).powi(2) + (y - self.cy).powi(2) < self.r2
+    }
+}
+
+pub fn triangulation<N, S>(drawing: &DrawingEuclidean2d<N, S>) -> Vec<(usize, usize)>
+where
+    N: DrawingIndex,
+    S: DrawingValue,
+{
+    let n = drawing.len();
+    let mut points = (0..n)
+        .map(|i| {
+            let p = drawing.raw_entry(i);
+            (p.0.to_f64().unwrap(), p.1.to_f64().unwrap())
+        })
+        .collect::<Vec<_>>();
+
+    let mut edges = HashSet::new();
+    let mut representative = HashMap::new();
+    let mut order = vec![];
+    for (i, &(x, y)) in points.iter().enumerate() {
+        let key = (x.to_bits(), y.to_bits());
+        if let Some(&j) = representative.get(&key) {
+            edges.insert((j, i));
+        } else {
+            representative.insert(key, i);
+            order.push(i);
+        }
+    }
+
+    let (mut x_min, mut x_max, mut y_min, mut y_max) = (
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    );
+    for &(x, y) in points.iter() {
+        x_min = x_min.min(x);
+        x_max = x_max.max(x);
+        y_min = y_min.min(y);
+        y_max = y_max.max(y);
+    }
+    let size = (x_max - x_min).max(y_max - y_min).max(1.);
+    let cx = (x_min + x_max) / 2.;
+    let cy = (y_min + y_max) / 2.;
+    points.push((cx - 20. * size, cy - 10. * size));
+    points.push((cx + 20. * size, cy - 10. * size));
+    points.push((cx, cy + 20. * size));
+
+    let mut triangles = vec![Triangle::new(&points, n, n + 1, n + 2).unwrap()];
+    for &i in order.iter() {
+        let p = points[i];
+        let mut boundary = HashMap::new();
+        triangles.retain(|t| {
+            if t.in_circumcircle(p) {
+                for k in 0..3 {
+                    let a = t.vertices[k];
+                    let b = t.vertices[(k + 1) % 3];
+                    *boundary.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+                }
+                false
+            } else {
+                true
+            }
+        });
+        for ((a, b), count) in boundary {
+            if count == 1 {
+                if let Some(t) = Triangle::new(&points, a, b, i) {
+                    triangles.push(t);
+                }
+            }
+        }
+    }
+
+    for t in triangles.iter() {
+        for k in 0..3 {
+            let a = t.vertices[k];
+            let b = t.vertices[(k + 1) % 3];
+            if a < n && b < n {
+                edges.insert((a.min(b), a.max(b)));
+            }
+        }
+    }
+    if order.len() > 1 && edges.len() < order.len() - 1 + (n - order.len()) {
+        order.sort_by(|&i, &j| {
+            points[i]
+                .0
+                .total_cmp(&points[j].0)
+                .then(points[i].1.total_cmp(&points[j].1))
+        });
+        for k in 1..order.len() {
+            let (a, b) = (order[k - 1], order[k]);
+            edges.insert((a.min(b), a.max(b)));
+        }
+    }
+    let mut edges = edges.into_iter().collect::<Vec<_>>();
+    edges.sort();
+    edges
+}
+
+pub fn triangulation_graph<N, S, Ix>(drawing: &DrawingEuclidean2d<N, S>) -> UnGraph<N, (), Ix>
+where
+    N: DrawingIndex + Copy,
+    S: DrawingValue,
+    Ix: IndexType,
+{
+    let mut graph = UnGraph::default();
+    for i in 0..drawing.len() {
+        graph.add_node(*drawing.node_id(i));
+    }
+    for (i, j) in triangulation(drawing) {
+        graph.add_edge(NodeIndex::new(i), NodeIndex::new(j), ());
+    }
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_triangulation() {
+        let mut graph = Graph::<(), ()>::new();
+        let nodes = (0..5).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut drawing = DrawingEuclidean2d::<_, f32>::new(&graph);
+        for (&u, (x, y)) in nodes
+            .iter()
+            .zip([(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0.5, 0.4)])
+        {
+            drawing.set_x(u, x);
+            drawing.set_y(u, y);
+        }
+        let edges = triangulation(&drawing);
+        assert_eq!(edges.len(), 8);
+        for i in 0..4 {
+            assert!(edges.contains(&(i, 4)));
+        }
+        assert!(!edges.contains(&(0, 2)));
+        assert!(!edges.contains(&(1, 3)));
+
+        let graph = triangulation_graph::<_, _, u32>(&drawing);
+        assert_eq!(graph.edge_count(), 8);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::triangulation;
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex, DrawingValue};
+
+fn clip(polygon: &[(f64, f64)], p: (f64, f64), q: (f64, f64)) -> Vec<(f64, f64)> {
+    let (nx, ny) = (q.0 - p.0, q.1 - p.1);
+    let (mx, my) = ((p.0 + q.0) / 2., (p.1 + q.1) / 2.);
+    let side = |(x, y): (f64, f64)| (x - mx) * nx + (y - my) * ny;
+    let mut result = vec![];
+    for k in 0..polygon.len() {
+        let a = polygon[k];
+        let b = polygon[(k + 1) % polygon.len()];
+        let (sa, sb) = (side(a), side(b));
+        if sa <= 0. {
+            result.push(a);
+        }
+        if (sa < 0. && sb > 0.) || (sa > 0. && sb < 0.) {
+            let t = sa / (sa - sb);
+            result.push((a.0 + t * (b.0 - a.0), a.1 + t * (b.1 - a.1)));
+        }
+    }
+    result
+}
+
+pub fn voronoi_cells<N, S>(
+    drawing: &DrawingEuclidean2d<N, S>,
+    x0: S,
+    y0: S,
+    x1: S,
+    y1: S,
+) -> Vec<Vec<(S, S)>>
+where
+    N: DrawingIndex,
+    S: DrawingValue,
+{
+    let n = drawing.len();
+    let points = (0..n)
+        .map(|i| {
+            let p = drawing.raw_entry(i);
+            (p.0.to_f64().unwrap(), p.1.to_f64().unwrap())
+        })
+        .collect::<Vec<_>>();
+    let (x0, y0, x1, y1) = (
+        x0.to_f64().unwrap(),
+        y0.to_f64().unwrap(),
+        x1.to_f64().unwrap(),
+        y1.to_f64().unwrap(),
+    );
+    let mut neighbors = vec![vec![]; n];
+    for (i, j) in triangulation(drawing) {
+        neighbors[i].push(j);
+        neighbors[j].push(i);
+    }
+    (0..n)
+        .map(|i| {
+            let mut cell = vec![(x0, y0), (x1, y0), (x1, y1), (x0, y1)];
+            for &j in neighbors[i].iter() {
+                if points[i] != points[j] {
+                    cell = clip(&cell, points[i], points[j]);
+                }
+            }
+            cell.into_iter()
+                .map(|(x, y)| (S::from_f64(x).unwrap(), S::from_f64(y).unwrap()))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area(polygon: &[(f32, f32)]) -> f32 {
+        (0..polygon.len())
+            .map(|k| {
+                let (ax, ay) = polygon[k];
+                let (bx, by) = polygon[(k + 1) % polygon.len()];
+                ax * by - bx * ay
+            })
+            .sum::<f32>()
+            / 2.
+    }
+
+    #[test]
+    fn test_voronoi_cells() {
+        let mut drawing = DrawingEuclidean2d::<usize, f32>::from_node_indices(&[0, 1, 2, 3, 4]);
+        for (i, (x, y)) in [(1., 1.), (3., 1.), (3., 3.), (1., 3.), (2., 2.)]
+            .into_iter()
+            .enumerate()
+        {
+            drawing.set_x(i, x);
+            drawing.set_y(i, y);
+        }
+        let cells = voronoi_cells(&drawing, 0., 0., 4., 4.);
+        assert_eq!(cells.len(), 5);
+        let total = cells.iter().map(|cell| area(cell)).sum::<f32>();
+        assert!((total - 16.).abs() < 1e-4);
+        assert!((area(&cells[4]) - 2.).abs() < 1e-4);
+        for cell in cells.iter().take(4) {
+            assert!((area(cell) - 3.5).abs() < 1e-4);
+        }
+    }
+}
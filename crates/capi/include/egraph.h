@@ -0,0 +1,62 @@
+#ifndef EGRAPH_H
+#define EGRAPH_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct EgraphGraph EgraphGraph;
+typedef struct EgraphDrawing EgraphDrawing;
+typedef struct EgraphRng EgraphRng;
+typedef struct EgraphSgd EgraphSgd;
+typedef struct EgraphBundledEdges EgraphBundledEdges;
+
+EgraphGraph *egraph_graph_new(void);
+void egraph_graph_free(EgraphGraph *graph);
+uint32_t egraph_graph_add_node(EgraphGraph *graph);
+uint32_t egraph_graph_add_edge(EgraphGraph *graph, uint32_t u, uint32_t v);
+size_t egraph_graph_node_count(const EgraphGraph *graph);
+size_t egraph_graph_edge_count(const EgraphGraph *graph);
+
+EgraphDrawing *egraph_drawing_initial_placement(const EgraphGraph *graph);
+void egraph_drawing_free(EgraphDrawing *drawing);
+size_t egraph_drawing_len(const EgraphDrawing *drawing);
+float egraph_drawing_x(const EgraphDrawing *drawing, uint32_t u);
+float egraph_drawing_y(const EgraphDrawing *drawing, uint32_t u);
+bool egraph_drawing_set_x(EgraphDrawing *drawing, uint32_t u, float x);
+bool egraph_drawing_set_y(EgraphDrawing *drawing, uint32_t u, float y);
+
+EgraphRng *egraph_rng_seed_from(uint64_t seed);
+void egraph_rng_free(EgraphRng *rng);
+
+EgraphSgd *egraph_full_sgd_new(const EgraphGraph *graph, float edge_length);
+EgraphSgd *egraph_sparse_sgd_new(const EgraphGraph *graph, float edge_length, size_t h,
+                                 EgraphRng *rng);
+void egraph_sgd_free(EgraphSgd *sgd);
+bool egraph_sgd_run(EgraphSgd *sgd, EgraphDrawing *drawing, EgraphRng *rng, size_t t_max,
+                    float epsilon);
+
+EgraphDrawing *egraph_classical_mds(const EgraphGraph *graph, float edge_length);
+EgraphDrawing *egraph_pivot_mds(const EgraphGraph *graph, float edge_length,
+                                const uint32_t *pivots, size_t num_pivots);
+
+bool egraph_stress_majorization_run(const EgraphGraph *graph, EgraphDrawing *drawing,
+                                    float edge_length);
+
+EgraphBundledEdges *egraph_fdeb(const EgraphGraph *graph, const EgraphDrawing *drawing);
+void egraph_bundled_edges_free(EgraphBundledEdges *edges);
+size_t egraph_bundled_edges_len(const EgraphBundledEdges *edges, uint32_t e);
+size_t egraph_bundled_edges_points(const EgraphBundledEdges *edges, uint32_t e, float *out);
+
+float egraph_quality_metric(const EgraphGraph *graph, const EgraphDrawing *drawing,
+                            const char *name, float edge_length);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,525 @@
+//! C binding of egraph.
+//!
+//! All objects are exposed as opaque handles that are created by the
+//! `egraph_*_new` style functions and must be released with the
+//! corresponding `egraph_*_free` function.
+//! Functions accept null handles and report failure with a null pointer,
+//! `NaN` or `false`, depending on the return type.
+//! Drawings must belong to the graph they are passed with, and panics are
+//! caught at the boundary and reported in the same way.
+#![allow(clippy::missing_safety_doc)]
+
+use petgraph::graph::{EdgeIndex, NodeIndex, UnGraph};
+use petgraph_algorithm_shortest_path::all_sources_dijkstra;
+use petgraph_drawing::{Drawing, DrawingEuclidean2d};
+use petgraph_edge_bundling_fdeb::{fdeb, EdgeBundlingOptions};
+use petgraph_layout_mds::{ClassicalMds, PivotMds};
+use petgraph_layout_sgd::{FullSgd, Scheduler, SchedulerExponential, Sgd, SparseSgd};
+use petgraph_layout_stress_majorization::StressMajorization;
+use petgraph_quality_metrics::{quality_metrics_with_targets, QualityMetric};
+use rand::prelude::*;
+use std::{
+    collections::HashMap,
+    ffi::{c_char, CStr},
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr, slice,
+};
+
+pub struct EgraphGraph {
+    graph: UnGraph<(), ()>,
+}
+
+pub struct EgraphDrawing {
+    drawing: DrawingEuclidean2d<NodeIndex, f32>,
+}
+
+pub struct EgraphRng {
+    rng: StdRng,
+}
+
+pub enum EgraphSgd {
+    Full(FullSgd<f32>),
+    Sparse(SparseSgd<f32>),
+}
+
+pub struct EgraphBundledEdges {
+    edges: HashMap<EdgeIndex, Vec<(f32, f32)>>,
+}
+
+unsafe fn free<T>(p: *mut T) {
+    if !p.is_null() {
+        drop(Box::from_raw(p));
+    }
+}
+
+fn guard<T, F>(failure: T, f: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    catch_unwind(AssertUnwindSafe(f)).unwrap_or(failure)
+}
+
+fn matches_graph(graph: &EgraphGraph, drawing: &EgraphDrawing) -> bool {
+    drawing.drawing.len() == graph.graph.node_count()
+}
+
+#[no_mangle]
+pub extern "C" fn egraph_graph_new() -> *mut EgraphGraph {
+    guard(ptr::null_mut(), || {
+        Box::into_raw(Box::new(EgraphGraph {
+            graph: UnGraph::new_undirected(),
+        }))
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_graph_free(graph: *mut EgraphGraph) {
+    guard((), || free(graph))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_graph_add_node(graph: *mut EgraphGraph) -> u32 {
+    guard(u32::MAX, || match graph.as_mut() {
+        Some(graph) => graph.graph.add_node(()).index() as u32,
+        None => u32::MAX,
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_graph_add_edge(graph: *mut EgraphGraph, u: u32, v: u32) -> u32 {
+    guard(u32::MAX, || match graph.as_mut() {
+        Some(graph) => {
+            let n = graph.graph.node_count() as u32;
+            if u >= n || v >= n {
+                return u32::MAX;
+            }
+            graph
+                .graph
+                .add_edge(NodeIndex::new(u as usize), NodeIndex::new(v as usize), ())
+                .index() as u32
+        }
+        None => u32::MAX,
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_graph_node_count(graph: *const EgraphGraph) -> usize {
+    guard(0, || {
+        graph.as_ref().map_or(0, |graph| graph.graph.node_count())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_graph_edge_count(graph: *const EgraphGraph) -> usize {
+    guard(0, || {
+        graph.as_ref().map_or(0, |graph| graph.graph.edge_count())
+    })
+}
+
+fn new_drawing(drawing: DrawingEuclidean2d<NodeIndex, f32>) -> *mut EgraphDrawing {
+    Box::into_raw(Box::new(EgraphDrawing { drawing }))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_drawing_initial_placement(
+    graph: *const EgraphGraph,
+) -> *mut EgraphDrawing {
+    guard(ptr::null_mut(), || match graph.as_ref() {
+        Some(graph) => new_drawing(DrawingEuclidean2d::initial_placement(&graph.graph)),
+        None => ptr::null_mut(),
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_drawing_free(drawing: *mut EgraphDrawing) {
+    guard((), || free(drawing))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_drawing_len(drawing: *const EgraphDrawing) -> usize {
+    guard(0, || {
+        drawing.as_ref().map_or(0, |drawing| drawing.drawing.len())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_drawing_x(drawing: *const EgraphDrawing, u: u32) -> f32 {
+    guard(f32::NAN, || {
+        drawing
+            .as_ref()
+            .and_then(|drawing| drawing.drawing.x(NodeIndex::new(u as usize)))
+            .unwrap_or(f32::NAN)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_drawing_y(drawing: *const EgraphDrawing, u: u32) -> f32 {
+    guard(f32::NAN, || {
+        drawing
+            .as_ref()
+            .and_then(|drawing| drawing.drawing.y(NodeIndex::new(u as usize)))
+            .unwrap_or(f32::NAN)
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_drawing_set_x(drawing: *mut EgraphDrawing, u: u32, x: f32) -> bool {
+    guard(false, || {
+        drawing
+            .as_mut()
+            .and_then(|drawing| drawing.drawing.set_x(NodeIndex::new(u as usize), x))
+            .is_some()
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_drawing_set_y(drawing: *mut EgraphDrawing, u: u32, y: f32) -> bool {
+    guard(false, || {
+        drawing
+            .as_mut()
+            .and_then(|drawing| drawing.drawing.set_y(NodeIndex::new(u as usize), y))
+            .is_some()
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn egraph_rng_seed_from(seed: u64) -> *mut EgraphRng {
+    guard(ptr::null_mut(), || {
+        Box::into_raw(Box::new(EgraphRng {
+            rng: StdRng::seed_from_u64(seed),
+        }))
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_rng_free(rng: *mut EgraphRng) {
+    guard((), || free(rng))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_full_sgd_new(
+    graph: *const EgraphGraph,
+    edge_length: f32,
+) -> *mut EgraphSgd {
+    guard(ptr::null_mut(), || match graph.as_ref() {
+        Some(graph) => Box::into_raw(Box::new(EgraphSgd::Full(FullSgd::new(
+            &graph.graph,
+            |_| edge_length,
+        )))),
+        None => ptr::null_mut(),
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_sparse_sgd_new(
+    graph: *const EgraphGraph,
+    edge_length: f32,
+    h: usize,
+    rng: *mut EgraphRng,
+) -> *mut EgraphSgd {
+    guard(ptr::null_mut(), || match (graph.as_ref(), rng.as_mut()) {
+        (Some(graph), Some(rng)) => Box::into_raw(Box::new(EgraphSgd::Sparse(
+            SparseSgd::new_with_rng(&graph.graph, |_| edge_length, h, &mut rng.rng),
+        ))),
+        _ => ptr::null_mut(),
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_sgd_free(sgd: *mut EgraphSgd) {
+    guard((), || free(sgd))
+}
+
+fn sgd_run<A: Sgd<f32>>(
+    sgd: &mut A,
+    drawing: &mut DrawingEuclidean2d<NodeIndex, f32>,
+    rng: &mut StdRng,
+    t_max: usize,
+    epsilon: f32,
+) -> bool {
+    let n = drawing.len();
+    if sgd.node_pairs().iter().any(|p| p.0 >= n || p.1 >= n) {
+        return false;
+    }
+    let mut scheduler = sgd.scheduler::<SchedulerExponential<f32>>(t_max, epsilon);
+    scheduler.run(&mut |eta| {
+        sgd.shuffle(rng);
+        sgd.apply(drawing, eta);
+    });
+    true
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_sgd_run(
+    sgd: *mut EgraphSgd,
+    drawing: *mut EgraphDrawing,
+    rng: *mut EgraphRng,
+    t_max: usize,
+    epsilon: f32,
+) -> bool {
+    guard(false, || {
+        match (sgd.as_mut(), drawing.as_mut(), rng.as_mut()) {
+            (Some(sgd), Some(drawing), Some(rng)) => match sgd {
+                EgraphSgd::Full(sgd) => {
+                    sgd_run(sgd, &mut drawing.drawing, &mut rng.rng, t_max, epsilon)
+                }
+                EgraphSgd::Sparse(sgd) => {
+                    sgd_run(sgd, &mut drawing.drawing, &mut rng.rng, t_max, epsilon)
+                }
+            },
+            _ => false,
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_classical_mds(
+    graph: *const EgraphGraph,
+    edge_length: f32,
+) -> *mut EgraphDrawing {
+    guard(ptr::null_mut(), || match graph.as_ref() {
+        Some(graph) => new_drawing(ClassicalMds::new(&graph.graph, |_| edge_length).run_2d()),
+        None => ptr::null_mut(),
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_pivot_mds(
+    graph: *const EgraphGraph,
+    edge_length: f32,
+    pivots: *const u32,
+    num_pivots: usize,
+) -> *mut EgraphDrawing {
+    guard(ptr::null_mut(), || {
+        let graph = match graph.as_ref() {
+            Some(graph) => graph,
+            None => return ptr::null_mut(),
+        };
+        if pivots.is_null() || num_pivots == 0 {
+            return ptr::null_mut();
+        }
+        let n = graph.graph.node_count();
+        let sources = slice::from_raw_parts(pivots, num_pivots)
+            .iter()
+            .map(|&u| NodeIndex::new(u as usize))
+            .collect::<Vec<_>>();
+        if sources.iter().any(|u| u.index() >= n) {
+            return ptr::null_mut();
+        }
+        new_drawing(PivotMds::new(&graph.graph, |_| edge_length, &sources).run_2d())
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_stress_majorization_run(
+    graph: *const EgraphGraph,
+    drawing: *mut EgraphDrawing,
+    edge_length: f32,
+) -> bool {
+    guard(false, || match (graph.as_ref(), drawing.as_mut()) {
+        (Some(graph), Some(drawing)) if matches_graph(graph, drawing) => {
+            StressMajorization::new(&graph.graph, &drawing.drawing, |_| edge_length)
+                .run(&mut drawing.drawing);
+            true
+        }
+        _ => false,
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_fdeb(
+    graph: *const EgraphGraph,
+    drawing: *const EgraphDrawing,
+) -> *mut EgraphBundledEdges {
+    guard(ptr::null_mut(), || {
+        match (graph.as_ref(), drawing.as_ref()) {
+            (Some(graph), Some(drawing)) if matches_graph(graph, drawing) => {
+                match fdeb(
+                    &graph.graph,
+                    &drawing.drawing,
+                    &EdgeBundlingOptions::<f32>::new(),
+                ) {
+                    Ok(edges) => Box::into_raw(Box::new(EgraphBundledEdges { edges })),
+                    Err(_) => ptr::null_mut(),
+                }
+            }
+            _ => ptr::null_mut(),
+        }
+    })
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_bundled_edges_free(edges: *mut EgraphBundledEdges) {
+    guard((), || free(edges))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn egraph_bundled_edges_len(
+    edges: *const EgraphBundledEdges,
+    e: u32,
+) -> usize {
+    guard(0, || {
+        edges
+            .as_ref()
+            .and_then(|edges| edges.edges.get(&EdgeIndex::new(e as usize)))
+            .map_or(0, |points| points.len())
+    })
+}
+
+/// Writes the control points of edge `e` to `out` as `x0, y0, x1, y1, ...`.
+/// `out` must have room for `2 * egraph_bundled_edges_len(edges, e)` values.
+#[no_mangle]
+pub unsafe extern "C" fn egraph_bundled_edges_points(
+    edges: *const EgraphBundledEdges,
+    e: u32,
+    out: *mut f32,
+) -> usize {
+    guard(0, || {
+        let points = match edges
+            .as_ref()
+            .and_then(|edges| edges.edges.get(&EdgeIndex::new(e as usize)))
+        {
+            Some(points) => points,
+            None => return 0,
+        };
+        if out.is_null() {
+            return 0;
+        }
+        let out = slice::from_raw_parts_mut(out, 2 * points.len());
+        for (i, &(x, y)) in points.iter().enumerate() {
+            out[2 * i] = x;
+            out[2 * i + 1] = y;
+        }
+        points.len()
+    })
+}
+
+/// Evaluates the quality metric named `name` (e.g. `"stress"` or
+/// `"crossing-number"`) and returns `NaN` for unknown names or when the
+/// metric cannot be computed.
+#[no_mangle]
+pub unsafe extern "C" fn egraph_quality_metric(
+    graph: *const EgraphGraph,
+    drawing: *const EgraphDrawing,
+    name: *const c_char,
+    edge_length: f32,
+) -> f32 {
+    guard(f32::NAN, || {
+        let (graph, drawing) = match (graph.as_ref(), drawing.as_ref()) {
+            (Some(graph), Some(drawing)) if matches_graph(graph, drawing) => (graph, drawing),
+            _ => return f32::NAN,
+        };
+        if name.is_null() {
+            return f32::NAN;
+        }
+        let metric = match CStr::from_ptr(name)
+            .to_str()
+            .ok()
+            .and_then(QualityMetric::from_name)
+        {
+            Some(metric) => metric,
+            None => return f32::NAN,
+        };
+        let d = all_sources_dijkstra(&graph.graph, |_| edge_length);
+        quality_metrics_with_targets(&graph.graph, &drawing.drawing, &d, &[metric])
+            .ok()
+            .and_then(|metrics| metrics.first().map(|&(_, value)| value))
+            .unwrap_or(f32::NAN)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_capi() {
+        unsafe {
+            let graph = egraph_graph_new();
+            for _ in 0..10 {
+                egraph_graph_add_node(graph);
+            }
+            for i in 0..10 {
+                egraph_graph_add_edge(graph, i, (i + 1) % 10);
+            }
+            assert_eq!(egraph_graph_add_edge(graph, 0, 10), u32::MAX);
+            assert_eq!(egraph_graph_edge_count(graph), 10);
+
+            let rng = egraph_rng_seed_from(0);
+            let drawing = egraph_drawing_initial_placement(graph);
+            let sgd = egraph_sparse_sgd_new(graph, 30., 5, rng);
+            assert!(egraph_sgd_run(sgd, drawing, rng, 15, 0.1));
+            assert!(egraph_stress_majorization_run(graph, drawing, 30.));
+            assert_eq!(egraph_drawing_len(drawing), 10);
+            assert!(egraph_drawing_x(drawing, 0).is_finite());
+            assert!(egraph_drawing_x(drawing, 10).is_nan());
+
+            let name = CString::new("stress").unwrap();
+            assert!(egraph_quality_metric(graph, drawing, name.as_ptr(), 30.) >= 0.);
+            let name = CString::new("unknown").unwrap();
+            assert!(egraph_quality_metric(graph, drawing, name.as_ptr(), 30.).is_nan());
+
+            let edges = egraph_fdeb(graph, drawing);
+            let n = egraph_bundled_edges_len(edges, 0);
+            assert!(n > 0);
+            let mut points = vec![0.; 2 * n];
+            assert_eq!(
+                egraph_bundled_edges_points(edges, 0, points.as_mut_ptr()),
+                n
+            );
+
+            let mds = egraph_classical_mds(graph, 30.);
+            assert_eq!(egraph_drawing_len(mds), 10);
+
+            egraph_drawing_free(mds);
+            egraph_bundled_edges_free(edges);
+            egraph_sgd_free(sgd);
+            egraph_drawing_free(drawing);
+            egraph_rng_free(rng);
+            egraph_graph_free(graph);
+            egraph_graph_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_capi_mismatched_drawing() {
+        unsafe {
+            let small = egraph_graph_new();
+            let large = egraph_graph_new();
+            for _ in 0..3 {
+                egraph_graph_add_node(small);
+            }
+            for _ in 0..5 {
+                egraph_graph_add_node(large);
+            }
+            for i in 0..4 {
+                egraph_graph_add_edge(large, i, i + 1);
+            }
+            egraph_graph_add_edge(small, 0, 1);
+            egraph_graph_add_edge(small, 1, 2);
+
+            let rng = egraph_rng_seed_from(0);
+            let drawing = egraph_drawing_initial_placement(small);
+            let sgd = egraph_full_sgd_new(large, 30.);
+            assert!(!egraph_sgd_run(sgd, drawing, rng, 15, 0.1));
+            assert!(!egraph_stress_majorization_run(large, drawing, 30.));
+            assert!(egraph_fdeb(large, drawing).is_null());
+            let name = CString::new("stress").unwrap();
+            assert!(egraph_quality_metric(large, drawing, name.as_ptr(), 30.).is_nan());
+            assert!(egraph_stress_majorization_run(small, drawing, 30.));
+
+            egraph_sgd_free(sgd);
+            egraph_drawing_free(drawing);
+            egraph_rng_free(rng);
+            egraph_graph_free(small);
+            egraph_graph_free(large);
+        }
+    }
+
+    #[test]
+    fn test_guard() {
+        assert!(!guard(false, || panic!("layout failed")));
+        assert_eq!(guard(0, || 1), 1);
+    }
+}
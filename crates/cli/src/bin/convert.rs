@@ -0,0 +1,52 @@
+use argparse::{ArgumentParser, Store};
+use egraph_cli::{
+    read_graph_binary, read_graph_with_attributes, write_graph_binary, write_graph_with_attributes,
+    StochasticRounding,
+};
+use serde_json::Value;
+
+fn parse_args(
+    input_path: &mut String,
+    output_path: &mut String,
+    rounding: &mut StochasticRounding,
+) {
+    let mut parser = ArgumentParser::new();
+    parser.set_description(
+        "convert graphs between JSON and the zstd-compressed binary format; \
+         paths ending with .json are read and written as JSON",
+    );
+    parser
+        .refer(input_path)
+        .add_argument("input", Store, "input file path")
+        .required();
+    parser
+        .refer(output_path)
+        .add_argument("output", Store, "output file path")
+        .required();
+    rounding.add_options(&mut parser);
+    parser.parse_args_or_exit();
+}
+
+fn is_json(path: &str) -> bool {
+    path.ends_with(".json")
+}
+
+fn main() {
+    let mut input_path = "".to_string();
+    let mut output_path = "".to_string();
+    let mut rounding = StochasticRounding::new(0., 0);
+    parse_args(&mut input_path, &mut output_path, &mut rounding);
+    let (graph, mut drawing, attributes) = if is_json(&input_path) {
+        read_graph_with_attributes::<Value, Value>(&input_path)
+    } else {
+        read_graph_binary::<Value, Value>(&input_path)
+    };
+    if rounding.grid_size > 0. {
+        rounding.apply_euclidean(&mut drawing);
+    }
+    if is_json(&output_path) {
+        write_graph_with_attributes(&graph, &drawing, &attributes, &output_path);
+    } else {
+        write_graph_binary(&graph, &drawing, &attributes, &output_path);
+    }
+}
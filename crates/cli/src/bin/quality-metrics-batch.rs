@@ -0,0 +1,162 @@
+use argparse::{ArgumentParser, List, Store};
+use egraph_cli::read_graph;
+use petgraph::prelude::*;
+use petgraph_algorithm_shortest_path::warshall_floyd;
+use petgraph_drawing::DrawingEuclidean2d;
+use petgraph_quality_metrics::{quality_metrics_batch, QualityMetric};
+use serde::Serialize;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+};
+
+#[derive(Serialize)]
+struct Record {
+    layout: String,
+    metric: String,
+    value: f32,
+}
+
+fn parse_args(input_paths: &mut Vec<String>, output_path: &mut String, metrics: &mut String) {
+    let mut parser = ArgumentParser::new();
+    parser
+        .refer(output_path)
+        .add_argument("output", Store, "output file path (.csv or .json)")
+        .required();
+    parser
+        .refer(input_paths)
+        .add_argument("inputs", List, "input file paths")
+        .required();
+    parser.refer(metrics).add_option(
+        &["--metrics"],
+        Store,
+        "comma separated metric names (default: all)",
+    );
+    parser.parse_args_or_exit();
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("quality-metrics-batch: {}", message);
+    std::process::exit(2);
+}
+
+fn parse_metrics(metrics: &str) -> Result<Vec<QualityMetric>, String> {
+    if metrics.is_empty() {
+        return Ok(QualityMetric::all());
+    }
+    metrics
+        .split(',')
+        .map(|name| {
+            QualityMetric::from_name(name.trim())
+                .ok_or_else(|| format!("unknown metric: {:?}", name.trim()))
+        })
+        .collect()
+}
+
+fn edge_set<N, E>(graph: &UnGraph<N, E>) -> Vec<(usize, usize)> {
+    let mut edges = graph
+        .edge_references()
+        .map(|e| {
+            let (u, v) = (e.source().index(), e.target().index());
+            (u.min(v), u.max(v))
+        })
+        .collect::<Vec<_>>();
+    edges.sort();
+    edges
+}
+
+fn same_graph<N, E>(a: &UnGraph<N, E>, b: &UnGraph<N, E>) -> bool {
+    a.node_count() == b.node_count() && edge_set(a) == edge_set(b)
+}
+
+fn write_result(records: &[Record], output_path: &str) {
+    let file = File::create(output_path).unwrap();
+    let mut writer = BufWriter::new(file);
+    if output_path.ends_with(".json") {
+        serde_json::to_writer(writer, records).unwrap();
+    } else {
+        writeln!(writer, "layout,metric,value").unwrap();
+        for record in records {
+            writeln!(
+                writer,
+                "\"{}\",{},{}",
+                record.layout.replace('"', "\"\""),
+                record.metric,
+                record.value
+            )
+            .unwrap();
+        }
+    }
+}
+
+fn main() {
+    let mut input_paths = vec![];
+    let mut output_path = "".to_string();
+    let mut metrics = "".to_string();
+    parse_args(&mut input_paths, &mut output_path, &mut metrics);
+    let targets = parse_metrics(&metrics).unwrap_or_else(|message| usage_error(&message));
+
+    let mut graph = None;
+    let mut drawings: Vec<DrawingEuclidean2d<NodeIndex, f32>> = vec![];
+    for input_path in input_paths.iter() {
+        let (input_graph, drawing) = read_graph::<(), ()>(input_path);
+        let graph = graph.get_or_insert(input_graph.clone());
+        if !same_graph(graph, &input_graph) {
+            usage_error(&format!(
+                "{} does not have the same graph as {}",
+                input_path, input_paths[0]
+            ));
+        }
+        drawings.push(drawing);
+    }
+    let graph = graph.unwrap();
+    let distance = warshall_floyd(&graph, &mut |_| 1.);
+    let records = input_paths
+        .iter()
+        .zip(
+            quality_metrics_batch(&graph, &drawings, &distance, &targets)
+                .unwrap_or_else(|e| usage_error(&e.to_string())),
+        )
+        .flat_map(|(path, values)| {
+            values.into_iter().map(move |(metric, value)| Record {
+                layout: path.clone(),
+                metric: metric.name(),
+                value,
+            })
+        })
+        .collect::<Vec<_>>();
+    write_result(&records, &output_path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_metrics() {
+        assert_eq!(parse_metrics("").unwrap().len(), QualityMetric::all().len());
+        let metrics = parse_metrics("stress, crossing-number").unwrap();
+        assert_eq!(metrics.len(), 2);
+        assert_eq!(metrics[1].name(), "crossing-number");
+        assert!(parse_metrics("stress,unknown").is_err());
+        assert!(parse_metrics("stress,").is_err());
+    }
+
+    #[test]
+    fn test_same_graph() {
+        let mut a = UnGraph::<(), ()>::new_undirected();
+        let nodes = (0..3).map(|_| a.add_node(())).collect::<Vec<_>>();
+        a.add_edge(nodes[0], nodes[1], ());
+        a.add_edge(nodes[1], nodes[2], ());
+        let mut b = UnGraph::<(), ()>::new_undirected();
+        let nodes = (0..3).map(|_| b.add_node(())).collect::<Vec<_>>();
+        b.add_edge(nodes[2], nodes[1], ());
+        b.add_edge(nodes[1], nodes[0], ());
+        assert!(same_graph(&a, &b));
+        b.add_edge(nodes[0], nodes[2], ());
+        assert!(!same_graph(&a, &b));
+        let mut c = a.clone();
+        c.add_node(());
+        assert!(!same_graph(&a, &c));
+    }
+}
@@ -24,7 +24,7 @@ fn compute_metrics(
     drawing: &DrawingEuclidean2d<NodeIndex, f32>,
 ) -> Vec<(QualityMetric, f32)> {
     let distance = warshall_floyd(graph, &mut |_| 1.);
-    quality_metrics(graph, drawing, &distance)
+    quality_metrics(graph, drawing, &distance).unwrap()
 }
 
 fn write_result(output: &[(QualityMetric, f32)], output_path: &str) {
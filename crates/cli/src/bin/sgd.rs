@@ -1,11 +1,18 @@
 use argparse::{ArgumentParser, Store};
-use egraph_cli::{read_graph, write_graph};
+use egraph_cli::{read_graph, write_graph, SnapshotTarget, SnapshotWriter, StochasticRounding};
 use petgraph::prelude::*;
 use petgraph_drawing::DrawingEuclidean2d;
 use petgraph_layout_sgd::{Scheduler, SchedulerExponential, Sgd, SparseSgd};
-use rand::thread_rng;
+use rand::prelude::*;
 
-fn parse_args(input_path: &mut String, output_path: &mut String) {
+fn parse_args(
+    input_path: &mut String,
+    output_path: &mut String,
+    seed: &mut u64,
+    rounding: &mut StochasticRounding,
+    snapshot_path: &mut String,
+    snapshot_interval: &mut usize,
+) {
     let mut parser = ArgumentParser::new();
     parser
         .refer(input_path)
@@ -15,27 +22,79 @@ fn parse_args(input_path: &mut String, output_path: &mut String) {
         .refer(output_path)
         .add_argument("output", Store, "output file path")
         .required();
+    parser
+        .refer(seed)
+        .add_option(&["--seed"], Store, "seed for the layout");
+    rounding.add_options(&mut parser);
+    parser.refer(snapshot_path).add_option(
+        &["--snapshot"],
+        Store,
+        "write drawing snapshots to this NDJSON file, or to numbered files with this prefix if it does not end with .ndjson",
+    );
+    parser.refer(snapshot_interval).add_option(
+        &["--snapshot-interval"],
+        Store,
+        "write a snapshot every this many iterations",
+    );
     parser.parse_args_or_exit();
 }
 
 fn layout(
     graph: &Graph<Option<()>, Option<()>, Undirected>,
     coordinates: &mut DrawingEuclidean2d<NodeIndex, f32>,
+    seed: u64,
+    snapshot: &mut Option<SnapshotWriter>,
 ) {
-    let mut rng = thread_rng();
+    let mut rng = StdRng::seed_from_u64(seed);
     let mut sgd = SparseSgd::new_with_rng(graph, |_| 30., 281, &mut rng);
     let mut scheduler = sgd.scheduler::<SchedulerExponential<f32>>(867, 0.1);
     scheduler.run(&mut |eta| {
         sgd.shuffle(&mut rng);
         sgd.apply(coordinates, eta);
+        if let Some(snapshot) = snapshot {
+            snapshot.step(coordinates);
+        }
     });
 }
 
 fn main() {
     let mut input_path = "".to_string();
     let mut output_path = "".to_string();
-    parse_args(&mut input_path, &mut output_path);
+    let mut seed = 0;
+    let mut rounding = StochasticRounding::new(0., 0);
+    let mut snapshot_path = "".to_string();
+    let mut snapshot_interval = 10;
+    parse_args(
+        &mut input_path,
+        &mut output_path,
+        &mut seed,
+        &mut rounding,
+        &mut snapshot_path,
+        &mut snapshot_interval,
+    );
     let (input_graph, mut coordinates) = read_graph(&input_path);
-    layout(&input_graph, &mut coordinates);
+    let mut snapshot = if snapshot_path.is_empty() {
+        None
+    } else if snapshot_path.ends_with(".ndjson") {
+        Some(SnapshotWriter::new(
+            SnapshotTarget::Ndjson(snapshot_path),
+            snapshot_interval,
+        ))
+    } else {
+        Some(SnapshotWriter::new(
+            SnapshotTarget::NumberedFiles(snapshot_path),
+            snapshot_interval,
+        ))
+    };
+    layout(&input_graph, &mut coordinates, seed, &mut snapshot);
+    if let Some(snapshot) = snapshot {
+        if let Err(err) = snapshot.finish() {
+            eprintln!("failed to write snapshots: {}", err);
+            std::process::exit(1);
+        }
+    }
+    if rounding.grid_size > 0. {
+        rounding.apply(&mut coordinates);
+    }
     write_graph(&input_graph, &coordinates, &output_path);
 }
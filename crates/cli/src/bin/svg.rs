@@ -0,0 +1,57 @@
+use argparse::{ArgumentParser, Store, StoreFalse, StoreTrue};
+use egraph_cli::{read_graph, render_svg, SvgOptions};
+use petgraph_edge_bundling_fdeb::{fdeb, EdgeBundlingOptions};
+use std::fs;
+
+fn parse_args(
+    input_path: &mut String,
+    output_path: &mut String,
+    bundling: &mut bool,
+    options: &mut SvgOptions,
+) {
+    let mut parser = ArgumentParser::new();
+    parser
+        .refer(input_path)
+        .add_argument("input", Store, "input file path")
+        .required();
+    parser
+        .refer(output_path)
+        .add_argument("output", Store, "output file path")
+        .required();
+    parser
+        .refer(bundling)
+        .add_option(&["--fdeb"], StoreTrue, "bundle edges with FDEB");
+    parser
+        .refer(&mut options.node_radius)
+        .add_option(&["--node-radius"], Store, "node radius");
+    parser
+        .refer(&mut options.edge_opacity)
+        .add_option(&["--edge-opacity"], Store, "edge opacity");
+    parser.refer(&mut options.smoothing).add_option(
+        &["--no-smoothing"],
+        StoreFalse,
+        "draw bundled edges as polylines instead of Bezier curves",
+    );
+    parser.parse_args_or_exit();
+}
+
+fn main() {
+    let mut input_path = "".to_string();
+    let mut output_path = "".to_string();
+    let mut bundling = false;
+    let mut options = SvgOptions::new();
+    parse_args(
+        &mut input_path,
+        &mut output_path,
+        &mut bundling,
+        &mut options,
+    );
+    let (graph, drawing) = read_graph::<(), ()>(&input_path);
+    let bundled_edges = if bundling {
+        Some(fdeb(&graph, &drawing, &EdgeBundlingOptions::<f32>::new()).unwrap())
+    } else {
+        None
+    };
+    let svg = render_svg(&graph, &drawing, bundled_edges.as_ref(), &options);
+    fs::write(output_path, svg).unwrap();
+}
@@ -0,0 +1,251 @@
+use crate::{GraphAttributes, JsonGraph};
+use petgraph::prelude::*;
+use petgraph_drawing::{Drawing, DrawingEuclidean};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+};
+
+const MAGIC: &[u8; 8] = b"EGRAPH\x00\x02";
+
+/// A JSON value in a form bincode can encode, since bincode cannot
+/// deserialize `serde_json::Value` itself.
+#[derive(Serialize, Deserialize)]
+enum BinaryValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Array(Vec<BinaryValue>),
+    Object(Vec<(String, BinaryValue)>),
+}
+
+impl From<Value> for BinaryValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => BinaryValue::Null,
+            Value::Bool(b) => BinaryValue::Bool(b),
+            Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    BinaryValue::Int(i)
+                } else if let Some(u) = n.as_u64() {
+                    BinaryValue::UInt(u)
+                } else {
+                    BinaryValue::Float(n.as_f64().unwrap())
+                }
+            }
+            Value::String(s) => BinaryValue::String(s),
+            Value::Array(a) => BinaryValue::Array(a.into_iter().map(BinaryValue::from).collect()),
+            Value::Object(o) => BinaryValue::Object(
+                o.into_iter()
+                    .map(|(k, v)| (k, BinaryValue::from(v)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<BinaryValue> for Value {
+    fn from(value: BinaryValue) -> Self {
+        match value {
+            BinaryValue::Null => Value::Null,
+            BinaryValue::Bool(b) => Value::Bool(b),
+            BinaryValue::Int(i) => Value::from(i),
+            BinaryValue::UInt(u) => Value::from(u),
+            BinaryValue::Float(f) => Value::from(f),
+            BinaryValue::String(s) => Value::String(s),
+            BinaryValue::Array(a) => Value::Array(a.into_iter().map(Value::from).collect()),
+            BinaryValue::Object(o) => {
+                Value::Object(o.into_iter().map(|(k, v)| (k, Value::from(v))).collect())
+            }
+        }
+    }
+}
+
+fn encode<T: Serialize>(data: &Option<T>) -> Option<BinaryValue> {
+    data.as_ref()
+        .map(|x| serde_json::to_value(x).unwrap().into())
+}
+
+fn decode<T: DeserializeOwned>(data: Option<BinaryValue>) -> Option<T> {
+    data.map(|x| serde_json::from_value(x.into()).unwrap())
+}
+
+#[derive(Serialize, Deserialize)]
+struct BinaryGraphData {
+    dimension: usize,
+    coordinates: Vec<Option<f32>>,
+    node_radius: Vec<Option<f32>>,
+    node_group: Vec<Option<usize>>,
+    node_data: Vec<Option<BinaryValue>>,
+    links: Vec<(usize, usize)>,
+    link_points: Vec<Option<Vec<(f32, f32)>>>,
+    link_data: Vec<Option<BinaryValue>>,
+}
+
+pub fn read_graph_binary<N: DeserializeOwned, E: DeserializeOwned>(
+    input_path: &str,
+) -> (
+    JsonGraph<N, E>,
+    DrawingEuclidean<NodeIndex, f32>,
+    GraphAttributes,
+) {
+    let file = File::open(input_path).unwrap();
+    let mut reader = BufReader::new(file);
+    let mut magic = [0; 8];
+    reader.read_exact(&mut magic).unwrap();
+    assert_eq!(&magic, MAGIC, "{} is not a binary graph file", input_path);
+    let decoder = zstd::Decoder::with_buffer(reader).unwrap();
+    let input_graph: BinaryGraphData = bincode::deserialize_from(decoder).unwrap();
+
+    let mut graph = Graph::new_undirected();
+    for data in input_graph.node_data {
+        graph.add_node(decode(data));
+    }
+    for (&(source, target), data) in input_graph.links.iter().zip(input_graph.link_data) {
+        graph.add_edge(NodeIndex::new(source), NodeIndex::new(target), decode(data));
+    }
+    let dimension = input_graph.dimension;
+    let mut drawing = DrawingEuclidean::new(&graph, dimension);
+    let mut attributes = GraphAttributes::default();
+    for u in graph.node_indices() {
+        let i = u.index();
+        for d in 0..dimension {
+            match input_graph.coordinates[i * dimension + d] {
+                Some(x) => {
+                    drawing.set(u, d, x);
+                }
+                None => {
+                    attributes.missing_coordinates.insert((u, d));
+                }
+            }
+        }
+        if let Some(radius) = input_graph.node_radius[i] {
+            attributes.node_radius.insert(u, radius);
+        }
+        if let Some(group) = input_graph.node_group[i] {
+            attributes.node_group.insert(u, group);
+        }
+    }
+    for (e, points) in graph.edge_indices().zip(input_graph.link_points) {
+        if let Some(points) = points {
+            attributes.edge_points.insert(e, points);
+        }
+    }
+    (graph, drawing, attributes)
+}
+
+pub fn write_graph_binary<N: Serialize, E: Serialize>(
+    graph: &JsonGraph<N, E>,
+    drawing: &DrawingEuclidean<NodeIndex, f32>,
+    attributes: &GraphAttributes,
+    output_path: &str,
+) {
+    let dimension = drawing.dimension();
+    let output = BinaryGraphData {
+        dimension,
+        coordinates: graph
+            .node_indices()
+            .flat_map(|u| (0..dimension).map(move |d| attributes.coordinate(drawing, u, d)))
+            .collect(),
+        node_radius: graph
+            .node_indices()
+            .map(|u| attributes.node_radius.get(&u).copied())
+            .collect(),
+        node_group: graph
+            .node_indices()
+            .map(|u| attributes.node_group.get(&u).copied())
+            .collect(),
+        node_data: graph.node_indices().map(|u| encode(&graph[u])).collect(),
+        links: graph
+            .edge_indices()
+            .map(|e| {
+                let (source, target) = graph.edge_endpoints(e).unwrap();
+                (source.index(), target.index())
+            })
+            .collect(),
+        link_points: graph
+            .edge_indices()
+            .map(|e| attributes.edge_points.get(&e).cloned())
+            .collect(),
+        link_data: graph.edge_indices().map(|e| encode(&graph[e])).collect(),
+    };
+
+    let file = File::create(output_path).unwrap();
+    let mut writer = BufWriter::new(file);
+    writer.write_all(MAGIC).unwrap();
+    let mut encoder = zstd::Encoder::new(writer, 0).unwrap();
+    bincode::serialize_into(&mut encoder, &output).unwrap();
+    encoder.finish().unwrap().flush().unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{HashMap, HashSet};
+
+    #[test]
+    fn test_binary_round_trip() {
+        let mut graph = Graph::new_undirected();
+        let u = graph.add_node(Some("a".to_string()));
+        let v = graph.add_node(None);
+        let e = graph.add_edge(u, v, Some(1.5));
+        let mut drawing = DrawingEuclidean::new(&graph, 3);
+        drawing.set(u, 2, 4.);
+        drawing.set(v, 0, -1.);
+        let attributes = GraphAttributes {
+            node_radius: HashMap::from([(u, 2.)]),
+            node_group: HashMap::from([(v, 3)]),
+            edge_points: HashMap::from([(e, vec![(0., 0.), (1., 1.)])]),
+            missing_coordinates: HashSet::from([(v, 1)]),
+        };
+        let path = std::env::temp_dir().join("egraph-cli-test-binary-round-trip.egb");
+        let path = path.to_str().unwrap();
+
+        write_graph_binary(&graph, &drawing, &attributes, path);
+        let (read, read_drawing, read_attributes) = read_graph_binary::<String, f64>(path);
+        assert_eq!(read[u], Some("a".to_string()));
+        assert_eq!(read[v], None);
+        assert_eq!(read[e], Some(1.5));
+        assert_eq!(read_drawing.dimension(), 3);
+        assert_eq!(read_drawing.get(u, 2), Some(4.));
+        assert_eq!(read_drawing.get(v, 0), Some(-1.));
+        assert_eq!(read_attributes.node_radius, attributes.node_radius);
+        assert_eq!(read_attributes.node_group, attributes.node_group);
+        assert_eq!(read_attributes.edge_points, attributes.edge_points);
+        assert_eq!(
+            read_attributes.missing_coordinates,
+            attributes.missing_coordinates
+        );
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_binary_json_values() {
+        let mut graph = Graph::new_undirected();
+        let data = serde_json::json!({
+            "name": "a",
+            "weight": 1.5,
+            "count": 3,
+            "big": u64::MAX,
+            "tags": ["x", null, true]
+        });
+        let u = graph.add_node(Some(data.clone()));
+        let v = graph.add_node(Some(Value::Null));
+        graph.add_edge(u, v, Some(serde_json::json!(-7)));
+        let drawing = DrawingEuclidean::new(&graph, 2);
+        let path = std::env::temp_dir().join("egraph-cli-test-binary-json-values.egb");
+        let path = path.to_str().unwrap();
+
+        write_graph_binary(&graph, &drawing, &GraphAttributes::default(), path);
+        let (read, _, _) = read_graph_binary::<Value, Value>(path);
+        assert_eq!(read[u], Some(data));
+        assert_eq!(read[v], Some(Value::Null));
+        assert_eq!(read[EdgeIndex::new(0)], Some(serde_json::json!(-7)));
+        let _ = std::fs::remove_file(path);
+    }
+}
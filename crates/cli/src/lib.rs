@@ -1,24 +1,54 @@
-use petgraph::prelude::*;
-use petgraph_drawing::DrawingEuclidean2d;
+mod binary;
+mod rounding;
+mod snapshot;
+mod svg;
+
+use petgraph::{graph::DefaultIx, prelude::*};
+use petgraph_drawing::{Drawing, DrawingEuclidean, DrawingEuclidean2d};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fs::File,
     io::{BufReader, BufWriter},
 };
 
+pub use binary::{read_graph_binary, write_graph_binary};
+pub use rounding::StochasticRounding;
+pub use snapshot::{SnapshotTarget, SnapshotWriter};
+pub use svg::{render_svg, EdgePoints, SvgOptions};
+
 #[derive(Clone, Serialize, Deserialize)]
 struct NodeData<N> {
     id: usize,
     x: Option<f32>,
     y: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    z: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    position: Option<Vec<Option<f32>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    radius: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    group: Option<usize>,
     data: Option<N>,
 }
 
+impl<N> NodeData<N> {
+    fn coordinates(&self) -> Vec<Option<f32>> {
+        match &self.position {
+            Some(position) => position.clone(),
+            None if self.z.is_some() => vec![self.x, self.y, self.z],
+            None => vec![self.x, self.y],
+        }
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 struct LinkData<E> {
     source: usize,
     target: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    points: Option<Vec<(f32, f32)>>,
     data: Option<E>,
 }
 
@@ -28,12 +58,36 @@ struct GraphData<N, E> {
     links: Vec<LinkData<E>>,
 }
 
-pub fn read_graph<N: Clone + DeserializeOwned, E: Clone + DeserializeOwned>(
+type JsonGraph<N, E> = Graph<Option<N>, Option<E>, Undirected>;
+
+#[derive(Clone, Default)]
+pub struct GraphAttributes {
+    pub node_radius: HashMap<NodeIndex, f32>,
+    pub node_group: HashMap<NodeIndex, usize>,
+    pub edge_points: EdgePoints<DefaultIx>,
+    /// Coordinates `(node, dimension)` that were absent in the input. They
+    /// hold zero in the drawing and are written back as missing.
+    pub missing_coordinates: HashSet<(NodeIndex, usize)>,
+}
+
+impl GraphAttributes {
+    pub(crate) fn coordinate(
+        &self,
+        drawing: &DrawingEuclidean<NodeIndex, f32>,
+        u: NodeIndex,
+        d: usize,
+    ) -> Option<f32> {
+        if self.missing_coordinates.contains(&(u, d)) {
+            None
+        } else {
+            drawing.get(u, d)
+        }
+    }
+}
+
+fn load_graph_data<N: Clone + DeserializeOwned, E: Clone + DeserializeOwned>(
     input_path: &str,
-) -> (
-    Graph<Option<N>, Option<E>, Undirected>,
-    DrawingEuclidean2d<NodeIndex, f32>,
-) {
+) -> (GraphData<N, E>, JsonGraph<N, E>, HashMap<usize, NodeIndex>) {
     let file = File::open(input_path).unwrap();
     let reader = BufReader::new(file);
     let input_graph: GraphData<N, E> = serde_json::from_reader(reader).unwrap();
@@ -50,6 +104,22 @@ pub fn read_graph<N: Clone + DeserializeOwned, E: Clone + DeserializeOwned>(
             link.data.clone(),
         );
     }
+    (input_graph, graph, node_ids)
+}
+
+fn save_graph_data<N: Serialize, E: Serialize>(output: &GraphData<N, E>, output_path: &str) {
+    let file = File::create(output_path).unwrap();
+    let writer = BufWriter::new(file);
+    serde_json::to_writer(writer, output).unwrap();
+}
+
+pub fn read_graph<N: Clone + DeserializeOwned, E: Clone + DeserializeOwned>(
+    input_path: &str,
+) -> (
+    Graph<Option<N>, Option<E>, Undirected>,
+    DrawingEuclidean2d<NodeIndex, f32>,
+) {
+    let (input_graph, graph, node_ids) = load_graph_data(input_path);
     let mut drawing = DrawingEuclidean2d::initial_placement(&graph);
     for node in input_graph.nodes.iter() {
         let u = node_ids[&node.id];
@@ -75,6 +145,10 @@ pub fn write_graph<N: Clone + Serialize, E: Clone + Serialize>(
                 id: u.index(),
                 x: Some(drawing.x(u).unwrap()),
                 y: Some(drawing.y(u).unwrap()),
+                z: None,
+                position: None,
+                radius: None,
+                group: None,
                 data: graph[u].clone(),
             })
             .collect::<Vec<_>>(),
@@ -85,13 +159,177 @@ pub fn write_graph<N: Clone + Serialize, E: Clone + Serialize>(
                 LinkData {
                     source: source.index(),
                     target: target.index(),
+                    points: None,
                     data: graph[e].clone(),
                 }
             })
             .collect::<Vec<_>>(),
     };
+    save_graph_data(&output, output_path);
+}
 
-    let file = File::create(output_path).unwrap();
-    let writer = BufWriter::new(file);
-    serde_json::to_writer(writer, &output).unwrap();
+pub fn read_graph_with_attributes<N: Clone + DeserializeOwned, E: Clone + DeserializeOwned>(
+    input_path: &str,
+) -> (
+    JsonGraph<N, E>,
+    DrawingEuclidean<NodeIndex, f32>,
+    GraphAttributes,
+) {
+    let (input_graph, graph, node_ids) = load_graph_data(input_path);
+    let dimension = input_graph
+        .nodes
+        .iter()
+        .map(|node| node.coordinates().len())
+        .max()
+        .unwrap_or(2);
+    let mut drawing = DrawingEuclidean::new(&graph, dimension);
+    let mut attributes = GraphAttributes::default();
+    for node in input_graph.nodes.iter() {
+        let u = node_ids[&node.id];
+        let coordinates = node.coordinates();
+        for d in 0..dimension {
+            match coordinates.get(d).copied().flatten() {
+                Some(x) => {
+                    drawing.set(u, d, x);
+                }
+                None => {
+                    attributes.missing_coordinates.insert((u, d));
+                }
+            }
+        }
+        if let Some(radius) = node.radius {
+            attributes.node_radius.insert(u, radius);
+        }
+        if let Some(group) = node.group {
+            attributes.node_group.insert(u, group);
+        }
+    }
+    for (e, link) in graph.edge_indices().zip(input_graph.links.iter()) {
+        if let Some(points) = &link.points {
+            attributes.edge_points.insert(e, points.clone());
+        }
+    }
+    (graph, drawing, attributes)
+}
+
+pub fn write_graph_with_attributes<N: Clone + Serialize, E: Clone + Serialize>(
+    graph: &Graph<Option<N>, Option<E>, Undirected>,
+    drawing: &DrawingEuclidean<NodeIndex, f32>,
+    attributes: &GraphAttributes,
+    output_path: &str,
+) {
+    let dimension = drawing.dimension();
+    let output = GraphData {
+        nodes: graph
+            .node_indices()
+            .map(|u| NodeData {
+                id: u.index(),
+                x: attributes.coordinate(drawing, u, 0),
+                y: attributes.coordinate(drawing, u, 1),
+                z: if dimension == 3 {
+                    attributes.coordinate(drawing, u, 2)
+                } else {
+                    None
+                },
+                position: if dimension > 3 {
+                    Some(
+                        (0..dimension)
+                            .map(|d| attributes.coordinate(drawing, u, d))
+                            .collect(),
+                    )
+                } else {
+                    None
+                },
+                radius: attributes.node_radius.get(&u).copied(),
+                group: attributes.node_group.get(&u).copied(),
+                data: graph[u].clone(),
+            })
+            .collect::<Vec<_>>(),
+        links: graph
+            .edge_indices()
+            .map(|e| {
+                let (source, target) = graph.edge_endpoints(e).unwrap();
+                LinkData {
+                    source: source.index(),
+                    target: target.index(),
+                    points: attributes.edge_points.get(&e).cloned(),
+                    data: graph[e].clone(),
+                }
+            })
+            .collect::<Vec<_>>(),
+    };
+    save_graph_data(&output, output_path);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_graph_attributes() {
+        let dir = std::env::temp_dir();
+        let input_path = dir.join("egraph-cli-test-graph-attributes-input.json");
+        let output_path = dir.join("egraph-cli-test-graph-attributes-output.json");
+        fs::write(
+            &input_path,
+            r#"{
+                "nodes": [
+                    {"id": 0, "x": 1.0, "y": 2.0, "z": 3.0, "radius": 4.0, "data": null},
+                    {"id": 1, "x": 5.0, "y": 6.0, "group": 7, "data": null},
+                    {"id": 2, "data": null}
+                ],
+                "links": [
+                    {"source": 0, "target": 1, "points": [[1.0, 2.0], [5.0, 6.0]], "data": null}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let input_path = input_path.to_str().unwrap();
+        let output_path = output_path.to_str().unwrap();
+
+        let (_, drawing) = read_graph::<(), ()>(input_path);
+        assert_eq!(drawing.x(NodeIndex::new(0)), Some(1.));
+        assert_eq!(drawing.y(NodeIndex::new(1)), Some(6.));
+
+        let (graph, drawing, attributes) = read_graph_with_attributes::<(), ()>(input_path);
+        let (u, v) = (NodeIndex::new(0), NodeIndex::new(1));
+        assert_eq!(drawing.dimension(), 3);
+        assert_eq!(drawing.get(u, 2), Some(3.));
+        assert_eq!(drawing.get(v, 2), Some(0.));
+        assert_eq!(
+            attributes.missing_coordinates,
+            HashSet::from([
+                (v, 2),
+                (NodeIndex::new(2), 0),
+                (NodeIndex::new(2), 1),
+                (NodeIndex::new(2), 2)
+            ])
+        );
+        assert_eq!(attributes.node_radius.get(&u), Some(&4.));
+        assert_eq!(attributes.node_group.get(&v), Some(&7));
+        assert_eq!(attributes.edge_points[&EdgeIndex::new(0)].len(), 2);
+
+        write_graph_with_attributes(&graph, &drawing, &attributes, output_path);
+        let (_, written, written_attributes) = read_graph_with_attributes::<(), ()>(output_path);
+        assert_eq!(written.get(u, 2), Some(3.));
+        assert_eq!(
+            written_attributes.missing_coordinates,
+            attributes.missing_coordinates
+        );
+        let output = fs::read_to_string(output_path).unwrap();
+        assert!(output.contains(r#"{"id":2,"x":null,"y":null,"data":null}"#));
+        assert_eq!(written_attributes.node_radius, attributes.node_radius);
+        assert_eq!(written_attributes.node_group, attributes.node_group);
+        assert_eq!(written_attributes.edge_points, attributes.edge_points);
+
+        let mut drawing = DrawingEuclidean::new(&graph, 4);
+        drawing.set(v, 3, 8.);
+        write_graph_with_attributes(&graph, &drawing, &GraphAttributes::default(), output_path);
+        let (_, written, _) = read_graph_with_attributes::<(), ()>(output_path);
+        assert_eq!(written.dimension(), 4);
+        assert_eq!(written.get(v, 3), Some(8.));
+        let _ = fs::remove_file(input_path);
+        let _ = fs::remove_file(output_path);
+    }
 }
@@ -0,0 +1,174 @@
+use argparse::{ArgumentParser, Store};
+use petgraph::prelude::*;
+use petgraph_drawing::{Drawing, DrawingEuclidean, DrawingEuclidean2d, MetricEuclidean2d};
+use rand::prelude::*;
+use std::collections::HashMap;
+
+pub struct StochasticRounding {
+    pub grid_size: f32,
+    pub seed: u64,
+    pub min_group_size: usize,
+}
+
+impl StochasticRounding {
+    pub fn new(grid_size: f32, seed: u64) -> StochasticRounding {
+        StochasticRounding {
+            grid_size,
+            seed,
+            min_group_size: 1,
+        }
+    }
+
+    fn round(&self, value: f32, rng: &mut StdRng) -> i64 {
+        let t = value / self.grid_size;
+        let floor = t.floor();
+        if rng.gen::<f32>() < t - floor {
+            floor as i64 + 1
+        } else {
+            floor as i64
+        }
+    }
+
+    /// Registers the `--round-grid`, `--round-seed` and `--min-group-size`
+    /// options on `parser`.
+    pub fn add_options<'a>(&'a mut self, parser: &mut ArgumentParser<'a>) {
+        parser.refer(&mut self.grid_size).add_option(
+            &["--round-grid"],
+            Store,
+            "snap output positions to a grid of this size",
+        );
+        parser.refer(&mut self.seed).add_option(
+            &["--round-seed"],
+            Store,
+            "seed for stochastic rounding",
+        );
+        parser.refer(&mut self.min_group_size).add_option(
+            &["--min-group-size"],
+            Store,
+            "merge grid cells holding fewer nodes than this",
+        );
+    }
+
+    /// Maps each point to a grid cell. Points whose cell holds fewer than
+    /// `min_group_size` points move to the nearest cell that holds enough,
+    /// or stay in their own cell if there is none.
+    fn snap(&self, points: &[Vec<f32>]) -> Vec<Vec<f32>> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let cells = points
+            .iter()
+            .map(|p| p.iter().map(|&x| self.round(x, &mut rng)).collect())
+            .collect::<Vec<Vec<_>>>();
+
+        let mut groups = HashMap::<&[i64], usize>::new();
+        for cell in cells.iter() {
+            *groups.entry(cell).or_insert(0) += 1;
+        }
+        let mut large_groups = groups
+            .iter()
+            .filter(|&(_, &count)| count >= self.min_group_size)
+            .map(|(&cell, _)| cell)
+            .collect::<Vec<_>>();
+        large_groups.sort();
+
+        cells
+            .iter()
+            .map(|cell| {
+                let cell = if groups[cell.as_slice()] >= self.min_group_size {
+                    cell.as_slice()
+                } else {
+                    large_groups
+                        .iter()
+                        .copied()
+                        .min_by_key(|other| {
+                            other
+                                .iter()
+                                .zip(cell)
+                                .map(|(a, b)| (a - b).pow(2))
+                                .sum::<i64>()
+                        })
+                        .unwrap_or(cell)
+                };
+                cell.iter().map(|&c| c as f32 * self.grid_size).collect()
+            })
+            .collect()
+    }
+
+    pub fn apply(&self, drawing: &mut DrawingEuclidean2d<NodeIndex, f32>) {
+        let points = (0..drawing.len())
+            .map(|i| {
+                let MetricEuclidean2d(x, y) = *drawing.raw_entry(i);
+                vec![x, y]
+            })
+            .collect::<Vec<_>>();
+        for (i, p) in self.snap(&points).into_iter().enumerate() {
+            *drawing.raw_entry_mut(i) = MetricEuclidean2d(p[0], p[1]);
+        }
+    }
+
+    pub fn apply_euclidean(&self, drawing: &mut DrawingEuclidean<NodeIndex, f32>) {
+        let points = (0..drawing.len())
+            .map(|i| drawing.raw_entry(i).0.clone())
+            .collect::<Vec<_>>();
+        for (i, p) in self.snap(&points).into_iter().enumerate() {
+            drawing.raw_entry_mut(i).0 = p;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stochastic_rounding() {
+        let mut graph = Graph::<(), (), Undirected>::new_undirected();
+        let nodes = (0..50).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut rounding = StochasticRounding::new(40., 0);
+        rounding.min_group_size = 3;
+
+        let mut a = DrawingEuclidean2d::initial_placement(&graph);
+        let mut b = DrawingEuclidean2d::initial_placement(&graph);
+        rounding.apply(&mut a);
+        rounding.apply(&mut b);
+        let mut groups = HashMap::new();
+        for &u in nodes.iter() {
+            let (x, y) = (a.x(u).unwrap(), a.y(u).unwrap());
+            assert_eq!((x, y), (b.x(u).unwrap(), b.y(u).unwrap()));
+            assert_eq!(x % 40., 0.);
+            assert_eq!(y % 40., 0.);
+            *groups.entry((x as i64, y as i64)).or_insert(0) += 1;
+        }
+        assert!(groups.len() > 1);
+        assert!(groups.values().all(|&count| count >= 3));
+    }
+
+    #[test]
+    fn test_stochastic_rounding_without_large_groups() {
+        let mut graph = Graph::<(), (), Undirected>::new_undirected();
+        let nodes = (0..3).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut drawing = DrawingEuclidean2d::new(&graph);
+        for (&u, x) in nodes.iter().zip([100., 200., 300.]) {
+            drawing.set_x(u, x);
+            drawing.set_y(u, -x);
+        }
+        let mut rounding = StochasticRounding::new(10., 0);
+        rounding.min_group_size = 5;
+        rounding.apply(&mut drawing);
+        for (&u, x) in nodes.iter().zip([100., 200., 300.]) {
+            assert_eq!((drawing.x(u).unwrap(), drawing.y(u).unwrap()), (x, -x));
+        }
+
+        let mut drawing = DrawingEuclidean::new(&graph, 3);
+        for (i, &u) in nodes.iter().enumerate() {
+            for d in 0..3 {
+                drawing.set(u, d, (i * 10 + d) as f32 * 10.);
+            }
+        }
+        rounding.apply_euclidean(&mut drawing);
+        for (i, &u) in nodes.iter().enumerate() {
+            for d in 0..3 {
+                assert_eq!(drawing.get(u, d), Some((i * 10 + d) as f32 * 10.));
+            }
+        }
+    }
+}
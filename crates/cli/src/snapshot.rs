@@ -0,0 +1,197 @@
+use petgraph::prelude::*;
+use petgraph_drawing::{Drawing, DrawingEuclidean2d};
+use serde::Serialize;
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    sync::mpsc::{sync_channel, Receiver, SyncSender},
+    thread::{spawn, JoinHandle},
+};
+
+#[derive(Serialize)]
+struct NodePosition {
+    id: usize,
+    x: f32,
+    y: f32,
+}
+
+#[derive(Serialize)]
+struct Snapshot {
+    iteration: usize,
+    nodes: Vec<NodePosition>,
+}
+
+pub enum SnapshotTarget {
+    NumberedFiles(String),
+    Ndjson(String),
+}
+
+pub struct SnapshotWriter {
+    pub interval: usize,
+    iteration: usize,
+    sender: Option<SyncSender<Snapshot>>,
+    handle: Option<JoinHandle<io::Result<()>>>,
+}
+
+fn write_snapshots(target: SnapshotTarget, receiver: Receiver<Snapshot>) -> io::Result<()> {
+    match target {
+        SnapshotTarget::NumberedFiles(prefix) => {
+            for (i, snapshot) in receiver.into_iter().enumerate() {
+                let file = File::create(format!("{}{:05}.json", prefix, i))?;
+                let mut writer = BufWriter::new(file);
+                serde_json::to_writer(&mut writer, &snapshot)?;
+                writer.flush()?;
+            }
+        }
+        SnapshotTarget::Ndjson(path) => {
+            let mut writer = BufWriter::new(File::create(path)?);
+            for snapshot in receiver {
+                serde_json::to_writer(&mut writer, &snapshot)?;
+                writeln!(writer)?;
+            }
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+impl SnapshotWriter {
+    pub fn new(target: SnapshotTarget, interval: usize) -> SnapshotWriter {
+        SnapshotWriter::with_capacity(target, interval, 4)
+    }
+
+    /// Creates a writer whose queue holds at most `capacity` pending
+    /// snapshots. `step` blocks while the queue is full.
+    pub fn with_capacity(
+        target: SnapshotTarget,
+        interval: usize,
+        capacity: usize,
+    ) -> SnapshotWriter {
+        let (sender, receiver) = sync_channel::<Snapshot>(capacity);
+        let handle = spawn(move || write_snapshots(target, receiver));
+        SnapshotWriter {
+            interval,
+            iteration: 0,
+            sender: Some(sender),
+            handle: Some(handle),
+        }
+    }
+
+    pub fn step(&mut self, drawing: &DrawingEuclidean2d<NodeIndex, f32>) {
+        if self.interval > 0 && self.iteration % self.interval == 0 {
+            if let Some(sender) = &self.sender {
+                let nodes = (0..drawing.len())
+                    .map(|i| {
+                        let p = drawing.raw_entry(i);
+                        NodePosition {
+                            id: drawing.node_id(i).index(),
+                            x: p.0,
+                            y: p.1,
+                        }
+                    })
+                    .collect();
+                let snapshot = Snapshot {
+                    iteration: self.iteration,
+                    nodes,
+                };
+                if sender.send(snapshot).is_err() {
+                    // The writer thread stopped on an error, which finish reports.
+                    self.sender = None;
+                }
+            }
+        }
+        self.iteration += 1;
+    }
+
+    /// Waits for the pending snapshots to be written and returns the first
+    /// error the writer thread ran into.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.close()
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        self.sender.take();
+        match self.handle.take() {
+            Some(handle) => handle.join().unwrap_or_else(|_| {
+                Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    "snapshot writer thread panicked",
+                ))
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for SnapshotWriter {
+    fn drop(&mut self) {
+        let _ = self.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_snapshot_writer() {
+        let mut graph = Graph::<(), (), Undirected>::new_undirected();
+        let u = graph.add_node(());
+        graph.add_node(());
+        let mut drawing = DrawingEuclidean2d::initial_placement(&graph);
+        let dir = std::env::temp_dir();
+
+        let path = dir.join("egraph-cli-test-snapshot.ndjson");
+        let mut writer =
+            SnapshotWriter::new(SnapshotTarget::Ndjson(path.to_str().unwrap().into()), 2);
+        for i in 0..5 {
+            drawing.set_x(u, i as f32);
+            writer.step(&drawing);
+        }
+        writer.finish().unwrap();
+        let lines = fs::read_to_string(&path).unwrap();
+        let snapshots = lines
+            .lines()
+            .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(snapshots.len(), 3);
+        assert_eq!(snapshots[2]["iteration"], 4);
+        assert_eq!(snapshots[2]["nodes"][0]["x"], 4.);
+        let _ = fs::remove_file(&path);
+
+        let prefix = dir.join("egraph-cli-test-snapshot-");
+        let prefix = prefix.to_str().unwrap();
+        let mut writer = SnapshotWriter::new(SnapshotTarget::NumberedFiles(prefix.into()), 3);
+        for _ in 0..4 {
+            writer.step(&drawing);
+        }
+        drop(writer);
+        for i in 0..2 {
+            let path = format!("{}{:05}.json", prefix, i);
+            assert!(fs::metadata(&path).is_ok());
+            let _ = fs::remove_file(&path);
+        }
+    }
+
+    #[test]
+    fn test_snapshot_writer_error() {
+        let mut graph = Graph::<(), (), Undirected>::new_undirected();
+        graph.add_node(());
+        let drawing = DrawingEuclidean2d::initial_placement(&graph);
+        let path = std::env::temp_dir().join("egraph-cli-missing-dir/snapshot.ndjson");
+        let target = || SnapshotTarget::Ndjson(path.to_str().unwrap().into());
+
+        let mut writer = SnapshotWriter::with_capacity(target(), 1, 1);
+        for _ in 0..5 {
+            writer.step(&drawing);
+        }
+        assert!(writer.finish().is_err());
+
+        let mut writer = SnapshotWriter::with_capacity(target(), 1, 1);
+        for _ in 0..5 {
+            writer.step(&drawing);
+        }
+        drop(writer);
+    }
+}
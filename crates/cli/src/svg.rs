@@ -0,0 +1,177 @@
+use petgraph::{graph::IndexType, prelude::*, EdgeType};
+use petgraph_drawing::DrawingEuclidean2d;
+use petgraph_edge_bundling_fdeb::BundledEdge;
+use std::{collections::HashMap, fmt::Write};
+
+pub type EdgePoints<Ix> = HashMap<EdgeIndex<Ix>, Vec<(f32, f32)>>;
+
+pub struct SvgOptions {
+    pub node_radius: f32,
+    pub node_color: String,
+    pub edge_color: String,
+    pub edge_opacity: f32,
+    pub edge_width: f32,
+    pub smoothing: bool,
+    pub margin: f32,
+}
+
+impl SvgOptions {
+    pub fn new() -> SvgOptions {
+        SvgOptions {
+            node_radius: 3.,
+            node_color: "#1f77b4".into(),
+            edge_color: "#888".into(),
+            edge_opacity: 0.5,
+            edge_width: 1.,
+            smoothing: true,
+            margin: 10.,
+        }
+    }
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn edge_path(points: &[(f32, f32)], smoothing: bool) -> String {
+    let mut d = String::new();
+    if let Some(&(x, y)) = points.first() {
+        write!(d, "M{},{}", x, y).unwrap();
+    }
+    if smoothing && points.len() > 2 {
+        for [_, c1, c2, p] in BundledEdge::new(points.to_vec()).cubic_bezier_segments() {
+            write!(d, " C{},{} {},{} {},{}", c1.0, c1.1, c2.0, c2.1, p.0, p.1).unwrap();
+        }
+    } else {
+        for &(x, y) in points.iter().skip(1) {
+            write!(d, " L{},{}", x, y).unwrap();
+        }
+    }
+    d
+}
+
+pub fn render_svg<N, E, Ty, Ix>(
+    graph: &Graph<N, E, Ty, Ix>,
+    drawing: &DrawingEuclidean2d<NodeIndex<Ix>, f32>,
+    bundled_edges: Option<&EdgePoints<Ix>>,
+    options: &SvgOptions,
+) -> String
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let edges = graph
+        .edge_indices()
+        .map(
+            |e| match bundled_edges.and_then(|bundled_edges| bundled_edges.get(&e)) {
+                Some(points) => points.clone(),
+                None => {
+                    let (u, v) = graph.edge_endpoints(e).unwrap();
+                    vec![
+                        (drawing.x(u).unwrap(), drawing.y(u).unwrap()),
+                        (drawing.x(v).unwrap(), drawing.y(v).unwrap()),
+                    ]
+                }
+            },
+        )
+        .collect::<Vec<_>>();
+    let nodes = graph
+        .node_indices()
+        .map(|u| (drawing.x(u).unwrap(), drawing.y(u).unwrap()))
+        .collect::<Vec<_>>();
+
+    let mut left = f32::INFINITY;
+    let mut right = f32::NEG_INFINITY;
+    let mut top = f32::INFINITY;
+    let mut bottom = f32::NEG_INFINITY;
+    for &(x, y) in nodes.iter().chain(edges.iter().flatten()) {
+        left = left.min(x);
+        right = right.max(x);
+        top = top.min(y);
+        bottom = bottom.max(y);
+    }
+    if left > right {
+        left = 0.;
+        right = 0.;
+        top = 0.;
+        bottom = 0.;
+    }
+    let padding = options.margin + options.node_radius;
+    let width = right - left + 2. * padding;
+    let height = bottom - top + 2. * padding;
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+        width,
+        height,
+        left - padding,
+        top - padding,
+        width,
+        height
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        r#"<g fill="none" stroke="{}" stroke-opacity="{}" stroke-width="{}">"#,
+        options.edge_color, options.edge_opacity, options.edge_width
+    )
+    .unwrap();
+    for points in edges.iter() {
+        writeln!(
+            svg,
+            r#"<path d="{}"/>"#,
+            edge_path(points, options.smoothing)
+        )
+        .unwrap();
+    }
+    writeln!(svg, "</g>").unwrap();
+    writeln!(svg, r#"<g fill="{}">"#, options.node_color).unwrap();
+    for &(x, y) in nodes.iter() {
+        writeln!(
+            svg,
+            r#"<circle cx="{}" cy="{}" r="{}"/>"#,
+            x, y, options.node_radius
+        )
+        .unwrap();
+    }
+    writeln!(svg, "</g>").unwrap();
+    writeln!(svg, "</svg>").unwrap();
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_svg() {
+        let mut graph = Graph::new_undirected();
+        let u = graph.add_node(());
+        let v = graph.add_node(());
+        let w = graph.add_node(());
+        let e = graph.add_edge(u, v, ());
+        graph.add_edge(v, w, ());
+        let drawing = DrawingEuclidean2d::initial_placement(&graph);
+        let mut bundled_edges = HashMap::new();
+        bundled_edges.insert(e, vec![(0., 0.), (5., 5.), (10., 0.)]);
+
+        let options = SvgOptions::new();
+        let svg = render_svg(&graph, &drawing, Some(&bundled_edges), &options);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<circle").count(), 3);
+        assert_eq!(svg.matches("<path").count(), 2);
+        assert_eq!(svg.matches(" C").count(), 2);
+
+        let options = SvgOptions {
+            smoothing: false,
+            ..SvgOptions::new()
+        };
+        let svg = render_svg(&graph, &drawing, Some(&bundled_edges), &options);
+        assert_eq!(svg.matches(" C").count(), 0);
+        assert_eq!(svg.matches(" L").count(), 3);
+    }
+}
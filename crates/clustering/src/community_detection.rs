@@ -0,0 +1,11 @@
+use petgraph::visit::IntoNodeIdentifiers;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+pub trait CommunityDetection<G>
+where
+    G: IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    fn detect_communities(&self, graph: G) -> HashMap<G::NodeId, usize>;
+}
@@ -0,0 +1,138 @@
+use crate::{modularity, relabel_communities, CommunityDetection};
+use petgraph::stable_graph::StableUnGraph;
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{
+    EdgeCount, EdgeRef, GraphProp, IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers,
+};
+use petgraph_algorithm_centrality::edge_betweenness_centrality;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+fn components(graph: &StableUnGraph<(), ()>) -> Vec<usize> {
+    let n = graph.node_count();
+    let mut union_find = UnionFind::new(n);
+    for e in graph.edge_references() {
+        union_find.union(e.source().index(), e.target().index());
+    }
+    union_find.into_labeling()
+}
+
+fn count(labels: &[usize]) -> usize {
+    labels.iter().enumerate().filter(|&(i, &l)| i == l).count()
+}
+
+pub struct GirvanNewman {
+    pub number_of_communities: Option<usize>,
+}
+
+impl GirvanNewman {
+    pub fn new() -> GirvanNewman {
+        GirvanNewman {
+            number_of_communities: None,
+        }
+    }
+
+    pub fn with_number_of_communities(k: usize) -> GirvanNewman {
+        GirvanNewman {
+            number_of_communities: Some(k),
+        }
+    }
+}
+
+impl Default for GirvanNewman {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G> CommunityDetection<G> for GirvanNewman
+where
+    G: EdgeCount + GraphProp + IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    fn detect_communities(&self, graph: G) -> HashMap<G::NodeId, usize> {
+        let nodes = graph.node_identifiers().collect::<Vec<_>>();
+        let mut working = StableUnGraph::with_capacity(nodes.len(), graph.edge_count());
+        let indices = nodes
+            .iter()
+            .map(|&u| (u, working.add_node(())))
+            .collect::<HashMap<_, _>>();
+        for e in graph.edge_references() {
+            if e.source() != e.target() {
+                working.add_edge(indices[&e.source()], indices[&e.target()], ());
+            }
+        }
+        let to_communities = |labels: &[usize]| {
+            nodes
+                .iter()
+                .map(|&u| (u, labels[indices[&u].index()]))
+                .collect::<HashMap<_, _>>()
+        };
+
+        let mut labels = components(&working);
+        let mut best: Option<(f32, Vec<usize>)> = None;
+        loop {
+            if let Some(k) = self.number_of_communities {
+                if count(&labels) >= k {
+                    break;
+                }
+            } else {
+                let q = modularity(graph, &to_communities(&labels));
+                if best.as_ref().map_or(true, |&(b, _)| q > b) {
+                    best = Some((q, labels.clone()));
+                }
+            }
+            let c = count(&labels);
+            while count(&labels) == c && working.edge_count() > 0 {
+                let (e, _) = edge_betweenness_centrality(&working)
+                    .into_iter()
+                    .max_by(|(e1, c1), (e2, c2)| c1.total_cmp(c2).then(e2.cmp(e1)))
+                    .unwrap();
+                working.remove_edge(e);
+                labels = components(&working);
+            }
+            if count(&labels) == c {
+                break;
+            }
+        }
+        if let Some((_, best)) = best {
+            labels = best;
+        }
+        let communities = to_communities(&labels);
+        relabel_communities(graph, &communities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_girvan_newman() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..9).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for c in 0..3 {
+            for i in 0..3 {
+                graph.add_edge(nodes[c * 3 + i], nodes[c * 3 + (i + 1) % 3], ());
+            }
+            graph.add_edge(nodes[c * 3], nodes[(c * 3 + 3) % 9], ());
+        }
+
+        let communities = GirvanNewman::new().detect_communities(&graph);
+        for c in 0..3 {
+            for i in 1..3 {
+                assert_eq!(communities[&nodes[c * 3]], communities[&nodes[c * 3 + i]]);
+            }
+            assert_ne!(
+                communities[&nodes[c * 3]],
+                communities[&nodes[(c * 3 + 3) % 9]]
+            );
+        }
+
+        let communities = GirvanNewman::with_number_of_communities(1).detect_communities(&graph);
+        assert!(communities.values().all(|&c| c == 0));
+        let communities = GirvanNewman::with_number_of_communities(9).detect_communities(&graph);
+        assert_eq!(communities.values().max(), Some(&8));
+    }
+}
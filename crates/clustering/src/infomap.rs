@@ -0,0 +1,356 @@
+use crate::CommunityDetection;
+use petgraph::visit::{IntoNeighbors, IntoNodeIdentifiers};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+fn plogp(p: f64) -> f64 {
+    if p > 0. {
+        p * p.log2()
+    } else {
+        0.
+    }
+}
+
+struct FlowGraph {
+    flow: Vec<f64>,
+    teleport_flow: Vec<f64>,
+    size: Vec<usize>,
+    out_links: Vec<Vec<(usize, f64)>>,
+    in_links: Vec<Vec<(usize, f64)>>,
+}
+
+impl FlowGraph {
+    fn len(&self) -> usize {
+        self.flow.len()
+    }
+
+    fn aggregate(&self, modules: &[usize], m: usize) -> FlowGraph {
+        let mut flow = vec![0.; m];
+        let mut teleport_flow = vec![0.; m];
+        let mut size = vec![0; m];
+        let mut links = vec![HashMap::new(); m];
+        for u in 0..self.len() {
+            let i = modules[u];
+            flow[i] += self.flow[u];
+            teleport_flow[i] += self.teleport_flow[u];
+            size[i] += self.size[u];
+            for &(v, f) in self.out_links[u].iter() {
+                let j = modules[v];
+                if i != j {
+                    *links[i].entry(j).or_insert(0.) += f;
+                }
+            }
+        }
+        let mut out_links = vec![vec![]; m];
+        let mut in_links = vec![vec![]; m];
+        for (i, links) in links.into_iter().enumerate() {
+            let mut links = links.into_iter().collect::<Vec<_>>();
+            links.sort_by_key(|&(j, _)| j);
+            for (j, f) in links {
+                out_links[i].push((j, f));
+                in_links[j].push((i, f));
+            }
+        }
+        FlowGraph {
+            flow,
+            teleport_flow,
+            size,
+            out_links,
+            in_links,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Module {
+    flow: f64,
+    teleport_flow: f64,
+    size: usize,
+    exit_link_flow: f64,
+}
+
+impl Module {
+    fn exit_flow(&self, n: usize) -> f64 {
+        self.teleport_flow * (n - self.size) as f64 / n as f64 + self.exit_link_flow
+    }
+}
+
+struct Codelength {
+    n: usize,
+    sum_exit: f64,
+    sum_plogp_exit: f64,
+    sum_plogp_exit_flow: f64,
+}
+
+impl Codelength {
+    fn new(modules: &[Module], n: usize) -> Codelength {
+        let mut codelength = Codelength {
+            n,
+            sum_exit: 0.,
+            sum_plogp_exit: 0.,
+            sum_plogp_exit_flow: 0.,
+        };
+        for module in modules.iter() {
+            codelength.add(module, 1.);
+        }
+        codelength
+    }
+
+    fn add(&mut self, module: &Module, sign: f64) {
+        let q = module.exit_flow(self.n);
+        self.sum_exit += sign * q;
+        self.sum_plogp_exit += sign * plogp(q);
+        self.sum_plogp_exit_flow += sign * plogp(q + module.flow);
+    }
+
+    fn value(&self) -> f64 {
+        plogp(self.sum_exit) - 2. * self.sum_plogp_exit + self.sum_plogp_exit_flow
+    }
+}
+
+pub struct Infomap {
+    pub teleportation: f64,
+    pub pagerank_iterations: usize,
+    pub max_passes: usize,
+    pub eps: f64,
+}
+
+impl Infomap {
+    pub fn new() -> Infomap {
+        Infomap {
+            teleportation: 0.15,
+            pagerank_iterations: 100,
+            max_passes: 100,
+            eps: 1e-10,
+        }
+    }
+
+    fn flow_graph<G>(&self, graph: G, indices: &HashMap<G::NodeId, usize>) -> FlowGraph
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers,
+        G::NodeId: Eq + Hash,
+    {
+        let n = indices.len();
+        let alpha = self.teleportation;
+        let mut neighbors = vec![vec![]; n];
+        for u in graph.node_identifiers() {
+            neighbors[indices[&u]] = graph.neighbors(u).map(|v| indices[&v]).collect();
+        }
+
+        let mut flow = vec![1. / n as f64; n];
+        for _ in 0..self.pagerank_iterations {
+            let mut dangling = 0.;
+            let mut next = vec![0.; n];
+            for u in 0..n {
+                if neighbors[u].is_empty() {
+                    dangling += flow[u];
+                } else {
+                    let f = flow[u] / neighbors[u].len() as f64;
+                    for &v in neighbors[u].iter() {
+                        next[v] += f;
+                    }
+                }
+            }
+            for x in next.iter_mut() {
+                *x = alpha / n as f64 + (1. - alpha) * (*x + dangling / n as f64);
+            }
+            flow = next;
+        }
+
+        let teleport_flow = (0..n)
+            .map(|u| {
+                if neighbors[u].is_empty() {
+                    flow[u]
+                } else {
+                    alpha * flow[u]
+                }
+            })
+            .collect();
+        let mut out_links = vec![vec![]; n];
+        let mut in_links = vec![vec![]; n];
+        for u in 0..n {
+            for &v in neighbors[u].iter() {
+                if u != v {
+                    let f = (1. - alpha) * flow[u] / neighbors[u].len() as f64;
+                    out_links[u].push((v, f));
+                    in_links[v].push((u, f));
+                }
+            }
+        }
+        FlowGraph {
+            flow,
+            teleport_flow,
+            size: vec![1; n],
+            out_links,
+            in_links,
+        }
+    }
+
+    fn move_nodes(&self, graph: &FlowGraph, n: usize) -> Option<Vec<usize>> {
+        let m = graph.len();
+        let mut assignment = (0..m).collect::<Vec<_>>();
+        let mut modules = (0..m)
+            .map(|u| Module {
+                flow: graph.flow[u],
+                teleport_flow: graph.teleport_flow[u],
+                size: graph.size[u],
+                exit_link_flow: graph.out_links[u].iter().map(|&(_, f)| f).sum(),
+            })
+            .collect::<Vec<_>>();
+        let mut improved = false;
+        for _ in 0..self.max_passes {
+            let mut codelength = Codelength::new(&modules, n);
+            let mut moved = false;
+            for u in 0..m {
+                let a = assignment[u];
+                let mut out_flow = HashMap::new();
+                let mut in_flow = HashMap::new();
+                for &(v, f) in graph.out_links[u].iter() {
+                    *out_flow.entry(assignment[v]).or_insert(0.) += f;
+                }
+                for &(v, f) in graph.in_links[u].iter() {
+                    *in_flow.entry(assignment[v]).or_insert(0.) += f;
+                }
+                let total_out = graph.out_links[u].iter().map(|&(_, f)| f).sum::<f64>();
+                let out_to = |i: usize| out_flow.get(&i).copied().unwrap_or(0.);
+                let in_from = |i: usize| in_flow.get(&i).copied().unwrap_or(0.);
+
+                let old_a = modules[a];
+                let new_a = Module {
+                    flow: old_a.flow - graph.flow[u],
+                    teleport_flow: old_a.teleport_flow - graph.teleport_flow[u],
+                    size: old_a.size - graph.size[u],
+                    exit_link_flow: old_a.exit_link_flow - (total_out - out_to(a)) + in_from(a),
+                };
+                let current = codelength.value();
+
+                let mut candidates = out_flow
+                    .keys()
+                    .chain(in_flow.keys())
+                    .copied()
+                    .collect::<Vec<_>>();
+                candidates.sort_unstable();
+                candidates.dedup();
+                let mut best = None;
+                let mut best_value = current - self.eps;
+                for b in candidates {
+                    if b == a {
+                        continue;
+                    }
+                    let old_b = modules[b];
+                    let new_b = Module {
+                        flow: old_b.flow + graph.flow[u],
+                        teleport_flow: old_b.teleport_flow + graph.teleport_flow[u],
+                        size: old_b.size + graph.size[u],
+                        exit_link_flow: old_b.exit_link_flow + (total_out - out_to(b)) - in_from(b),
+                    };
+                    codelength.add(&old_a, -1.);
+                    codelength.add(&old_b, -1.);
+                    codelength.add(&new_a, 1.);
+                    codelength.add(&new_b, 1.);
+                    let value = codelength.value();
+                    codelength.add(&new_b, -1.);
+                    codelength.add(&new_a, -1.);
+                    codelength.add(&old_b, 1.);
+                    codelength.add(&old_a, 1.);
+                    if value < best_value {
+                        best_value = value;
+                        best = Some((b, new_b));
+                    }
+                }
+                if let Some((b, new_b)) = best {
+                    codelength.add(&modules[a], -1.);
+                    codelength.add(&modules[b], -1.);
+                    codelength.add(&new_a, 1.);
+                    codelength.add(&new_b, 1.);
+                    modules[a] = new_a;
+                    modules[b] = new_b;
+                    assignment[u] = b;
+                    moved = true;
+                    improved = true;
+                }
+            }
+            if !moved {
+                break;
+            }
+        }
+        if improved {
+            Some(assignment)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for Infomap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G> CommunityDetection<G> for Infomap
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    fn detect_communities(&self, graph: G) -> HashMap<G::NodeId, usize> {
+        let indices = graph
+            .node_identifiers()
+            .enumerate()
+            .map(|(i, u)| (u, i))
+            .collect::<HashMap<_, _>>();
+        let n = indices.len();
+        if n == 0 {
+            return HashMap::new();
+        }
+        let mut flow_graph = self.flow_graph(graph, &indices);
+        let mut communities = (0..n).collect::<Vec<_>>();
+        while let Some(assignment) = self.move_nodes(&flow_graph, n) {
+            let mut labels = HashMap::new();
+            let assignment = assignment
+                .into_iter()
+                .map(|i| {
+                    let m = labels.len();
+                    *labels.entry(i).or_insert(m)
+                })
+                .collect::<Vec<_>>();
+            for c in communities.iter_mut() {
+                *c = assignment[*c];
+            }
+            flow_graph = flow_graph.aggregate(&assignment, labels.len());
+        }
+        indices
+            .into_iter()
+            .map(|(u, i)| (u, communities[i]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_infomap() {
+        let mut graph = Graph::<(), ()>::new();
+        let nodes = (0..12).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for c in 0..3 {
+            for i in 0..4 {
+                graph.add_edge(nodes[c * 4 + i], nodes[c * 4 + (i + 1) % 4], ());
+                graph.add_edge(nodes[c * 4 + i], nodes[c * 4 + (i + 2) % 4], ());
+            }
+            graph.add_edge(nodes[c * 4], nodes[(c * 4 + 4) % 12], ());
+        }
+        let communities = Infomap::new().detect_communities(&graph);
+        for c in 0..3 {
+            for i in 1..4 {
+                assert_eq!(communities[&nodes[c * 4]], communities[&nodes[c * 4 + i]]);
+            }
+            assert_ne!(
+                communities[&nodes[c * 4]],
+                communities[&nodes[(c * 4 + 4) % 12]]
+            );
+        }
+    }
+}
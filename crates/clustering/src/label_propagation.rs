@@ -0,0 +1,141 @@
+use crate::{relabel_communities, CommunityDetection};
+use petgraph::visit::{IntoNeighbors, IntoNodeIdentifiers};
+use rand::prelude::*;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+fn dominant_label<R: Rng>(
+    labels: &[usize],
+    neighbors: &[usize],
+    current: usize,
+    rng: &mut R,
+) -> usize {
+    let mut counts = HashMap::new();
+    for &v in neighbors {
+        *counts.entry(labels[v]).or_insert(0) += 1;
+    }
+    let Some(&max) = counts.values().max() else {
+        return current;
+    };
+    if counts.get(&current) == Some(&max) {
+        return current;
+    }
+    let mut candidates = counts
+        .into_iter()
+        .filter(|&(_, c)| c == max)
+        .map(|(l, _)| l)
+        .collect::<Vec<_>>();
+    candidates.sort_unstable();
+    *candidates.choose(rng).unwrap()
+}
+
+pub struct LabelPropagation {
+    pub max_iterations: usize,
+    pub synchronous: bool,
+    pub seed: u64,
+}
+
+impl LabelPropagation {
+    pub fn new() -> LabelPropagation {
+        LabelPropagation {
+            max_iterations: 100,
+            synchronous: false,
+            seed: 0,
+        }
+    }
+}
+
+impl Default for LabelPropagation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G> CommunityDetection<G> for LabelPropagation
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    fn detect_communities(&self, graph: G) -> HashMap<G::NodeId, usize> {
+        let nodes = graph.node_identifiers().collect::<Vec<_>>();
+        let indices = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i))
+            .collect::<HashMap<_, _>>();
+        let neighbors = nodes
+            .iter()
+            .map(|&u| graph.neighbors(u).map(|v| indices[&v]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let n = nodes.len();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut labels = (0..n).collect::<Vec<_>>();
+        let mut order = (0..n).collect::<Vec<_>>();
+        for _ in 0..self.max_iterations {
+            let mut changed = false;
+            if self.synchronous {
+                let next = (0..n)
+                    .map(|u| dominant_label(&labels, &neighbors[u], labels[u], &mut rng))
+                    .collect::<Vec<_>>();
+                changed = next != labels;
+                labels = next;
+            } else {
+                order.shuffle(&mut rng);
+                for &u in order.iter() {
+                    let label = dominant_label(&labels, &neighbors[u], labels[u], &mut rng);
+                    if label != labels[u] {
+                        labels[u] = label;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        let communities = nodes
+            .iter()
+            .zip(labels)
+            .map(|(&u, l)| (u, l))
+            .collect::<HashMap<_, _>>();
+        relabel_communities(graph, &communities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_label_propagation() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..10).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for c in 0..2 {
+            for i in 0..5 {
+                for j in i + 1..5 {
+                    graph.add_edge(nodes[c * 5 + i], nodes[c * 5 + j], ());
+                }
+            }
+        }
+        graph.add_edge(nodes[0], nodes[5], ());
+
+        for synchronous in [false, true] {
+            let label_propagation = LabelPropagation {
+                synchronous,
+                seed: 7,
+                ..LabelPropagation::new()
+            };
+            let communities = label_propagation.detect_communities(&graph);
+            assert_eq!(communities, label_propagation.detect_communities(&graph));
+            if !synchronous {
+                for c in 0..2 {
+                    for i in 1..5 {
+                        assert_eq!(communities[&nodes[c * 5]], communities[&nodes[c * 5 + i]]);
+                    }
+                }
+                assert_ne!(communities[&nodes[0]], communities[&nodes[5]]);
+            }
+        }
+    }
+}
@@ -1,10 +1,56 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_clustering::{modularity, CommunityDetection, Louvain};
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for &(i, j) in &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3)] {
+//!     graph.add_edge(nodes[i], nodes[j], ());
+//! }
+//! let communities = Louvain::new().detect_communities(&graph);
+//! assert_eq!(communities.len(), 6);
+//! assert_eq!(communities[&nodes[0]], communities[&nodes[1]]);
+//! assert_ne!(communities[&nodes[0]], communities[&nodes[5]]);
+//! assert!(modularity(&graph, &communities) > 0.);
+//! ```
+mod community_detection;
+mod girvan_newman;
+mod infomap;
+mod label_propagation;
+mod louvain;
+mod motif;
+mod spectral_clustering;
+
 use petgraph::graph::{EdgeIndex, Graph, IndexType, NodeIndex};
-use petgraph::visit::{EdgeCount, IntoNeighbors, IntoNodeIdentifiers};
+use petgraph::visit::{
+    EdgeCount, EdgeRef, GraphProp, IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers,
+};
 use petgraph::EdgeType;
 use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
+pub use community_detection::CommunityDetection;
+pub use girvan_newman::GirvanNewman;
+pub use infomap::Infomap;
+pub use label_propagation::LabelPropagation;
+pub use louvain::Louvain;
+pub use motif::{
+    cliques, collapse_motifs, fans, parallel_paths, Motif, MotifKind, SummaryGraph, SummaryNode,
+};
+pub use spectral_clustering::SpectralClustering;
+
 pub fn louvain_step<G>(graph: &G) -> Option<HashMap<G::NodeId, G::NodeId>>
+where
+    G: EdgeCount + IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    louvain_step_with_resolution(graph, 1.)
+}
+
+pub fn louvain_step_with_resolution<G>(
+    graph: &G,
+    resolution: f32,
+) -> Option<HashMap<G::NodeId, G::NodeId>>
 where
     G: EdgeCount + IntoNeighbors + IntoNodeIdentifiers,
     G::NodeId: Eq + Hash,
@@ -26,11 +72,14 @@ where
     let mut improve = false;
 
     for u in graph.node_identifiers() {
-        let mut neighboring_communities = HashSet::new();
+        let mut visited = HashSet::new();
+        visited.insert(communities[&u]);
+        let mut neighboring_communities = vec![];
         for v in graph.neighbors(u) {
-            neighboring_communities.insert(communities[&v]);
+            if visited.insert(communities[&v]) {
+                neighboring_communities.push(communities[&v]);
+            }
         }
-        neighboring_communities.remove(&communities[&u]);
         for &c in neighboring_communities.iter() {
             let prev_c = communities[&u];
             community_nodes.get_mut(&prev_c).unwrap().remove(&u);
@@ -41,7 +90,7 @@ where
                     k_in += 1.;
                 }
             }
-            let delta_q = 0.5 * (k_in - k[&u] * sigma_total[&c] / m) / m;
+            let delta_q = 0.5 * (k_in - resolution * k[&u] * sigma_total[&c] / m) / m;
             if delta_q > 0. {
                 *sigma_total.get_mut(&c).unwrap() += k[&u];
                 *sigma_total.get_mut(&prev_c).unwrap() -= k[&u];
@@ -60,6 +109,111 @@ where
     }
 }
 
+pub fn relabel_communities<G, C>(
+    graph: G,
+    communities: &HashMap<G::NodeId, C>,
+) -> HashMap<G::NodeId, usize>
+where
+    G: IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+    C: Eq + Hash,
+{
+    let mut order = vec![];
+    let mut sizes = HashMap::new();
+    for u in graph.node_identifiers() {
+        if let Some(c) = communities.get(&u) {
+            let size = sizes.entry(c).or_insert_with(|| {
+                order.push(c);
+                0
+            });
+            *size += 1;
+        }
+    }
+    order.sort_by_key(|c| std::cmp::Reverse(sizes[c]));
+    let labels = order
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| (c, i))
+        .collect::<HashMap<_, _>>();
+    graph
+        .node_identifiers()
+        .filter_map(|u| communities.get(&u).map(|c| (u, labels[c])))
+        .collect()
+}
+
+pub fn modularity<G, C>(graph: G, communities: &HashMap<G::NodeId, C>) -> f32
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+    C: Eq + Hash,
+{
+    modularity_with_resolution(graph, communities, 1.)
+}
+
+/// Computes the modularity of `communities` with resolution `resolution`.
+/// Nodes that are missing from `communities` are treated as singleton
+/// communities. Directed graphs use the directed modularity of Leicht and
+/// Newman, with out-degrees and in-degrees in the null model.
+pub fn modularity_with_resolution<G, C>(
+    graph: G,
+    communities: &HashMap<G::NodeId, C>,
+    resolution: f32,
+) -> f32
+where
+    G: GraphProp + IntoEdgeReferences + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+    C: Eq + Hash,
+{
+    let mut labels = HashMap::new();
+    let mut next = 0;
+    let mut new_label = || {
+        next += 1;
+        next - 1
+    };
+    let community = graph
+        .node_identifiers()
+        .map(|u| {
+            let c = match communities.get(&u) {
+                Some(c) => *labels.entry(c).or_insert_with(&mut new_label),
+                None => new_label(),
+            };
+            (u, c)
+        })
+        .collect::<HashMap<_, _>>();
+    let mut m = 0.;
+    let mut internal = 0.;
+    let mut out_degree = HashMap::new();
+    let mut in_degree = HashMap::new();
+    for e in graph.edge_references() {
+        let c = community[&e.source()];
+        let d = community[&e.target()];
+        m += 1.;
+        if c == d {
+            internal += 1.;
+        }
+        *out_degree.entry(c).or_insert(0.) += 1.;
+        *in_degree.entry(d).or_insert(0.) += 1.;
+    }
+    if m == 0. {
+        return 0.;
+    }
+    let expected = if graph.is_directed() {
+        out_degree
+            .iter()
+            .map(|(c, &d_out): (_, &f32)| d_out * in_degree.get(c).copied().unwrap_or(0.))
+            .sum::<f32>()
+            / (m * m)
+    } else {
+        out_degree
+            .iter()
+            .map(|(c, &d_out): (_, &f32)| {
+                ((d_out + in_degree.get(c).copied().unwrap_or(0.)) / (2. * m)).powi(2)
+            })
+            .sum::<f32>()
+    };
+    internal / m - resolution * expected
+}
+
 pub fn coarsen<
     N1,
     N2,
@@ -103,20 +257,87 @@ pub fn coarsen<
         group_edges.entry(key).or_insert(vec![]).push(e);
     }
 
+    let mut group_ids = groups.keys().copied().collect::<Vec<_>>();
+    group_ids.sort_unstable();
+    let mut group_edge_keys = group_edges.keys().copied().collect::<Vec<_>>();
+    group_edge_keys.sort_unstable();
+
     let mut coarsened_graph = Graph::with_capacity(0, 0);
     let mut coarsened_node_ids = HashMap::new();
-    for (&group_id, node_ids) in groups.iter() {
+    for group_id in group_ids {
         coarsened_node_ids.insert(
             group_id,
-            coarsened_graph.add_node(shrink_node(graph, &node_ids)),
+            coarsened_graph.add_node(shrink_node(graph, &groups[&group_id])),
         );
     }
-    for (&(u, v), edge_ids) in group_edges.iter() {
+    for (u, v) in group_edge_keys {
         coarsened_graph.add_edge(
             coarsened_node_ids[&u],
             coarsened_node_ids[&v],
-            shrink_edge(graph, &edge_ids),
+            shrink_edge(graph, &group_edges[&(u, v)]),
         );
     }
     (coarsened_graph, coarsened_node_ids)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modularity() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for &(u, v) in &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3)] {
+            graph.add_edge(nodes[u], nodes[v], ());
+        }
+        let communities = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i / 3))
+            .collect::<HashMap<_, _>>();
+        assert!((modularity(&graph, &communities) - 5. / 14.).abs() < 1e-6);
+        let single = nodes.iter().map(|&u| (u, 0)).collect::<HashMap<_, _>>();
+        assert!(modularity(&graph, &single).abs() < 1e-6);
+
+        let mut partial = communities.clone();
+        partial.remove(&nodes[5]);
+        let mut separated = communities.clone();
+        separated.insert(nodes[5], 2);
+        assert!((modularity(&graph, &partial) - modularity(&graph, &separated)).abs() < 1e-6);
+        let singletons = nodes.iter().map(|&u| (u, u)).collect::<HashMap<_, _>>();
+        let empty = HashMap::<_, usize>::new();
+        assert!((modularity(&graph, &empty) - modularity(&graph, &singletons)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_modularity_directed() {
+        let mut graph = Graph::new();
+        let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for &(u, v) in &[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (0, 3)] {
+            graph.add_edge(nodes[u], nodes[v], ());
+        }
+        let communities = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i / 3))
+            .collect::<HashMap<_, _>>();
+        assert!((modularity(&graph, &communities) - 18. / 49.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_relabel_communities() {
+        let mut graph = Graph::<(), ()>::new();
+        let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let communities = nodes
+            .iter()
+            .zip([7, 3, 3, 5, 3, 5])
+            .map(|(&u, c)| (u, c))
+            .collect::<HashMap<_, _>>();
+        let labels = relabel_communities(&graph, &communities);
+        let expected = [2, 0, 0, 1, 0, 1];
+        for (&u, &c) in nodes.iter().zip(expected.iter()) {
+            assert_eq!(labels[&u], c);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{relabel_communities, CommunityDetection};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Symmetric weighted adjacency. A self loop of weight `w` is stored as
+/// `2 * w` so that the row sums are the weighted degrees.
+struct WeightedGraph {
+    neighbors: Vec<Vec<(usize, f64)>>,
+    degree: Vec<f64>,
+    total: f64,
+}
+
+impl WeightedGraph {
+    fn new(neighbors: Vec<HashMap<usize, f64>>) -> WeightedGraph {
+        let neighbors = neighbors
+            .into_iter()
+            .map(|row| {
+                let mut row = row.into_iter().collect::<Vec<_>>();
+                row.sort_by_key(|&(j, _)| j);
+                row
+            })
+            .collect::<Vec<_>>();
+        let degree = neighbors
+            .iter()
+            .map(|row| row.iter().map(|&(_, w)| w).sum())
+            .collect::<Vec<f64>>();
+        let total = degree.iter().sum();
+        WeightedGraph {
+            neighbors,
+            degree,
+            total,
+        }
+    }
+
+    fn aggregate(&self, assignment: &[usize], m: usize) -> WeightedGraph {
+        let mut neighbors = vec![HashMap::new(); m];
+        for (i, row) in self.neighbors.iter().enumerate() {
+            for &(j, w) in row {
+                *neighbors[assignment[i]].entry(assignment[j]).or_insert(0.) += w;
+            }
+        }
+        WeightedGraph::new(neighbors)
+    }
+}
+
+/// Louvain community detection. Edges are treated as undirected and
+/// unweighted, and `resolution` is the γ of the modularity being optimized.
+pub struct Louvain {
+    resolution: f32,
+    eps: f64,
+}
+
+impl Louvain {
+    pub fn new() -> Louvain {
+        Louvain {
+            resolution: 1.,
+            eps: 1e-10,
+        }
+    }
+
+    /// Sets the resolution γ. Values above 1 favour smaller communities and
+    /// values below 1 favour larger ones.
+    pub fn resolution(mut self, gamma: f32) -> Louvain {
+        assert!(
+            gamma.is_finite() && gamma >= 0.,
+            "resolution must be a non-negative finite number"
+        );
+        self.resolution = gamma;
+        self
+    }
+
+    /// Runs the local moving phase and returns the assignment of each node
+    /// to a community numbered from zero, or `None` if no node moved.
+    fn move_nodes(&self, graph: &WeightedGraph) -> Option<Vec<usize>> {
+        let n = graph.degree.len();
+        if graph.total == 0. {
+            return None;
+        }
+        let gamma = self.resolution as f64;
+        let mut community = (0..n).collect::<Vec<_>>();
+        let mut sigma_total = graph.degree.clone();
+        let mut moved = false;
+        loop {
+            let mut improved = false;
+            for i in 0..n {
+                let k = graph.degree[i];
+                let current = community[i];
+                let mut candidates = vec![];
+                let mut weights = HashMap::new();
+                for &(j, w) in graph.neighbors[i].iter().filter(|&&(j, _)| j != i) {
+                    let c = community[j];
+                    *weights.entry(c).or_insert_with(|| {
+                        candidates.push(c);
+                        0.
+                    }) += w;
+                }
+                sigma_total[current] -= k;
+                let gain = |c: usize| {
+                    weights.get(&c).copied().unwrap_or(0.)
+                        - gamma * sigma_total[c] * k / graph.total
+                };
+                let mut best = current;
+                let mut best_gain = gain(current) + self.eps;
+                for c in candidates {
+                    let g = gain(c);
+                    if g > best_gain {
+                        best = c;
+                        best_gain = g;
+                    }
+                }
+                sigma_total[best] += k;
+                if best != current {
+                    community[i] = best;
+                    improved = true;
+                    moved = true;
+                }
+            }
+            if !improved {
+                break;
+            }
+        }
+        if !moved {
+            return None;
+        }
+        let mut labels = HashMap::new();
+        Some(
+            community
+                .into_iter()
+                .map(|c| {
+                    let m = labels.len();
+                    *labels.entry(c).or_insert(m)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl Default for Louvain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<G> CommunityDetection<G> for Louvain
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    fn detect_communities(&self, graph: G) -> HashMap<G::NodeId, usize> {
+        let nodes = graph.node_identifiers().collect::<Vec<_>>();
+        let indices = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i))
+            .collect::<HashMap<_, _>>();
+        let mut neighbors = vec![HashMap::new(); nodes.len()];
+        for e in graph.edge_references() {
+            let i = indices[&e.source()];
+            let j = indices[&e.target()];
+            *neighbors[i].entry(j).or_insert(0.) += 1.;
+            *neighbors[j].entry(i).or_insert(0.) += 1.;
+        }
+        let mut weighted_graph = WeightedGraph::new(neighbors);
+        let mut communities = (0..nodes.len()).collect::<Vec<_>>();
+        while let Some(assignment) = self.move_nodes(&weighted_graph) {
+            let m = assignment.iter().max().map_or(0, |&c| c + 1);
+            for c in communities.iter_mut() {
+                *c = assignment[*c];
+            }
+            weighted_graph = weighted_graph.aggregate(&assignment, m);
+        }
+        let communities = nodes
+            .into_iter()
+            .zip(communities)
+            .collect::<HashMap<_, _>>();
+        relabel_communities(graph, &communities)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modularity_with_resolution;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_louvain() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..15).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for c in 0..3 {
+            for i in 0..5 {
+                for j in i + 1..5 {
+                    graph.add_edge(nodes[c * 5 + i], nodes[c * 5 + j], ());
+                }
+            }
+        }
+        graph.add_edge(nodes[0], nodes[5], ());
+        graph.add_edge(nodes[5], nodes[10], ());
+
+        let communities = Louvain::new().detect_communities(&graph);
+        for c in 0..3 {
+            for i in 1..5 {
+                assert_eq!(communities[&nodes[c * 5]], communities[&nodes[c * 5 + i]]);
+            }
+        }
+        assert_ne!(communities[&nodes[0]], communities[&nodes[5]]);
+        assert_ne!(communities[&nodes[5]], communities[&nodes[10]]);
+        assert!(modularity_with_resolution(&graph, &communities, 1.) > 0.5);
+
+        let count = |communities: &HashMap<_, usize>| communities.values().max().unwrap() + 1;
+        let coarse = Louvain::new().resolution(0.).detect_communities(&graph);
+        assert_eq!(count(&coarse), 1);
+        let fine = Louvain::new().resolution(20.).detect_communities(&graph);
+        assert!(count(&fine) > 3);
+    }
+
+    #[test]
+    fn test_louvain_empty() {
+        let graph = Graph::<(), ()>::new();
+        assert!(Louvain::new().detect_communities(&graph).is_empty());
+        let mut graph = Graph::<(), ()>::new();
+        let u = graph.add_node(());
+        assert_eq!(Louvain::new().detect_communities(&graph)[&u], 0);
+    }
+}
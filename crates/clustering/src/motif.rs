@@ -0,0 +1,262 @@
+use crate::coarsen;
+use petgraph::graph::{Graph, IndexType, NodeIndex};
+use petgraph::visit::{IntoNeighbors, IntoNodeIdentifiers};
+use petgraph::EdgeType;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MotifKind {
+    Fan,
+    Clique,
+    ParallelPaths,
+}
+
+#[derive(Clone, Debug)]
+pub struct Motif<N> {
+    pub kind: MotifKind,
+    pub nodes: Vec<N>,
+    pub anchors: Vec<N>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SummaryNode<N> {
+    pub motif: Option<usize>,
+    pub nodes: Vec<N>,
+}
+
+fn distinct_neighbors<G>(graph: G, u: G::NodeId) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors,
+    G::NodeId: Eq + Hash,
+{
+    let mut visited = HashSet::new();
+    graph
+        .neighbors(u)
+        .filter(|&v| v != u && visited.insert(v))
+        .collect()
+}
+
+pub fn fans<G>(graph: G, min_size: usize) -> Vec<Motif<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let mut hubs = vec![];
+    let mut leaves = HashMap::<G::NodeId, Vec<G::NodeId>>::new();
+    for u in graph.node_identifiers() {
+        let neighbors = distinct_neighbors(graph, u);
+        if neighbors.len() == 1 {
+            let hub = neighbors[0];
+            if distinct_neighbors(graph, hub).len() == 1 {
+                continue;
+            }
+            if !leaves.contains_key(&hub) {
+                hubs.push(hub);
+            }
+            leaves.entry(hub).or_default().push(u);
+        }
+    }
+    hubs.into_iter()
+        .filter_map(|hub| {
+            let nodes = leaves.remove(&hub).unwrap();
+            if nodes.len() >= min_size {
+                Some(Motif {
+                    kind: MotifKind::Fan,
+                    nodes,
+                    anchors: vec![hub],
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+pub fn parallel_paths<G>(graph: G, min_size: usize) -> Vec<Motif<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let indices = graph
+        .node_identifiers()
+        .enumerate()
+        .map(|(i, u)| (u, i))
+        .collect::<HashMap<_, _>>();
+    let mut keys = vec![];
+    let mut groups = HashMap::<(usize, usize), (Vec<G::NodeId>, Vec<G::NodeId>)>::new();
+    for u in graph.node_identifiers() {
+        let mut neighbors = distinct_neighbors(graph, u);
+        if neighbors.len() != 2 {
+            continue;
+        }
+        neighbors.sort_by_key(|v| indices[v]);
+        let key = (indices[&neighbors[0]], indices[&neighbors[1]]);
+        let group = groups.entry(key).or_insert_with(|| {
+            keys.push(key);
+            (vec![], neighbors)
+        });
+        group.0.push(u);
+    }
+    keys.into_iter()
+        .filter_map(|key| {
+            let (nodes, anchors) = groups.remove(&key).unwrap();
+            if nodes.len() >= min_size {
+                Some(Motif {
+                    kind: MotifKind::ParallelPaths,
+                    nodes,
+                    anchors,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+pub fn cliques<G>(graph: G, min_size: usize, min_density: f32) -> Vec<Motif<G::NodeId>>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let neighbors = graph
+        .node_identifiers()
+        .map(|u| (u, distinct_neighbors(graph, u)))
+        .collect::<HashMap<_, _>>();
+    let mut nodes = graph.node_identifiers().collect::<Vec<_>>();
+    nodes.sort_by_key(|u| std::cmp::Reverse(neighbors[u].len()));
+
+    let mut assigned = HashSet::new();
+    let mut motifs = vec![];
+    for &u in nodes.iter() {
+        if assigned.contains(&u) {
+            continue;
+        }
+        let mut candidates = neighbors[&u]
+            .iter()
+            .copied()
+            .filter(|v| !assigned.contains(v))
+            .collect::<Vec<_>>();
+        candidates.sort_by_key(|v| std::cmp::Reverse(neighbors[v].len()));
+        let mut members = vec![u];
+        let mut member_set = HashSet::new();
+        member_set.insert(u);
+        let mut internal_edges = 0;
+        for v in candidates {
+            let k = neighbors[&v]
+                .iter()
+                .filter(|w| member_set.contains(*w))
+                .count();
+            let size = members.len() + 1;
+            let density = (internal_edges + k) as f32 / (size * (size - 1) / 2) as f32;
+            if density >= min_density {
+                members.push(v);
+                member_set.insert(v);
+                internal_edges += k;
+            }
+        }
+        if members.len() >= min_size {
+            for &v in members.iter() {
+                assigned.insert(v);
+            }
+            motifs.push(Motif {
+                kind: MotifKind::Clique,
+                nodes: members,
+                anchors: vec![],
+            });
+        }
+    }
+    motifs
+}
+
+pub type SummaryGraph<Ty, Ix> = Graph<SummaryNode<NodeIndex<Ix>>, usize, Ty, Ix>;
+
+pub fn collapse_motifs<N, E, Ty, Ix>(
+    graph: &Graph<N, E, Ty, Ix>,
+    motifs: &[Motif<NodeIndex<Ix>>],
+) -> (SummaryGraph<Ty, Ix>, HashMap<NodeIndex<Ix>, NodeIndex<Ix>>)
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    let mut node_groups = HashMap::new();
+    let mut group_motifs = HashMap::new();
+    for (i, motif) in motifs.iter().enumerate() {
+        if motif.nodes.iter().any(|u| node_groups.contains_key(u)) {
+            continue;
+        }
+        for &u in motif.nodes.iter() {
+            node_groups.insert(u, i);
+        }
+        group_motifs.insert(i, i);
+    }
+    let mut next_group = motifs.len();
+    for u in graph.node_indices() {
+        node_groups.entry(u).or_insert_with(|| {
+            next_group += 1;
+            next_group - 1
+        });
+    }
+    let (summary, group_ids) = coarsen(
+        graph,
+        &mut |_, u| node_groups[&u],
+        &mut |_, nodes| SummaryNode {
+            motif: None,
+            nodes: nodes.clone(),
+        },
+        &mut |_, edges| edges.len(),
+    );
+    let mut summary = summary;
+    for (group, &motif) in group_motifs.iter() {
+        summary[group_ids[group]].motif = Some(motif);
+    }
+    let node_map = node_groups
+        .iter()
+        .map(|(&u, g)| (u, group_ids[g]))
+        .collect();
+    (summary, node_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collapse_motifs() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..13).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..5 {
+            for j in 0..i {
+                graph.add_edge(nodes[j], nodes[i], ());
+            }
+        }
+        for i in 5..9 {
+            graph.add_edge(nodes[0], nodes[i], ());
+        }
+        for i in 9..12 {
+            graph.add_edge(nodes[1], nodes[i], ());
+            graph.add_edge(nodes[i], nodes[12], ());
+        }
+
+        let fan_motifs = fans(&graph, 3);
+        assert_eq!(fan_motifs.len(), 1);
+        assert_eq!(fan_motifs[0].anchors, vec![nodes[0]]);
+        assert_eq!(fan_motifs[0].nodes.len(), 4);
+
+        let path_motifs = parallel_paths(&graph, 3);
+        assert_eq!(path_motifs.len(), 1);
+        assert_eq!(path_motifs[0].nodes.len(), 3);
+
+        let clique_motifs = cliques(&graph, 4, 1.);
+        assert_eq!(clique_motifs.len(), 1);
+        assert_eq!(clique_motifs[0].nodes.len(), 5);
+
+        let mut motifs = fan_motifs;
+        motifs.extend(path_motifs);
+        let (summary, node_map) = collapse_motifs(&graph, &motifs);
+        assert_eq!(summary.node_count(), 13 - 4 - 3 + 2);
+        assert_eq!(node_map[&nodes[5]], node_map[&nodes[8]]);
+        assert_eq!(summary[node_map[&nodes[5]]].motif, Some(0));
+        assert_eq!(summary[node_map[&nodes[0]]].motif, None);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::CommunityDetection;
+use petgraph::visit::{IntoEdgeReferences, IntoNodeIdentifiers};
+use petgraph_algorithm_laplacian::{spectral_embedding, LaplacianStructure};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+fn squared_distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum()
+}
+
+fn nearest(centers: &[Vec<f32>], x: &[f32]) -> usize {
+    let mut best = 0;
+    let mut best_distance = f32::INFINITY;
+    for (c, center) in centers.iter().enumerate() {
+        let d = squared_distance(center, x);
+        if d < best_distance {
+            best = c;
+            best_distance = d;
+        }
+    }
+    best
+}
+
+fn k_means(points: &[Vec<f32>], k: usize, iterations: usize) -> Vec<usize> {
+    let mut centers = vec![points[0].clone()];
+    let mut distance = points
+        .iter()
+        .map(|x| squared_distance(&centers[0], x))
+        .collect::<Vec<_>>();
+    while centers.len() < k {
+        let i = (0..points.len())
+            .max_by(|&a, &b| distance[a].total_cmp(&distance[b]))
+            .unwrap();
+        centers.push(points[i].clone());
+        for (d, x) in distance.iter_mut().zip(points.iter()) {
+            *d = d.min(squared_distance(&points[i], x));
+        }
+    }
+
+    let mut assignment = points
+        .iter()
+        .map(|x| nearest(&centers, x))
+        .collect::<Vec<_>>();
+    for _ in 0..iterations {
+        let mut sums = vec![vec![0.; points[0].len()]; k];
+        let mut counts = vec![0; k];
+        for (x, &c) in points.iter().zip(assignment.iter()) {
+            for (s, &y) in sums[c].iter_mut().zip(x.iter()) {
+                *s += y;
+            }
+            counts[c] += 1;
+        }
+        for ((center, sum), &count) in centers.iter_mut().zip(sums).zip(counts.iter()) {
+            if count > 0 {
+                *center = sum.into_iter().map(|s| s / count as f32).collect();
+            }
+        }
+        let next = points
+            .iter()
+            .map(|x| nearest(&centers, x))
+            .collect::<Vec<_>>();
+        if next == assignment {
+            break;
+        }
+        assignment = next;
+    }
+    assignment
+}
+
+pub struct SpectralClustering {
+    pub k: usize,
+    pub normalized: bool,
+    pub k_means_iterations: usize,
+}
+
+impl SpectralClustering {
+    pub fn new(k: usize) -> SpectralClustering {
+        SpectralClustering {
+            k,
+            normalized: true,
+            k_means_iterations: 100,
+        }
+    }
+}
+
+impl<G> CommunityDetection<G> for SpectralClustering
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    fn detect_communities(&self, graph: G) -> HashMap<G::NodeId, usize> {
+        let nodes = graph.node_identifiers().collect::<Vec<_>>();
+        let k = self.k.min(nodes.len());
+        if k <= 1 {
+            return nodes.into_iter().map(|u| (u, 0)).collect();
+        }
+        let laplacian = LaplacianStructure::new(graph);
+        let (embedding, _) = spectral_embedding(&laplacian, k - 1, self.normalized, 1000, 1e-4);
+        let points = embedding
+            .rows()
+            .into_iter()
+            .map(|row| row.to_vec())
+            .collect::<Vec<_>>();
+        let assignment = k_means(&points, k, self.k_means_iterations);
+        nodes.into_iter().zip(assignment).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_spectral_clustering() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..12).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for c in 0..3 {
+            for i in 0..4 {
+                for j in i + 1..4 {
+                    graph.add_edge(nodes[c * 4 + i], nodes[c * 4 + j], ());
+                }
+            }
+            graph.add_edge(nodes[c * 4], nodes[(c * 4 + 5) % 12], ());
+        }
+        let communities = SpectralClustering::new(3).detect_communities(&graph);
+        for c in 0..3 {
+            for i in 1..4 {
+                assert_eq!(communities[&nodes[c * 4]], communities[&nodes[c * 4 + i]]);
+            }
+            assert_ne!(
+                communities[&nodes[c * 4]],
+                communities[&nodes[(c * 4 + 4) % 12]]
+            );
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::parse;
+use petgraph::{graph::IndexType, prelude::*, EdgeType};
+use sha2::{Digest, Sha256};
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BASE_URL: &str =
+    "https://raw.githubusercontent.com/likr/egraph-rs/main/crates/dataset/src/data";
+
+const DATASETS: &[(&str, &str, &str)] = &[
+    (
+        "1138_bus",
+        "1138_bus.csv",
+        "fc97c79b3bebfc5c47b198284428d9b9974607ae82fdae187c7c317aceddeedb",
+    ),
+    (
+        "3_elt",
+        "3elt.csv",
+        "37cf95c0840470b877f80f862a5bcdcd4643a2cd6952b1f2d6b44b1a7f68a173",
+    ),
+    (
+        "dwt_1005",
+        "dwt_1005.csv",
+        "82a1e48486bd9193ad0f4ab2bd2f04427f1907af2f810d62484d1ee65b589f01",
+    ),
+    (
+        "dwt_2680",
+        "dwt_2680.csv",
+        "8f71b60239a08c9dc96c091b44997e6d202a8bd36b1a8ab2eeaa1df5fc13d0bf",
+    ),
+    (
+        "poli",
+        "poli.csv",
+        "440c1fe4b7d9ef2a1cff302dadde093fb6e50828236d48c218aba66924214ec0",
+    ),
+    (
+        "qh882",
+        "qh882.csv",
+        "e063aca9e5580a5bc430481e1867a2216da48d43da773cedb5329cc6657109f0",
+    ),
+    (
+        "USpowerGrid",
+        "USpowerGrid.csv",
+        "3a1ab5d545c6fff4143caf630f93138e7a3938412a0c4695837fc9c0eb7db41b",
+    ),
+];
+
+pub fn dataset_names() -> Vec<&'static str> {
+    DATASETS.iter().map(|&(name, _, _)| name).collect()
+}
+
+pub fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("EGRAPH_DATASET_CACHE") {
+        return PathBuf::from(dir);
+    }
+    let base = env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .unwrap_or_else(env::temp_dir);
+    base.join("egraph-dataset")
+}
+
+fn sha256(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn download(url: &str) -> io::Result<String> {
+    let response = ureq::get(url)
+        .call()
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    let mut content = String::new();
+    response.into_reader().read_to_string(&mut content)?;
+    Ok(content)
+}
+
+pub fn load_dataset<N: Default, E: Default, Ty: EdgeType, Ix: IndexType>(
+    name: &str,
+) -> io::Result<Graph<N, E, Ty, Ix>> {
+    load_dataset_with(name, &default_cache_dir(), DEFAULT_BASE_URL)
+}
+
+pub fn load_dataset_with<N: Default, E: Default, Ty: EdgeType, Ix: IndexType>(
+    name: &str,
+    cache_dir: &Path,
+    base_url: &str,
+) -> io::Result<Graph<N, E, Ty, Ix>> {
+    let &(_, filename, checksum) =
+        DATASETS
+            .iter()
+            .find(|&&(n, _, _)| n == name)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("unknown dataset: {}", name),
+                )
+            })?;
+    let path = cache_dir.join(filename);
+    if let Ok(content) = fs::read_to_string(&path) {
+        if sha256(&content) == checksum {
+            return Ok(parse(&content));
+        }
+    }
+    let content = download(&format!("{}/{}", base_url, filename))?;
+    if sha256(&content) != checksum {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("checksum mismatch for dataset: {}", name),
+        ));
+    }
+    fs::create_dir_all(cache_dir)?;
+    fs::write(&path, &content)?;
+    Ok(parse(&content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_dataset_from_cache() {
+        let cache_dir = env::temp_dir().join("egraph-dataset-test");
+        fs::create_dir_all(&cache_dir).unwrap();
+        fs::write(cache_dir.join("qh882.csv"), include_str!("data/qh882.csv")).unwrap();
+        let graph: UnGraph<(), ()> =
+            load_dataset_with("qh882", &cache_dir, "http://localhost:0").unwrap();
+        assert_eq!(graph.node_count(), 882);
+        assert!(load_dataset_with::<(), (), Undirected, u32>("unknown", &cache_dir, "").is_err());
+    }
+}
@@ -1,5 +1,14 @@
+#[cfg(feature = "download")]
+mod download;
+
 use petgraph::{graph::IndexType, prelude::*, EdgeType};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, BufReader, Read};
+
+#[cfg(feature = "download")]
+pub use download::{
+    dataset_names, default_cache_dir, load_dataset, load_dataset_with, DEFAULT_BASE_URL,
+};
 
 #[allow(dead_code)]
 fn parse<N: Default, E: Default, Ty: EdgeType, Ix: IndexType>(input: &str) -> Graph<N, E, Ty, Ix> {
@@ -26,6 +35,92 @@ fn parse<N: Default, E: Default, Ty: EdgeType, Ix: IndexType>(input: &str) -> Gr
     graph
 }
 
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+pub fn load_matrix_market<N, E, Ty, Ix, R>(reader: R) -> io::Result<Graph<N, E, Ty, Ix>>
+where
+    N: Default,
+    E: Default,
+    Ty: EdgeType,
+    Ix: IndexType,
+    R: Read,
+{
+    let mut lines = BufReader::new(reader).lines();
+    let header = lines
+        .next()
+        .ok_or_else(|| invalid_data("empty input"))??
+        .to_lowercase();
+    let header = header.split_ascii_whitespace().collect::<Vec<_>>();
+    if header.len() < 5 || header[0] != "%%matrixmarket" || header[1] != "matrix" {
+        return Err(invalid_data("missing MatrixMarket header"));
+    }
+    if header[2] != "coordinate" {
+        return Err(invalid_data("only coordinate format is supported"));
+    }
+    let symmetric = header[4] != "general";
+
+    let mut size = None;
+    let mut graph = Graph::default();
+    let mut edges = HashSet::new();
+    for line in lines {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+        let values = line
+            .split_ascii_whitespace()
+            .take(2)
+            .map(|v| v.parse::<usize>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| invalid_data("invalid index"))?;
+        if values.len() < 2 {
+            return Err(invalid_data("too few values in line"));
+        }
+        match size {
+            None => {
+                let n = values[0].max(values[1]);
+                size = Some(n);
+                graph = Graph::with_capacity(n, 0);
+                for _ in 0..n {
+                    graph.add_node(N::default());
+                }
+            }
+            Some(n) => {
+                let (i, j) = (values[0], values[1]);
+                if i == 0 || j == 0 || i > n || j > n {
+                    return Err(invalid_data("index out of range"));
+                }
+                if i == j {
+                    continue;
+                }
+                let (i, j) = if Ty::is_directed() {
+                    (i, j)
+                } else {
+                    (i.min(j), i.max(j))
+                };
+                // A symmetric matrix stores one triangle, so directed graphs
+                // get both directions of each entry.
+                let mut keys = vec![(i, j)];
+                if symmetric && Ty::is_directed() {
+                    keys.push((j, i));
+                }
+                for (i, j) in keys {
+                    if edges.insert((i, j)) {
+                        graph.add_edge(NodeIndex::new(i - 1), NodeIndex::new(j - 1), E::default());
+                    }
+                }
+            }
+        }
+    }
+    if size.is_none() {
+        return Err(invalid_data("missing size line"));
+    }
+    Ok(graph)
+}
+
 #[cfg(feature = "1138_bus")]
 pub fn dataset_1138_bus<N: Default, E: Default, Ty: EdgeType, Ix: IndexType>() -> Graph<N, E, Ty, Ix>
 {
@@ -64,3 +159,59 @@ pub fn dataset_USpowerGrid<N: Default, E: Default, Ty: EdgeType, Ix: IndexType>(
 ) -> Graph<N, E, Ty, Ix> {
     parse(include_str!("data/USpowerGrid.csv"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_matrix_market() {
+        let input = "%%MatrixMarket matrix coordinate real symmetric
+% comment
+4 4 5
+1 1 1.0
+2 1 -1.0
+3 2 -1.0
+4 3 -1.0
+4 1 -1.0
+";
+        let graph: UnGraph<(), ()> = load_matrix_market(input.as_bytes()).unwrap();
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 4);
+        assert!(graph.contains_edge(NodeIndex::new(0), NodeIndex::new(3)));
+
+        let input = "%%MatrixMarket matrix coordinate pattern general
+3 3 4
+1 2
+2 1
+2 3
+3 3
+";
+        let graph: UnGraph<(), ()> = load_matrix_market(input.as_bytes()).unwrap();
+        assert_eq!(graph.edge_count(), 2);
+
+        let input = "%%MatrixMarket matrix coordinate pattern symmetric
+3 3 2
+2 1
+3 2
+";
+        let graph: DiGraph<(), ()> = load_matrix_market(input.as_bytes()).unwrap();
+        assert_eq!(graph.edge_count(), 4);
+        for (i, j) in [(0, 1), (1, 0), (1, 2), (2, 1)] {
+            assert!(graph.contains_edge(NodeIndex::new(i), NodeIndex::new(j)));
+        }
+
+        let input = "%%MatrixMarket matrix coordinate pattern general
+3 3 3
+1 2
+2 1
+2 3
+";
+        let graph: DiGraph<(), ()> = load_matrix_market(input.as_bytes()).unwrap();
+        assert_eq!(graph.edge_count(), 3);
+        assert!(!graph.contains_edge(NodeIndex::new(2), NodeIndex::new(1)));
+
+        let input = "%%MatrixMarket matrix array real general\n2 2\n";
+        assert!(load_matrix_market::<(), (), Undirected, u32, _>(input.as_bytes()).is_err());
+    }
+}
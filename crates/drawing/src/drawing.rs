@@ -3,8 +3,27 @@ pub mod drawing_euclidean_2d;
 pub mod drawing_hyperbolic_2d;
 pub mod drawing_spherical_2d;
 pub mod drawing_torus2d;
+#[cfg(feature = "std")]
+pub mod initial_placement;
 
 use crate::{metric::Metric, DrawingIndex};
+use core::fmt;
+
+/// Error returned when a node of the graph has no position in the drawing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MissingPosition<N>(pub N);
+
+impl<N> fmt::Display for MissingPosition<N>
+where
+    N: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "node {:?} has no position in the drawing", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<N> std::error::Error for MissingPosition<N> where N: fmt::Debug {}
 
 pub trait Drawing {
     type Index: DrawingIndex;
@@ -16,6 +35,13 @@ pub trait Drawing {
 
     fn position(&self, u: Self::Index) -> Option<&Self::Item>;
 
+    fn try_position(&self, u: Self::Index) -> Result<&Self::Item, MissingPosition<Self::Index>>
+    where
+        Self::Index: Copy,
+    {
+        self.position(u).ok_or(MissingPosition(u))
+    }
+
     fn position_mut(&mut self, u: Self::Index) -> Option<&mut Self::Item>;
 
     fn node_id(&self, i: usize) -> &Self::Index;
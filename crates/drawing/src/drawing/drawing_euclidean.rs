@@ -1,6 +1,7 @@
-use crate::{DeltaEuclidean, Drawing, DrawingIndex, DrawingValue, MetricEuclidean};
+use crate::{DeltaEuclidean, Drawing, DrawingIndex, DrawingValue, HashMap, MetricEuclidean};
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
 use petgraph::visit::IntoNodeIdentifiers;
-use std::collections::HashMap;
 
 pub struct DrawingEuclidean<N, S> {
     indices: Vec<N>,
@@ -14,6 +15,7 @@ where
     N: DrawingIndex,
     S: DrawingValue,
 {
+    #[cfg(feature = "std")]
     pub fn new<G>(graph: G, dimension: usize) -> Self
     where
         G: IntoNodeIdentifiers,
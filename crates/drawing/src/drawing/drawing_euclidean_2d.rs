@@ -1,7 +1,12 @@
-use crate::{DeltaEuclidean2d, Drawing, DrawingIndex, DrawingValue, MetricEuclidean2d};
-use num_traits::{clamp, FloatConst, FromPrimitive};
+use crate::{DeltaEuclidean2d, Drawing, DrawingIndex, DrawingValue, HashMap, MetricEuclidean2d};
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
+use num_traits::FloatConst;
+use num_traits::{clamp, FromPrimitive};
+#[cfg(feature = "std")]
 use petgraph::visit::{IntoNeighbors, IntoNodeIdentifiers};
-use std::collections::{HashMap, VecDeque};
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
 
 pub struct DrawingEuclidean2d<N, S> {
     indices: Vec<N>,
@@ -14,6 +19,7 @@ where
     N: DrawingIndex,
     S: DrawingValue,
 {
+    #[cfg(feature = "std")]
     pub fn new<G>(graph: G) -> Self
     where
         G: IntoNodeIdentifiers,
@@ -95,6 +101,7 @@ where
         }
     }
 
+    #[cfg(feature = "std")]
     pub fn initial_placement<G>(graph: G) -> Self
     where
         G: IntoNodeIdentifiers,
@@ -106,6 +113,7 @@ where
         Self::initial_placement_with_node_order(graph, &nodes)
     }
 
+    #[cfg(feature = "std")]
     pub fn initial_placement_with_node_order<G>(graph: G, nodes: &[G::NodeId]) -> Self
     where
         G: IntoNodeIdentifiers,
@@ -128,6 +136,7 @@ where
         drawing
     }
 
+    #[cfg(feature = "std")]
     pub fn initial_placement_with_bfs_order<G>(graph: G, s: G::NodeId) -> Self
     where
         G: IntoNeighbors + IntoNodeIdentifiers,
@@ -150,7 +159,7 @@ where
             }
         }
         let mut nodes = graph.node_identifiers().collect::<Vec<_>>();
-        nodes.sort_by_key(|&u| order.get(&u).or(Some(&std::usize::MAX)));
+        nodes.sort_by_key(|&u| order.get(&u).or(Some(&usize::MAX)));
         Self::initial_placement_with_node_order(graph, &nodes)
     }
 
@@ -1,7 +1,9 @@
-use crate::{DeltaHyperbolic2d, Drawing, DrawingIndex, DrawingValue, MetricHyperbolic2d};
+use crate::{DeltaHyperbolic2d, Drawing, DrawingIndex, DrawingValue, HashMap, MetricHyperbolic2d};
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
 use num_traits::{FloatConst, FromPrimitive};
+#[cfg(feature = "std")]
 use petgraph::visit::IntoNodeIdentifiers;
-use std::collections::HashMap;
 
 pub struct DrawingHyperbolic2d<N, S> {
     indices: Vec<N>,
@@ -14,6 +16,7 @@ where
     N: DrawingIndex,
     S: DrawingValue,
 {
+    #[cfg(feature = "std")]
     pub fn new<G>(graph: G) -> Self
     where
         G: IntoNodeIdentifiers,
@@ -63,6 +66,7 @@ where
         self.position_mut(u).map(|p| p.1 = value)
     }
 
+    #[cfg(feature = "std")]
     pub fn initial_placement<G>(graph: G) -> Self
     where
         G: IntoNodeIdentifiers,
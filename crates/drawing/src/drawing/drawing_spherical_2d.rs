@@ -1,7 +1,19 @@
-use crate::{DeltaSpherical2d, Drawing, DrawingIndex, DrawingValue, MetricSpherical2d};
+use crate::{DeltaSpherical2d, Drawing, DrawingIndex, DrawingValue, HashMap, MetricSpherical2d};
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
 use num_traits::{FloatConst, FromPrimitive};
+#[cfg(feature = "std")]
 use petgraph::visit::IntoNodeIdentifiers;
-use std::collections::HashMap;
+
+/// Converts a (longitude, polar angle) position in radians to geographic
+/// `(longitude, latitude)` degrees. The polar angle θ is measured from the
+/// north pole, so the latitude is π/2 − θ. Longitudes are wrapped to
+/// (-180, 180].
+fn to_geographic<S: DrawingValue>(p: &MetricSpherical2d<S>) -> (S, S) {
+    let lon = p.0.sin().atan2(p.0.cos());
+    let lat = S::from_f64(core::f64::consts::FRAC_PI_2).unwrap() - p.1;
+    (lon.to_degrees(), lat.to_degrees())
+}
 
 pub struct DrawingSpherical2d<N, S> {
     indices: Vec<N>,
@@ -14,6 +26,7 @@ where
     N: DrawingIndex,
     S: DrawingValue,
 {
+    #[cfg(feature = "std")]
     pub fn new<G>(graph: G) -> Self
     where
         G: IntoNodeIdentifiers,
@@ -63,6 +76,79 @@ where
         self.position_mut(u).map(|p| p.1 = value)
     }
 
+    /// Samples the great-circle arc from `u` to `v` with `segments + 1`
+    /// points in the drawing's own (longitude, polar angle) radians. Any great
+    /// circle through antipodal endpoints is shortest; the arc then passes
+    /// over the north pole, or along longitude 0 if the endpoints are the
+    /// poles.
+    pub fn edge_arc(&self, u: N, v: N, segments: usize) -> Option<Vec<MetricSpherical2d<S>>> {
+        let embed =
+            |p: &MetricSpherical2d<S>| (p.0.cos() * p.1.sin(), p.1.cos(), p.0.sin() * p.1.sin());
+        let (p, q) = self.position(u).zip(self.position(v))?;
+        let (a, b) = (embed(p), embed(q));
+        let cos_omega = (a.0 * b.0 + a.1 * b.1 + a.2 * b.2)
+            .max(-S::one())
+            .min(S::one());
+        let omega = cos_omega.acos();
+        // Unit tangent at a pointing along the arc towards b.
+        let w = if omega.sin() > S::epsilon() {
+            let w = (
+                b.0 - cos_omega * a.0,
+                b.1 - cos_omega * a.1,
+                b.2 - cos_omega * a.2,
+            );
+            let norm = (w.0 * w.0 + w.1 * w.1 + w.2 * w.2).sqrt();
+            Some((w.0 / norm, w.1 / norm, w.2 / norm))
+        } else if cos_omega < S::zero() {
+            let axis = if a.1.abs() < S::one() - S::epsilon() {
+                (S::zero(), S::one(), S::zero())
+            } else {
+                (S::one(), S::zero(), S::zero())
+            };
+            let dot = a.0 * axis.0 + a.1 * axis.1 + a.2 * axis.2;
+            let w = (axis.0 - dot * a.0, axis.1 - dot * a.1, axis.2 - dot * a.2);
+            let norm = (w.0 * w.0 + w.1 * w.1 + w.2 * w.2).sqrt();
+            Some((w.0 / norm, w.1 / norm, w.2 / norm))
+        } else {
+            None
+        };
+        let segments = segments.max(1);
+        Some(
+            (0..=segments)
+                .map(|k| {
+                    if k == 0 {
+                        return *p;
+                    }
+                    if k == segments {
+                        return *q;
+                    }
+                    let Some(w) = w else {
+                        return *p;
+                    };
+                    let t = S::from_usize(k).unwrap() / S::from_usize(segments).unwrap();
+                    let (c, s) = ((t * omega).cos(), (t * omega).sin());
+                    let x = c * a.0 + s * w.0;
+                    let y = c * a.1 + s * w.1;
+                    let z = c * a.2 + s * w.2;
+                    MetricSpherical2d(z.atan2(x), y.max(-S::one()).min(S::one()).acos())
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns the geographic `(longitude, latitude)` of `u` in degrees.
+    pub fn geographic(&self, u: N) -> Option<(S, S)> {
+        self.position(u).map(to_geographic)
+    }
+
+    /// Same as [`DrawingSpherical2d::edge_arc`] with the samples converted to
+    /// geographic `(longitude, latitude)` degrees.
+    pub fn edge_arc_geographic(&self, u: N, v: N, segments: usize) -> Option<Vec<(S, S)>> {
+        self.edge_arc(u, v, segments)
+            .map(|arc| arc.iter().map(to_geographic).collect())
+    }
+
+    #[cfg(feature = "std")]
     pub fn initial_placement<G>(graph: G) -> Self
     where
         G: IntoNodeIdentifiers,
@@ -125,3 +211,60 @@ where
         self.raw_entry(i) - self.raw_entry(j)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Delta;
+
+    #[test]
+    fn test_edge_arc() {
+        let mut drawing = DrawingSpherical2d::<usize, f32>::from_node_indices(&[0, 1]);
+        drawing.set_lon(0, 0.);
+        drawing.set_lat(0, core::f32::consts::FRAC_PI_2);
+        drawing.set_lon(1, core::f32::consts::FRAC_PI_2);
+        drawing.set_lat(1, core::f32::consts::FRAC_PI_2);
+        let arc = drawing.edge_arc(0, 1, 4).unwrap();
+        assert_eq!(arc.len(), 5);
+        for (k, p) in arc.iter().enumerate() {
+            assert!((p.0 - core::f32::consts::FRAC_PI_8 * k as f32).abs() < 1e-4);
+            assert!((p.1 - core::f32::consts::FRAC_PI_2).abs() < 1e-4);
+        }
+        assert!(drawing.edge_arc(0, 2, 4).is_none());
+    }
+
+    #[test]
+    fn test_edge_arc_antipodal() {
+        let mut drawing = DrawingSpherical2d::<usize, f32>::from_node_indices(&[0, 1, 2, 3]);
+        drawing.set_lon(0, 0.);
+        drawing.set_lat(0, core::f32::consts::FRAC_PI_2);
+        drawing.set_lon(1, core::f32::consts::PI);
+        drawing.set_lat(1, core::f32::consts::FRAC_PI_2);
+        drawing.set_lat(2, 0.);
+        drawing.set_lat(3, core::f32::consts::PI);
+        for (u, v) in [(0, 1), (2, 3)] {
+            let arc = drawing.edge_arc(u, v, 4).unwrap();
+            assert_eq!(arc.len(), 5);
+            for w in arc.windows(2) {
+                let d = (&w[0] - &w[1]).norm();
+                assert!((d - core::f32::consts::FRAC_PI_4).abs() < 1e-3);
+            }
+        }
+        let arc = drawing.edge_arc_geographic(0, 1, 2).unwrap();
+        assert!((arc[1].1 - 90.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_geographic() {
+        let mut drawing = DrawingSpherical2d::<usize, f32>::from_node_indices(&[0, 1]);
+        drawing.set_lon(0, -core::f32::consts::FRAC_PI_2);
+        drawing.set_lat(0, core::f32::consts::FRAC_PI_6);
+        drawing.set_lon(1, 3. * core::f32::consts::FRAC_PI_2);
+        drawing.set_lat(1, core::f32::consts::FRAC_PI_2);
+        let (lon, lat) = drawing.geographic(0).unwrap();
+        assert!((lon + 90.).abs() < 1e-4 && (lat - 60.).abs() < 1e-4);
+        let (lon, lat) = drawing.geographic(1).unwrap();
+        assert!((lon + 90.).abs() < 1e-4 && lat.abs() < 1e-4);
+        assert!(drawing.geographic(2).is_none());
+    }
+}
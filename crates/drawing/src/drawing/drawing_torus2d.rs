@@ -1,7 +1,55 @@
-use crate::{DeltaTorus2d, Drawing, DrawingIndex, DrawingValue, MetricTorus2d, TorusValue};
+use crate::{
+    DeltaTorus2d, Drawing, DrawingIndex, DrawingValue, HashMap, MetricTorus2d, TorusValue,
+};
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "std")]
 use num_traits::{FloatConst, FromPrimitive};
-use petgraph::visit::IntoNodeIdentifiers;
-use std::collections::HashMap;
+#[cfg(feature = "std")]
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers};
+
+#[cfg(feature = "std")]
+fn least_covered_point<S>(arcs: &[(S, S)]) -> S
+where
+    S: DrawingValue,
+{
+    let mut events = vec![];
+    let mut count = 0;
+    for &(a, length) in arcs.iter() {
+        if length == S::zero() {
+            continue;
+        }
+        let b = a + length;
+        if b >= S::one() {
+            count += 1;
+            events.push((b - S::one(), -1));
+        } else {
+            events.push((b, -1));
+        }
+        events.push((a, 1));
+    }
+    if events.is_empty() {
+        return S::zero();
+    }
+    events.sort_by(|p, q| {
+        p.0.partial_cmp(&q.0)
+            .unwrap_or_else(|| p.0.is_nan().cmp(&q.0.is_nan()))
+            .then(p.1.cmp(&q.1))
+    });
+    let two = S::one() + S::one();
+    let mut best = (count, events[0].0 / two);
+    for k in 0..events.len() {
+        count += events[k].1;
+        let next = if k + 1 < events.len() {
+            events[k + 1].0
+        } else {
+            S::one() + events[0].0
+        };
+        if next > events[k].0 && count < best.0 {
+            best = (count, (events[k].0 + next) / two);
+        }
+    }
+    best.1
+}
 
 pub struct DrawingTorus2d<N, S> {
     indices: Vec<N>,
@@ -14,6 +62,7 @@ where
     N: DrawingIndex,
     S: DrawingValue,
 {
+    #[cfg(feature = "std")]
     pub fn new<G>(graph: G) -> Self
     where
         G: IntoNodeIdentifiers,
@@ -62,6 +111,7 @@ where
         self.position_mut(u).map(|p| p.1 = TorusValue::new(value))
     }
 
+    #[cfg(feature = "std")]
     pub fn initial_placement<G>(graph: G) -> Self
     where
         G: IntoNodeIdentifiers,
@@ -88,6 +138,43 @@ where
         drawing
     }
 
+    #[cfg(feature = "std")]
+    pub fn minimize_boundary_crossings<G>(&mut self, graph: G) -> (S, S)
+    where
+        G: IntoEdgeReferences,
+        G::NodeId: Into<N>,
+    {
+        let half = S::one() / (S::one() + S::one());
+        let mut x_arcs = vec![];
+        let mut y_arcs = vec![];
+        for e in graph.edge_references() {
+            let u = e.source().into();
+            let v = e.target().into();
+            if let Some((&p, &q)) = self.position(u).zip(self.position(v)) {
+                for (arcs, a, b) in [(&mut x_arcs, p.0 .0, q.0 .0), (&mut y_arcs, p.1 .0, q.1 .0)] {
+                    let mut d = b - a;
+                    if d > half {
+                        d -= S::one();
+                    } else if d < -half {
+                        d += S::one();
+                    }
+                    if d < S::zero() {
+                        arcs.push((TorusValue::new(a + d).0, -d));
+                    } else {
+                        arcs.push((a, d));
+                    }
+                }
+            }
+        }
+        let cx = least_covered_point(&x_arcs);
+        let cy = least_covered_point(&y_arcs);
+        for p in self.coordinates.iter_mut() {
+            p.0 -= cx;
+            p.1 -= cy;
+        }
+        (cx, cy)
+    }
+
     pub fn edge_segments(&self, u: N, v: N) -> Option<Vec<(MetricTorus2d<S>, MetricTorus2d<S>)>> {
         self.position(u).zip(self.position(v)).map(|(&p, &q)| {
             let (dx, dy) = p.nearest_dxdy(&q);
@@ -259,3 +346,35 @@ where
         self.raw_entry(i) - self.raw_entry(j)
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use petgraph::graph::{Graph, NodeIndex};
+
+    #[test]
+    fn test_minimize_boundary_crossings() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        graph.add_edge(nodes[0], nodes[1], ());
+        graph.add_edge(nodes[1], nodes[2], ());
+        graph.add_edge(nodes[2], nodes[3], ());
+        let mut drawing = DrawingTorus2d::<NodeIndex, f32>::new(&graph);
+        for (i, &u) in nodes.iter().enumerate() {
+            drawing.set_x(u, 0.9 + 0.1 * i as f32);
+            drawing.set_y(u, 0.95);
+        }
+        let crossings = |drawing: &DrawingTorus2d<NodeIndex, f32>| {
+            graph
+                .edge_indices()
+                .filter(|&e| {
+                    let (u, v) = graph.edge_endpoints(e).unwrap();
+                    drawing.edge_segments(u, v).unwrap().len() > 1
+                })
+                .count()
+        };
+        assert!(crossings(&drawing) > 0);
+        drawing.minimize_boundary_crossings(&graph);
+        assert_eq!(crossings(&drawing), 0);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{Drawing, DrawingEuclidean2d, DrawingIndex, DrawingValue, MetricEuclidean2d};
+use num_traits::{FloatConst, FromPrimitive};
+use petgraph::visit::{IntoNeighbors, IntoNodeIdentifiers};
+use rand::prelude::*;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+pub enum InitialPlacement<'a, N, S> {
+    Phyllotaxis,
+    CircleBfs(N),
+    CircleDfs(N),
+    Grid,
+    RandomDisk {
+        radius: S,
+        seed: u64,
+    },
+    Coarse {
+        drawing: &'a DrawingEuclidean2d<N, S>,
+        parent: &'a HashMap<N, N>,
+        jitter: S,
+    },
+}
+
+fn traversal_order<G>(graph: G, s: G::NodeId, depth_first: bool) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers,
+    G::NodeId: DrawingIndex + Copy,
+{
+    let mut visited = HashSet::new();
+    let mut order = vec![];
+    for root in Some(s).into_iter().chain(graph.node_identifiers()) {
+        if visited.contains(&root) {
+            continue;
+        }
+        if depth_first {
+            let mut stack = vec![root];
+            while let Some(u) = stack.pop() {
+                if !visited.insert(u) {
+                    continue;
+                }
+                order.push(u);
+                for v in graph.neighbors(u) {
+                    if !visited.contains(&v) {
+                        stack.push(v);
+                    }
+                }
+            }
+        } else {
+            visited.insert(root);
+            order.push(root);
+            let mut queue = VecDeque::new();
+            queue.push_back(root);
+            while let Some(u) = queue.pop_front() {
+                for v in graph.neighbors(u) {
+                    if visited.insert(v) {
+                        order.push(v);
+                        queue.push_back(v);
+                    }
+                }
+            }
+        }
+    }
+    order
+}
+
+impl<N, S> DrawingEuclidean2d<N, S>
+where
+    N: DrawingIndex + Copy,
+    S: DrawingValue + FloatConst + FromPrimitive + Default,
+{
+    pub fn initial_placement_with_strategy<G>(graph: G, strategy: &InitialPlacement<N, S>) -> Self
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers<NodeId = N>,
+    {
+        let unit = S::from_usize(10).unwrap();
+        match strategy {
+            InitialPlacement::Phyllotaxis => Self::initial_placement(graph),
+            InitialPlacement::CircleBfs(s) | InitialPlacement::CircleDfs(s) => {
+                let depth_first = matches!(strategy, InitialPlacement::CircleDfs(_));
+                let order = traversal_order(graph, *s, depth_first);
+                let n = S::from_usize(order.len()).unwrap();
+                let radius = unit * n / (S::PI() + S::PI());
+                let mut drawing = Self::new(graph);
+                for (i, &u) in order.iter().enumerate() {
+                    let theta = (S::PI() + S::PI()) * S::from_usize(i).unwrap() / n;
+                    drawing.set_x(u, radius * theta.cos());
+                    drawing.set_y(u, radius * theta.sin());
+                }
+                drawing
+            }
+            InitialPlacement::Grid => {
+                let mut drawing = Self::new(graph);
+                let columns = (drawing.len() as f64).sqrt().ceil() as usize;
+                for i in 0..drawing.len() {
+                    *drawing.raw_entry_mut(i) = MetricEuclidean2d(
+                        unit * S::from_usize(i % columns).unwrap(),
+                        unit * S::from_usize(i / columns).unwrap(),
+                    );
+                }
+                drawing
+            }
+            InitialPlacement::RandomDisk { radius, seed } => {
+                let mut rng = StdRng::seed_from_u64(*seed);
+                let mut drawing = Self::new(graph);
+                for i in 0..drawing.len() {
+                    let r = *radius * S::from_f64(rng.gen::<f64>().sqrt()).unwrap();
+                    let theta = (S::PI() + S::PI()) * S::from_f64(rng.gen::<f64>()).unwrap();
+                    *drawing.raw_entry_mut(i) = MetricEuclidean2d(r * theta.cos(), r * theta.sin());
+                }
+                drawing
+            }
+            InitialPlacement::Coarse {
+                drawing: coarse,
+                parent,
+                jitter,
+            } => {
+                let golden_angle =
+                    S::PI() * (S::from_usize(3).unwrap() - S::from_usize(5).unwrap().sqrt());
+                let mut children = HashMap::new();
+                let mut drawing = Self::new(graph);
+                for u in graph.node_identifiers() {
+                    let Some(&MetricEuclidean2d(x, y)) =
+                        parent.get(&u).and_then(|&p| coarse.position(p))
+                    else {
+                        continue;
+                    };
+                    let k = children.entry(parent[&u]).or_insert(0usize);
+                    let angle = golden_angle * S::from_usize(*k).unwrap();
+                    let r = *jitter * S::from_usize(*k).unwrap().sqrt();
+                    *k += 1;
+                    drawing.set_x(u, x + r * angle.cos());
+                    drawing.set_y(u, y + r * angle.sin());
+                }
+                drawing
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_initial_placement_with_strategy() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..9).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..9 {
+            graph.add_edge(nodes[i - 1], nodes[i], ());
+        }
+
+        for strategy in [
+            InitialPlacement::CircleBfs(nodes[4]),
+            InitialPlacement::CircleDfs(nodes[4]),
+        ] {
+            let drawing =
+                DrawingEuclidean2d::<_, f32>::initial_placement_with_strategy(&graph, &strategy);
+            let r = drawing
+                .x(nodes[0])
+                .unwrap()
+                .hypot(drawing.y(nodes[0]).unwrap());
+            for &u in &nodes {
+                let ru = drawing.x(u).unwrap().hypot(drawing.y(u).unwrap());
+                assert!((ru - r).abs() < 1e-3);
+            }
+            assert_eq!(drawing.y(nodes[4]), Some(0.));
+        }
+
+        let drawing = DrawingEuclidean2d::<_, f32>::initial_placement_with_strategy(
+            &graph,
+            &InitialPlacement::Grid,
+        );
+        assert_eq!(drawing.x(nodes[5]), Some(20.));
+        assert_eq!(drawing.y(nodes[5]), Some(10.));
+
+        let strategy = InitialPlacement::RandomDisk {
+            radius: 5.,
+            seed: 42,
+        };
+        let a = DrawingEuclidean2d::<_, f32>::initial_placement_with_strategy(&graph, &strategy);
+        let b = DrawingEuclidean2d::<_, f32>::initial_placement_with_strategy(&graph, &strategy);
+        for &u in &nodes {
+            assert_eq!(a.x(u), b.x(u));
+            assert!(a.x(u).unwrap().hypot(a.y(u).unwrap()) <= 5.);
+        }
+
+        let parent = nodes
+            .iter()
+            .map(|&u| (u, nodes[u.index() / 3 * 3]))
+            .collect::<HashMap<_, _>>();
+        let drawing = DrawingEuclidean2d::<_, f32>::initial_placement_with_strategy(
+            &graph,
+            &InitialPlacement::Coarse {
+                drawing: &a,
+                parent: &parent,
+                jitter: 1.,
+            },
+        );
+        assert_eq!(drawing.x(nodes[3]), a.x(nodes[3]));
+        let dx = drawing.x(nodes[4]).unwrap() - a.x(nodes[3]).unwrap();
+        let dy = drawing.y(nodes[4]).unwrap() - a.y(nodes[3]).unwrap();
+        assert!((dx.hypot(dy) - 1.).abs() < 1e-4);
+    }
+}
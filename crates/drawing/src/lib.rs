@@ -1,19 +1,81 @@
+//! Drawing containers and the metric spaces they are embedded in.
+//!
+//! With `default-features = false, features = ["alloc"]` the crate builds under
+//! `#![no_std]`: the drawing types, metrics and transforms only need `alloc`
+//! and `libm`.
+//! The `std` feature adds the petgraph constructors and the ndarray/rand
+//! helpers.
+//!
+//! Only this crate is `no_std`.
+//! The layout crates, including the SGD kernels in `petgraph-layout-sgd`,
+//! still depend on petgraph, ndarray and the shortest-path crate, which all
+//! require `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "alloc")))]
+compile_error!("petgraph-drawing requires either the \"std\" or the \"alloc\" feature");
+
+extern crate alloc;
+
 mod drawing;
 mod metric;
+#[cfg(feature = "serde")]
+mod serialize;
+mod transform;
 
-use ndarray::prelude::*;
+use core::hash::Hash;
 use num_traits::FromPrimitive;
-use std::hash::Hash;
+
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 pub trait DrawingIndex: Eq + Hash {}
 impl<T> DrawingIndex for T where T: Eq + Hash {}
-pub trait DrawingValue: NdFloat + FromPrimitive {}
-impl<T> DrawingValue for T where T: NdFloat + FromPrimitive {}
+#[cfg(feature = "std")]
+pub trait DrawingValue: ndarray::NdFloat + FromPrimitive {}
+#[cfg(feature = "std")]
+impl<T> DrawingValue for T where T: ndarray::NdFloat + FromPrimitive {}
+
+#[cfg(not(feature = "std"))]
+pub trait DrawingValue:
+    num_traits::Float
+    + core::ops::AddAssign
+    + core::ops::SubAssign
+    + core::ops::MulAssign
+    + core::ops::DivAssign
+    + core::fmt::Debug
+    + core::fmt::Display
+    + Send
+    + Sync
+    + 'static
+    + FromPrimitive
+{
+}
+#[cfg(not(feature = "std"))]
+impl<T> DrawingValue for T where
+    T: num_traits::Float
+        + core::ops::AddAssign
+        + core::ops::SubAssign
+        + core::ops::MulAssign
+        + core::ops::DivAssign
+        + core::fmt::Debug
+        + core::fmt::Display
+        + Send
+        + Sync
+        + 'static
+        + FromPrimitive
+{
+}
 
+#[cfg(feature = "std")]
+pub use drawing::initial_placement::InitialPlacement;
 pub use drawing::{
     drawing_euclidean::DrawingEuclidean, drawing_euclidean_2d::DrawingEuclidean2d,
     drawing_hyperbolic_2d::DrawingHyperbolic2d, drawing_spherical_2d::DrawingSpherical2d,
-    drawing_torus2d::DrawingTorus2d, Drawing,
+    drawing_torus2d::DrawingTorus2d, Drawing, MissingPosition,
 };
 pub use metric::{
     metric_euclidean::{DeltaEuclidean, MetricEuclidean},
@@ -23,3 +85,4 @@ pub use metric::{
     metric_torus2d::{DeltaTorus2d, MetricTorus2d, TorusValue},
     Delta, Metric,
 };
+pub use transform::Transform;
@@ -1,5 +1,6 @@
 use crate::{Delta, DrawingValue, Metric};
-use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
 
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct DeltaEuclidean<S>(pub Vec<S>);
@@ -84,6 +85,7 @@ where
 }
 
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MetricEuclidean<S>(pub Vec<S>);
 
 impl<S> MetricEuclidean<S>
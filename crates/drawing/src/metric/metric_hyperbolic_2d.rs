@@ -1,5 +1,5 @@
 use crate::{Delta, DrawingValue, Metric};
-use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
 
 #[derive(Copy, Clone, Debug, Default)]
 pub struct DeltaHyperbolic2d<S>(pub S, pub S);
@@ -59,6 +59,7 @@ where
 }
 
 #[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MetricHyperbolic2d<S>(pub S, pub S);
 
 impl<S> AddAssign<DeltaHyperbolic2d<S>> for MetricHyperbolic2d<S>
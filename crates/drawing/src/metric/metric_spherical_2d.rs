@@ -1,7 +1,7 @@
 use num_traits::clamp;
 
 use crate::{Delta, DrawingValue, Metric};
-use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
 
 #[derive(Copy, Clone, Debug, Default)]
 pub struct DeltaSpherical2d<S>(pub S, pub S);
@@ -61,6 +61,7 @@ where
 }
 
 #[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MetricSpherical2d<S>(pub S, pub S);
 
 impl<S> AddAssign<DeltaSpherical2d<S>> for MetricSpherical2d<S>
@@ -114,18 +115,20 @@ fn to_tangent_space<S>(x: (S, S), y: (S, S)) -> (S, S)
 where
     S: DrawingValue,
 {
-    let ux = (-x.0.sin() * x.1.sin(), S::zero(), x.0.cos() * x.1.sin());
+    let ux = (-x.0.sin(), S::zero(), x.0.cos());
     let vx = (x.0.cos() * x.1.cos(), -x.1.sin(), x.0.sin() * x.1.cos());
+    let ex = (x.0.cos() * x.1.sin(), x.1.cos(), x.0.sin() * x.1.sin());
     let ey = (y.0.cos() * y.1.sin(), y.1.cos(), y.0.sin() * y.1.sin());
-    let d = clamp(
-        x.1.sin() * y.1.sin() * (y.0 - x.0).cos() + x.1.cos() * y.1.cos(),
-        -S::one(),
-        S::one(),
-    )
-    .acos();
+    let c = clamp(ex.0 * ey.0 + ex.1 * ey.1 + ex.2 * ey.2, -S::one(), S::one());
+    let t = (ey.0 - c * ex.0, ey.1 - c * ex.1, ey.2 - c * ex.2);
+    let t_norm = (t.0 * t.0 + t.1 * t.1 + t.2 * t.2).sqrt();
+    if t_norm == S::zero() {
+        return (S::zero(), S::zero());
+    }
+    let d = c.acos() / t_norm;
     (
-        d * (ux.0 * ey.0 + ux.1 * ey.1 + ux.2 * ey.2),
-        d * (vx.0 * ey.0 + vx.1 * ey.1 + vx.2 * ey.2),
+        d * (ux.0 * t.0 + ux.1 * t.1 + ux.2 * t.2),
+        d * (vx.0 * t.0 + vx.1 * t.1 + vx.2 * t.2),
     )
 }
 
@@ -133,7 +136,10 @@ fn from_tangent_space<S>(x: (S, S), z: (S, S)) -> (S, S)
 where
     S: DrawingValue,
 {
-    let ux = (-x.0.sin() * x.1.sin(), S::zero(), x.0.cos() * x.1.sin());
+    if z.0 == S::zero() && z.1 == S::zero() {
+        return x;
+    }
+    let ux = (-x.0.sin(), S::zero(), x.0.cos());
     let vx = (x.0.cos() * x.1.cos(), -x.1.sin(), x.0.sin() * x.1.cos());
     let p = (z.1, -z.0);
     let n = {
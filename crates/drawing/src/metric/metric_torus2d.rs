@@ -1,18 +1,25 @@
 use crate::{Delta, DrawingValue, Metric};
-use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
 
 fn torus_value<S>(value: S) -> S
 where
     S: DrawingValue,
 {
-    if value < S::zero() {
-        value.fract() + S::one()
+    let value = value.fract();
+    let value = if value < S::zero() {
+        value + S::one()
     } else {
-        value.fract()
+        value
+    };
+    if value < S::one() {
+        value
+    } else {
+        S::zero()
     }
 }
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TorusValue<S>(pub S);
 
 impl<S> TorusValue<S>
@@ -153,6 +160,7 @@ where
 }
 
 #[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MetricTorus2d<S>(pub TorusValue<S>, pub TorusValue<S>);
 
 impl<S> MetricTorus2d<S>
@@ -237,6 +245,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
     #[test]
     fn test_torus_value() {
         let a = 8.625;
@@ -264,4 +273,24 @@ mod tests {
         let z = DeltaTorus2d(-0.5, 0.25);
         assert_eq!(&x - &y, z);
     }
+
+    proptest! {
+        #[test]
+        fn test_torus_value_range(a in -1e6f32..1e6) {
+            let v = torus_value(a);
+            prop_assert!((0. ..1.).contains(&v));
+        }
+
+        #[test]
+        fn test_torus_delta_is_shortest(
+            x1 in 0f32..1., y1 in 0f32..1., x2 in 0f32..1., y2 in 0f32..1.
+        ) {
+            let x = MetricTorus2d(TorusValue::new(x1), TorusValue::new(y1));
+            let y = MetricTorus2d(TorusValue::new(x2), TorusValue::new(y2));
+            let d = &x - &y;
+            prop_assert!(d.0.abs() <= 0.5 + 1e-6);
+            prop_assert!(d.1.abs() <= 0.5 + 1e-6);
+            prop_assert!((d.norm() - (&y - &x).norm()).abs() < 1e-5);
+        }
+    }
 }
@@ -0,0 +1,139 @@
+use crate::{
+    Drawing, DrawingEuclidean, DrawingEuclidean2d, DrawingHyperbolic2d, DrawingIndex,
+    DrawingSpherical2d, DrawingTorus2d, DrawingValue, MetricEuclidean, MetricEuclidean2d,
+    MetricHyperbolic2d, MetricSpherical2d, MetricTorus2d,
+};
+use alloc::vec::Vec;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize)]
+struct DrawingRef<'a, N, M> {
+    indices: Vec<&'a N>,
+    coordinates: Vec<&'a M>,
+}
+
+#[derive(Deserialize)]
+struct DrawingData<N, M> {
+    indices: Vec<N>,
+    coordinates: Vec<M>,
+}
+
+impl<N, M> DrawingData<N, M> {
+    fn check<E: Error>(&self) -> Result<(), E> {
+        if self.indices.len() == self.coordinates.len() {
+            Ok(())
+        } else {
+            Err(E::custom(
+                "indices and coordinates must have the same length",
+            ))
+        }
+    }
+}
+
+macro_rules! impl_serde {
+    ($drawing:ident, $metric:ident) => {
+        impl<N, S> Serialize for $drawing<N, S>
+        where
+            N: DrawingIndex + Serialize,
+            S: DrawingValue + Serialize,
+        {
+            fn serialize<Se: Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+                DrawingRef {
+                    indices: (0..self.len()).map(|i| self.node_id(i)).collect(),
+                    coordinates: (0..self.len()).map(|i| self.raw_entry(i)).collect(),
+                }
+                .serialize(serializer)
+            }
+        }
+
+        impl<'de, N, S> Deserialize<'de> for $drawing<N, S>
+        where
+            N: DrawingIndex + Copy + Deserialize<'de>,
+            S: DrawingValue + Default + Deserialize<'de>,
+        {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let data = DrawingData::<N, $metric<S>>::deserialize(deserializer)?;
+                data.check()?;
+                let mut drawing = Self::from_node_indices(&data.indices);
+                for (i, p) in data.coordinates.into_iter().enumerate() {
+                    *drawing.raw_entry_mut(i) = p;
+                }
+                Ok(drawing)
+            }
+        }
+    };
+}
+
+impl_serde!(DrawingEuclidean2d, MetricEuclidean2d);
+impl_serde!(DrawingHyperbolic2d, MetricHyperbolic2d);
+impl_serde!(DrawingSpherical2d, MetricSpherical2d);
+impl_serde!(DrawingTorus2d, MetricTorus2d);
+
+impl<N, S> Serialize for DrawingEuclidean<N, S>
+where
+    N: DrawingIndex + Serialize,
+    S: DrawingValue + Serialize,
+{
+    fn serialize<Se: Serializer>(&self, serializer: Se) -> Result<Se::Ok, Se::Error> {
+        DrawingRef {
+            indices: (0..self.len()).map(|i| self.node_id(i)).collect(),
+            coordinates: (0..self.len()).map(|i| self.raw_entry(i)).collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, N, S> Deserialize<'de> for DrawingEuclidean<N, S>
+where
+    N: DrawingIndex + Copy + Deserialize<'de>,
+    S: DrawingValue + Default + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = DrawingData::<N, MetricEuclidean<S>>::deserialize(deserializer)?;
+        data.check()?;
+        let dimension = data.coordinates.first().map_or(0, |p| p.0.len());
+        if data.coordinates.iter().any(|p| p.0.len() != dimension) {
+            return Err(D::Error::custom("coordinates must have the same dimension"));
+        }
+        let mut drawing = Self::from_node_indices(&data.indices, dimension);
+        for (i, p) in data.coordinates.into_iter().enumerate() {
+            *drawing.raw_entry_mut(i) = p;
+        }
+        Ok(drawing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serde_drawing_euclidean_2d() {
+        let mut drawing = DrawingEuclidean2d::<u32, f32>::from_node_indices(&[3, 1, 2]);
+        for i in 0..3 {
+            drawing.raw_entry_mut(i).0 = i as f32;
+            drawing.raw_entry_mut(i).1 = -(i as f32);
+        }
+        let json = serde_json::to_string(&drawing).unwrap();
+        let restored: DrawingEuclidean2d<u32, f32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.len(), 3);
+        for &u in &[3, 1, 2] {
+            assert_eq!(restored.x(u), drawing.x(u));
+            assert_eq!(restored.y(u), drawing.y(u));
+        }
+        assert!(serde_json::from_str::<DrawingEuclidean2d<u32, f32>>(
+            r#"{"indices":[0,1],"coordinates":[[0.0,0.0]]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_serde_drawing_euclidean() {
+        let mut drawing = DrawingEuclidean::<u32, f32>::from_node_indices(&[0, 1], 3);
+        drawing.set(1, 2, 5.);
+        let json = serde_json::to_string(&drawing).unwrap();
+        let restored: DrawingEuclidean<u32, f32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.dimension(), 3);
+        assert_eq!(restored.get(1, 2), Some(5.));
+    }
+}
@@ -0,0 +1,258 @@
+use crate::{Drawing, DrawingEuclidean, DrawingEuclidean2d, DrawingIndex, DrawingValue};
+use alloc::{vec, vec::Vec};
+use num_traits::{Float, FromPrimitive, One, Zero};
+
+fn jacobi_eigenvectors<S>(a: &mut [Vec<S>]) -> Vec<Vec<S>>
+where
+    S: DrawingValue,
+{
+    let n = a.len();
+    let mut v = (0..n)
+        .map(|i| {
+            let mut row = vec![S::zero(); n];
+            row[i] = S::one();
+            row
+        })
+        .collect::<Vec<_>>();
+    let two = S::one() + S::one();
+    for _ in 0..50 * n * n {
+        let mut p = 0;
+        let mut q = 0;
+        let mut max = S::zero();
+        for (i, row) in a.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate().skip(i + 1) {
+                if x.abs() > max {
+                    max = x.abs();
+                    p = i;
+                    q = j;
+                }
+            }
+        }
+        if max <= S::epsilon() {
+            break;
+        }
+        let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+        let t = theta.signum() / (theta.abs() + (theta * theta + S::one()).sqrt());
+        let c = S::one() / (t * t + S::one()).sqrt();
+        let s = t * c;
+        for row in a.iter_mut().chain(v.iter_mut()) {
+            let xp = row[p];
+            let xq = row[q];
+            row[p] = c * xp - s * xq;
+            row[q] = s * xp + c * xq;
+        }
+        let (head, tail) = a.split_at_mut(q);
+        for (xp, xq) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+            let (yp, yq) = (*xp, *xq);
+            *xp = c * yp - s * yq;
+            *xq = s * yp + c * yq;
+        }
+    }
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| {
+        a[j][j]
+            .partial_cmp(&a[i][i])
+            .unwrap_or_else(|| a[i][i].is_nan().cmp(&a[j][j].is_nan()))
+    });
+    order
+        .into_iter()
+        .map(|j| (0..n).map(|i| v[i][j]).collect())
+        .collect()
+}
+
+pub trait Transform: Drawing {
+    type S: DrawingValue;
+
+    fn coordinate(&self, i: usize, d: usize) -> Self::S;
+
+    fn coordinate_mut(&mut self, i: usize, d: usize) -> &mut Self::S;
+
+    fn translate(&mut self, offset: &[Self::S]) {
+        for i in 0..self.len() {
+            for (d, &o) in offset.iter().enumerate().take(self.dimension()) {
+                *self.coordinate_mut(i, d) += o;
+            }
+        }
+    }
+
+    fn scale(&mut self, factor: Self::S) {
+        for i in 0..self.len() {
+            for d in 0..self.dimension() {
+                *self.coordinate_mut(i, d) *= factor;
+            }
+        }
+    }
+
+    fn mirror(&mut self, axis: usize) {
+        for i in 0..self.len() {
+            let x = self.coordinate_mut(i, axis);
+            *x = -*x;
+        }
+    }
+
+    fn rotate(&mut self, a: usize, b: usize, angle: Self::S) {
+        let (sin, cos) = angle.sin_cos();
+        for i in 0..self.len() {
+            let x = self.coordinate(i, a);
+            let y = self.coordinate(i, b);
+            *self.coordinate_mut(i, a) = cos * x - sin * y;
+            *self.coordinate_mut(i, b) = sin * x + cos * y;
+        }
+    }
+
+    fn bounding_box(&self) -> (Vec<Self::S>, Vec<Self::S>) {
+        let dimension = self.dimension();
+        let mut min = vec![Self::S::infinity(); dimension];
+        let mut max = vec![Self::S::neg_infinity(); dimension];
+        for i in 0..self.len() {
+            for d in 0..dimension {
+                let x = self.coordinate(i, d);
+                min[d] = min[d].min(x);
+                max[d] = max[d].max(x);
+            }
+        }
+        (min, max)
+    }
+
+    fn fit_to_rect(&mut self, min: &[Self::S], max: &[Self::S]) {
+        if self.len() == 0 {
+            return;
+        }
+        let two = Self::S::one() + Self::S::one();
+        let (lower, upper) = self.bounding_box();
+        let mut factor = Self::S::infinity();
+        for d in 0..self.dimension() {
+            let extent = upper[d] - lower[d];
+            if extent > Self::S::zero() {
+                factor = factor.min((max[d] - min[d]) / extent);
+            }
+        }
+        if !factor.is_finite() {
+            factor = Self::S::one();
+        }
+        let offset = (0..self.dimension())
+            .map(|d| (min[d] + max[d]) / two - (lower[d] + upper[d]) / two * factor)
+            .collect::<Vec<_>>();
+        self.scale(factor);
+        self.translate(&offset);
+    }
+
+    fn align_principal_axes(&mut self) {
+        let n = self.len();
+        let dimension = self.dimension();
+        if n == 0 {
+            return;
+        }
+        let count = Self::S::from_usize(n).unwrap();
+        let mean = (0..dimension)
+            .map(|d| (0..n).fold(Self::S::zero(), |s, i| s + self.coordinate(i, d)) / count)
+            .collect::<Vec<_>>();
+        self.translate(&mean.iter().map(|&m| -m).collect::<Vec<_>>());
+        let mut covariance = vec![vec![Self::S::zero(); dimension]; dimension];
+        for i in 0..n {
+            for (a, row) in covariance.iter_mut().enumerate() {
+                for (b, c) in row.iter_mut().enumerate() {
+                    *c += self.coordinate(i, a) * self.coordinate(i, b);
+                }
+            }
+        }
+        let axes = jacobi_eigenvectors(&mut covariance);
+        let mut projected = vec![Self::S::zero(); dimension];
+        for i in 0..n {
+            for (p, axis) in projected.iter_mut().zip(axes.iter()) {
+                *p = (0..dimension)
+                    .fold(Self::S::zero(), |s, d| s + axis[d] * self.coordinate(i, d));
+            }
+            for (d, &p) in projected.iter().enumerate() {
+                *self.coordinate_mut(i, d) = p;
+            }
+        }
+    }
+}
+
+impl<N, S> Transform for DrawingEuclidean2d<N, S>
+where
+    N: DrawingIndex,
+    S: DrawingValue,
+{
+    type S = S;
+
+    fn coordinate(&self, i: usize, d: usize) -> S {
+        let p = self.raw_entry(i);
+        if d == 0 {
+            p.0
+        } else {
+            p.1
+        }
+    }
+
+    fn coordinate_mut(&mut self, i: usize, d: usize) -> &mut S {
+        let p = self.raw_entry_mut(i);
+        if d == 0 {
+            &mut p.0
+        } else {
+            &mut p.1
+        }
+    }
+}
+
+impl<N, S> Transform for DrawingEuclidean<N, S>
+where
+    N: DrawingIndex,
+    S: DrawingValue,
+{
+    type S = S;
+
+    fn coordinate(&self, i: usize, d: usize) -> S {
+        self.raw_entry(i).0[d]
+    }
+
+    fn coordinate_mut(&mut self, i: usize, d: usize) -> &mut S {
+        &mut self.raw_entry_mut(i).0[d]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drawing(points: &[(f32, f32)]) -> DrawingEuclidean2d<usize, f32> {
+        let indices = (0..points.len()).collect::<Vec<_>>();
+        let mut drawing = DrawingEuclidean2d::from_node_indices(&indices);
+        for (i, &(x, y)) in points.iter().enumerate() {
+            drawing.set_x(i, x);
+            drawing.set_y(i, y);
+        }
+        drawing
+    }
+
+    #[test]
+    fn test_fit_to_rect() {
+        let mut drawing = drawing(&[(0., 0.), (2., 1.), (4., 0.)]);
+        drawing.fit_to_rect(&[0., 0.], &[100., 100.]);
+        let (min, max) = drawing.bounding_box();
+        assert!((min[0] - 0.).abs() < 1e-4 && (max[0] - 100.).abs() < 1e-4);
+        assert!((min[1] - 37.5).abs() < 1e-4 && (max[1] - 62.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_mirror_and_rotate() {
+        let mut drawing = drawing(&[(1., 2.)]);
+        drawing.mirror(0);
+        assert_eq!(drawing.x(0), Some(-1.));
+        drawing.rotate(0, 1, core::f32::consts::FRAC_PI_2);
+        assert!((drawing.x(0).unwrap() + 2.).abs() < 1e-5);
+        assert!((drawing.y(0).unwrap() + 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_align_principal_axes() {
+        let mut drawing = drawing(&[(0., 0.), (1., 1.), (2., 2.), (3., 3.)]);
+        drawing.align_principal_axes();
+        for i in 0..4 {
+            assert!(drawing.y(i).unwrap().abs() < 1e-4);
+        }
+        let (min, max) = drawing.bounding_box();
+        assert!((max[0] - min[0] - 3. * 2f32.sqrt()).abs() < 1e-4);
+    }
+}
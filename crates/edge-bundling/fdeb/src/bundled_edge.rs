@@ -0,0 +1,117 @@
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BundledEdge {
+    pub points: Vec<(f32, f32)>,
+}
+
+fn segment_length(p: (f32, f32), q: (f32, f32)) -> f32 {
+    (q.0 - p.0).hypot(q.1 - p.1)
+}
+
+impl BundledEdge {
+    pub fn new(points: Vec<(f32, f32)>) -> BundledEdge {
+        BundledEdge { points }
+    }
+
+    pub fn length(&self) -> f32 {
+        self.points
+            .windows(2)
+            .map(|w| segment_length(w[0], w[1]))
+            .sum()
+    }
+
+    pub fn curvature(&self) -> f32 {
+        self.points
+            .windows(3)
+            .map(|w| {
+                let (ax, ay) = (w[1].0 - w[0].0, w[1].1 - w[0].1);
+                let (bx, by) = (w[2].0 - w[1].0, w[2].1 - w[1].1);
+                if (ax == 0. && ay == 0.) || (bx == 0. && by == 0.) {
+                    0.
+                } else {
+                    (ax * by - ay * bx).atan2(ax * bx + ay * by).abs()
+                }
+            })
+            .sum()
+    }
+
+    pub fn resample(&self, num_points: usize) -> BundledEdge {
+        let n = self.points.len();
+        if n < 2 || num_points < 2 {
+            return BundledEdge::new(self.points.iter().take(num_points).copied().collect());
+        }
+        let total = self.length();
+        let mut points = Vec::with_capacity(num_points);
+        points.push(self.points[0]);
+        let mut k = 0;
+        let mut offset = 0.;
+        for i in 1..num_points - 1 {
+            let target = total * i as f32 / (num_points - 1) as f32;
+            while k < n - 2 && offset + segment_length(self.points[k], self.points[k + 1]) < target
+            {
+                offset += segment_length(self.points[k], self.points[k + 1]);
+                k += 1;
+            }
+            let p = self.points[k];
+            let q = self.points[k + 1];
+            let l = segment_length(p, q);
+            let t = if l > 0. {
+                ((target - offset) / l).clamp(0., 1.)
+            } else {
+                0.
+            };
+            points.push((p.0 + t * (q.0 - p.0), p.1 + t * (q.1 - p.1)));
+        }
+        points.push(self.points[n - 1]);
+        BundledEdge::new(points)
+    }
+
+    pub fn cubic_bezier_segments(&self) -> Vec<[(f32, f32); 4]> {
+        let n = self.points.len();
+        (1..n)
+            .map(|i| {
+                let p0 = self.points[i.saturating_sub(2)];
+                let p1 = self.points[i - 1];
+                let p2 = self.points[i];
+                let p3 = self.points[(i + 1).min(n - 1)];
+                [
+                    p1,
+                    (p1.0 + (p2.0 - p0.0) / 6., p1.1 + (p2.1 - p0.1) / 6.),
+                    (p2.0 - (p3.0 - p1.0) / 6., p2.1 - (p3.1 - p1.1) / 6.),
+                    p2,
+                ]
+            })
+            .collect()
+    }
+}
+
+impl From<Vec<(f32, f32)>> for BundledEdge {
+    fn from(points: Vec<(f32, f32)>) -> BundledEdge {
+        BundledEdge::new(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_edge() {
+        let edge = BundledEdge::new(vec![(0., 0.), (3., 0.), (3., 4.)]);
+        assert!((edge.length() - 7.).abs() < 1e-6);
+        assert!((edge.curvature() - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+
+        let resampled = edge.resample(8);
+        assert_eq!(resampled.points.len(), 8);
+        assert_eq!(resampled.points[0], (0., 0.));
+        assert_eq!(resampled.points[7], (3., 4.));
+        for w in resampled.points.windows(2) {
+            assert!((segment_length(w[0], w[1]) - 1.).abs() < 1e-5);
+        }
+
+        let segments = edge.cubic_bezier_segments();
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0][0], (0., 0.));
+        assert_eq!(segments[0][3], segments[1][0]);
+        assert_eq!(segments[1][3], (3., 4.));
+    }
+}
@@ -1,6 +1,42 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_drawing::DrawingEuclidean2d;
+//! use petgraph_edge_bundling_fdeb::{fdeb, EdgeBundlingOptions};
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! graph.add_edge(nodes[0], nodes[1], ());
+//! graph.add_edge(nodes[2], nodes[3], ());
+//! let mut drawing = DrawingEuclidean2d::new(&graph);
+//! for (&u, (x, y)) in nodes.iter().zip([(0., 0.), (10., 0.), (0., 1.), (10., 1.)]) {
+//!     drawing.set_x(u, x);
+//!     drawing.set_y(u, y);
+//! }
+//! let options = EdgeBundlingOptions::<f32>::new();
+//! let bundled = fdeb(&graph, &drawing, &options).unwrap();
+//! assert_eq!(bundled.len(), 2);
+//! for points in bundled.values() {
+//!     assert!(points.len() > 2);
+//!     assert!(points.iter().all(|&(x, y)| x.is_finite() && y.is_finite()));
+//! }
+//! ```
+mod bundled_edge;
+
 use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers};
-use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex, MetricEuclidean2d};
-use std::{collections::HashMap, f32, hash::Hash};
+use petgraph_drawing::{
+    Drawing, DrawingEuclidean2d, DrawingIndex, MetricEuclidean2d, MissingPosition,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    f32,
+    hash::Hash,
+};
+
+pub use bundled_edge::BundledEdge;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "simd")]
+use wide::{f32x4, CmpGt, CmpLe};
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug)]
@@ -66,7 +102,7 @@ fn angle(p1: Point, p2: Point, q1: Point, q2: Point) -> f32 {
     let p_norm = distance(p1.x, p1.y, p2.x, p2.y);
     let q_norm = distance(q1.x, q1.y, q2.x, q2.y);
     let pq = (p2.x - p1.x) * (q2.x - q1.x) + (p2.y - p1.y) * (q2.y - q1.y);
-    (pq / p_norm / q_norm).acos()
+    (pq / p_norm / q_norm).clamp(-1., 1.).acos()
 }
 
 fn compatibility(p1: Point, p2: Point, q1: Point, q2: Point) -> f32 {
@@ -152,44 +188,193 @@ fn apply_spring_force(
     }
 }
 
-fn apply_electrostatic_force(
-    mid_points: &mut Vec<Point>,
-    segments: &Vec<LineSegment>,
-    edge_pairs: &Vec<EdgePair>,
-    num_p: usize,
-) {
+fn near_segment_pairs(
+    points: &[Point],
+    segments: &[LineSegment],
+    radius: f32,
+) -> HashSet<(usize, usize)> {
+    let total_length = segments
+        .iter()
+        .map(|segment| {
+            let (p, q) = (points[segment.source], points[segment.target]);
+            distance(p.x, p.y, q.x, q.y)
+        })
+        .sum::<f32>();
+    let cell_size = radius.max(total_length / segments.len().max(1) as f32);
+    let cell = |x: f32| (x / cell_size).floor() as i64;
+    let mut grid = HashMap::<(i64, i64), Vec<usize>>::new();
+    for (s, segment) in segments.iter().enumerate() {
+        let (p, q) = (points[segment.source], points[segment.target]);
+        let steps = (distance(p.x, p.y, q.x, q.y) / cell_size).ceil().max(1.) as usize;
+        for k in 0..steps {
+            let r0 = k as f32 / steps as f32;
+            let r1 = (k + 1) as f32 / steps as f32;
+            let (x0, y0) = (p.x + r0 * (q.x - p.x), p.y + r0 * (q.y - p.y));
+            let (x1, y1) = (p.x + r1 * (q.x - p.x), p.y + r1 * (q.y - p.y));
+            for cx in cell(x0.min(x1))..=cell(x0.max(x1)) {
+                for cy in cell(y0.min(y1))..=cell(y0.max(y1)) {
+                    let entry = grid.entry((cx, cy)).or_default();
+                    if entry.last() != Some(&s) {
+                        entry.push(s);
+                    }
+                }
+            }
+        }
+    }
+    let mut pairs = HashSet::new();
+    for (&(cx, cy), cell) in grid.iter() {
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                if let Some(neighbors) = grid.get(&(cx + dx, cy + dy)) {
+                    for &p in cell.iter() {
+                        for &q in neighbors.iter() {
+                            if p < q {
+                                pairs.insert((p, q));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    pairs
+}
+
+/// Maps `f` over `0..n`, using the rayon thread pool when the `parallel`
+/// feature is enabled.
+#[cfg(not(feature = "parallel"))]
+fn map_indices<T, F>(n: usize, f: F) -> Vec<T>
+where
+    F: Fn(usize) -> T,
+{
+    (0..n).map(f).collect()
+}
+
+#[cfg(feature = "parallel")]
+fn map_indices<T, F>(n: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync + Send,
+{
+    (0..n).into_par_iter().map(f).collect()
+}
+
+/// Lists the compatible partners of each segment as `(segment,
+/// compatibility, reversed)`, where `reversed` tells whether the partner's
+/// subdivision points pair up in the opposite order.
+fn interactions(num_segments: usize, edge_pairs: &[EdgePair]) -> Vec<Vec<(usize, f32, bool)>> {
+    let mut interactions = vec![vec![]; num_segments];
     for pair in edge_pairs {
-        let EdgePair {
-            p,
-            q,
-            theta,
-            compatibility: c_e,
-        } = pair;
-        let segment_p = &segments[*p];
-        let segment_q = &segments[*q];
-        for i in 0..num_p {
-            let j = if *theta < f32::consts::PI / 2.0 {
-                i
-            } else {
-                num_p - i - 1
-            };
-            let pi = mid_points[segment_p.point_indices[i as usize]];
-            let qi = mid_points[segment_q.point_indices[j as usize]];
-            let dx = qi.x - pi.x;
-            let dy = qi.y - pi.y;
-            if dx.abs() > 1e-6 || dy.abs() > 1e-6 {
-                let w = c_e / (dx * dx + dy * dy).sqrt();
-                {
-                    let ref mut qi = mid_points[segment_q.point_indices[j as usize]];
-                    qi.vx -= dx * w;
-                    qi.vy -= dy * w;
+        let reversed = pair.theta >= f32::consts::PI / 2.0;
+        interactions[pair.p].push((pair.q, pair.compatibility, reversed));
+        interactions[pair.q].push((pair.p, pair.compatibility, reversed));
+    }
+    interactions
+}
+
+#[cfg(not(feature = "simd"))]
+fn electrostatic_force(
+    mid_points: &[Point],
+    segments: &[LineSegment],
+    segment: &LineSegment,
+    partners: &[(usize, f32, bool)],
+    interaction_radius: Option<f32>,
+) -> Vec<(f32, f32)> {
+    let num_p = segment.point_indices.len();
+    (0..num_p)
+        .map(|i| {
+            let pi = mid_points[segment.point_indices[i]];
+            let mut force = (0., 0.);
+            for &(q, c_e, reversed) in partners {
+                let j = if reversed { num_p - i - 1 } else { i };
+                let qi = mid_points[segments[q].point_indices[j]];
+                let dx = qi.x - pi.x;
+                let dy = qi.y - pi.y;
+                if let Some(radius) = interaction_radius {
+                    if dx * dx + dy * dy > radius * radius {
+                        continue;
+                    }
                 }
-                {
-                    let ref mut pi = mid_points[segment_p.point_indices[i as usize]];
-                    pi.vx += dx * w;
-                    pi.vy += dy * w;
+                if dx.abs() > 1e-6 || dy.abs() > 1e-6 {
+                    let w = c_e / (dx * dx + dy * dy).sqrt();
+                    force.0 += dx * w;
+                    force.1 += dy * w;
+                }
+            }
+            force
+        })
+        .collect()
+}
+
+/// Same as the scalar version, processing four partners at a time. On
+/// wasm32 this compiles to simd128 instructions when the target feature is
+/// enabled.
+#[cfg(feature = "simd")]
+fn electrostatic_force(
+    mid_points: &[Point],
+    segments: &[LineSegment],
+    segment: &LineSegment,
+    partners: &[(usize, f32, bool)],
+    interaction_radius: Option<f32>,
+) -> Vec<(f32, f32)> {
+    let num_p = segment.point_indices.len();
+    let radius2 = f32x4::splat(interaction_radius.map_or(f32::INFINITY, |r| r * r));
+    let eps = f32x4::splat(1e-6);
+    (0..num_p)
+        .map(|i| {
+            let pi = mid_points[segment.point_indices[i]];
+            let (px, py) = (f32x4::splat(pi.x), f32x4::splat(pi.y));
+            let mut fx = f32x4::ZERO;
+            let mut fy = f32x4::ZERO;
+            for chunk in partners.chunks(4) {
+                // Unused lanes sit on pi itself and are masked out below.
+                let mut qx = [pi.x; 4];
+                let mut qy = [pi.y; 4];
+                let mut c = [0.; 4];
+                for (k, &(q, c_e, reversed)) in chunk.iter().enumerate() {
+                    let j = if reversed { num_p - i - 1 } else { i };
+                    let qi = mid_points[segments[q].point_indices[j]];
+                    qx[k] = qi.x;
+                    qy[k] = qi.y;
+                    c[k] = c_e;
                 }
+                let dx = f32x4::from(qx) - px;
+                let dy = f32x4::from(qy) - py;
+                let d2 = dx * dx + dy * dy;
+                let mask = d2.cmp_le(radius2) & (dx.abs().cmp_gt(eps) | dy.abs().cmp_gt(eps));
+                let w = mask.blend(f32x4::from(c) / d2.sqrt(), f32x4::ZERO);
+                fx += dx * w;
+                fy += dy * w;
             }
+            (fx.reduce_add(), fy.reduce_add())
+        })
+        .collect()
+}
+
+/// Each segment gathers the forces on its own subdivision points, so the
+/// segments can be processed independently.
+fn apply_electrostatic_force(
+    mid_points: &mut [Point],
+    segments: &[LineSegment],
+    interactions: &[Vec<(usize, f32, bool)>],
+    interaction_radius: Option<f32>,
+) {
+    let forces = {
+        let mid_points = &*mid_points;
+        map_indices(segments.len(), |s| {
+            electrostatic_force(
+                mid_points,
+                segments,
+                &segments[s],
+                &interactions[s],
+                interaction_radius,
+            )
+        })
+    };
+    for (segment, force) in segments.iter().zip(forces) {
+        for (&k, (fx, fy)) in segment.point_indices.iter().zip(force) {
+            mid_points[k].vx += fx;
+            mid_points[k].vy += fy;
         }
     }
 }
@@ -201,6 +386,7 @@ pub struct EdgeBundlingOptions<S> {
     s_step: S,
     i_step: S,
     minimum_edge_compatibility: S,
+    interaction_radius: Option<S>,
 }
 
 impl<S> EdgeBundlingOptions<S> {
@@ -212,125 +398,161 @@ impl<S> EdgeBundlingOptions<S> {
             s_step: 0.5,
             i_step: 2. / 3.,
             minimum_edge_compatibility: 0.6,
+            interaction_radius: None,
         }
     }
+
+    pub fn interaction_radius(&self) -> Option<S>
+    where
+        S: Copy,
+    {
+        self.interaction_radius
+    }
+
+    /// Limits electrostatic forces to subdivision points closer than
+    /// `radius`. Only edge pairs that come within `radius` of each other in
+    /// the input drawing are considered. `None`, like a radius that is not
+    /// positive, lets all compatible pairs interact.
+    pub fn set_interaction_radius(&mut self, radius: Option<S>)
+    where
+        S: PartialOrd + Default,
+    {
+        self.interaction_radius = radius.filter(|r| *r > S::default());
+    }
 }
 
-pub fn fdeb<G>(
-    graph: G,
-    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+fn edge_pairs(
+    points: &[Point],
+    segments: &[LineSegment],
+    groups: &[Vec<usize>],
     options: &EdgeBundlingOptions<f32>,
-) -> HashMap<G::EdgeId, Vec<(f32, f32)>>
-where
-    G: IntoNodeIdentifiers + IntoEdgeReferences,
-    G::NodeId: DrawingIndex,
-    G::EdgeId: Eq + Hash,
-{
-    let EdgeBundlingOptions {
-        cycles,
-        s0,
-        i0,
-        s_step,
-        i_step,
-        minimum_edge_compatibility,
-    } = options;
-    let points = graph
-        .node_identifiers()
-        .map(|u| {
-            let MetricEuclidean2d(x, y) = drawing.position(u).unwrap();
-            Point::new(*x, *y)
-        })
-        .collect::<Vec<Point>>();
-    let node_indices = graph
-        .node_identifiers()
-        .enumerate()
-        .map(|(i, u)| (u, i))
-        .collect::<HashMap<G::NodeId, usize>>();
-    let mut mid_points = Vec::new();
-    let mut segments = graph
-        .edge_references()
-        .map(|e| {
-            let u = e.source();
-            let v = e.target();
-            LineSegment::new(node_indices[&u], node_indices[&v])
-        })
+) -> Vec<EdgePair> {
+    let minimum_edge_compatibility = options.minimum_edge_compatibility;
+    let near_pairs = options
+        .interaction_radius
+        .map(|radius| near_segment_pairs(points, segments, radius));
+    let items = groups
+        .iter()
+        .flat_map(|group| (0..group.len()).map(move |k| (group, k)))
         .collect::<Vec<_>>();
-
-    let mut num_iter = *i0;
-    let mut alpha = *s0;
-
-    let edge_pairs = {
-        let mut edge_pairs = Vec::new();
-        let m = segments.len();
-        for p in 0..m {
-            let segment_p = &segments[p];
-            for q in (p + 1)..m {
-                let segment_q = &segments[q];
-                let c_e = compatibility(
+    map_indices(items.len(), |t| {
+        let (group, k) = items[t];
+        let p = group[k];
+        let segment_p = &segments[p];
+        let mut edge_pairs = vec![];
+        for &q in group[k + 1..].iter() {
+            if let Some(near_pairs) = &near_pairs {
+                if !near_pairs.contains(&(p.min(q), p.max(q))) {
+                    continue;
+                }
+            }
+            let segment_q = &segments[q];
+            let c_e = compatibility(
+                points[segment_p.source],
+                points[segment_p.target],
+                points[segment_q.source],
+                points[segment_q.target],
+            );
+            if c_e >= minimum_edge_compatibility {
+                let theta = angle(
                     points[segment_p.source],
                     points[segment_p.target],
                     points[segment_q.source],
                     points[segment_q.target],
                 );
-                if c_e >= *minimum_edge_compatibility {
-                    let theta = angle(
-                        points[segment_p.source],
-                        points[segment_p.target],
-                        points[segment_q.source],
-                        points[segment_q.target],
-                    );
-                    edge_pairs.push(EdgePair::new(p, q, c_e, theta));
-                }
+                edge_pairs.push(EdgePair::new(p, q, c_e, theta));
             }
         }
         edge_pairs
-    };
+    })
+    .into_iter()
+    .flatten()
+    .collect()
+}
 
-    for cycle in 0..*cycles {
-        let dp = (2 as i32).pow(cycle as u32);
-        for segment in segments.iter_mut() {
-            for j in 0..dp {
-                let p0 = if j == 0 {
-                    points[segment.source]
-                } else {
-                    mid_points[segment.point_indices[(j * 2 - 1) as usize]]
-                };
-                let p1 = if j == dp - 1 {
-                    points[segment.target]
-                } else {
-                    mid_points[segment.point_indices[(j * 2) as usize]]
-                };
-                mid_points.push(Point::new((p0.x + p1.x) / 2., (p0.y + p1.y) / 2.));
-                segment
-                    .point_indices
-                    .insert((j * 2) as usize, mid_points.len() - 1);
-            }
+fn subdivide(segments: &mut [LineSegment], mid_points: &mut Vec<Point>, points: &[Point]) {
+    for segment in segments.iter_mut() {
+        let dp = segment.point_indices.len() + 1;
+        for j in 0..dp {
+            let p0 = if j == 0 {
+                points[segment.source]
+            } else {
+                mid_points[segment.point_indices[j * 2 - 1]]
+            };
+            let p1 = if j == dp - 1 {
+                points[segment.target]
+            } else {
+                mid_points[segment.point_indices[j * 2]]
+            };
+            mid_points.push(Point::new((p0.x + p1.x) / 2., (p0.y + p1.y) / 2.));
+            segment.point_indices.insert(j * 2, mid_points.len() - 1);
         }
+    }
+}
 
-        let num_p = (dp * 2 - 1) as usize;
-        for _ in 0..num_iter {
-            for point in mid_points.iter_mut() {
-                point.vx = 0.;
-                point.vy = 0.;
-            }
+fn relax(
+    mid_points: &mut Vec<Point>,
+    segments: &Vec<LineSegment>,
+    points: &Vec<Point>,
+    edge_pairs: &Vec<EdgePair>,
+    num_iter: usize,
+    alpha: f32,
+    interaction_radius: Option<f32>,
+) {
+    let num_p = segments
+        .first()
+        .map(|segment| segment.point_indices.len())
+        .unwrap_or(0);
+    let interactions = interactions(segments.len(), edge_pairs);
+    for _ in 0..num_iter {
+        for point in mid_points.iter_mut() {
+            point.vx = 0.;
+            point.vy = 0.;
+        }
 
-            apply_spring_force(&mut mid_points, &segments, &points, num_p, 0.1);
-            apply_electrostatic_force(&mut mid_points, &segments, &edge_pairs, num_p);
+        apply_spring_force(mid_points, segments, points, num_p, 0.1);
+        apply_electrostatic_force(mid_points, segments, &interactions, interaction_radius);
 
-            for point in mid_points.iter_mut() {
-                point.x += alpha * point.vx;
-                point.y += alpha * point.vy;
-            }
+        for point in mid_points.iter_mut() {
+            point.x += alpha * point.vx;
+            point.y += alpha * point.vy;
         }
+    }
+}
 
-        alpha *= s_step;
-        num_iter = (num_iter as f32 * i_step) as usize;
+fn bundle(
+    points: &Vec<Point>,
+    segments: &mut Vec<LineSegment>,
+    mid_points: &mut Vec<Point>,
+    edge_pairs: &Vec<EdgePair>,
+    options: &EdgeBundlingOptions<f32>,
+) {
+    let mut num_iter = options.i0;
+    let mut alpha = options.s0;
+    for _ in 0..options.cycles {
+        subdivide(segments, mid_points, points);
+        relax(
+            mid_points,
+            segments,
+            points,
+            edge_pairs,
+            num_iter,
+            alpha,
+            options.interaction_radius,
+        );
+        alpha *= options.s_step;
+        num_iter = (num_iter as f32 * options.i_step) as usize;
     }
+}
 
+fn polylines(
+    points: &[Point],
+    segments: &[LineSegment],
+    mid_points: &[Point],
+) -> Vec<Vec<(f32, f32)>> {
     segments
         .iter()
-        .zip(graph.edge_references())
-        .map(|(segment, e)| {
+        .map(|segment| {
             let mut ps = vec![];
             let p0 = points[segment.source];
             ps.push((p0.x, p0.y));
@@ -340,7 +562,412 @@ where
             }
             let p1 = points[segment.target];
             ps.push((p1.x, p1.y));
-            (e.id(), ps)
+            ps
         })
         .collect()
 }
+
+type NodePoints<N> = (Vec<Point>, HashMap<N, usize>);
+
+pub type Polylines<E> = HashMap<E, Vec<(f32, f32)>>;
+
+fn node_points<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+) -> Result<NodePoints<G::NodeId>, MissingPosition<G::NodeId>>
+where
+    G: IntoNodeIdentifiers,
+    G::NodeId: DrawingIndex,
+{
+    let points = graph
+        .node_identifiers()
+        .map(|u| {
+            drawing
+                .try_position(u)
+                .map(|&MetricEuclidean2d(x, y)| Point::new(x, y))
+        })
+        .collect::<Result<Vec<Point>, _>>()?;
+    let node_indices = graph
+        .node_identifiers()
+        .enumerate()
+        .map(|(i, u)| (u, i))
+        .collect::<HashMap<G::NodeId, usize>>();
+    Ok((points, node_indices))
+}
+
+pub fn fdeb<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+    options: &EdgeBundlingOptions<f32>,
+) -> Result<Polylines<G::EdgeId>, MissingPosition<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoEdgeReferences,
+    G::NodeId: DrawingIndex,
+    G::EdgeId: Eq + Hash,
+{
+    let (points, node_indices) = node_points(graph, drawing)?;
+    let mut mid_points = Vec::new();
+    let edges = graph.edge_references().collect::<Vec<_>>();
+    let mut segments = edges
+        .iter()
+        .map(|e| LineSegment::new(node_indices[&e.source()], node_indices[&e.target()]))
+        .collect::<Vec<_>>();
+
+    let edge_pairs = edge_pairs(
+        &points,
+        &segments,
+        &[(0..segments.len()).collect()],
+        options,
+    );
+    bundle(
+        &points,
+        &mut segments,
+        &mut mid_points,
+        &edge_pairs,
+        options,
+    );
+
+    Ok(edges
+        .iter()
+        .map(|e| e.id())
+        .zip(polylines(&points, &segments, &mid_points))
+        .collect())
+}
+
+/// Bundles the edges between communities first and refines each edge from its
+/// bundle. Edges with an endpoint missing from `communities` are returned as
+/// straight lines.
+pub fn fdeb_multilevel<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+    communities: &HashMap<G::NodeId, usize>,
+    options: &EdgeBundlingOptions<f32>,
+    refinement_iterations: usize,
+) -> Result<Polylines<G::EdgeId>, MissingPosition<G::NodeId>>
+where
+    G: IntoNodeIdentifiers + IntoEdgeReferences,
+    G::NodeId: DrawingIndex,
+    G::EdgeId: Eq + Hash,
+{
+    let (points, node_indices) = node_points(graph, drawing)?;
+    let (edges, unassigned): (Vec<_>, Vec<_>) = graph.edge_references().partition(|e| {
+        communities.contains_key(&e.source()) && communities.contains_key(&e.target())
+    });
+
+    let mut group_ids = HashMap::new();
+    let mut groups = vec![];
+    let mut edge_groups = vec![];
+    for e in edges.iter() {
+        let a = communities[&e.source()];
+        let b = communities[&e.target()];
+        let key = (a.min(b), a.max(b));
+        let g = *group_ids.entry(key).or_insert_with(|| {
+            groups.push(vec![]);
+            groups.len() - 1
+        });
+        groups[g].push(edge_groups.len());
+        edge_groups.push((g, a > b));
+    }
+
+    let mut coarse_points = vec![Point::new(0., 0.); 2 * groups.len()];
+    for (e, &(g, reversed)) in edges.iter().zip(edge_groups.iter()) {
+        let (s, t) = if reversed {
+            (e.target(), e.source())
+        } else {
+            (e.source(), e.target())
+        };
+        let n = groups[g].len() as f32;
+        let ps = points[node_indices[&s]];
+        let pt = points[node_indices[&t]];
+        coarse_points[2 * g].x += ps.x / n;
+        coarse_points[2 * g].y += ps.y / n;
+        coarse_points[2 * g + 1].x += pt.x / n;
+        coarse_points[2 * g + 1].y += pt.y / n;
+    }
+    let mut group_keys = vec![(0, 0); groups.len()];
+    for (&key, &g) in group_ids.iter() {
+        group_keys[g] = key;
+    }
+    let mut coarse_segments = (0..groups.len())
+        .map(|g| LineSegment::new(2 * g, 2 * g + 1))
+        .collect::<Vec<_>>();
+    let mut coarse_mid_points = Vec::new();
+    let coarse_edge_pairs = edge_pairs(
+        &coarse_points,
+        &coarse_segments,
+        &[(0..groups.len())
+            .filter(|&g| group_keys[g].0 != group_keys[g].1)
+            .collect()],
+        options,
+    );
+    bundle(
+        &coarse_points,
+        &mut coarse_segments,
+        &mut coarse_mid_points,
+        &coarse_edge_pairs,
+        options,
+    );
+
+    let mut mid_points = Vec::new();
+    let segments = edges
+        .iter()
+        .zip(edge_groups.iter())
+        .map(|(e, &(g, reversed))| {
+            let mut segment =
+                LineSegment::new(node_indices[&e.source()], node_indices[&e.target()]);
+            let coarse_segment = &coarse_segments[g];
+            let num_p = coarse_segment.point_indices.len();
+            let (s, t) = if reversed {
+                (points[segment.target], points[segment.source])
+            } else {
+                (points[segment.source], points[segment.target])
+            };
+            let cs = coarse_points[coarse_segment.source];
+            let ct = coarse_points[coarse_segment.target];
+            let mut ps = (0..num_p)
+                .map(|i| {
+                    let c = coarse_mid_points[coarse_segment.point_indices[i]];
+                    let r = (i + 1) as f32 / (num_p + 1) as f32;
+                    Point::new(
+                        c.x + (1. - r) * (s.x - cs.x) + r * (t.x - ct.x),
+                        c.y + (1. - r) * (s.y - cs.y) + r * (t.y - ct.y),
+                    )
+                })
+                .collect::<Vec<_>>();
+            if reversed {
+                ps.reverse();
+            }
+            for p in ps {
+                mid_points.push(p);
+                segment.point_indices.push(mid_points.len() - 1);
+            }
+            segment
+        })
+        .collect::<Vec<_>>();
+
+    let edge_pairs = edge_pairs(&points, &segments, &groups, options);
+    let alpha = options.s0 * options.s_step.powi(options.cycles.max(1) as i32 - 1);
+    relax(
+        &mut mid_points,
+        &segments,
+        &points,
+        &edge_pairs,
+        refinement_iterations,
+        alpha,
+        options.interaction_radius,
+    );
+
+    let mut result = edges
+        .iter()
+        .map(|e| e.id())
+        .zip(polylines(&points, &segments, &mid_points))
+        .collect::<Polylines<_>>();
+    for e in unassigned {
+        let s = points[node_indices[&e.source()]];
+        let t = points[node_indices[&e.target()]];
+        result.insert(e.id(), vec![(s.x, s.y), (t.x, t.y)]);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{graph::NodeIndex, Graph};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_fdeb_multilevel() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..20).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::new(&graph);
+        for i in 0..10 {
+            drawing.set_x(nodes[i], 0.);
+            drawing.set_y(nodes[i], i as f32);
+            drawing.set_x(nodes[i + 10], 100.);
+            drawing.set_y(nodes[i + 10], i as f32 * 2.);
+            graph.add_edge(nodes[i], nodes[i + 10], ());
+        }
+        let communities = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i / 10))
+            .collect::<HashMap<_, _>>();
+        let options = EdgeBundlingOptions::<f32>::new();
+        let polylines = fdeb_multilevel(&graph, &drawing, &communities, &options, 20).unwrap();
+        assert_eq!(polylines.len(), 10);
+        for e in graph.edge_indices() {
+            let ps = &polylines[&e];
+            let (u, v) = graph.edge_endpoints(e).unwrap();
+            assert_eq!(ps.len(), 65);
+            assert_eq!(ps[0], (drawing.x(u).unwrap(), drawing.y(u).unwrap()));
+            assert_eq!(ps[64], (drawing.x(v).unwrap(), drawing.y(v).unwrap()));
+            assert!(ps.iter().all(|&(x, y)| x.is_finite() && y.is_finite()));
+        }
+    }
+
+    #[test]
+    fn test_fdeb_multilevel_unassigned_node() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..5).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::new(&graph);
+        for (i, &u) in nodes.iter().enumerate() {
+            drawing.set_x(u, (i % 2) as f32 * 100.);
+            drawing.set_y(u, i as f32);
+        }
+        graph.add_edge(nodes[0], nodes[1], ());
+        graph.add_edge(nodes[2], nodes[3], ());
+        let e = graph.add_edge(nodes[0], nodes[4], ());
+        let communities =
+            HashMap::from([(nodes[0], 0), (nodes[1], 1), (nodes[2], 0), (nodes[3], 1)]);
+        let options = EdgeBundlingOptions::<f32>::new();
+        let polylines = fdeb_multilevel(&graph, &drawing, &communities, &options, 5).unwrap();
+        assert_eq!(polylines.len(), 3);
+        assert_eq!(polylines[&e], vec![(0., 0.), (0., 4.)]);
+    }
+
+    #[test]
+    fn test_fdeb_interaction_radius() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..8).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::new(&graph);
+        for i in 0..4 {
+            drawing.set_x(nodes[i], 0.);
+            drawing.set_y(nodes[i], i as f32 * 10.);
+            drawing.set_x(nodes[i + 4], 100.);
+            drawing.set_y(nodes[i + 4], i as f32 * 10.);
+            graph.add_edge(nodes[i], nodes[i + 4], ());
+        }
+        let mut options = EdgeBundlingOptions::<f32>::new();
+        let expected = fdeb(&graph, &drawing, &options).unwrap();
+        options.set_interaction_radius(Some(1e4));
+        assert_eq!(fdeb(&graph, &drawing, &options).unwrap(), expected);
+
+        options.set_interaction_radius(Some(1e-3));
+        let polylines = fdeb(&graph, &drawing, &options).unwrap();
+        for e in graph.edge_indices() {
+            let (u, _) = graph.edge_endpoints(e).unwrap();
+            let y = drawing.y(u).unwrap();
+            assert!(polylines[&e].iter().all(|&(_, py)| (py - y).abs() < 1e-3));
+        }
+    }
+
+    #[test]
+    fn test_fdeb_missing_position() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..3).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        graph.add_edge(nodes[0], nodes[1], ());
+        graph.add_edge(nodes[1], nodes[2], ());
+        let drawing = DrawingEuclidean2d::<NodeIndex, f32>::from_node_indices(&nodes[..2]);
+        let options = EdgeBundlingOptions::<f32>::new();
+        assert_eq!(
+            fdeb(&graph, &drawing, &options),
+            Err(MissingPosition(nodes[2]))
+        );
+        let communities = nodes.iter().map(|&u| (u, 0)).collect::<HashMap<_, _>>();
+        assert_eq!(
+            fdeb_multilevel(&graph, &drawing, &communities, &options, 1),
+            Err(MissingPosition(nodes[2]))
+        );
+    }
+
+    #[test]
+    fn test_fdeb_interaction_radius_not_positive() {
+        let mut options = EdgeBundlingOptions::<f32>::new();
+        for radius in [0., -1., f32::NAN] {
+            options.set_interaction_radius(Some(1.));
+            options.set_interaction_radius(Some(radius));
+            assert_eq!(options.interaction_radius(), None);
+        }
+    }
+
+    #[test]
+    fn test_electrostatic_force() {
+        let points = vec![
+            Point::new(0., 0.),
+            Point::new(10., 0.),
+            Point::new(10., 2.),
+            Point::new(0., 2.),
+        ];
+        let mut segments = vec![LineSegment::new(0, 1), LineSegment::new(2, 3)];
+        let mut mid_points = vec![];
+        subdivide(&mut segments, &mut mid_points, &points);
+        subdivide(&mut segments, &mut mid_points, &points);
+        let edge_pairs = vec![EdgePair::new(0, 1, 1., f32::consts::PI)];
+        let interactions = interactions(segments.len(), &edge_pairs);
+        apply_electrostatic_force(&mut mid_points, &segments, &interactions, None);
+        for (&i, &j) in segments[0]
+            .point_indices
+            .iter()
+            .zip(segments[1].point_indices.iter().rev())
+        {
+            assert!((mid_points[i].vx - 0.).abs() < 1e-6);
+            assert!((mid_points[i].vy - 1.).abs() < 1e-6);
+            assert!((mid_points[i].vx + mid_points[j].vx).abs() < 1e-6);
+            assert!((mid_points[i].vy + mid_points[j].vy).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_electrostatic_force_partners() {
+        let offsets = [1., -2., 3., -4., 5., 0.];
+        let mut points = vec![Point::new(0., 0.), Point::new(10., 0.)];
+        let mut segments = vec![LineSegment::new(0, 1)];
+        for &y in offsets.iter() {
+            points.push(Point::new(0., y));
+            points.push(Point::new(10., y));
+            segments.push(LineSegment::new(points.len() - 2, points.len() - 1));
+        }
+        let mut mid_points = vec![];
+        subdivide(&mut segments, &mut mid_points, &points);
+        let edge_pairs = (1..segments.len())
+            .map(|q| EdgePair::new(0, q, 0.5, 0.))
+            .collect::<Vec<_>>();
+        let interactions = interactions(segments.len(), &edge_pairs);
+        apply_electrostatic_force(&mut mid_points, &segments, &interactions, Some(4.5));
+        let p = mid_points[segments[0].point_indices[0]];
+        let expected = offsets
+            .iter()
+            .filter(|&&y| y != 0. && y * y <= 4.5 * 4.5)
+            .map(|&y| 0.5 * y.signum())
+            .sum::<f32>();
+        assert!(p.vx.abs() < 1e-6);
+        assert!((p.vy - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_near_segment_pairs() {
+        let points = [
+            (0., 0.),
+            (10., 0.),
+            (0., 0.5),
+            (10., 0.5),
+            (0., 50.),
+            (10., 60.),
+        ]
+        .map(|(x, y)| Point::new(x, y));
+        let segments = (0..3)
+            .map(|i| LineSegment::new(2 * i, 2 * i + 1))
+            .collect::<Vec<_>>();
+        let pairs = near_segment_pairs(&points, &segments, 1.);
+        assert_eq!(pairs, HashSet::from([(0, 1)]));
+    }
+
+    fn point() -> impl Strategy<Value = Point> {
+        (-1e3f32..1e3, -1e3f32..1e3).prop_map(|(x, y)| Point::new(x, y))
+    }
+
+    proptest! {
+        #[test]
+        fn test_compatibility_range(p1 in point(), p2 in point(), q1 in point(), q2 in point()) {
+            let c = compatibility(p1, p2, q1, q2);
+            prop_assert!(c.is_finite());
+            prop_assert!((0. ..=1. + 1e-4).contains(&c));
+        }
+
+        #[test]
+        fn test_angle_finite(p1 in point(), p2 in point(), q1 in point(), q2 in point()) {
+            prop_assert!(angle(p1, p2, q1, q2).is_finite());
+        }
+    }
+}
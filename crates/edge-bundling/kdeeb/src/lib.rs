@@ -0,0 +1,247 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_drawing::DrawingEuclidean2d;
+//! use petgraph_edge_bundling_kdeeb::{kdeeb, KdeebOptions};
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! graph.add_edge(nodes[0], nodes[1], ());
+//! graph.add_edge(nodes[2], nodes[3], ());
+//! let mut drawing = DrawingEuclidean2d::new(&graph);
+//! for (&u, (x, y)) in nodes.iter().zip([(0., 0.), (10., 0.), (0., 1.), (10., 1.)]) {
+//!     drawing.set_x(u, x);
+//!     drawing.set_y(u, y);
+//! }
+//! let options = KdeebOptions::new();
+//! let bundled = kdeeb(&graph, &drawing, &options);
+//! assert_eq!(bundled.len(), 2);
+//! for points in bundled.values() {
+//!     assert_eq!(points.len(), options.num_points);
+//!     assert!(points.iter().all(|&(x, y)| x.is_finite() && y.is_finite()));
+//! }
+//! ```
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph_drawing::{DrawingEuclidean2d, DrawingIndex};
+use std::{collections::HashMap, hash::Hash};
+
+pub struct KdeebOptions {
+    pub iterations: usize,
+    pub num_points: usize,
+    pub grid_size: usize,
+    pub bandwidth: f32,
+    pub bandwidth_decay: f32,
+    pub step_size: f32,
+    pub smoothing: f32,
+}
+
+impl KdeebOptions {
+    pub fn new() -> KdeebOptions {
+        KdeebOptions {
+            iterations: 10,
+            num_points: 32,
+            grid_size: 256,
+            bandwidth: 0.05,
+            bandwidth_decay: 0.7,
+            step_size: 0.5,
+            smoothing: 0.5,
+        }
+    }
+}
+
+impl Default for KdeebOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct DensityGrid {
+    x0: f32,
+    y0: f32,
+    cell: f32,
+    width: usize,
+    height: usize,
+    values: Vec<f32>,
+}
+
+impl DensityGrid {
+    fn new(polylines: &[Vec<(f32, f32)>], grid_size: usize, margin: f32) -> DensityGrid {
+        let mut x0 = f32::INFINITY;
+        let mut y0 = f32::INFINITY;
+        let mut x1 = f32::NEG_INFINITY;
+        let mut y1 = f32::NEG_INFINITY;
+        for &(x, y) in polylines.iter().flatten() {
+            x0 = x0.min(x);
+            y0 = y0.min(y);
+            x1 = x1.max(x);
+            y1 = y1.max(y);
+        }
+        let margin = margin * (x1 - x0).max(y1 - y0);
+        x0 -= margin;
+        y0 -= margin;
+        x1 += margin;
+        y1 += margin;
+        let cell = ((x1 - x0).max(y1 - y0) / grid_size.max(1) as f32).max(1e-6);
+        let width = ((x1 - x0) / cell) as usize + 1;
+        let height = ((y1 - y0) / cell) as usize + 1;
+        DensityGrid {
+            x0,
+            y0,
+            cell,
+            width,
+            height,
+            values: vec![0.; width * height],
+        }
+    }
+
+    fn cell_index(&self, x: f32, y: f32) -> (isize, isize) {
+        (
+            ((x - self.x0) / self.cell).round() as isize,
+            ((y - self.y0) / self.cell).round() as isize,
+        )
+    }
+
+    fn value(&self, i: isize, j: isize) -> f32 {
+        let i = i.clamp(0, self.width as isize - 1) as usize;
+        let j = j.clamp(0, self.height as isize - 1) as usize;
+        self.values[j * self.width + i]
+    }
+
+    fn splat(&mut self, polylines: &[Vec<(f32, f32)>], h: f32) {
+        for v in self.values.iter_mut() {
+            *v = 0.;
+        }
+        let r = (h / self.cell).ceil() as isize;
+        for &(x, y) in polylines.iter().flatten() {
+            let (ci, cj) = self.cell_index(x, y);
+            for j in (cj - r).max(0)..=(cj + r).min(self.height as isize - 1) {
+                for i in (ci - r).max(0)..=(ci + r).min(self.width as isize - 1) {
+                    let dx = self.x0 + i as f32 * self.cell - x;
+                    let dy = self.y0 + j as f32 * self.cell - y;
+                    let d2 = (dx * dx + dy * dy) / (h * h);
+                    if d2 < 1. {
+                        self.values[j as usize * self.width + i as usize] += 1. - d2;
+                    }
+                }
+            }
+        }
+    }
+
+    fn gradient(&self, x: f32, y: f32) -> (f32, f32) {
+        let (i, j) = self.cell_index(x, y);
+        (
+            (self.value(i + 1, j) - self.value(i - 1, j)) / 2.,
+            (self.value(i, j + 1) - self.value(i, j - 1)) / 2.,
+        )
+    }
+}
+
+fn advect(polylines: &mut [Vec<(f32, f32)>], grid: &DensityGrid, step: f32) {
+    for polyline in polylines.iter_mut() {
+        let n = polyline.len();
+        for p in polyline[1..n - 1].iter_mut() {
+            let (gx, gy) = grid.gradient(p.0, p.1);
+            let norm = gx.hypot(gy);
+            if norm > 1e-6 {
+                p.0 += step * gx / norm;
+                p.1 += step * gy / norm;
+            }
+        }
+    }
+}
+
+fn smooth(polylines: &mut [Vec<(f32, f32)>], smoothing: f32) {
+    for polyline in polylines.iter_mut() {
+        let previous = polyline.clone();
+        let n = polyline.len();
+        for i in 1..n - 1 {
+            let mx = (previous[i - 1].0 + previous[i + 1].0) / 2.;
+            let my = (previous[i - 1].1 + previous[i + 1].1) / 2.;
+            polyline[i].0 += smoothing * (mx - previous[i].0);
+            polyline[i].1 += smoothing * (my - previous[i].1);
+        }
+    }
+}
+
+pub fn kdeeb<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+    options: &KdeebOptions,
+) -> HashMap<G::EdgeId, Vec<(f32, f32)>>
+where
+    G: IntoEdgeReferences,
+    G::NodeId: DrawingIndex,
+    G::EdgeId: Eq + Hash,
+{
+    let num_points = options.num_points.max(2);
+    let mut edge_ids = vec![];
+    let mut polylines = vec![];
+    for e in graph.edge_references() {
+        if let (Some(x0), Some(y0), Some(x1), Some(y1)) = (
+            drawing.x(e.source()),
+            drawing.y(e.source()),
+            drawing.x(e.target()),
+            drawing.y(e.target()),
+        ) {
+            edge_ids.push(e.id());
+            polylines.push(
+                (0..num_points)
+                    .map(|i| {
+                        let t = i as f32 / (num_points - 1) as f32;
+                        (x0 + t * (x1 - x0), y0 + t * (y1 - y0))
+                    })
+                    .collect::<Vec<_>>(),
+            );
+        }
+    }
+    if polylines.is_empty() {
+        return HashMap::new();
+    }
+
+    let mut grid = DensityGrid::new(&polylines, options.grid_size, options.bandwidth);
+    let extent = grid.width.max(grid.height) as f32 * grid.cell;
+    let mut h = (options.bandwidth * extent).max(grid.cell);
+    for _ in 0..options.iterations {
+        grid.splat(&polylines, h);
+        advect(&mut polylines, &grid, options.step_size * h);
+        smooth(&mut polylines, options.smoothing);
+        h = (h * options.bandwidth_decay).max(grid.cell);
+    }
+
+    edge_ids.into_iter().zip(polylines).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{graph::NodeIndex, Graph};
+
+    #[test]
+    fn test_kdeeb() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let e1 = graph.add_edge(nodes[0], nodes[1], ());
+        let e2 = graph.add_edge(nodes[2], nodes[3], ());
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::new(&graph);
+        for (i, &(x, y)) in [(0., 0.), (100., 0.), (0., 10.), (100., 10.)]
+            .iter()
+            .enumerate()
+        {
+            drawing.set_x(nodes[i], x);
+            drawing.set_y(nodes[i], y);
+        }
+        let mut options = KdeebOptions::new();
+        options.bandwidth = 0.2;
+        let polylines = kdeeb(&graph, &drawing, &options);
+        assert_eq!(polylines.len(), 2);
+        let p1 = &polylines[&e1];
+        let p2 = &polylines[&e2];
+        assert_eq!(p1.len(), 32);
+        assert_eq!(p1[0], (0., 0.));
+        assert_eq!(p1[31], (100., 0.));
+        assert!(p1
+            .iter()
+            .chain(p2.iter())
+            .all(|&(x, y)| x.is_finite() && y.is_finite()));
+        assert!((p2[16].1 - p1[16].1).abs() < 5.);
+    }
+}
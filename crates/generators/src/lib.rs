@@ -0,0 +1,162 @@
+use petgraph::graph::{NodeIndex, UnGraph};
+use rand::prelude::*;
+use std::collections::HashSet;
+
+fn graph_from_edges(n: usize, edges: impl IntoIterator<Item = (usize, usize)>) -> UnGraph<(), ()> {
+    let mut graph = UnGraph::with_capacity(n, 0);
+    for _ in 0..n {
+        graph.add_node(());
+    }
+    for (u, v) in edges {
+        graph.add_edge(NodeIndex::new(u), NodeIndex::new(v), ());
+    }
+    graph
+}
+
+pub fn complete_graph(n: usize) -> UnGraph<(), ()> {
+    graph_from_edges(n, (0..n).flat_map(|j| (0..j).map(move |i| (i, j))))
+}
+
+pub fn grid_graph(rows: usize, cols: usize) -> UnGraph<(), ()> {
+    let mut edges = vec![];
+    for i in 0..rows {
+        for j in 0..cols {
+            let u = i * cols + j;
+            if j + 1 < cols {
+                edges.push((u, u + 1));
+            }
+            if i + 1 < rows {
+                edges.push((u, u + cols));
+            }
+        }
+    }
+    graph_from_edges(rows * cols, edges)
+}
+
+pub fn torus_graph(rows: usize, cols: usize) -> UnGraph<(), ()> {
+    let mut edges = HashSet::new();
+    for i in 0..rows {
+        for j in 0..cols {
+            let u = i * cols + j;
+            for v in [i * cols + (j + 1) % cols, (i + 1) % rows * cols + j] {
+                if u != v {
+                    edges.insert((u.min(v), u.max(v)));
+                }
+            }
+        }
+    }
+    let mut edges = edges.into_iter().collect::<Vec<_>>();
+    edges.sort();
+    graph_from_edges(rows * cols, edges)
+}
+
+pub fn tree_graph(branching: usize, height: usize) -> UnGraph<(), ()> {
+    let mut n = 1;
+    let mut width = 1;
+    for _ in 0..height {
+        width *= branching;
+        n += width;
+    }
+    graph_from_edges(n, (1..n).map(|v| ((v - 1) / branching, v)))
+}
+
+pub fn erdos_renyi<R: Rng>(n: usize, p: f64, rng: &mut R) -> UnGraph<(), ()> {
+    let mut edges = vec![];
+    for j in 0..n {
+        for i in 0..j {
+            if rng.gen::<f64>() < p {
+                edges.push((i, j));
+            }
+        }
+    }
+    graph_from_edges(n, edges)
+}
+
+pub fn watts_strogatz<R: Rng>(n: usize, k: usize, beta: f64, rng: &mut R) -> UnGraph<(), ()> {
+    let mut edges = vec![];
+    let mut edge_set = HashSet::new();
+    for u in 0..n {
+        for j in 1..=k / 2 {
+            let v = (u + j) % n;
+            if u != v && edge_set.insert((u.min(v), u.max(v))) {
+                edges.push((u, v));
+            }
+        }
+    }
+    for edge in edges.iter_mut() {
+        let (u, v) = *edge;
+        if rng.gen::<f64>() >= beta {
+            continue;
+        }
+        let candidates = (0..n)
+            .filter(|&w| w != u && !edge_set.contains(&(u.min(w), u.max(w))))
+            .collect::<Vec<_>>();
+        if let Some(&w) = candidates.choose(rng) {
+            edge_set.remove(&(u.min(v), u.max(v)));
+            edge_set.insert((u.min(w), u.max(w)));
+            *edge = (u, w);
+        }
+    }
+    graph_from_edges(n, edges)
+}
+
+pub fn barabasi_albert<R: Rng>(n: usize, m: usize, rng: &mut R) -> UnGraph<(), ()> {
+    let mut edges = vec![];
+    let mut targets = (0..m.min(n)).collect::<Vec<_>>();
+    let mut repeated = vec![];
+    for u in m..n {
+        for &v in targets.iter() {
+            edges.push((v, u));
+        }
+        repeated.extend(targets.iter().copied());
+        repeated.extend(std::iter::repeat(u).take(m));
+        let mut selected = HashSet::new();
+        while selected.len() < m {
+            selected.insert(*repeated.choose(rng).unwrap());
+        }
+        targets = selected.into_iter().collect();
+        targets.sort();
+    }
+    graph_from_edges(n, edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deterministic_generators() {
+        let graph = complete_graph(5);
+        assert_eq!((graph.node_count(), graph.edge_count()), (5, 10));
+        let graph = grid_graph(3, 4);
+        assert_eq!((graph.node_count(), graph.edge_count()), (12, 17));
+        let graph = torus_graph(3, 4);
+        assert_eq!((graph.node_count(), graph.edge_count()), (12, 24));
+        let graph = tree_graph(2, 3);
+        assert_eq!((graph.node_count(), graph.edge_count()), (15, 14));
+    }
+
+    #[test]
+    fn test_random_generators() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let graph = erdos_renyi(20, 1., &mut rng);
+        assert_eq!(graph.edge_count(), 190);
+        let graph = erdos_renyi(20, 0., &mut rng);
+        assert_eq!(graph.edge_count(), 0);
+
+        let graph = watts_strogatz(20, 4, 0.3, &mut rng);
+        assert_eq!((graph.node_count(), graph.edge_count()), (20, 40));
+        for e in graph.edge_indices() {
+            let (u, v) = graph.edge_endpoints(e).unwrap();
+            assert_ne!(u, v);
+            assert_eq!(graph.edges_connecting(u, v).count(), 1);
+        }
+
+        let graph = barabasi_albert(30, 2, &mut rng);
+        assert_eq!((graph.node_count(), graph.edge_count()), (30, 56));
+        for e in graph.edge_indices() {
+            let (u, v) = graph.edge_endpoints(e).unwrap();
+            assert_eq!(graph.edges_connecting(u, v).count(), 1);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use petgraph::visit::{
+    EdgeRef, IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable,
+};
+use petgraph_algorithm_shortest_path::{DistanceMatrix, FullDistanceMatrix};
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex};
+use petgraph_quality_metrics::{
+    quality_metrics_with_targets, QualityMetric, QualityMetricsError, Sense,
+};
+
+fn cross(p1: (f32, f32), p2: (f32, f32), q1: (f32, f32), q2: (f32, f32)) -> bool {
+    let side = |a: (f32, f32), b: (f32, f32), c: (f32, f32)| {
+        (a.0 - b.0) * (c.1 - a.1) - (a.1 - b.1) * (c.0 - a.0)
+    };
+    side(p1, p2, q1) * side(p1, p2, q2) <= 0. && side(q1, q2, p1) * side(q1, q2, p2) <= 0.
+}
+
+/// Weighted sum of quality metrics that is updated move by move.
+///
+/// Stress, ideal edge lengths and crossing number only change in the terms
+/// that involve the moved node, so their values are updated from the
+/// contribution of that node before and after the move. The remaining
+/// metrics are recomputed from scratch.
+pub(crate) struct Energy<'a, G>
+where
+    G: IntoNodeIdentifiers,
+{
+    graph: G,
+    d: &'a FullDistanceMatrix<G::NodeId, f32>,
+    weights: &'a [(QualityMetric, f32)],
+    edges: Vec<(usize, usize, Option<f32>)>,
+    incident: Vec<Vec<usize>>,
+    global: Vec<usize>,
+    values: Vec<f32>,
+}
+
+impl<'a, G> Energy<'a, G>
+where
+    G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: DrawingIndex,
+{
+    pub(crate) fn new(
+        graph: G,
+        drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+        d: &'a FullDistanceMatrix<G::NodeId, f32>,
+        weights: &'a [(QualityMetric, f32)],
+    ) -> Result<Self, QualityMetricsError<G::NodeId>> {
+        let mut edges = vec![];
+        let mut incident = vec![vec![]; drawing.len()];
+        for e in graph.edge_references() {
+            drawing.try_position(e.source())?;
+            drawing.try_position(e.target())?;
+            let i = drawing.index(e.source());
+            let j = drawing.index(e.target());
+            let l = d.get(e.source(), e.target()).filter(|&l| l != 0.);
+            incident[i].push(edges.len());
+            if i != j {
+                incident[j].push(edges.len());
+            }
+            edges.push((i, j, l));
+        }
+        let global = weights
+            .iter()
+            .enumerate()
+            .filter(|(_, &(metric, _))| !Self::is_local(metric))
+            .map(|(k, _)| k)
+            .collect();
+        let mut energy = Energy {
+            graph,
+            d,
+            weights,
+            edges,
+            incident,
+            global,
+            values: vec![],
+        };
+        energy.reset(drawing)?;
+        Ok(energy)
+    }
+
+    fn is_local(metric: QualityMetric) -> bool {
+        matches!(
+            metric,
+            QualityMetric::Stress | QualityMetric::IdealEdgeLengths | QualityMetric::CrossingNumber
+        )
+    }
+
+    /// Recomputes every metric, discarding rounding errors accumulated by
+    /// the incremental updates.
+    pub(crate) fn reset(
+        &mut self,
+        drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+    ) -> Result<(), QualityMetricsError<G::NodeId>> {
+        let targets = self.weights.iter().map(|&(m, _)| m).collect::<Vec<_>>();
+        self.values = quality_metrics_with_targets(self.graph, drawing, self.d, &targets)?
+            .into_iter()
+            .map(|(_, value)| value)
+            .collect();
+        Ok(())
+    }
+
+    pub(crate) fn value(&self) -> f32 {
+        self.value_of(&self.values)
+    }
+
+    /// Returns the metric values after node `i`, which has already been
+    /// moved in `drawing`, left `previous`.
+    pub(crate) fn moved(
+        &self,
+        drawing: &mut DrawingEuclidean2d<G::NodeId, f32>,
+        i: usize,
+        previous: (f32, f32),
+    ) -> Result<Vec<f32>, QualityMetricsError<G::NodeId>> {
+        let mut values = self.values.clone();
+        let current = {
+            let p = drawing.raw_entry(i);
+            (p.0, p.1)
+        };
+        let mut local = |drawing: &mut DrawingEuclidean2d<G::NodeId, f32>, sign: f32| {
+            for (k, &(metric, _)) in self.weights.iter().enumerate() {
+                if Self::is_local(metric) {
+                    values[k] += sign * self.contribution(metric, drawing, i);
+                }
+            }
+        };
+        local(drawing, 1.);
+        drawing.raw_entry_mut(i).0 = previous.0;
+        drawing.raw_entry_mut(i).1 = previous.1;
+        local(drawing, -1.);
+        drawing.raw_entry_mut(i).0 = current.0;
+        drawing.raw_entry_mut(i).1 = current.1;
+        if !self.global.is_empty() {
+            let targets = self
+                .global
+                .iter()
+                .map(|&k| self.weights[k].0)
+                .collect::<Vec<_>>();
+            for (&k, (_, value)) in self.global.iter().zip(quality_metrics_with_targets(
+                self.graph, drawing, self.d, &targets,
+            )?) {
+                values[k] = value;
+            }
+        }
+        Ok(values)
+    }
+
+    pub(crate) fn set_values(&mut self, values: Vec<f32>) {
+        self.values = values;
+    }
+
+    pub(crate) fn value_of(&self, values: &[f32]) -> f32 {
+        values
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(&value, &(metric, weight))| match metric.sense() {
+                Sense::Minimize => weight * value,
+                Sense::Maximize => -weight * value,
+            })
+            .sum()
+    }
+
+    /// Terms of `metric` that involve node `i`.
+    fn contribution(
+        &self,
+        metric: QualityMetric,
+        drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+        i: usize,
+    ) -> f32 {
+        let point = |k: usize| {
+            let p = drawing.raw_entry(k);
+            (p.0, p.1)
+        };
+        let (x, y) = point(i);
+        match metric {
+            QualityMetric::Stress => (0..drawing.len())
+                .filter(|&j| j != i)
+                .map(|j| {
+                    let (xj, yj) = point(j);
+                    let dij = self.d.get_by_index(i, j);
+                    let e = ((x - xj).hypot(y - yj) - dij) / dij;
+                    e * e
+                })
+                .sum(),
+            QualityMetric::IdealEdgeLengths => self.incident[i]
+                .iter()
+                .filter_map(|&e| {
+                    let (u, v, l) = self.edges[e];
+                    let l = l?;
+                    let (x1, y1) = point(u);
+                    let (x2, y2) = point(v);
+                    Some((((x1 - x2).hypot(y1 - y2) - l) / l).powi(2))
+                })
+                .sum(),
+            QualityMetric::CrossingNumber => {
+                let mut count = 0;
+                for &e in self.incident[i].iter() {
+                    let (u1, v1, _) = self.edges[e];
+                    if u1 == v1 {
+                        continue;
+                    }
+                    for &(u2, v2, _) in self.edges.iter() {
+                        if u2 == v2 || u2 == u1 || u2 == v1 || v2 == u1 || v2 == v1 {
+                            continue;
+                        }
+                        if cross(point(u1), point(v1), point(u2), point(v2)) {
+                            count += 1;
+                        }
+                    }
+                }
+                count as f32
+            }
+            _ => unreachable!(),
+        }
+    }
+}
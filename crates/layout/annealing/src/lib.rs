@@ -0,0 +1,262 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_algorithm_shortest_path::warshall_floyd;
+//! use petgraph_drawing::{Drawing, DrawingEuclidean2d};
+//! use petgraph_layout_annealing::SimulatedAnnealing;
+//! use petgraph_quality_metrics::QualityMetric;
+//! use rand::prelude::*;
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 0..4 {
+//!     graph.add_edge(nodes[i], nodes[(i + 1) % 4], ());
+//! }
+//! let mut drawing = DrawingEuclidean2d::<_, f32>::initial_placement(&graph);
+//! let d = warshall_floyd(&graph, &mut |_| 1.);
+//! let mut annealing = SimulatedAnnealing::new(vec![(QualityMetric::Stress, 1.)]);
+//! annealing.generations = 10;
+//! let initial = annealing.energy(&graph, &drawing, &d).unwrap();
+//! let mut rng = StdRng::seed_from_u64(0);
+//! let best = annealing
+//!     .run(&graph, &mut drawing, &d, &mut rng, |_, _, _| {})
+//!     .unwrap();
+//! assert!(best <= initial);
+//! for &u in &nodes {
+//!     assert!(drawing.x(u).unwrap().is_finite() && drawing.y(u).unwrap().is_finite());
+//! }
+//! ```
+mod energy;
+
+use energy::Energy;
+use petgraph::visit::{IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+use petgraph_algorithm_shortest_path::FullDistanceMatrix;
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex};
+use petgraph_quality_metrics::{
+    quality_metrics_with_targets, QualityMetric, QualityMetricsError, Sense,
+};
+use rand::prelude::*;
+
+pub struct SimulatedAnnealing {
+    pub weights: Vec<(QualityMetric, f32)>,
+    pub initial_temperature: f32,
+    pub cooling_rate: f32,
+    pub generations: usize,
+    pub moves_per_generation: usize,
+    pub step_size: f32,
+}
+
+impl SimulatedAnnealing {
+    pub fn new(weights: Vec<(QualityMetric, f32)>) -> SimulatedAnnealing {
+        SimulatedAnnealing {
+            weights,
+            initial_temperature: 1.,
+            cooling_rate: 0.95,
+            generations: 100,
+            moves_per_generation: 10,
+            step_size: 10.,
+        }
+    }
+
+    pub fn energy<G>(
+        &self,
+        graph: G,
+        drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+        d: &FullDistanceMatrix<G::NodeId, f32>,
+    ) -> Result<f32, QualityMetricsError<G::NodeId>>
+    where
+        G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+        G::NodeId: DrawingIndex,
+    {
+        let targets = self.weights.iter().map(|&(m, _)| m).collect::<Vec<_>>();
+        Ok(quality_metrics_with_targets(graph, drawing, d, &targets)?
+            .into_iter()
+            .zip(self.weights.iter())
+            .map(|((metric, value), &(_, weight))| match metric.sense() {
+                Sense::Minimize => weight * value,
+                Sense::Maximize => -weight * value,
+            })
+            .sum())
+    }
+
+    /// Runs with the geometric schedule `initial_temperature *
+    /// cooling_rate^generation`.
+    pub fn run<G, R, F>(
+        &self,
+        graph: G,
+        drawing: &mut DrawingEuclidean2d<G::NodeId, f32>,
+        d: &FullDistanceMatrix<G::NodeId, f32>,
+        rng: &mut R,
+        callback: F,
+    ) -> Result<f32, QualityMetricsError<G::NodeId>>
+    where
+        G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+        G::NodeId: DrawingIndex,
+        R: Rng,
+        F: FnMut(usize, f32, f32),
+    {
+        let initial_temperature = self.initial_temperature;
+        let cooling_rate = self.cooling_rate;
+        self.run_with_schedule(
+            graph,
+            drawing,
+            d,
+            rng,
+            |generation| initial_temperature * cooling_rate.powi(generation as i32),
+            callback,
+        )
+    }
+
+    /// Runs with the temperature of each generation given by `schedule`. The
+    /// step size shrinks in proportion to the temperature of the first
+    /// generation.
+    pub fn run_with_schedule<G, R, T, F>(
+        &self,
+        graph: G,
+        drawing: &mut DrawingEuclidean2d<G::NodeId, f32>,
+        d: &FullDistanceMatrix<G::NodeId, f32>,
+        rng: &mut R,
+        schedule: T,
+        callback: F,
+    ) -> Result<f32, QualityMetricsError<G::NodeId>>
+    where
+        G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+        G::NodeId: DrawingIndex,
+        R: Rng,
+        T: FnMut(usize) -> f32,
+        F: FnMut(usize, f32, f32),
+    {
+        let mut schedule = schedule;
+        let mut callback = callback;
+        let n = drawing.len();
+        let mut objective = Energy::new(graph, drawing, d, &self.weights)?;
+        let mut energy = objective.value();
+        if n == 0 {
+            return Ok(energy);
+        }
+        let mut best_energy = energy;
+        let mut best = (0..n).map(|i| *drawing.raw_entry(i)).collect::<Vec<_>>();
+        let mut first_temperature = None;
+        for generation in 0..self.generations {
+            let temperature = schedule(generation);
+            let t0 = *first_temperature.get_or_insert(temperature);
+            let step = if t0 > 0. {
+                self.step_size * temperature / t0
+            } else {
+                self.step_size
+            };
+            for _ in 0..self.moves_per_generation {
+                let i = rng.gen_range(0..n);
+                let previous = *drawing.raw_entry(i);
+                drawing.raw_entry_mut(i).0 += step * rng.gen_range(-1.0..1.0);
+                drawing.raw_entry_mut(i).1 += step * rng.gen_range(-1.0..1.0);
+                let values = objective.moved(drawing, i, (previous.0, previous.1))?;
+                let candidate = objective.value_of(&values);
+                let delta = candidate - energy;
+                if delta <= 0. || rng.gen::<f32>() < (-delta / temperature).exp() {
+                    objective.set_values(values);
+                    energy = candidate;
+                    if energy < best_energy {
+                        best_energy = energy;
+                        for (j, p) in best.iter_mut().enumerate() {
+                            *p = *drawing.raw_entry(j);
+                        }
+                    }
+                } else {
+                    *drawing.raw_entry_mut(i) = previous;
+                }
+            }
+            objective.reset(drawing)?;
+            energy = objective.value();
+            callback(generation, temperature, energy);
+        }
+        for (i, p) in best.into_iter().enumerate() {
+            *drawing.raw_entry_mut(i) = p;
+        }
+        objective.reset(drawing)?;
+        Ok(objective.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+    use petgraph_algorithm_shortest_path::warshall_floyd;
+
+    #[test]
+    fn test_simulated_annealing() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..4 {
+            graph.add_edge(nodes[i], nodes[(i + 1) % 4], ());
+        }
+        let mut drawing = DrawingEuclidean2d::new(&graph);
+        for (&u, (x, y)) in nodes.iter().zip([(0., 0.), (1., 1.), (1., 0.), (0., 1.)]) {
+            drawing.set_x(u, x);
+            drawing.set_y(u, y);
+        }
+        let d = warshall_floyd(&graph, &mut |_| 1.);
+        let mut annealing = SimulatedAnnealing::new(vec![
+            (QualityMetric::CrossingNumber, 1.),
+            (QualityMetric::Stress, 0.1),
+        ]);
+        annealing.step_size = 1.;
+        let initial = annealing.energy(&graph, &drawing, &d).unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut generations = 0;
+        let best = annealing
+            .run(&graph, &mut drawing, &d, &mut rng, |_, _, _| {
+                generations += 1
+            })
+            .unwrap();
+        assert_eq!(generations, annealing.generations);
+        assert!(best < initial);
+        assert!((annealing.energy(&graph, &drawing, &d).unwrap() - best).abs() < 1e-4);
+
+        let mut temperatures = vec![];
+        annealing
+            .run_with_schedule(
+                &graph,
+                &mut drawing,
+                &d,
+                &mut rng,
+                |generation| 1. / (generation + 1) as f32,
+                |_, t, _| temperatures.push(t),
+            )
+            .unwrap();
+        assert_eq!(temperatures.len(), annealing.generations);
+        assert_eq!(temperatures[3], 0.25);
+    }
+
+    #[test]
+    fn test_energy_moved() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..6 {
+            graph.add_edge(nodes[i], nodes[(i + 1) % 6], ());
+            graph.add_edge(nodes[i], nodes[(i + 3) % 6], ());
+        }
+        let mut drawing = DrawingEuclidean2d::<_, f32>::initial_placement(&graph);
+        let d = warshall_floyd(&graph, &mut |_| 1.);
+        let annealing = SimulatedAnnealing::new(vec![
+            (QualityMetric::Stress, 1.),
+            (QualityMetric::IdealEdgeLengths, 0.5),
+            (QualityMetric::CrossingNumber, 2.),
+            (QualityMetric::AspectRatio, 1.),
+        ]);
+        let mut objective = Energy::new(&graph, &drawing, &d, &annealing.weights).unwrap();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..50 {
+            let i = rng.gen_range(0..6);
+            let previous = *drawing.raw_entry(i);
+            drawing.raw_entry_mut(i).0 += rng.gen_range(-1.0..1.0);
+            drawing.raw_entry_mut(i).1 += rng.gen_range(-1.0..1.0);
+            let values = objective
+                .moved(&mut drawing, i, (previous.0, previous.1))
+                .unwrap();
+            objective.set_values(values);
+            let expected = annealing.energy(&graph, &drawing, &d).unwrap();
+            assert!((objective.value() - expected).abs() < 1e-5 * expected.abs());
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_drawing::Drawing;
+//! use petgraph_layout_circular::Circular;
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 1..4 {
+//!     graph.add_edge(nodes[i - 1], nodes[i], ());
+//! }
+//! let drawing = Circular::<f32>::new().run(&graph);
+//! assert_eq!(drawing.len(), 4);
+//! for &u in &nodes {
+//!     assert!(drawing.x(u).unwrap().is_finite() && drawing.y(u).unwrap().is_finite());
+//! }
+//! ```
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers};
+use petgraph_drawing::{DrawingEuclidean2d, DrawingIndex, DrawingValue};
+use std::collections::HashMap;
+
+fn count_crossings(edges: &[(usize, usize)], order: &[usize]) -> usize {
+    let mut position = vec![0; order.len()];
+    for (i, &u) in order.iter().enumerate() {
+        position[u] = i;
+    }
+    let chords = edges
+        .iter()
+        .map(|&(u, v)| {
+            let (a, b) = (position[u], position[v]);
+            (a.min(b), a.max(b))
+        })
+        .collect::<Vec<_>>();
+    let mut count = 0;
+    for i in 0..chords.len() {
+        let (a, b) = chords[i];
+        for &(c, d) in &chords[i + 1..] {
+            if (a < c && c < b && b < d) || (c < a && a < d && d < b) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+fn dfs_order(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let n = neighbors.len();
+    let mut visited = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+    for s in 0..n {
+        if visited[s] {
+            continue;
+        }
+        let mut stack = vec![s];
+        while let Some(u) = stack.pop() {
+            if visited[u] {
+                continue;
+            }
+            visited[u] = true;
+            order.push(u);
+            for &v in neighbors[u].iter().rev() {
+                if !visited[v] {
+                    stack.push(v);
+                }
+            }
+        }
+    }
+    order
+}
+
+fn barycenter_order(neighbors: &[Vec<usize>], order: &[usize]) -> Vec<usize> {
+    let n = order.len();
+    let two_pi = 2. * std::f64::consts::PI;
+    let mut angle = vec![0.; n];
+    for (i, &u) in order.iter().enumerate() {
+        angle[u] = two_pi * i as f64 / n as f64;
+    }
+    let mut key = angle.clone();
+    for u in 0..n {
+        if neighbors[u].is_empty() {
+            continue;
+        }
+        let mut sx = 0.;
+        let mut sy = 0.;
+        for &v in &neighbors[u] {
+            sx += angle[v].cos();
+            sy += angle[v].sin();
+        }
+        if sx != 0. || sy != 0. {
+            key[u] = sy.atan2(sx).rem_euclid(two_pi);
+        }
+    }
+    let mut new_order = order.to_vec();
+    new_order.sort_by(|&u, &v| key[u].total_cmp(&key[v]));
+    new_order
+}
+
+pub struct Circular<S> {
+    pub radius: S,
+    pub iterations: usize,
+}
+
+impl<S> Circular<S>
+where
+    S: DrawingValue + Default,
+{
+    pub fn new() -> Circular<S> {
+        Circular {
+            radius: S::from_usize(100).unwrap(),
+            iterations: 10,
+        }
+    }
+
+    pub fn order<G>(&self, graph: G) -> Vec<G::NodeId>
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers,
+        G::NodeId: DrawingIndex + Copy,
+    {
+        let nodes = graph.node_identifiers().collect::<Vec<_>>();
+        let indices = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i))
+            .collect::<HashMap<_, _>>();
+        let mut neighbors = vec![vec![]; nodes.len()];
+        let mut edges = vec![];
+        for e in graph.edge_references() {
+            let u = indices[&e.source()];
+            let v = indices[&e.target()];
+            if u != v {
+                neighbors[u].push(v);
+                neighbors[v].push(u);
+                edges.push((u, v));
+            }
+        }
+
+        let mut order = dfs_order(&neighbors);
+        let mut best_order = order.clone();
+        let mut best_crossings = count_crossings(&edges, &order);
+        for _ in 0..self.iterations {
+            if best_crossings == 0 {
+                break;
+            }
+            order = barycenter_order(&neighbors, &order);
+            let crossings = count_crossings(&edges, &order);
+            if crossings < best_crossings {
+                best_crossings = crossings;
+                best_order = order.clone();
+            }
+        }
+        best_order.into_iter().map(|i| nodes[i]).collect()
+    }
+
+    pub fn run<G>(&self, graph: G) -> DrawingEuclidean2d<G::NodeId, S>
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers,
+        G::NodeId: DrawingIndex + Copy,
+    {
+        let order = self.order(graph);
+        let n = order.len();
+        let mut drawing = DrawingEuclidean2d::from_node_indices(&order);
+        let two_pi = S::from_f64(2. * std::f64::consts::PI).unwrap();
+        for (i, &u) in order.iter().enumerate() {
+            let t = two_pi * S::from_usize(i).unwrap() / S::from_usize(n).unwrap();
+            drawing.set_x(u, self.radius * t.cos());
+            drawing.set_y(u, self.radius * t.sin());
+        }
+        drawing
+    }
+}
+
+impl<S> Default for Circular<S>
+where
+    S: DrawingValue + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_circular_cycle() {
+        let n = 8;
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..n {
+            graph.add_edge(nodes[i], nodes[(i + 1) % n], ());
+        }
+        let drawing = Circular::<f32>::new().run(&graph);
+        for &u in &nodes {
+            let x = drawing.x(u).unwrap();
+            let y = drawing.y(u).unwrap();
+            assert!((x.hypot(y) - 100.).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_circular_order_reduces_crossings() {
+        // A cycle whose node indices are scrambled, so the identity order has
+        // many crossings.
+        let n = 10;
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut edges = vec![];
+        for i in 0..n {
+            let (u, v) = (3 * i % n, 3 * (i + 1) % n);
+            graph.add_edge(nodes[u], nodes[v], ());
+            edges.push((u, v));
+        }
+        let identity = (0..n).collect::<Vec<_>>();
+        let order = Circular::<f32>::new()
+            .order(&graph)
+            .into_iter()
+            .map(|u| u.index())
+            .collect::<Vec<_>>();
+        let crossings = count_crossings(&edges, &order);
+        assert!(crossings < count_crossings(&edges, &identity));
+        assert_eq!(crossings, 0);
+    }
+}
@@ -1,19 +1,187 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_drawing::{Drawing, DrawingEuclidean2d};
+//! use petgraph_layout_kamada_kawai::KamadaKawai;
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 1..4 {
+//!     graph.add_edge(nodes[i - 1], nodes[i], ());
+//! }
+//! let mut drawing = DrawingEuclidean2d::<_, f32>::initial_placement(&graph);
+//! let kamada_kawai = KamadaKawai::new(&graph, |_| 1.);
+//! kamada_kawai.run(&mut drawing);
+//! assert_eq!(drawing.len(), 4);
+//! for &u in &nodes {
+//!     assert!(drawing.x(u).unwrap().is_finite() && drawing.y(u).unwrap().is_finite());
+//! }
+//! ```
+
 use ndarray::prelude::*;
 use petgraph::visit::{IntoEdges, IntoNodeIdentifiers, NodeCount};
-use petgraph_algorithm_shortest_path::{all_sources_dijkstra, DistanceMatrix, FullDistanceMatrix};
-use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex, DrawingValue};
+use petgraph_algorithm_shortest_path::{
+    all_sources_shortest_path, DistanceMatrix, FullDistanceMatrix,
+};
+use petgraph_drawing::{
+    Delta, DeltaEuclidean, DeltaEuclidean2d, DeltaSpherical2d, DeltaTorus2d, Drawing, DrawingIndex,
+    DrawingValue, Metric,
+};
+
+pub trait KamadaKawaiDelta: Delta {
+    fn dimension(&self) -> usize;
+
+    fn component(&self, i: usize) -> Self::S;
+
+    fn from_components(components: &[Self::S]) -> Self;
+
+    fn default_min_distance() -> Self::S;
+}
+
+impl<S> KamadaKawaiDelta for DeltaEuclidean2d<S>
+where
+    S: DrawingValue,
+{
+    fn dimension(&self) -> usize {
+        2
+    }
+
+    fn component(&self, i: usize) -> S {
+        if i == 0 {
+            self.0
+        } else {
+            self.1
+        }
+    }
+
+    fn from_components(components: &[S]) -> Self {
+        DeltaEuclidean2d(components[0], components[1])
+    }
+
+    fn default_min_distance() -> S {
+        S::one()
+    }
+}
+
+impl<S> KamadaKawaiDelta for DeltaEuclidean<S>
+where
+    S: DrawingValue,
+{
+    fn dimension(&self) -> usize {
+        self.0.len()
+    }
+
+    fn component(&self, i: usize) -> S {
+        self.0[i]
+    }
+
+    fn from_components(components: &[S]) -> Self {
+        DeltaEuclidean(components.to_vec())
+    }
+
+    fn default_min_distance() -> S {
+        S::one()
+    }
+}
+
+impl<S> KamadaKawaiDelta for DeltaSpherical2d<S>
+where
+    S: DrawingValue,
+{
+    fn dimension(&self) -> usize {
+        2
+    }
+
+    fn component(&self, i: usize) -> S {
+        if i == 0 {
+            self.0
+        } else {
+            self.1
+        }
+    }
 
-fn norm<S>(x: S, y: S) -> S
+    fn from_components(components: &[S]) -> Self {
+        DeltaSpherical2d(components[0], components[1])
+    }
+
+    fn default_min_distance() -> S {
+        S::from_f32(1e-3).unwrap()
+    }
+}
+
+impl<S> KamadaKawaiDelta for DeltaTorus2d<S>
 where
     S: DrawingValue,
 {
-    x.hypot(y).max(S::one())
+    fn dimension(&self) -> usize {
+        2
+    }
+
+    fn component(&self, i: usize) -> S {
+        if i == 0 {
+            self.0
+        } else {
+            self.1
+        }
+    }
+
+    fn from_components(components: &[S]) -> Self {
+        DeltaTorus2d(components[0], components[1])
+    }
+
+    fn default_min_distance() -> S {
+        S::from_f32(1e-3).unwrap()
+    }
+}
+
+fn solve<S>(mut a: Vec<Vec<S>>, mut b: Vec<S>) -> Vec<S>
+where
+    S: DrawingValue,
+{
+    let n = b.len();
+    for k in 0..n {
+        let p = (k..n)
+            .max_by(|&i, &j| {
+                a[i][k]
+                    .abs()
+                    .partial_cmp(&a[j][k].abs())
+                    .unwrap_or_else(|| a[j][k].is_nan().cmp(&a[i][k].is_nan()))
+            })
+            .unwrap();
+        a.swap(k, p);
+        b.swap(k, p);
+        if a[k][k] == S::zero() {
+            continue;
+        }
+        let (upper, lower) = a.split_at_mut(k + 1);
+        let pivot = &upper[k];
+        for (i, row) in lower.iter_mut().enumerate() {
+            let c = row[k] / pivot[k];
+            for (x, &y) in row[k..].iter_mut().zip(pivot[k..].iter()) {
+                *x -= c * y;
+            }
+            let bk = b[k];
+            b[k + 1 + i] -= c * bk;
+        }
+    }
+    let mut x = vec![S::zero(); n];
+    for k in (0..n).rev() {
+        if a[k][k] == S::zero() {
+            continue;
+        }
+        let mut s = b[k];
+        for j in k + 1..n {
+            s -= a[k][j] * x[j];
+        }
+        x[k] = s / a[k][k];
+    }
+    x
 }
 
 pub struct KamadaKawai<S> {
     k: Array2<S>,
     l: Array2<S>,
     pub eps: S,
+    pub min_distance: Option<S>,
 }
 
 impl<S> KamadaKawai<S> {
@@ -24,7 +192,7 @@ impl<S> KamadaKawai<S> {
         F: FnMut(G::EdgeRef) -> S,
         S: DrawingValue,
     {
-        let l = all_sources_dijkstra(graph, length);
+        let l = all_sources_shortest_path(graph, length);
         KamadaKawai::new_with_distance_matrix(&l)
     }
 
@@ -44,87 +212,155 @@ impl<S> KamadaKawai<S> {
                 k[[i, j]] = S::one() / (l[[i, j]] * l[[i, j]]);
             }
         }
-        KamadaKawai { k, l, eps }
+        KamadaKawai {
+            k,
+            l,
+            eps,
+            min_distance: None,
+        }
     }
 
-    pub fn select_node<N>(&self, drawing: &DrawingEuclidean2d<N, S>) -> Option<usize>
+    fn gradient<D, Diff, M>(&self, m: usize, drawing: &D) -> Vec<S>
     where
-        N: DrawingIndex,
+        D: Drawing<Item = M>,
+        Diff: KamadaKawaiDelta<S = S>,
+        M: Metric<D = Diff>,
+        S: DrawingValue,
+    {
+        let n = drawing.len();
+        let KamadaKawai { k, l, .. } = self;
+        let min_distance = self.min_distance.unwrap_or_else(Diff::default_min_distance);
+        let mut gradient = vec![S::zero(); drawing.dimension()];
+        for i in 0..n {
+            if i != m {
+                let delta = drawing.delta(m, i);
+                let d = delta.norm().max(min_distance);
+                for (a, g) in gradient.iter_mut().enumerate() {
+                    *g += k[[m, i]] * (S::one() - l[[m, i]] / d) * delta.component(a);
+                }
+            }
+        }
+        gradient
+    }
+
+    /// Returns the node with the largest energy gradient and the norm of
+    /// that gradient.
+    pub fn max_gradient<D, Diff, M>(&self, drawing: &D) -> (usize, S)
+    where
+        D: Drawing<Item = M>,
+        Diff: KamadaKawaiDelta<S = S>,
+        M: Metric<D = Diff>,
         S: DrawingValue,
     {
         let n = drawing.len();
-        let KamadaKawai { k, l, eps, .. } = self;
         let mut delta2_max = S::zero();
         let mut m_target = 0;
         for m in 0..n {
-            let xm = drawing.raw_entry(m).0;
-            let ym = drawing.raw_entry(m).1;
-            let mut dedx = S::zero();
-            let mut dedy = S::zero();
-            for i in 0..n {
-                if i != m {
-                    let xi = drawing.raw_entry(i).0;
-                    let yi = drawing.raw_entry(i).1;
-                    let dx = xm - xi;
-                    let dy = ym - yi;
-                    let d = norm(dx, dy);
-                    dedx += k[[m, i]] * (S::one() - l[[m, i]] / d) * dx;
-                    dedy += k[[m, i]] * (S::one() - l[[m, i]] / d) * dy;
-                }
-            }
-            let delta2 = dedx * dedx + dedy * dedy;
+            let delta2 = self
+                .gradient(m, drawing)
+                .iter()
+                .fold(S::zero(), |s, &g| s + g * g);
             if delta2 > delta2_max {
                 delta2_max = delta2;
                 m_target = m;
             }
         }
+        (m_target, delta2_max.sqrt())
+    }
 
-        if delta2_max < *eps * *eps {
+    pub fn select_node<D, Diff, M>(&self, drawing: &D) -> Option<usize>
+    where
+        D: Drawing<Item = M>,
+        Diff: KamadaKawaiDelta<S = S>,
+        M: Metric<D = Diff>,
+        S: DrawingValue,
+    {
+        let (m, delta) = self.max_gradient(drawing);
+        if delta < self.eps {
             None
         } else {
-            Some(m_target)
+            Some(m)
         }
     }
 
-    pub fn apply_to_node<N>(&self, m: usize, drawing: &mut DrawingEuclidean2d<N, S>)
+    pub fn apply_to_node<D, Diff, M>(&self, m: usize, drawing: &mut D)
     where
-        N: DrawingIndex,
+        D: Drawing<Item = M>,
+        Diff: KamadaKawaiDelta<S = S>,
+        M: Metric<D = Diff>,
         S: DrawingValue,
     {
         let n = drawing.len();
+        let dimension = drawing.dimension();
         let KamadaKawai { k, l, .. } = self;
-        let xm = drawing.raw_entry(m).0;
-        let ym = drawing.raw_entry(m).1;
-        let mut hxx = S::zero();
-        let mut hyy = S::zero();
-        let mut hxy = S::zero();
-        let mut dedx = S::zero();
-        let mut dedy = S::zero();
+        let min_distance = self.min_distance.unwrap_or_else(Diff::default_min_distance);
+        let mut hessian = vec![vec![S::zero(); dimension]; dimension];
         for i in 0..n {
             if i != m {
-                let xi = drawing.raw_entry(i).0;
-                let yi = drawing.raw_entry(i).1;
-                let dx = xm - xi;
-                let dy = ym - yi;
-                let d = norm(dx, dy);
+                let delta = drawing.delta(m, i);
+                let d = delta.norm().max(min_distance);
                 let d3 = d * d * d;
-                hxx += k[[m, i]] * (S::one() - l[[m, i]] * dy * dy / d3);
-                hyy += k[[m, i]] * (S::one() - l[[m, i]] * dx * dx / d3);
-                hxy += k[[m, i]] * l[[m, i]] * dx * dy / d3;
-                dedx += k[[m, i]] * (S::one() - l[[m, i]] / d) * dx;
-                dedy += k[[m, i]] * (S::one() - l[[m, i]] / d) * dy;
+                for (a, row) in hessian.iter_mut().enumerate() {
+                    for (b, h) in row.iter_mut().enumerate() {
+                        let mut hab = l[[m, i]] * delta.component(a) * delta.component(b) / d3;
+                        if a == b {
+                            hab += S::one() - l[[m, i]] / d;
+                        }
+                        *h += k[[m, i]] * hab;
+                    }
+                }
             }
         }
-        let det = hxx * hyy - hxy * hxy;
-        let delta_x = (hyy * dedx - hxy * dedy) / det;
-        let delta_y = (hxx * dedy - hxy * dedx) / det;
-        drawing.raw_entry_mut(m).0 -= delta_x;
-        drawing.raw_entry_mut(m).1 -= delta_y;
+        let gradient = self.gradient(m, drawing);
+        let step = solve(hessian, gradient);
+        *drawing.raw_entry_mut(m) -= Diff::from_components(&step);
     }
 
-    pub fn run<N>(&self, drawing: &mut DrawingEuclidean2d<N, S>)
+    pub fn energy<D, Diff, M>(&self, drawing: &D) -> S
     where
-        N: DrawingIndex,
+        D: Drawing<Item = M>,
+        Diff: KamadaKawaiDelta<S = S>,
+        M: Metric<D = Diff>,
+        S: DrawingValue,
+    {
+        let n = drawing.len();
+        let KamadaKawai { k, l, .. } = self;
+        let mut e = S::zero();
+        for j in 1..n {
+            for i in 0..j {
+                let d = drawing.delta(i, j).norm() - l[[i, j]];
+                e += k[[i, j]] * d * d;
+            }
+        }
+        e / S::from_usize(2).unwrap()
+    }
+
+    pub fn apply<D, Diff, M>(&self, drawing: &mut D) -> S
+    where
+        D: Drawing<Item = M>,
+        Diff: KamadaKawaiDelta<S = S>,
+        M: Metric<D = Diff>,
+        S: DrawingValue,
+    {
+        let n = drawing.len();
+        for m in 0..n {
+            self.apply_to_node(m, drawing);
+        }
+        (0..n)
+            .map(|m| {
+                self.gradient(m, drawing)
+                    .iter()
+                    .fold(S::zero(), |s, &g| s + g * g)
+                    .sqrt()
+            })
+            .fold(S::zero(), |a, b| a.max(b))
+    }
+
+    pub fn run<D, Diff, M>(&self, drawing: &mut D)
+    where
+        D: Drawing<Item = M>,
+        Diff: KamadaKawaiDelta<S = S>,
+        M: Metric<D = Diff>,
         S: DrawingValue,
     {
         while let Some(m) = self.select_node(drawing) {
@@ -136,6 +372,7 @@ impl<S> KamadaKawai<S> {
 #[test]
 fn test_kamada_kawai() {
     use petgraph::Graph;
+    use petgraph_drawing::DrawingEuclidean2d;
 
     let n = 10;
     let mut graph = Graph::new_undirected();
@@ -159,3 +396,114 @@ fn test_kamada_kawai() {
         println!("{:?}", coordinates.position(u));
     }
 }
+
+#[test]
+fn test_kamada_kawai_euclidean() {
+    use petgraph::Graph;
+    use petgraph_drawing::DrawingEuclidean;
+
+    let n = 10;
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for i in 1..n {
+        graph.add_edge(nodes[i - 1], nodes[i], ());
+    }
+
+    let mut drawing = DrawingEuclidean::new(&graph, 3);
+    for (i, &u) in nodes.iter().enumerate() {
+        for d in 0..3 {
+            drawing.set(u, d, ((i * 7 + d * 3) % 10) as f32);
+        }
+    }
+
+    let kamada_kawai = KamadaKawai::new(&graph, &mut |_| 1.);
+    kamada_kawai.run(&mut drawing);
+    assert_eq!(kamada_kawai.select_node(&drawing), None);
+}
+
+#[test]
+fn test_kamada_kawai_torus() {
+    use petgraph::Graph;
+    use petgraph_drawing::DrawingTorus2d;
+
+    let n = 10;
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for i in 0..n {
+        graph.add_edge(nodes[i], nodes[(i + 1) % n], ());
+    }
+
+    let mut drawing = DrawingTorus2d::<petgraph::graph::NodeIndex, f32>::initial_placement(&graph);
+    let kamada_kawai = KamadaKawai::new(&graph, &mut |_| 0.05);
+    let energy = |drawing: &DrawingTorus2d<petgraph::graph::NodeIndex, f32>| {
+        let mut s = 0.;
+        for i in 0..n {
+            for j in 0..i {
+                let e = drawing.delta(i, j).norm() - kamada_kawai.l[[i, j]];
+                s += kamada_kawai.k[[i, j]] * e * e;
+            }
+        }
+        s
+    };
+    let e0 = energy(&drawing);
+    for _ in 0..100 {
+        match kamada_kawai.select_node(&drawing) {
+            Some(m) => kamada_kawai.apply_to_node(m, &mut drawing),
+            None => break,
+        }
+    }
+    assert!(energy(&drawing) < e0);
+}
+
+#[test]
+fn test_kamada_kawai_spherical() {
+    use petgraph::Graph;
+    use petgraph_drawing::DrawingSpherical2d;
+
+    let n = 10;
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for i in 0..n {
+        graph.add_edge(nodes[i], nodes[(i + 1) % n], ());
+    }
+
+    let mut drawing =
+        DrawingSpherical2d::<petgraph::graph::NodeIndex, f32>::initial_placement(&graph);
+    let kamada_kawai = KamadaKawai::new(&graph, &mut |_| 0.3);
+    let e0 = kamada_kawai.energy(&drawing);
+    for _ in 0..1000 {
+        match kamada_kawai.select_node(&drawing) {
+            Some(m) => kamada_kawai.apply_to_node(m, &mut drawing),
+            None => break,
+        }
+    }
+    assert_eq!(kamada_kawai.select_node(&drawing), None);
+    assert!(kamada_kawai.energy(&drawing) < e0);
+}
+
+#[test]
+fn test_kamada_kawai_apply() {
+    use petgraph::Graph;
+    use petgraph_drawing::DrawingEuclidean2d;
+
+    let n = 10;
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for i in 1..n {
+        graph.add_edge(nodes[i - 1], nodes[i], ());
+    }
+
+    let mut drawing =
+        DrawingEuclidean2d::<petgraph::graph::NodeIndex, f32>::initial_placement(&graph);
+    let kamada_kawai = KamadaKawai::new(&graph, &mut |_| 1.);
+    let e0 = kamada_kawai.energy(&drawing);
+    let mut gradient = f32::INFINITY;
+    for _ in 0..100 {
+        gradient = kamada_kawai.apply(&mut drawing);
+        if gradient < kamada_kawai.eps {
+            break;
+        }
+    }
+    assert!(gradient < kamada_kawai.eps);
+    assert!(kamada_kawai.energy(&drawing) < e0);
+}
@@ -47,12 +47,20 @@ where
         }
     }
 
+    pub fn indices(&self) -> &[N] {
+        &self.indices
+    }
+
+    pub fn embedding(&self, d: usize) -> Array2<f32> {
+        let (e, v) = eigendecomposition(&self.b, d, self.eps);
+        v.dot(&Array2::from_diag(&e.mapv(|v| v.sqrt())))
+    }
+
     pub fn run_2d(&self) -> DrawingEuclidean2d<N, f32>
     where
         N: Copy,
     {
-        let (e, v) = eigendecomposition(&self.b, 2, self.eps);
-        let xy = v.dot(&Array2::from_diag(&e.mapv(|v| v.sqrt())));
+        let xy = self.embedding(2);
         let mut drawing = DrawingEuclidean2d::from_node_indices(&self.indices);
         for (i, &u) in self.indices.iter().enumerate() {
             drawing.position_mut(u).map(|p| {
@@ -67,8 +75,7 @@ where
     where
         N: Copy,
     {
-        let (e, v) = eigendecomposition(&self.b, d, self.eps);
-        let x = v.dot(&Array2::from_diag(&e.mapv(|v| v.sqrt())));
+        let x = self.embedding(d);
         let mut drawing = DrawingEuclidean::from_node_indices(&self.indices, d);
         for (i, &u) in self.indices.iter().enumerate() {
             drawing.position_mut(u).map(|p| {
@@ -1,3 +1,22 @@
+//! ```
+//! use petgraph::{graph::NodeIndex, Graph};
+//! use petgraph_drawing::Drawing;
+//! use petgraph_layout_mds::{ClassicalMds, PivotMds};
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 0..6 {
+//!     graph.add_edge(nodes[i], nodes[(i + 1) % 6], ());
+//! }
+//! let drawing = ClassicalMds::<NodeIndex>::new(&graph, |_| 1.).run_2d();
+//! assert_eq!(drawing.len(), 6);
+//! let drawing = PivotMds::<NodeIndex>::new(&graph, |_| 1., &[nodes[0], nodes[2], nodes[4]]).run(2);
+//! assert_eq!(drawing.dimension(), 2);
+//! for &u in &nodes {
+//!     assert!(drawing.position(u).unwrap().0.iter().all(|x| x.is_finite()));
+//! }
+//! ```
+
 mod classical_mds;
 mod double_centering;
 mod eigendecomposition;
@@ -47,19 +47,27 @@ where
         }
     }
 
+    pub fn indices(&self) -> &[N] {
+        &self.indices
+    }
+
+    pub fn embedding(&self, d: usize) -> Array2<f32> {
+        let ct_c = self.c.t().dot(&self.c);
+        let (e, v) = eigendecomposition(&ct_c, d, self.eps);
+        let x = v.dot(&Array2::from_diag(&e.mapv(|v| v.sqrt())));
+        self.c.dot(&x)
+    }
+
     pub fn run_2d(&self) -> DrawingEuclidean2d<N, f32>
     where
         N: Copy,
     {
-        let ct_c = self.c.t().dot(&self.c);
-        let (e, v) = eigendecomposition(&ct_c, 2, self.eps);
-        let xy = v.dot(&Array2::from_diag(&e.mapv(|v| v.sqrt())));
-        let xy = self.c.dot(&xy);
+        let xy = self.embedding(2);
         let mut drawing = DrawingEuclidean2d::from_node_indices(&self.indices);
         for (i, &u) in self.indices.iter().enumerate() {
             drawing.position_mut(u).map(|p| {
                 p.0 = xy[[i, 0]];
-                p.1 = xy[[i, 1]]
+                p.1 = xy[[i, 1]];
             });
         }
         drawing
@@ -69,10 +77,7 @@ where
     where
         N: Copy,
     {
-        let ct_c = self.c.t().dot(&self.c);
-        let (e, v) = eigendecomposition(&ct_c, d, self.eps);
-        let x = v.dot(&Array2::from_diag(&e.mapv(|v| v.sqrt())));
-        let x = self.c.dot(&x);
+        let x = self.embedding(d);
         let mut drawing = DrawingEuclidean::from_node_indices(&self.indices, d);
         for (i, &u) in self.indices.iter().enumerate() {
             drawing.position_mut(u).map(|p| {
@@ -49,3 +49,19 @@ fn test_pivot_mds_3d() {
         assert!(drawing.get(u, 2).unwrap().is_finite());
     }
 }
+
+#[test]
+fn test_mds_embedding() {
+    let graph: UnGraph<(), ()> = dataset_1138_bus();
+    let mds = ClassicalMds::<NodeIndex>::new(&graph, |_| 1.);
+    let x = mds.embedding(5);
+    assert_eq!(x.shape(), &[graph.node_count(), 5]);
+    assert_eq!(mds.indices().len(), graph.node_count());
+    assert!(x.iter().all(|v| v.is_finite()));
+
+    let pivot = graph.node_indices().take(50).collect::<Vec<_>>();
+    let mds = PivotMds::<NodeIndex>::new(&graph, |_| 1., &pivot);
+    let x = mds.embedding(5);
+    assert_eq!(x.shape(), &[graph.node_count(), 5]);
+    assert!(x.iter().all(|v| v.is_finite()));
+}
@@ -0,0 +1,217 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_clustering::{CommunityDetection, Louvain};
+//! use petgraph_drawing::Drawing;
+//! use petgraph_layout_multilevel::MultilevelLayout;
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..20).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 1..20 {
+//!     graph.add_edge(nodes[i - 1], nodes[i], ());
+//! }
+//! let mut multilevel = MultilevelLayout::new();
+//! multilevel.min_node_count = 4;
+//! let drawing = multilevel.run(
+//!     &graph,
+//!     &mut |g| Some(Louvain::new().detect_communities(g)),
+//!     &mut |_: &_, _: &mut _| {},
+//! );
+//! assert_eq!(drawing.len(), 20);
+//! for &u in &nodes {
+//!     assert!(drawing.x(u).unwrap().is_finite() && drawing.y(u).unwrap().is_finite());
+//! }
+//! ```
+use petgraph::graph::{Graph, IndexType, NodeIndex};
+use petgraph::EdgeType;
+use petgraph_clustering::coarsen;
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, MetricEuclidean2d};
+use std::collections::HashMap;
+
+pub type LevelGraph<Ty, Ix> = Graph<usize, usize, Ty, Ix>;
+
+pub trait Layout<Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn apply(
+        &mut self,
+        graph: &LevelGraph<Ty, Ix>,
+        drawing: &mut DrawingEuclidean2d<NodeIndex<Ix>, f32>,
+    );
+}
+
+impl<Ty, Ix, F> Layout<Ty, Ix> for F
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+    F: FnMut(&LevelGraph<Ty, Ix>, &mut DrawingEuclidean2d<NodeIndex<Ix>, f32>),
+{
+    fn apply(
+        &mut self,
+        graph: &LevelGraph<Ty, Ix>,
+        drawing: &mut DrawingEuclidean2d<NodeIndex<Ix>, f32>,
+    ) {
+        self(graph, drawing)
+    }
+}
+
+pub struct Hierarchy<Ty, Ix>
+where
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    pub levels: Vec<LevelGraph<Ty, Ix>>,
+    pub parents: Vec<Vec<NodeIndex<Ix>>>,
+}
+
+pub struct MultilevelLayout {
+    pub max_levels: usize,
+    pub min_node_count: usize,
+    pub jitter: f32,
+}
+
+impl MultilevelLayout {
+    pub fn new() -> MultilevelLayout {
+        MultilevelLayout {
+            max_levels: 10,
+            min_node_count: 10,
+            jitter: 1.,
+        }
+    }
+
+    pub fn hierarchy<N, E, Ty, Ix, GF>(
+        &self,
+        graph: &Graph<N, E, Ty, Ix>,
+        node_groups: &mut GF,
+    ) -> Hierarchy<Ty, Ix>
+    where
+        Ty: EdgeType,
+        Ix: IndexType,
+        GF: FnMut(&LevelGraph<Ty, Ix>) -> Option<HashMap<NodeIndex<Ix>, usize>>,
+    {
+        let mut levels = vec![graph.map(|_, _| 1, |_, _| 1)];
+        let mut parents = vec![];
+        while levels.len() < self.max_levels {
+            let fine = levels.last().unwrap();
+            if fine.node_count() <= self.min_node_count {
+                break;
+            }
+            let groups = match node_groups(fine) {
+                Some(groups) => groups,
+                None => break,
+            };
+            let (coarse, group_ids) = coarsen(
+                fine,
+                &mut |_, u| groups[&u],
+                &mut |g, nodes| nodes.iter().map(|&u| g[u]).sum(),
+                &mut |g, edges| edges.iter().map(|&e| g[e]).sum(),
+            );
+            if coarse.node_count() == fine.node_count() {
+                break;
+            }
+            parents.push(
+                fine.node_indices()
+                    .map(|u| group_ids[&groups[&u]])
+                    .collect(),
+            );
+            levels.push(coarse);
+        }
+        Hierarchy { levels, parents }
+    }
+
+    pub fn run<N, E, Ty, Ix, GF, L>(
+        &self,
+        graph: &Graph<N, E, Ty, Ix>,
+        node_groups: &mut GF,
+        layout: &mut L,
+    ) -> DrawingEuclidean2d<NodeIndex<Ix>, f32>
+    where
+        Ty: EdgeType,
+        Ix: IndexType,
+        GF: FnMut(&LevelGraph<Ty, Ix>) -> Option<HashMap<NodeIndex<Ix>, usize>>,
+        L: Layout<Ty, Ix>,
+    {
+        let Hierarchy { levels, parents } = self.hierarchy(graph, node_groups);
+        let coarsest = levels.last().unwrap();
+        let mut drawing = DrawingEuclidean2d::initial_placement(coarsest);
+        layout.apply(coarsest, &mut drawing);
+        for (fine, parent) in levels.iter().zip(parents.iter()).rev() {
+            let mut fine_drawing = DrawingEuclidean2d::new(fine);
+            let mut children = HashMap::new();
+            for u in fine.node_indices() {
+                let p = parent[u.index()];
+                let k = children.entry(p).or_insert(0usize);
+                let angle = *k as f32 * 2.399_963;
+                let r = self.jitter * (*k as f32).sqrt();
+                *k += 1;
+                let MetricEuclidean2d(x, y) = *drawing.position(p).unwrap();
+                fine_drawing.set_x(u, x + r * angle.cos());
+                fine_drawing.set_y(u, y + r * angle.sin());
+            }
+            layout.apply(fine, &mut fine_drawing);
+            drawing = fine_drawing;
+        }
+        drawing
+    }
+}
+
+impl Default for MultilevelLayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::prelude::*;
+    use petgraph_clustering::louvain_step;
+    use petgraph_layout_sgd::{Scheduler, SchedulerExponential, Sgd, SparseSgd};
+    use rand::prelude::*;
+
+    #[test]
+    fn test_multilevel_layout() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..100).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..10 {
+            for j in 0..10 {
+                if i + 1 < 10 {
+                    graph.add_edge(nodes[i * 10 + j], nodes[i * 10 + j + 10], ());
+                }
+                if j + 1 < 10 {
+                    graph.add_edge(nodes[i * 10 + j], nodes[i * 10 + j + 1], ());
+                }
+            }
+        }
+
+        let multilevel = MultilevelLayout::new();
+        let mut node_groups = |g: &LevelGraph<Undirected, u32>| {
+            louvain_step(&g).map(|communities| {
+                communities
+                    .into_iter()
+                    .map(|(u, c)| (u, c.index()))
+                    .collect::<HashMap<_, _>>()
+            })
+        };
+        let levels = multilevel.hierarchy(&graph, &mut node_groups).levels;
+        assert!(levels.len() > 1);
+        assert_eq!(levels.last().unwrap().node_weights().sum::<usize>(), 100);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut layout =
+            |g: &LevelGraph<Undirected, u32>, drawing: &mut DrawingEuclidean2d<NodeIndex, f32>| {
+                let mut sgd = SparseSgd::new_with_rng(g, |_| 30., 50.min(g.node_count()), &mut rng);
+                let mut scheduler = sgd.scheduler::<SchedulerExponential<f32>>(30, 0.1);
+                scheduler.run(&mut |eta| {
+                    sgd.shuffle(&mut rng);
+                    sgd.apply(drawing, eta);
+                });
+            };
+        let drawing = multilevel.run(&graph, &mut node_groups, &mut layout);
+        for &u in nodes.iter() {
+            assert!(drawing.x(u).unwrap().is_finite());
+            assert!(drawing.y(u).unwrap().is_finite());
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_drawing::{Drawing, DrawingEuclidean2d};
+//! use petgraph_layout_neighbor_embedding::NeighborEmbedding;
+//! use rand::prelude::*;
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 1..4 {
+//!     graph.add_edge(nodes[i - 1], nodes[i], ());
+//! }
+//! let mut rng = StdRng::seed_from_u64(0);
+//! let mut drawing = DrawingEuclidean2d::<_, f32>::initial_placement(&graph);
+//! NeighborEmbedding::new(&graph, 2).run(&mut drawing, 10, &mut rng);
+//! assert_eq!(drawing.len(), 4);
+//! for &u in &nodes {
+//!     assert!(drawing.x(u).unwrap().is_finite() && drawing.y(u).unwrap().is_finite());
+//! }
+//! ```
+
+use petgraph::visit::{IntoNeighbors, IntoNodeIdentifiers};
+use petgraph_drawing::{Delta, Drawing, DrawingIndex, DrawingValue, Metric};
+use rand::prelude::*;
+use std::collections::{hash_map::Entry, HashMap, VecDeque};
+
+fn k_nearest_neighbors<G>(
+    graph: G,
+    indices: &HashMap<G::NodeId, usize>,
+    s: G::NodeId,
+    k: usize,
+) -> Vec<(usize, usize)>
+where
+    G: IntoNeighbors,
+    G::NodeId: DrawingIndex + Copy,
+{
+    let mut distance = HashMap::new();
+    distance.insert(s, 0);
+    let mut queue = VecDeque::new();
+    queue.push_back(s);
+    let mut neighbors = vec![];
+    while let Some(u) = queue.pop_front() {
+        let du = distance[&u];
+        for v in graph.neighbors(u) {
+            if let Entry::Vacant(e) = distance.entry(v) {
+                e.insert(du + 1);
+            } else {
+                continue;
+            }
+            neighbors.push((indices[&v], du + 1));
+            if neighbors.len() == k {
+                return neighbors;
+            }
+            queue.push_back(v);
+        }
+    }
+    neighbors
+}
+
+pub struct NeighborEmbedding<S> {
+    n: usize,
+    edges: Vec<(usize, usize, S)>,
+    pub a: S,
+    pub b: S,
+    pub negative_samples: usize,
+    pub repulsion_strength: S,
+}
+
+impl<S> NeighborEmbedding<S>
+where
+    S: DrawingValue,
+{
+    pub fn new<G>(graph: G, k: usize) -> Self
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers,
+        G::NodeId: DrawingIndex + Copy,
+    {
+        let indices = graph
+            .node_identifiers()
+            .enumerate()
+            .map(|(i, u)| (u, i))
+            .collect::<HashMap<_, _>>();
+        let n = indices.len();
+        let mut membership = HashMap::new();
+        for u in graph.node_identifiers() {
+            let i = indices[&u];
+            for (j, d) in k_nearest_neighbors(graph, &indices, u, k) {
+                let w = (S::one() - S::from_usize(d).unwrap()).exp();
+                membership.insert((i, j), w);
+            }
+        }
+        let mut edges = vec![];
+        for (&(i, j), &wij) in membership.iter() {
+            match membership.get(&(j, i)) {
+                Some(&wji) => {
+                    if i < j {
+                        edges.push((i, j, wij + wji - wij * wji));
+                    }
+                }
+                None => edges.push((i, j, wij)),
+            }
+        }
+        edges.sort_by_key(|e| (e.0, e.1));
+        NeighborEmbedding {
+            n,
+            edges,
+            a: S::from_f32(1.577).unwrap(),
+            b: S::from_f32(0.895).unwrap(),
+            negative_samples: 5,
+            repulsion_strength: S::one(),
+        }
+    }
+
+    pub fn edges(&self) -> &Vec<(usize, usize, S)> {
+        &self.edges
+    }
+
+    fn move_pair<Diff, D, M>(drawing: &mut D, i: usize, j: usize, c: S, move_j: bool)
+    where
+        D: Drawing<Item = M>,
+        Diff: Delta<S = S>,
+        M: Metric<D = Diff>,
+    {
+        let delta = drawing.delta(i, j);
+        let norm = delta.norm();
+        let clip = S::from_usize(4).unwrap();
+        let c = if c * norm > clip {
+            clip / norm
+        } else if c * norm < -clip {
+            -clip / norm
+        } else {
+            c
+        };
+        *drawing.raw_entry_mut(i) += delta.clone() * c;
+        if move_j {
+            *drawing.raw_entry_mut(j) += delta * -c;
+        }
+    }
+
+    pub fn apply<Diff, D, M, R>(&self, drawing: &mut D, eta: S, rng: &mut R)
+    where
+        D: Drawing<Item = M>,
+        Diff: Delta<S = S>,
+        M: Metric<D = Diff>,
+        R: Rng,
+    {
+        let NeighborEmbedding { a, b, .. } = *self;
+        let one = S::one();
+        let two = one + one;
+        let eps = S::from_f32(1e-3).unwrap();
+        for &(i, j, w) in self.edges.iter() {
+            if rng.gen::<f64>() > w.to_f64().unwrap() {
+                continue;
+            }
+            let d2 = drawing.delta(i, j).norm().powi(2);
+            if d2 > S::zero() {
+                let c = -two * a * b * d2.powf(b - one) / (one + a * d2.powf(b));
+                Self::move_pair(drawing, i, j, c * eta, true);
+            }
+            for _ in 0..self.negative_samples {
+                let k = rng.gen_range(0..self.n);
+                if k == i {
+                    continue;
+                }
+                let d2 = drawing.delta(i, k).norm().powi(2);
+                let c = two * b / ((eps + d2) * (one + a * d2.powf(b)));
+                Self::move_pair(drawing, i, k, c * eta * self.repulsion_strength, false);
+            }
+        }
+    }
+
+    pub fn run<Diff, D, M, R>(&self, drawing: &mut D, epochs: usize, rng: &mut R)
+    where
+        D: Drawing<Item = M>,
+        Diff: Delta<S = S>,
+        M: Metric<D = Diff>,
+        R: Rng,
+    {
+        for epoch in 0..epochs {
+            let eta = S::one() - S::from_usize(epoch).unwrap() / S::from_usize(epochs).unwrap();
+            self.apply(drawing, eta, rng);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{graph::NodeIndex, Graph};
+    use petgraph_drawing::DrawingEuclidean2d;
+
+    #[test]
+    fn test_neighbor_embedding_clusters() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..20).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for c in 0..2 {
+            for i in 0..10 {
+                for j in 0..i {
+                    graph.add_edge(nodes[c * 10 + j], nodes[c * 10 + i], ());
+                }
+            }
+        }
+        graph.add_edge(nodes[0], nodes[10], ());
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::initial_placement(&graph);
+        let mut rng = StdRng::seed_from_u64(0);
+        let embedding = NeighborEmbedding::new(&graph, 9);
+        embedding.run(&mut drawing, 200, &mut rng);
+
+        let distance = |i: usize, j: usize| drawing.delta(i, j).norm();
+        let mut intra = 0.;
+        let mut inter = 0.;
+        for i in 0..20 {
+            for j in 0..20 {
+                if i / 10 == j / 10 {
+                    intra += distance(i, j) / 180.;
+                } else {
+                    inter += distance(i, j) / 200.;
+                }
+            }
+        }
+        assert!(intra < inter);
+    }
+}
@@ -0,0 +1,245 @@
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers};
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex};
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LabelAnchor {
+    Right,
+    TopRight,
+    Top,
+    TopLeft,
+    Left,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+const ANCHORS: [LabelAnchor; 8] = [
+    LabelAnchor::Right,
+    LabelAnchor::TopRight,
+    LabelAnchor::Top,
+    LabelAnchor::TopLeft,
+    LabelAnchor::Left,
+    LabelAnchor::BottomLeft,
+    LabelAnchor::Bottom,
+    LabelAnchor::BottomRight,
+];
+
+impl LabelAnchor {
+    fn direction(&self) -> (f32, f32) {
+        match self {
+            LabelAnchor::Right => (1., 0.),
+            LabelAnchor::TopRight => (1., -1.),
+            LabelAnchor::Top => (0., -1.),
+            LabelAnchor::TopLeft => (-1., -1.),
+            LabelAnchor::Left => (-1., 0.),
+            LabelAnchor::BottomLeft => (-1., 1.),
+            LabelAnchor::Bottom => (0., 1.),
+            LabelAnchor::BottomRight => (1., 1.),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct LabelPlacement {
+    pub anchor: LabelAnchor,
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Clone, Copy)]
+struct Rect {
+    left: f32,
+    top: f32,
+    right: f32,
+    bottom: f32,
+}
+
+impl Rect {
+    fn overlap_area(&self, other: &Rect) -> f32 {
+        let w = self.right.min(other.right) - self.left.max(other.left);
+        let h = self.bottom.min(other.bottom) - self.top.max(other.top);
+        if w > 0. && h > 0. {
+            w * h
+        } else {
+            0.
+        }
+    }
+
+    fn clip_length(&self, (x1, y1): (f32, f32), (x2, y2): (f32, f32)) -> f32 {
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let mut t0 = 0f32;
+        let mut t1 = 1f32;
+        for (p, q) in [
+            (-dx, x1 - self.left),
+            (dx, self.right - x1),
+            (-dy, y1 - self.top),
+            (dy, self.bottom - y1),
+        ] {
+            if p == 0. {
+                if q < 0. {
+                    return 0.;
+                }
+            } else {
+                let t = q / p;
+                if p < 0. {
+                    t0 = t0.max(t);
+                } else {
+                    t1 = t1.min(t);
+                }
+            }
+        }
+        if t0 < t1 {
+            (t1 - t0) * dx.hypot(dy)
+        } else {
+            0.
+        }
+    }
+}
+
+pub struct LabelPlacer {
+    extents: Vec<(f32, f32)>,
+    edges: Vec<(usize, usize)>,
+    pub gap: f32,
+    pub edge_weight: f32,
+    pub iterations: usize,
+}
+
+impl LabelPlacer {
+    pub fn new<G, F>(graph: G, extent: F) -> LabelPlacer
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers,
+        G::NodeId: DrawingIndex,
+        F: FnMut(G::NodeId) -> (f32, f32),
+    {
+        let indices = graph
+            .node_identifiers()
+            .enumerate()
+            .map(|(i, u)| (u, i))
+            .collect::<HashMap<_, _>>();
+        LabelPlacer {
+            extents: graph.node_identifiers().map(extent).collect(),
+            edges: graph
+                .edge_references()
+                .map(|e| (indices[&e.source()], indices[&e.target()]))
+                .filter(|&(i, j)| i != j)
+                .collect(),
+            gap: 1.,
+            edge_weight: 1.,
+            iterations: 10,
+        }
+    }
+
+    fn rect(&self, (x, y): (f32, f32), i: usize, anchor: LabelAnchor) -> Rect {
+        let (w, h) = self.extents[i];
+        let (dx, dy) = anchor.direction();
+        let cx = x + dx * (self.gap + w / 2.);
+        let cy = y + dy * (self.gap + h / 2.);
+        Rect {
+            left: cx - w / 2.,
+            top: cy - h / 2.,
+            right: cx + w / 2.,
+            bottom: cy + h / 2.,
+        }
+    }
+
+    fn cost(&self, points: &[(f32, f32)], rects: &[Rect], i: usize, rect: &Rect) -> f32 {
+        let label_overlap = rects
+            .iter()
+            .enumerate()
+            .filter(|&(j, _)| j != i)
+            .map(|(_, r)| rect.overlap_area(r))
+            .sum::<f32>();
+        let edge_overlap = self
+            .edges
+            .iter()
+            .map(|&(u, v)| rect.clip_length(points[u], points[v]))
+            .sum::<f32>();
+        label_overlap + self.edge_weight * edge_overlap
+    }
+
+    pub fn place<N>(&self, drawing: &DrawingEuclidean2d<N, f32>) -> HashMap<N, LabelPlacement>
+    where
+        N: DrawingIndex + Copy,
+    {
+        let n = drawing.len();
+        let points = (0..n)
+            .map(|i| {
+                let p = drawing.raw_entry(i);
+                (p.0, p.1)
+            })
+            .collect::<Vec<_>>();
+        let mut anchors = vec![LabelAnchor::Right; n];
+        let mut rects = (0..n)
+            .map(|i| self.rect(points[i], i, anchors[i]))
+            .collect::<Vec<_>>();
+        for _ in 0..self.iterations {
+            let mut changed = false;
+            for i in 0..n {
+                let mut best = self.cost(&points, &rects, i, &rects[i]);
+                for anchor in ANCHORS {
+                    let rect = self.rect(points[i], i, anchor);
+                    let c = self.cost(&points, &rects, i, &rect);
+                    if c < best {
+                        best = c;
+                        anchors[i] = anchor;
+                        rects[i] = rect;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        (0..n)
+            .map(|i| {
+                let r = rects[i];
+                (
+                    *drawing.node_id(i),
+                    LabelPlacement {
+                        anchor: anchors[i],
+                        x: (r.left + r.right) / 2.,
+                        y: (r.top + r.bottom) / 2.,
+                    },
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_label_placer() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..3).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        graph.add_edge(nodes[0], nodes[1], ());
+        graph.add_edge(nodes[1], nodes[2], ());
+        let mut drawing = DrawingEuclidean2d::new(&graph);
+        for (&u, (x, y)) in nodes.iter().zip([(0., 0.), (10., 0.), (10., 10.)]) {
+            drawing.set_x(u, x);
+            drawing.set_y(u, y);
+        }
+        let placer = LabelPlacer::new(&graph, |_| (6., 2.));
+        let placements = placer.place(&drawing);
+        assert_eq!(placements.len(), 3);
+
+        let points = [(0., 0.), (10., 0.), (10., 10.)];
+        let rects = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, u)| placer.rect(points[i], i, placements[u].anchor))
+            .collect::<Vec<_>>();
+        for i in 0..3 {
+            assert_eq!(placer.cost(&points, &rects, i, &rects[i]), 0.);
+        }
+        assert_ne!(placements[&nodes[0]].anchor, LabelAnchor::Right);
+        let p = placements[&nodes[2]];
+        assert_eq!(p.anchor, LabelAnchor::Right);
+        assert_eq!((p.x, p.y), (14., 10.));
+    }
+}
@@ -1,5 +1,44 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_drawing::{Drawing, DrawingEuclidean2d};
+//! use petgraph_layout_overwrap_removal::OverwrapRemoval;
+//!
+//! let mut graph = Graph::new_undirected();
+//! let a = graph.add_node(());
+//! let b = graph.add_node(());
+//! graph.add_edge(a, b, ());
+//! let mut drawing = DrawingEuclidean2d::<_, f32>::initial_placement(&graph);
+//! let mut overwrap_removal = OverwrapRemoval::new(&graph, |_| 100.);
+//! overwrap_removal.iterations = 5;
+//! overwrap_removal.apply(&mut drawing);
+//! assert_eq!(drawing.len(), 2);
+//! assert!(drawing.x(a).unwrap().is_finite() && drawing.y(b).unwrap().is_finite());
+//! ```
+
+mod label_placement;
+mod prism;
+
 use petgraph::visit::IntoNodeIdentifiers;
-use petgraph_drawing::{Delta, Drawing, DrawingValue, Metric};
+use petgraph_drawing::{Delta, Drawing, DrawingEuclidean2d, DrawingIndex, DrawingValue, Metric};
+use std::collections::HashMap;
+
+pub use label_placement::{LabelAnchor, LabelPlacement, LabelPlacer};
+pub use prism::Prism;
+
+/// Returns the selection mask and `nodes` without duplicates, keeping the
+/// first occurrence of each index.
+fn select_nodes(n: usize, nodes: &[usize]) -> (Vec<bool>, Vec<usize>) {
+    let mut selected = vec![false; n];
+    let mut unique = Vec::with_capacity(nodes.len());
+    for &i in nodes {
+        assert!(i < n, "node index {} is out of range for {} nodes", i, n);
+        if !selected[i] {
+            selected[i] = true;
+            unique.push(i);
+        }
+    }
+    (selected, unique)
+}
 
 pub struct OverwrapRemoval<S> {
     radius: Vec<S>,
@@ -29,6 +68,48 @@ where
         }
     }
 
+    fn separate<DR, M, D>(&self, drawing: &mut DR, i: usize, j: usize)
+    where
+        DR: Drawing<Item = M>,
+        M: Metric<D = D>,
+        D: Delta<S = S>,
+    {
+        let ri = self.radius[i];
+        let rj = self.radius[j];
+        let delta1 = drawing.delta(i, j);
+        let delta2 = drawing.delta(i, j);
+        let r = ri + rj;
+        let l = delta1.norm().max(self.min_distance);
+        if l < r {
+            let d = (r - l) / l * self.strength;
+            let rr = (rj * rj) / (ri * ri + rj * rj);
+            *drawing.raw_entry_mut(i) += delta1 * (d * rr);
+            *drawing.raw_entry_mut(j) -= delta2 * (d * (S::one() - rr));
+        }
+    }
+
+    /// Sums how far each pair of circles overlaps. Zero means the drawing is
+    /// free of overlaps.
+    pub fn overlap<DR, M, D>(&self, drawing: &DR) -> S
+    where
+        DR: Drawing<Item = M>,
+        M: Metric<D = D>,
+        D: Delta<S = S>,
+    {
+        let n = drawing.len();
+        let mut overlap = S::zero();
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let l = drawing.delta(i, j).norm();
+                let r = self.radius[i] + self.radius[j];
+                if l < r {
+                    overlap += r - l;
+                }
+            }
+        }
+        overlap
+    }
+
     pub fn apply<DR, M, D>(&self, drawing: &mut DR)
     where
         DR: Drawing<Item = M>,
@@ -38,21 +119,147 @@ where
         let n = drawing.len();
         for _ in 0..self.iterations {
             for i in 0..n {
-                let ri = self.radius[i];
                 for j in (i + 1)..n {
-                    let rj = self.radius[j];
-                    let delta1 = drawing.delta(i, j);
-                    let delta2 = drawing.delta(i, j);
-                    let r = ri + rj;
-                    let l = delta1.norm().max(self.min_distance);
-                    if l < r {
-                        let d = (r - l) / l * self.strength;
-                        let rr = (rj * rj) / (ri * ri + rj * rj);
-                        *drawing.raw_entry_mut(i) += delta1 * (d * rr);
-                        *drawing.raw_entry_mut(j) -= delta2 * (d * (S::one() - rr));
+                    self.separate(drawing, i, j);
+                }
+            }
+        }
+    }
+
+    pub fn apply_to_nodes<DR, M, D>(&self, drawing: &mut DR, nodes: &[usize])
+    where
+        DR: Drawing<Item = M>,
+        M: Metric<D = D>,
+        D: Delta<S = S>,
+    {
+        let (selected, nodes) = select_nodes(drawing.len(), nodes);
+        for _ in 0..self.iterations {
+            for &i in nodes.iter() {
+                for (j, &s) in selected.iter().enumerate() {
+                    if j != i && !(s && j < i) {
+                        self.separate(drawing, i, j);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn apply_to_nodes_with_grid<N>(
+        &self,
+        drawing: &mut DrawingEuclidean2d<N, S>,
+        nodes: &[usize],
+    ) where
+        N: DrawingIndex,
+    {
+        let n = drawing.len();
+        let max_radius = self.radius.iter().fold(S::zero(), |m, &r| m.max(r));
+        let cell_size = (max_radius + max_radius).max(self.min_distance);
+        if !(cell_size > S::zero() && cell_size.is_finite()) {
+            self.apply_to_nodes(drawing, nodes);
+            return;
+        }
+        // Nodes at non-finite or out of range positions get no cell and are
+        // skipped.
+        let cell = |x: S, y: S| {
+            Some((
+                (x / cell_size).floor().to_i64()?,
+                (y / cell_size).floor().to_i64()?,
+            ))
+        };
+        let (selected, nodes) = select_nodes(n, nodes);
+        for _ in 0..self.iterations {
+            let mut grid = HashMap::<_, Vec<usize>>::new();
+            for j in 0..n {
+                let p = drawing.raw_entry(j);
+                if let Some(c) = cell(p.0, p.1) {
+                    grid.entry(c).or_default().push(j);
+                }
+            }
+            for &i in nodes.iter() {
+                let p = drawing.raw_entry(i);
+                let Some((cx, cy)) = cell(p.0, p.1) else {
+                    continue;
+                };
+                for dx in -1..=1 {
+                    for dy in -1..=1 {
+                        let Some(candidates) = grid.get(&(cx + dx, cy + dy)) else {
+                            continue;
+                        };
+                        for &j in candidates {
+                            if j != i && !(selected[j] && j < i) {
+                                self.separate(drawing, i, j);
+                            }
+                        }
                     }
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_apply_to_nodes() {
+        let mut graph = Graph::<(), ()>::new();
+        let nodes = (0..5).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let initial_drawing = || {
+            let mut drawing = DrawingEuclidean2d::new(&graph);
+            for (i, &u) in nodes.iter().enumerate() {
+                drawing.set_x(u, 10. * i as f32);
+            }
+            drawing.set_x(nodes[4], 0.5);
+            drawing.set_y(nodes[4], 0.5);
+            drawing
+        };
+        let mut overwrap_removal = OverwrapRemoval::new(&graph, |_| 1.);
+        overwrap_removal.iterations = 10;
+
+        let mut drawing = initial_drawing();
+        let mut expected = initial_drawing();
+        let overlap = 2. - (0.5f32).hypot(0.5);
+        assert!((overwrap_removal.overlap(&drawing) - overlap).abs() < 1e-5);
+        overwrap_removal.apply_to_nodes(&mut expected, &[4]);
+        overwrap_removal.apply_to_nodes_with_grid(&mut drawing, &[4]);
+        for &u in &nodes {
+            assert!((drawing.x(u).unwrap() - expected.x(u).unwrap()).abs() < 1e-5);
+            assert!((drawing.y(u).unwrap() - expected.y(u).unwrap()).abs() < 1e-5);
+        }
+        for &u in &nodes[1..4] {
+            assert_eq!(drawing.y(u), Some(0.));
+        }
+        assert!(drawing.delta(0, 4).norm() > 1.99);
+        assert!(overwrap_removal.overlap(&drawing) < 1e-2);
+    }
+
+    #[test]
+    fn test_apply_to_nodes_with_grid_degenerate() {
+        let mut graph = Graph::<(), ()>::new();
+        let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut drawing = DrawingEuclidean2d::new(&graph);
+        drawing.set_x(nodes[1], 0.5);
+        drawing.set_x(nodes[2], f32::NAN);
+        drawing.set_x(nodes[3], f32::INFINITY);
+        let overwrap_removal = OverwrapRemoval::new(&graph, |_| 1.);
+        overwrap_removal.apply_to_nodes_with_grid(&mut drawing, &[0, 2, 3, 0]);
+        assert!(drawing.delta(0, 1).norm() > 1.99);
+        assert!(drawing.x(nodes[2]).unwrap().is_nan());
+
+        let mut once = DrawingEuclidean2d::new(&graph);
+        once.set_x(nodes[1], 0.5);
+        let mut twice = DrawingEuclidean2d::new(&graph);
+        twice.set_x(nodes[1], 0.5);
+        overwrap_removal.apply_to_nodes_with_grid(&mut once, &[1]);
+        overwrap_removal.apply_to_nodes_with_grid(&mut twice, &[1, 1]);
+        assert_eq!(once.x(nodes[1]), twice.x(nodes[1]));
+
+        let mut overwrap_removal = OverwrapRemoval::new(&graph, |_| 0.);
+        overwrap_removal.min_distance = 0.;
+        let mut drawing = DrawingEuclidean2d::new(&graph);
+        overwrap_removal.apply_to_nodes_with_grid(&mut drawing, &[0, 1]);
+        assert_eq!(drawing.x(nodes[0]), Some(0.));
+    }
+}
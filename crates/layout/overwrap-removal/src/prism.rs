@@ -0,0 +1,235 @@
+use petgraph::visit::IntoNodeIdentifiers;
+use petgraph_algorithm_triangulation::triangulation;
+use petgraph_drawing::{Delta, Drawing, DrawingEuclidean2d, DrawingIndex};
+
+fn overlap_edges(points: &[(f32, f32)], radius: &[f32]) -> Vec<(usize, usize)> {
+    let mut order = (0..points.len()).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| (points[i].0 - radius[i]).total_cmp(&(points[j].0 - radius[j])));
+    let mut active: Vec<usize> = vec![];
+    let mut edges = vec![];
+    for &i in order.iter() {
+        let left = points[i].0 - radius[i];
+        active.retain(|&j| points[j].0 + radius[j] > left);
+        for &j in active.iter() {
+            let (dx, dy) = (points[i].0 - points[j].0, points[i].1 - points[j].1);
+            if dx.hypot(dy) < radius[i] + radius[j] {
+                edges.push((i.min(j), i.max(j)));
+            }
+        }
+        active.push(i);
+    }
+    edges
+}
+
+pub struct Prism {
+    radius: Vec<f32>,
+    pub iterations: usize,
+    pub stress_iterations: usize,
+    pub max_scale: f32,
+    pub min_distance: f32,
+}
+
+impl Prism {
+    pub fn new<G, F>(graph: G, radius: F) -> Prism
+    where
+        G: IntoNodeIdentifiers,
+        F: FnMut(G::NodeId) -> f32,
+    {
+        Prism {
+            radius: graph.node_identifiers().map(radius).collect::<Vec<_>>(),
+            iterations: 100,
+            stress_iterations: 100,
+            max_scale: 1.5,
+            min_distance: 1e-3,
+        }
+    }
+
+    pub fn apply<N>(&self, drawing: &mut DrawingEuclidean2d<N, f32>)
+    where
+        N: DrawingIndex + Copy,
+    {
+        if drawing.len() < 2 {
+            return;
+        }
+        for _ in 0..self.iterations {
+            self.separate_coincident(drawing);
+            let edges = triangulation(drawing);
+            if !self.majorize(drawing, &edges) {
+                break;
+            }
+        }
+        for _ in 0..self.iterations {
+            self.separate_coincident(drawing);
+            let points = Self::points(drawing);
+            let overlaps = overlap_edges(&points, &self.radius);
+            if overlaps.is_empty() {
+                break;
+            }
+            let mut edges = triangulation(drawing);
+            edges.extend(overlaps);
+            edges.sort();
+            edges.dedup();
+            self.majorize(drawing, &edges);
+        }
+    }
+
+    fn points<N>(drawing: &DrawingEuclidean2d<N, f32>) -> Vec<(f32, f32)>
+    where
+        N: DrawingIndex,
+    {
+        (0..drawing.len())
+            .map(|i| {
+                let p = drawing.raw_entry(i);
+                (p.0, p.1)
+            })
+            .collect()
+    }
+
+    fn separate_coincident<N>(&self, drawing: &mut DrawingEuclidean2d<N, f32>)
+    where
+        N: DrawingIndex,
+    {
+        let points = Self::points(drawing);
+        let mut order = (0..points.len()).collect::<Vec<_>>();
+        order.sort_by(|&i, &j| {
+            points[i]
+                .0
+                .total_cmp(&points[j].0)
+                .then(points[i].1.total_cmp(&points[j].1))
+        });
+        for k in 1..order.len() {
+            let (i, j) = (order[k], order[k - 1]);
+            if drawing.delta(i, j).norm() < self.min_distance {
+                let a = i as f32;
+                drawing.raw_entry_mut(i).0 += self.min_distance * a.cos();
+                drawing.raw_entry_mut(i).1 += self.min_distance * a.sin();
+            }
+        }
+    }
+
+    /// Runs sparse stress majorization on `edges`, each stretched to clear
+    /// the overlap of its endpoints. Returns false if no edge overlapped.
+    fn majorize<N>(
+        &self,
+        drawing: &mut DrawingEuclidean2d<N, f32>,
+        edges: &[(usize, usize)],
+    ) -> bool
+    where
+        N: DrawingIndex,
+    {
+        let n = drawing.len();
+        let mut neighbors = vec![vec![]; n];
+        let mut overlapped = false;
+        for &(i, j) in edges.iter() {
+            let l = drawing.delta(i, j).norm().max(self.min_distance);
+            let t = ((self.radius[i] + self.radius[j]) / l).clamp(1., self.max_scale);
+            if t > 1. {
+                overlapped = true;
+            }
+            let d = t * l;
+            let w = 1. / (d * d);
+            neighbors[i].push((j, d, w));
+            neighbors[j].push((i, d, w));
+        }
+        if !overlapped {
+            return false;
+        }
+        for _ in 0..self.stress_iterations {
+            let mut movement = 0f32;
+            for (i, neighbors) in neighbors.iter().enumerate() {
+                let (xi, yi) = (drawing.raw_entry(i).0, drawing.raw_entry(i).1);
+                let mut x = 0.;
+                let mut y = 0.;
+                let mut w_sum = 0.;
+                for &(j, d, w) in neighbors.iter() {
+                    let (xj, yj) = (drawing.raw_entry(j).0, drawing.raw_entry(j).1);
+                    let norm = (xi - xj).hypot(yi - yj).max(self.min_distance);
+                    x += w * (xj + d * (xi - xj) / norm);
+                    y += w * (yj + d * (yi - yj) / norm);
+                    w_sum += w;
+                }
+                if w_sum > 0. {
+                    let (x, y) = (x / w_sum, y / w_sum);
+                    movement = movement.max((x - xi).hypot(y - yi));
+                    drawing.raw_entry_mut(i).0 = x;
+                    drawing.raw_entry_mut(i).1 = y;
+                }
+            }
+            if movement < self.min_distance {
+                break;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::{graph::NodeIndex, Graph};
+
+    #[test]
+    fn test_overlap_edges() {
+        let points = (0..50)
+            .map(|i| {
+                let a = i as f32;
+                ((a * 1.7).sin() * 5., (a * 2.3).cos() * 5.)
+            })
+            .collect::<Vec<_>>();
+        let radius = (0..50)
+            .map(|i| 0.5 + (i % 3) as f32 * 0.2)
+            .collect::<Vec<_>>();
+        let mut expected = vec![];
+        for i in 0..50 {
+            for j in i + 1..50 {
+                let (dx, dy) = (points[i].0 - points[j].0, points[i].1 - points[j].1);
+                if dx.hypot(dy) < radius[i] + radius[j] {
+                    expected.push((i, j));
+                }
+            }
+        }
+        let mut edges = overlap_edges(&points, &radius);
+        edges.sort();
+        assert!(!expected.is_empty());
+        assert_eq!(edges, expected);
+    }
+
+    #[test]
+    fn test_prism() {
+        let mut graph = Graph::<(), ()>::new();
+        let nodes = (0..16).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut drawing = DrawingEuclidean2d::new(&graph);
+        for (i, &u) in nodes.iter().enumerate() {
+            drawing.set_x(u, (i % 4) as f32);
+            drawing.set_y(u, (i / 4) as f32 + 0.1 * (i % 4) as f32);
+        }
+        let prism = Prism::new(&graph, |_| 1.);
+        prism.apply(&mut drawing);
+        let mut min_distance = f32::INFINITY;
+        for i in 1..16 {
+            for j in 0..i {
+                min_distance = min_distance.min(drawing.delta(i, j).norm());
+            }
+        }
+        assert!(min_distance > 2. - 1e-3);
+        assert!(drawing.x(nodes[0]).unwrap() < drawing.x(nodes[3]).unwrap());
+        assert!(drawing.y(nodes[0]).unwrap() < drawing.y(nodes[12]).unwrap());
+    }
+
+    #[test]
+    fn test_prism_initial_placement() {
+        let mut graph = Graph::<(), ()>::new();
+        let nodes = (0..80).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..80 {
+            graph.add_edge(nodes[i / 2], nodes[i], ());
+        }
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::initial_placement(&graph);
+        let prism = Prism::new(&graph, |_| 10.);
+        prism.apply(&mut drawing);
+        for i in 1..80 {
+            for j in 0..i {
+                assert!(drawing.delta(i, j).norm() > 20. - 1e-2);
+            }
+        }
+    }
+}
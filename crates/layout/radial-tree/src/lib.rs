@@ -0,0 +1,169 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_drawing::Drawing;
+//! use petgraph_layout_radial_tree::RadialTree;
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 1..4 {
+//!     graph.add_edge(nodes[0], nodes[i], ());
+//! }
+//! let drawing = RadialTree::<f32>::new().run(&graph, nodes[0]);
+//! assert_eq!(drawing.len(), 4);
+//! for &u in &nodes {
+//!     assert!(drawing.x(u).unwrap().is_finite() && drawing.y(u).unwrap().is_finite());
+//! }
+//! ```
+
+use petgraph::visit::{IntoNeighbors, IntoNodeIdentifiers};
+use petgraph_drawing::{DrawingEuclidean2d, DrawingHyperbolic2d, DrawingIndex, DrawingValue};
+use std::collections::{hash_map::Entry, HashMap, VecDeque};
+
+struct RadialTreeNode<N, S> {
+    id: N,
+    depth: usize,
+    angle: S,
+}
+
+pub struct RadialTree<S> {
+    pub layer_distance: S,
+    pub hyperbolic_layer_distance: S,
+}
+
+impl<S> RadialTree<S>
+where
+    S: DrawingValue + Default,
+{
+    pub fn new() -> RadialTree<S> {
+        RadialTree {
+            layer_distance: S::from_usize(50).unwrap(),
+            hyperbolic_layer_distance: S::one(),
+        }
+    }
+
+    fn layout<G>(&self, graph: G, root: G::NodeId) -> Vec<RadialTreeNode<G::NodeId, S>>
+    where
+        G: IntoNeighbors,
+        G::NodeId: DrawingIndex + Copy,
+    {
+        let mut order = vec![root];
+        let mut parent = vec![None];
+        let mut depth = vec![0];
+        let mut indices = HashMap::new();
+        indices.insert(root, 0);
+        let mut queue = VecDeque::new();
+        queue.push_back(0);
+        while let Some(i) = queue.pop_front() {
+            for v in graph.neighbors(order[i]) {
+                if let Entry::Vacant(e) = indices.entry(v) {
+                    let j = order.len();
+                    e.insert(j);
+                    order.push(v);
+                    parent.push(Some(i));
+                    depth.push(depth[i] + 1);
+                    queue.push_back(j);
+                }
+            }
+        }
+
+        let n = order.len();
+        let mut size = vec![1usize; n];
+        for i in (1..n).rev() {
+            if let Some(p) = parent[i] {
+                size[p] += size[i];
+            }
+        }
+
+        let two_pi = S::from_f64(2. * std::f64::consts::PI).unwrap();
+        let mut start = vec![S::zero(); n];
+        let mut width = vec![S::zero(); n];
+        let mut offset = vec![S::zero(); n];
+        width[0] = two_pi;
+        for i in 1..n {
+            let p = parent[i].unwrap();
+            width[i] =
+                width[p] * S::from_usize(size[i]).unwrap() / S::from_usize(size[p] - 1).unwrap();
+            start[i] = start[p] + offset[p];
+            offset[p] += width[i];
+        }
+
+        (0..n)
+            .map(|i| RadialTreeNode {
+                id: order[i],
+                depth: depth[i],
+                angle: start[i] + width[i] / S::from_usize(2).unwrap(),
+            })
+            .collect()
+    }
+
+    pub fn run<G>(&self, graph: G, root: G::NodeId) -> DrawingEuclidean2d<G::NodeId, S>
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers,
+        G::NodeId: DrawingIndex + Copy,
+    {
+        let mut drawing = DrawingEuclidean2d::new(graph);
+        for node in self.layout(graph, root) {
+            let r = self.layer_distance * S::from_usize(node.depth).unwrap();
+            drawing.set_x(node.id, r * node.angle.cos());
+            drawing.set_y(node.id, r * node.angle.sin());
+        }
+        drawing
+    }
+
+    pub fn run_hyperbolic<G>(&self, graph: G, root: G::NodeId) -> DrawingHyperbolic2d<G::NodeId, S>
+    where
+        G: IntoNeighbors + IntoNodeIdentifiers,
+        G::NodeId: DrawingIndex + Copy,
+    {
+        let mut drawing = DrawingHyperbolic2d::new(graph);
+        for node in self.layout(graph, root) {
+            let d = self.hyperbolic_layer_distance * S::from_usize(node.depth).unwrap();
+            let r = (d / S::from_usize(2).unwrap()).tanh();
+            drawing.set_x(node.id, r * node.angle.cos());
+            drawing.set_y(node.id, r * node.angle.sin());
+        }
+        drawing
+    }
+}
+
+impl<S> Default for RadialTree<S>
+where
+    S: DrawingValue + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_radial_tree() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..7).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..7 {
+            graph.add_edge(nodes[(i - 1) / 2], nodes[i], ());
+        }
+        let radial_tree = RadialTree::<f32>::new();
+        let drawing = radial_tree.run(&graph, nodes[0]);
+        for (i, &u) in nodes.iter().enumerate() {
+            let depth = if i == 0 {
+                0.
+            } else if i < 3 {
+                1.
+            } else {
+                2.
+            };
+            let r = drawing.x(u).unwrap().hypot(drawing.y(u).unwrap());
+            assert!((r - 50. * depth).abs() < 1e-3);
+        }
+
+        let drawing = radial_tree.run_hyperbolic(&graph, nodes[0]);
+        for &u in &nodes {
+            assert!(drawing.x(u).unwrap().hypot(drawing.y(u).unwrap()) < 1.);
+        }
+    }
+}
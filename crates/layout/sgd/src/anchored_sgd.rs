@@ -0,0 +1,206 @@
+use crate::{Scheduler, Sgd};
+use petgraph_drawing::{Delta, Drawing, DrawingValue, Metric};
+use rand::prelude::*;
+use std::ops::Sub;
+
+pub struct AnchoredSgd<A, S, M>
+where
+    A: Sgd<S>,
+{
+    pub stability: S,
+    sgd: A,
+    anchors: Vec<Option<M>>,
+}
+
+impl<A, S, M> AnchoredSgd<A, S, M>
+where
+    A: Sgd<S>,
+    M: Clone,
+{
+    pub fn new(sgd: A, stability: S) -> AnchoredSgd<A, S, M> {
+        Self {
+            stability,
+            sgd,
+            anchors: vec![],
+        }
+    }
+
+    pub fn sgd(&self) -> &A {
+        &self.sgd
+    }
+
+    pub fn sgd_mut(&mut self) -> &mut A {
+        &mut self.sgd
+    }
+
+    pub fn set_anchor(&mut self, i: usize, position: M) {
+        if i >= self.anchors.len() {
+            self.anchors.resize(i + 1, None);
+        }
+        self.anchors[i] = Some(position);
+    }
+
+    pub fn clear_anchors(&mut self) {
+        self.anchors.clear();
+    }
+
+    pub fn anchor(&self, i: usize) -> Option<&M> {
+        self.anchors.get(i).and_then(|p| p.as_ref())
+    }
+
+    pub fn set_anchors_from_drawing<D>(&mut self, previous: &D, current: &D)
+    where
+        D: Drawing<Item = M>,
+        D::Index: Copy,
+        M: Metric,
+    {
+        self.anchors = (0..current.len())
+            .map(|i| previous.position(*current.node_id(i)).cloned())
+            .collect();
+    }
+
+    pub fn initialize<D>(&self, drawing: &mut D)
+    where
+        D: Drawing<Item = M>,
+        M: Metric,
+    {
+        for (i, p) in self.anchors.iter().enumerate() {
+            if let Some(p) = p {
+                *drawing.raw_entry_mut(i) = p.clone();
+            }
+        }
+    }
+
+    pub fn shuffle<R: Rng>(&mut self, rng: &mut R) {
+        self.sgd.shuffle(rng);
+    }
+
+    pub fn stress<Diff, D>(&self, drawing: &D) -> S
+    where
+        D: Drawing<Item = M>,
+        Diff: Delta<S = S>,
+        M: Metric<D = Diff>,
+        S: DrawingValue,
+    {
+        self.sgd.stress(drawing)
+    }
+
+    pub fn scheduler<SC>(&self, t_max: usize, epsilon: S) -> SC
+    where
+        SC: Scheduler<S>,
+        S: DrawingValue,
+    {
+        self.sgd.scheduler(t_max, epsilon)
+    }
+
+    pub fn apply<D, Diff>(&self, drawing: &mut D, eta: S)
+    where
+        D: Drawing<Item = M>,
+        Diff: Delta<S = S>,
+        M: Metric<D = Diff>,
+        for<'a> &'a M: Sub<&'a M, Output = Diff>,
+        S: DrawingValue,
+    {
+        self.sgd.apply(drawing, eta);
+        let mu = (eta * self.stability).min(S::one());
+        for (i, p) in self.anchors.iter().enumerate() {
+            if let Some(p) = p {
+                let delta = drawing.raw_entry(i) - p;
+                *drawing.raw_entry_mut(i) -= delta * mu;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FullSgd;
+    use petgraph::{graph::NodeIndex, Graph, Undirected};
+    use petgraph_drawing::{
+        DrawingEuclidean2d, DrawingHyperbolic2d, DrawingSpherical2d, MetricHyperbolic2d,
+        MetricSpherical2d,
+    };
+
+    #[test]
+    fn test_anchored_sgd() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..6 {
+            graph.add_edge(nodes[i - 1], nodes[i], ());
+        }
+        let mut previous = DrawingEuclidean2d::<NodeIndex, f32>::initial_placement(&graph);
+        let sgd = FullSgd::new(&graph, |_| 1.);
+        for t in 0..30 {
+            sgd.apply(&mut previous, 1. - t as f32 / 30.);
+        }
+
+        let u = graph.add_node(());
+        graph.add_edge(nodes[5], u, ());
+        graph.add_edge(nodes[0], u, ());
+        let displacement = |stability: f32| {
+            let mut current = DrawingEuclidean2d::<NodeIndex, f32>::initial_placement(&graph);
+            let mut sgd = AnchoredSgd::new(FullSgd::new(&graph, |_| 1.), stability);
+            sgd.set_anchors_from_drawing(&previous, &current);
+            sgd.initialize(&mut current);
+            for t in 0..30 {
+                sgd.apply(&mut current, 1. - t as f32 / 30.);
+            }
+            nodes
+                .iter()
+                .map(|&v| {
+                    let p = previous.position(v).unwrap();
+                    let q = current.position(v).unwrap();
+                    (p - q).norm()
+                })
+                .sum::<f32>()
+        };
+        assert!(displacement(10.) < displacement(0.));
+    }
+
+    fn check_anchors<D, M, Diff, F>(initial_placement: F, anchors: &[M])
+    where
+        D: Drawing<Item = M>,
+        F: Fn(&Graph<(), (), Undirected>) -> D,
+        Diff: Delta<S = f32>,
+        M: Metric<D = Diff> + Clone,
+        for<'a> &'a M: Sub<&'a M, Output = Diff>,
+    {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..anchors.len())
+            .map(|_| graph.add_node(()))
+            .collect::<Vec<_>>();
+        for i in 1..nodes.len() {
+            graph.add_edge(nodes[i - 1], nodes[i], ());
+        }
+        let mut drawing = initial_placement(&graph);
+        let mut sgd = AnchoredSgd::new(FullSgd::new(&graph, |_| 0.5), 1.);
+        for (i, p) in anchors.iter().enumerate() {
+            sgd.set_anchor(i, p.clone());
+        }
+        sgd.apply(&mut drawing, 1.);
+        for (i, p) in anchors.iter().enumerate() {
+            assert!((drawing.raw_entry(i) - p).norm() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_anchored_sgd_spherical() {
+        let anchors = [(0.3, 0.2), (1.2, -0.4), (-2.5, 0.9), (2.9, -1.1)]
+            .map(|(lon, lat)| MetricSpherical2d(lon, lat));
+        check_anchors(
+            |graph| DrawingSpherical2d::<NodeIndex, f32>::initial_placement(graph),
+            &anchors,
+        );
+    }
+
+    #[test]
+    fn test_anchored_sgd_hyperbolic() {
+        let anchors = [(0.1, 0.2), (-0.5, 0.3), (0.6, -0.4), (-0.2, -0.7)]
+            .map(|(x, y)| MetricHyperbolic2d(x, y));
+        check_anchors(
+            |graph| DrawingHyperbolic2d::<NodeIndex, f32>::initial_placement(graph),
+            &anchors,
+        );
+    }
+}
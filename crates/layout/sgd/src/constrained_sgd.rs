@@ -0,0 +1,199 @@
+use crate::{Scheduler, Sgd};
+use petgraph_drawing::{
+    Delta, Drawing, DrawingValue, Metric, MetricEuclidean, MetricEuclidean2d, MetricHyperbolic2d,
+    MetricSpherical2d, MetricTorus2d,
+};
+use rand::prelude::*;
+use std::marker::PhantomData;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Constraint {
+    FixedX,
+    FixedY,
+    FixedBoth,
+}
+
+pub trait ConstrainedMetric {
+    fn restore_axis(&mut self, previous: &Self, axis: usize);
+}
+
+macro_rules! impl_constrained_metric_2d {
+    ($metric:ident) => {
+        impl<S> ConstrainedMetric for $metric<S>
+        where
+            S: Copy,
+        {
+            fn restore_axis(&mut self, previous: &Self, axis: usize) {
+                if axis == 0 {
+                    self.0 = previous.0;
+                } else {
+                    self.1 = previous.1;
+                }
+            }
+        }
+    };
+}
+
+impl_constrained_metric_2d!(MetricEuclidean2d);
+impl_constrained_metric_2d!(MetricHyperbolic2d);
+impl_constrained_metric_2d!(MetricSpherical2d);
+impl_constrained_metric_2d!(MetricTorus2d);
+
+impl<S> ConstrainedMetric for MetricEuclidean<S>
+where
+    S: Copy,
+{
+    fn restore_axis(&mut self, previous: &Self, axis: usize) {
+        self.0[axis] = previous.0[axis];
+    }
+}
+
+pub struct ConstrainedSgd<A, S>
+where
+    A: Sgd<S>,
+{
+    sgd: A,
+    constraints: Vec<Option<Constraint>>,
+    phantom: PhantomData<S>,
+}
+
+impl<A, S> ConstrainedSgd<A, S>
+where
+    A: Sgd<S>,
+{
+    pub fn new(sgd: A) -> ConstrainedSgd<A, S> {
+        Self {
+            sgd,
+            constraints: vec![],
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn sgd(&self) -> &A {
+        &self.sgd
+    }
+
+    pub fn sgd_mut(&mut self) -> &mut A {
+        &mut self.sgd
+    }
+
+    pub fn set_fixed(&mut self, i: usize, constraint: Constraint) {
+        if i >= self.constraints.len() {
+            self.constraints.resize(i + 1, None);
+        }
+        self.constraints[i] = Some(constraint);
+    }
+
+    pub fn unset_fixed(&mut self, i: usize) {
+        if let Some(constraint) = self.constraints.get_mut(i) {
+            *constraint = None;
+        }
+    }
+
+    pub fn clear_fixed(&mut self) {
+        self.constraints.clear();
+    }
+
+    pub fn fixed(&self, i: usize) -> Option<Constraint> {
+        self.constraints.get(i).copied().flatten()
+    }
+
+    pub fn shuffle<R: Rng>(&mut self, rng: &mut R) {
+        self.sgd.shuffle(rng);
+    }
+
+    pub fn stress<Diff, D, M>(&self, drawing: &D) -> S
+    where
+        D: Drawing<Item = M>,
+        Diff: Delta<S = S>,
+        M: Metric<D = Diff>,
+        S: DrawingValue,
+    {
+        self.sgd.stress(drawing)
+    }
+
+    pub fn scheduler<SC>(&self, t_max: usize, epsilon: S) -> SC
+    where
+        SC: Scheduler<S>,
+        S: DrawingValue,
+    {
+        self.sgd.scheduler(t_max, epsilon)
+    }
+
+    fn move_node<D, M>(&self, drawing: &mut D, i: usize, delta: M::D)
+    where
+        D: Drawing<Item = M>,
+        M: Metric + Clone + ConstrainedMetric,
+    {
+        match self.fixed(i) {
+            None => *drawing.raw_entry_mut(i) += delta,
+            Some(Constraint::FixedBoth) => {}
+            Some(constraint) => {
+                let axis = if constraint == Constraint::FixedX {
+                    0
+                } else {
+                    1
+                };
+                let previous = drawing.raw_entry(i).clone();
+                let entry = drawing.raw_entry_mut(i);
+                *entry += delta;
+                entry.restore_axis(&previous, axis);
+            }
+        }
+    }
+
+    pub fn apply<D, Diff, M>(&self, drawing: &mut D, eta: S)
+    where
+        D: Drawing<Item = M>,
+        Diff: Delta<S = S>,
+        M: Metric<D = Diff> + Clone + ConstrainedMetric,
+        S: DrawingValue,
+    {
+        self.sgd.apply_with(drawing, eta, |drawing, i, delta| {
+            self.move_node(drawing, i, delta)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FullSgd;
+    use petgraph::{graph::NodeIndex, Graph};
+    use petgraph_drawing::DrawingEuclidean2d;
+
+    #[test]
+    fn test_constrained_sgd() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..5).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..5 {
+            graph.add_edge(nodes[i - 1], nodes[i], ());
+        }
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::initial_placement(&graph);
+        let mut sgd = ConstrainedSgd::new(FullSgd::new(&graph, |_| 1.));
+        sgd.set_fixed(0, Constraint::FixedBoth);
+        sgd.set_fixed(4, Constraint::FixedX);
+        let p0 = *drawing.raw_entry(0);
+        let p4 = *drawing.raw_entry(4);
+        for t in 0..30 {
+            sgd.apply(&mut drawing, 1. - t as f32 / 30.);
+        }
+        assert_eq!(drawing.raw_entry(0).0, p0.0);
+        assert_eq!(drawing.raw_entry(0).1, p0.1);
+        assert_eq!(drawing.raw_entry(4).0, p4.0);
+        assert_ne!(drawing.raw_entry(4).1, p4.1);
+
+        sgd.unset_fixed(0);
+        sgd.unset_fixed(10);
+        assert_eq!(sgd.fixed(0), None);
+        assert_eq!(sgd.fixed(4), Some(Constraint::FixedX));
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut scheduler = sgd.scheduler::<crate::SchedulerExponential<f32>>(30, 0.1);
+        scheduler.run(&mut |eta| {
+            sgd.shuffle(&mut rng);
+            sgd.apply(&mut drawing, eta);
+        });
+        assert_ne!(drawing.raw_entry(0).0, p0.0);
+        assert_eq!(drawing.raw_entry(4).0, p4.0);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Sgd;
+use crate::{sgd::wrapped_max_distance, Sgd};
 use petgraph::visit::{IntoEdges, IntoNodeIdentifiers};
 use petgraph_algorithm_shortest_path::{all_sources_dijkstra, DistanceMatrix, FullDistanceMatrix};
 use petgraph_drawing::{DrawingIndex, DrawingValue};
@@ -19,7 +19,29 @@ impl<S> FullSgd<S> {
         Self::new_with_distance_matrix(&d)
     }
 
+    /// Builds node pairs for a torus drawing with the given period.
+    /// Pairs farther apart than the largest wrapped distance cannot be
+    /// realized on the torus and are left out.
+    pub fn new_with_period<G, F>(graph: G, length: F, period: S) -> Self
+    where
+        G: IntoEdges + IntoNodeIdentifiers,
+        G::NodeId: DrawingIndex + Ord,
+        F: FnMut(G::EdgeRef) -> S,
+        S: DrawingValue,
+    {
+        let d = all_sources_dijkstra(graph, length);
+        Self::build(&d, wrapped_max_distance(period))
+    }
+
     pub fn new_with_distance_matrix<N>(d: &FullDistanceMatrix<N, S>) -> Self
+    where
+        N: DrawingIndex,
+        S: DrawingValue,
+    {
+        Self::build(d, S::infinity())
+    }
+
+    fn build<N>(d: &FullDistanceMatrix<N, S>, max_distance: S) -> Self
     where
         N: DrawingIndex,
         S: DrawingValue,
@@ -29,6 +51,9 @@ impl<S> FullSgd<S> {
         for j in 1..n {
             for i in 0..j {
                 let dij = d.get_by_index(i, j);
+                if dij > max_distance {
+                    continue;
+                }
                 let wij = S::one() / (dij * dij);
                 node_pairs.push((i, j, dij, dij, wij, wij));
             }
@@ -1,11 +1,42 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_drawing::{Drawing, DrawingEuclidean2d};
+//! use petgraph_layout_sgd::{Scheduler, SchedulerExponential, Sgd, SparseSgd};
+//! use rand::prelude::*;
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 1..4 {
+//!     graph.add_edge(nodes[i - 1], nodes[i], ());
+//! }
+//! let mut rng = StdRng::seed_from_u64(0);
+//! let mut drawing = DrawingEuclidean2d::<_, f32>::initial_placement(&graph);
+//! let mut sgd = SparseSgd::new_with_rng(&graph, |_| 1., 2, &mut rng);
+//! let mut scheduler = sgd.scheduler::<SchedulerExponential<f32>>(15, 0.1);
+//! scheduler.run(&mut |eta| {
+//!     sgd.shuffle(&mut rng);
+//!     sgd.apply(&mut drawing, eta);
+//! });
+//! assert_eq!(drawing.len(), 4);
+//! for &u in &nodes {
+//!     assert!(drawing.x(u).unwrap().is_finite() && drawing.y(u).unwrap().is_finite());
+//! }
+//! ```
+
+mod anchored_sgd;
+mod constrained_sgd;
 mod distance_adjusted_sgd;
 mod full_sgd;
 mod scheduler;
 mod sgd;
+mod simd;
 mod sparse_sgd;
 
+pub use anchored_sgd::AnchoredSgd;
+pub use constrained_sgd::{ConstrainedMetric, ConstrainedSgd, Constraint};
 pub use distance_adjusted_sgd::DistanceAdjustedSgd;
 pub use full_sgd::FullSgd;
 pub use scheduler::*;
 pub use sgd::Sgd;
+pub use simd::apply_euclidean_2d;
 pub use sparse_sgd::SparseSgd;
@@ -1,25 +1,78 @@
+use petgraph_drawing::DrawingValue;
+
 mod scheduler_constant;
+mod scheduler_cosine;
 mod scheduler_exponential;
 mod scheduler_linear;
 mod scheduler_quadratic;
 mod scheduler_reciprocal;
+mod scheduler_warm_restarts;
 
 pub trait Scheduler<S> {
-    fn init(t_max: usize, eta_min: S, eta_max: S) -> Self;
+    fn init(t_max: usize, eta_min: S, eta_max: S) -> Self
+    where
+        Self: Sized;
 
-    fn run<F: FnMut(S)>(&mut self, callback: &mut F) {
+    fn run(&mut self, callback: &mut dyn FnMut(S)) {
         while !self.is_finished() {
             self.step(callback)
         }
     }
 
-    fn step<F: FnMut(S)>(&mut self, callback: &mut F);
+    fn step(&mut self, callback: &mut dyn FnMut(S));
 
     fn is_finished(&self) -> bool;
 }
 
+pub fn scheduler_by_name<S>(
+    name: &str,
+    t_max: usize,
+    eta_min: S,
+    eta_max: S,
+) -> Option<Box<dyn Scheduler<S>>>
+where
+    S: DrawingValue + 'static,
+{
+    Some(match name {
+        "constant" => Box::new(SchedulerConstant::init(t_max, eta_min, eta_max)),
+        "linear" => Box::new(SchedulerLinear::init(t_max, eta_min, eta_max)),
+        "quadratic" => Box::new(SchedulerQuadratic::init(t_max, eta_min, eta_max)),
+        "exponential" => Box::new(SchedulerExponential::init(t_max, eta_min, eta_max)),
+        "reciprocal" => Box::new(SchedulerReciprocal::init(t_max, eta_min, eta_max)),
+        "cosine" => Box::new(SchedulerCosine::init(t_max, eta_min, eta_max)),
+        "warm-restarts" => Box::new(SchedulerWarmRestarts::init(t_max, eta_min, eta_max)),
+        _ => return None,
+    })
+}
+
 pub use scheduler_constant::SchedulerConstant;
+pub use scheduler_cosine::SchedulerCosine;
 pub use scheduler_exponential::SchedulerExponential;
 pub use scheduler_linear::SchedulerLinear;
 pub use scheduler_quadratic::SchedulerQuadratic;
 pub use scheduler_reciprocal::SchedulerReciprocal;
+pub use scheduler_warm_restarts::SchedulerWarmRestarts;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheduler_by_name() {
+        let mut scheduler = scheduler_by_name("cosine", 11, 0.1f32, 1.).unwrap();
+        let mut etas = vec![];
+        scheduler.run(&mut |eta| etas.push(eta));
+        assert_eq!(etas.len(), 11);
+        assert!((etas[0] - 1.).abs() < 1e-6);
+        assert!((etas[10] - 0.1).abs() < 1e-6);
+
+        let mut scheduler = SchedulerWarmRestarts::init(12, 0.1f32, 1.).with_cycles(4, 2);
+        let mut etas = vec![];
+        scheduler.run(&mut |eta| etas.push(eta));
+        assert!((etas[4] - 1.).abs() < 1e-6);
+        assert!(etas[3] < etas[4]);
+        assert!(etas[11] < etas[10]);
+
+        assert!(scheduler_by_name::<f32>("unknown", 10, 0.1, 1.).is_none());
+    }
+}
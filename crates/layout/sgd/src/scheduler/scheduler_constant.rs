@@ -30,7 +30,7 @@ where
         }
     }
 
-    fn step<F: FnMut(S)>(&mut self, callback: &mut F) {
+    fn step(&mut self, callback: &mut dyn FnMut(S)) {
         callback(S::one());
         self.t += 1;
     }
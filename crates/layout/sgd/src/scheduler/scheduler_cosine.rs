@@ -0,0 +1,53 @@
+use crate::{scheduler::Scheduler, Sgd};
+use petgraph_drawing::DrawingValue;
+
+pub struct SchedulerCosine<S> {
+    t: usize,
+    t_max: usize,
+    eta_min: S,
+    eta_max: S,
+}
+
+impl<S> SchedulerCosine<S> {
+    pub fn new<SGD>(sgd: SGD, t_max: usize, epsilon: S) -> Self
+    where
+        SGD: Sgd<S>,
+        S: DrawingValue,
+    {
+        sgd.scheduler(t_max, epsilon)
+    }
+}
+
+impl<S> Scheduler<S> for SchedulerCosine<S>
+where
+    S: DrawingValue,
+{
+    fn init(t_max: usize, eta_min: S, eta_max: S) -> Self {
+        Self {
+            t: 0,
+            t_max,
+            eta_min,
+            eta_max,
+        }
+    }
+
+    fn step(&mut self, callback: &mut dyn FnMut(S)) {
+        let one = S::one();
+        let two = one + one;
+        let r = if self.t_max > 1 {
+            S::from_usize(self.t).unwrap() / S::from_usize(self.t_max - 1).unwrap()
+        } else {
+            S::zero()
+        };
+        let eta = self.eta_min
+            + (self.eta_max - self.eta_min)
+                * (one + (S::from_f64(std::f64::consts::PI).unwrap() * r).cos())
+                / two;
+        callback(eta);
+        self.t += 1;
+    }
+
+    fn is_finished(&self) -> bool {
+        self.t >= self.t_max
+    }
+}
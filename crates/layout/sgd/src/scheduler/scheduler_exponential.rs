@@ -31,7 +31,7 @@ where
         }
     }
 
-    fn step<F: FnMut(S)>(&mut self, callback: &mut F) {
+    fn step(&mut self, callback: &mut dyn FnMut(S)) {
         let eta = self.a * (self.b * S::from_usize(self.t).unwrap()).exp();
         callback(eta);
         self.t += 1;
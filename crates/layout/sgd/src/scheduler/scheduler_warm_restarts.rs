@@ -0,0 +1,70 @@
+use crate::{scheduler::Scheduler, Sgd};
+use petgraph_drawing::DrawingValue;
+
+pub struct SchedulerWarmRestarts<S> {
+    t: usize,
+    t_max: usize,
+    t_cycle: usize,
+    cycle_length: usize,
+    cycle_multiplier: usize,
+    eta_min: S,
+    eta_max: S,
+}
+
+impl<S> SchedulerWarmRestarts<S> {
+    pub fn new<SGD>(sgd: SGD, t_max: usize, epsilon: S) -> Self
+    where
+        SGD: Sgd<S>,
+        S: DrawingValue,
+    {
+        sgd.scheduler(t_max, epsilon)
+    }
+
+    pub fn with_cycles(mut self, cycle_length: usize, cycle_multiplier: usize) -> Self {
+        self.cycle_length = cycle_length.max(1);
+        self.cycle_multiplier = cycle_multiplier.max(1);
+        self
+    }
+}
+
+impl<S> Scheduler<S> for SchedulerWarmRestarts<S>
+where
+    S: DrawingValue,
+{
+    fn init(t_max: usize, eta_min: S, eta_max: S) -> Self {
+        Self {
+            t: 0,
+            t_max,
+            t_cycle: 0,
+            cycle_length: t_max.div_ceil(3).max(1),
+            cycle_multiplier: 1,
+            eta_min,
+            eta_max,
+        }
+    }
+
+    fn step(&mut self, callback: &mut dyn FnMut(S)) {
+        let one = S::one();
+        let two = one + one;
+        let r = if self.cycle_length > 1 {
+            S::from_usize(self.t_cycle).unwrap() / S::from_usize(self.cycle_length - 1).unwrap()
+        } else {
+            S::zero()
+        };
+        let eta = self.eta_min
+            + (self.eta_max - self.eta_min)
+                * (one + (S::from_f64(std::f64::consts::PI).unwrap() * r).cos())
+                / two;
+        callback(eta);
+        self.t += 1;
+        self.t_cycle += 1;
+        if self.t_cycle >= self.cycle_length {
+            self.t_cycle = 0;
+            self.cycle_length *= self.cycle_multiplier;
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.t >= self.t_max
+    }
+}
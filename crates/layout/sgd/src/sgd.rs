@@ -1,6 +1,7 @@
-use crate::Scheduler;
+use crate::{scheduler_by_name, Scheduler};
 use petgraph_drawing::{Delta, Drawing, DrawingValue, Metric};
 use rand::prelude::*;
+use std::collections::HashSet;
 
 pub trait Sgd<S> {
     fn node_pairs(&self) -> &Vec<(usize, usize, S, S, S, S)>;
@@ -11,12 +12,42 @@ pub trait Sgd<S> {
         self.node_pairs_mut().shuffle(rng);
     }
 
+    fn retain_node_pairs<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize) -> bool,
+    {
+        self.node_pairs_mut().retain(|p| f(p.0, p.1));
+    }
+
+    fn remove_nodes(&mut self, nodes: &HashSet<usize>) {
+        self.retain_node_pairs(|i, j| !nodes.contains(&i) && !nodes.contains(&j));
+    }
+
+    fn retain_nodes(&mut self, nodes: &HashSet<usize>) {
+        self.retain_node_pairs(|i, j| nodes.contains(&i) && nodes.contains(&j));
+    }
+
     fn apply<Diff, D, M>(&self, drawing: &mut D, eta: S)
     where
         D: Drawing<Item = M>,
         Diff: Delta<S = S>,
         M: Metric<D = Diff>,
         S: DrawingValue,
+    {
+        self.apply_with(drawing, eta, |drawing, i, delta| {
+            *drawing.raw_entry_mut(i) += delta;
+        });
+    }
+
+    /// Same as [`Sgd::apply`], but each node update is handed to `move_node`
+    /// instead of being added to the drawing.
+    fn apply_with<Diff, D, M, F>(&self, drawing: &mut D, eta: S, mut move_node: F)
+    where
+        D: Drawing<Item = M>,
+        Diff: Delta<S = S>,
+        M: Metric<D = Diff>,
+        S: DrawingValue,
+        F: FnMut(&mut D, usize, Diff),
     {
         for &(i, j, dij, dji, wij, wji) in self.node_pairs().iter() {
             let mu_i = (eta * wij).min(S::one());
@@ -26,37 +57,85 @@ pub trait Sgd<S> {
             if norm > S::zero() {
                 let r_i = S::from_f32(0.5).unwrap() * (norm - dij) / norm;
                 let r_j = S::from_f32(0.5).unwrap() * (norm - dji) / norm;
-                *drawing.raw_entry_mut(i) += delta.clone() * -r_i * mu_i;
-                *drawing.raw_entry_mut(j) += delta.clone() * r_j * mu_j;
+                move_node(drawing, i, delta.clone() * -r_i * mu_i);
+                move_node(drawing, j, delta * r_j * mu_j);
             }
         }
     }
 
-    fn scheduler<SC>(&self, t_max: usize, epsilon: S) -> SC
+    fn stress<Diff, D, M>(&self, drawing: &D) -> S
     where
-        SC: Scheduler<S>,
+        D: Drawing<Item = M>,
+        Diff: Delta<S = S>,
+        M: Metric<D = Diff>,
         S: DrawingValue,
     {
-        let mut w_min = S::infinity();
-        let mut w_max = S::zero();
-        for &(_, _, _, _, wij, wji) in self.node_pairs().iter() {
-            for w in [wij, wji] {
-                if w == S::zero() {
-                    continue;
-                }
-                if w < w_min {
-                    w_min = w;
-                }
-                if w > w_max {
-                    w_max = w;
+        let mut s = S::zero();
+        for &(i, j, dij, dji, wij, wji) in self.node_pairs().iter() {
+            let norm = drawing.delta(i, j).norm();
+            s += wij * (norm - dij).powi(2) + wji * (norm - dji).powi(2);
+        }
+        s / S::from_usize(2).unwrap()
+    }
+
+    fn run_until_converged<Diff, D, M, R>(
+        &mut self,
+        drawing: &mut D,
+        scheduler: &mut dyn Scheduler<S>,
+        rng: &mut R,
+        interval: usize,
+        tolerance: S,
+    ) -> usize
+    where
+        D: Drawing<Item = M>,
+        Diff: Delta<S = S>,
+        M: Metric<D = Diff>,
+        R: Rng,
+        S: DrawingValue,
+    {
+        let interval = interval.max(1);
+        let mut stress = self.stress(drawing);
+        let mut iterations = 0;
+        while !scheduler.is_finished() {
+            scheduler.step(&mut |eta| {
+                self.shuffle(rng);
+                self.apply(drawing, eta);
+            });
+            iterations += 1;
+            if iterations % interval == 0 {
+                let new_stress = self.stress(drawing);
+                let converged = (stress - new_stress).abs() <= tolerance * stress;
+                stress = new_stress;
+                if converged {
+                    break;
                 }
             }
         }
-        let eta_max = S::one() / w_min;
-        let eta_min = epsilon / w_max;
+        iterations
+    }
+
+    fn scheduler<SC>(&self, t_max: usize, epsilon: S) -> SC
+    where
+        SC: Scheduler<S>,
+        S: DrawingValue,
+    {
+        let (eta_min, eta_max) = learning_rate_range(self.node_pairs(), epsilon);
         SC::init(t_max, eta_min, eta_max)
     }
 
+    fn scheduler_by_name(
+        &self,
+        name: &str,
+        t_max: usize,
+        epsilon: S,
+    ) -> Option<Box<dyn Scheduler<S>>>
+    where
+        S: DrawingValue + 'static,
+    {
+        let (eta_min, eta_max) = learning_rate_range(self.node_pairs(), epsilon);
+        scheduler_by_name(name, t_max, eta_min, eta_max)
+    }
+
     fn update_distance<F>(&mut self, mut distance: F)
     where
         F: FnMut(usize, usize, S, S) -> S,
@@ -69,6 +148,21 @@ pub trait Sgd<S> {
         }
     }
 
+    fn wrap_distance(&mut self, period: S)
+    where
+        S: DrawingValue,
+    {
+        let max_distance = wrapped_max_distance(period);
+        for p in self.node_pairs_mut() {
+            for (d, w) in [(&mut p.2, &mut p.4), (&mut p.3, &mut p.5)] {
+                if *d > max_distance {
+                    *w *= (*d / max_distance).powi(2);
+                    *d = max_distance;
+                }
+            }
+        }
+    }
+
     fn update_weight<F>(&mut self, mut weight: F)
     where
         F: FnMut(usize, usize, S, S) -> S,
@@ -81,3 +175,188 @@ pub trait Sgd<S> {
         }
     }
 }
+
+/// Largest distance between two points on a square torus with the given period.
+pub(crate) fn wrapped_max_distance<S>(period: S) -> S
+where
+    S: DrawingValue,
+{
+    assert!(period > S::zero(), "period must be positive");
+    S::from_f32(std::f32::consts::FRAC_1_SQRT_2).unwrap() * period
+}
+
+fn learning_rate_range<S>(node_pairs: &[(usize, usize, S, S, S, S)], epsilon: S) -> (S, S)
+where
+    S: DrawingValue,
+{
+    let mut w_min = S::infinity();
+    let mut w_max = S::zero();
+    for &(_, _, _, _, wij, wji) in node_pairs.iter() {
+        for w in [wij, wji] {
+            if w == S::zero() {
+                continue;
+            }
+            if w < w_min {
+                w_min = w;
+            }
+            if w > w_max {
+                w_max = w;
+            }
+        }
+    }
+    (epsilon / w_max, S::one() / w_min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FullSgd, SchedulerExponential, SparseSgd};
+    use petgraph::{graph::NodeIndex, Graph};
+    use petgraph_drawing::{DrawingEuclidean2d, DrawingTorus2d};
+
+    #[test]
+    fn test_run_until_converged() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..10).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..10 {
+            graph.add_edge(nodes[i], nodes[(i + 1) % 10], ());
+        }
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::initial_placement(&graph);
+        let mut sgd = FullSgd::new(&graph, |_| 1.);
+        let initial = sgd.stress(&drawing);
+        let mut scheduler = sgd.scheduler::<SchedulerExponential<f32>>(1000, 0.1);
+        let mut rng = StdRng::seed_from_u64(0);
+        let iterations = sgd.run_until_converged(&mut drawing, &mut scheduler, &mut rng, 5, 1e-3);
+        assert!(iterations < 1000);
+        assert!(sgd.stress(&drawing) < initial);
+    }
+
+    #[test]
+    fn test_retain_node_pairs() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..6 {
+            graph.add_edge(nodes[i - 1], nodes[i], ());
+        }
+        let mut sgd = FullSgd::new(&graph, |_| 1f32);
+        assert_eq!(sgd.node_pairs().len(), 15);
+        sgd.retain_node_pairs(|i, j| i + j != 5);
+        assert_eq!(sgd.node_pairs().len(), 12);
+        assert!(sgd.node_pairs().iter().all(|&(i, j, ..)| i + j != 5));
+
+        let mut sgd = FullSgd::new(&graph, |_| 1f32);
+        sgd.remove_nodes(&HashSet::from([0, 3]));
+        assert_eq!(sgd.node_pairs().len(), 6);
+        for &(i, j, ..) in sgd.node_pairs() {
+            assert!(![0, 3].contains(&i) && ![0, 3].contains(&j));
+        }
+
+        let mut sgd = FullSgd::new(&graph, |_| 1f32);
+        sgd.retain_nodes(&HashSet::from([1, 2, 4]));
+        assert_eq!(sgd.node_pairs().len(), 3);
+        for &(i, j, ..) in sgd.node_pairs() {
+            assert!([1, 2, 4].contains(&i) && [1, 2, 4].contains(&j));
+        }
+
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::initial_placement(&graph);
+        let fixed = [0, 3, 5].map(|i| (drawing.raw_entry(i).0, drawing.raw_entry(i).1));
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..10 {
+            sgd.shuffle(&mut rng);
+            sgd.apply(&mut drawing, 0.1);
+        }
+        for (k, i) in [0, 3, 5].into_iter().enumerate() {
+            assert_eq!((drawing.raw_entry(i).0, drawing.raw_entry(i).1), fixed[k]);
+        }
+    }
+
+    #[test]
+    fn test_wrap_distance() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..10).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..10 {
+            graph.add_edge(nodes[i], nodes[(i + 1) % 10], ());
+        }
+        let max_distance = std::f32::consts::FRAC_1_SQRT_2;
+        let mut sgd = FullSgd::new(&graph, |_| 0.25f32);
+        sgd.wrap_distance(1.);
+        for &(i, j, dij, dji, wij, wji) in sgd.node_pairs().iter() {
+            let k = (j - i).min(10 - (j - i));
+            let expected = (0.25 * k as f32).min(max_distance);
+            assert!((dij - expected).abs() < 1e-6);
+            assert!((dji - expected).abs() < 1e-6);
+            assert!((wij - 1. / (expected * expected)).abs() < 1e-3);
+            assert!((wji - 1. / (expected * expected)).abs() < 1e-3);
+        }
+
+        let mut sgd = FullSgd::new(&graph, |_| 0.1f32);
+        sgd.wrap_distance(1.);
+        let mut drawing = DrawingTorus2d::<NodeIndex, f32>::initial_placement(&graph);
+        let mut rng = StdRng::seed_from_u64(0);
+        for t in 0..100 {
+            sgd.shuffle(&mut rng);
+            sgd.apply(&mut drawing, 0.1 * (1. - t as f32 / 100.));
+        }
+        for i in 0..10 {
+            assert!((drawing.delta(i, (i + 1) % 10).norm() - 0.1).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_wrapped_node_pairs() {
+        let n = 20;
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..n {
+            graph.add_edge(nodes[i], nodes[(i + 1) % n], ());
+        }
+        let max_distance = std::f32::consts::FRAC_1_SQRT_2;
+        let reference = FullSgd::new_with_period(&graph, |_| 0.1f32, 1.);
+        assert_eq!(reference.node_pairs().len(), 7 * n);
+        assert!(reference.node_pairs().iter().all(|p| p.2 <= max_distance));
+
+        let layout_stress = |sgd: &mut dyn FnMut(&mut DrawingTorus2d<NodeIndex, f32>, f32)| {
+            let mut drawing = DrawingTorus2d::<NodeIndex, f32>::initial_placement(&graph);
+            let mut scheduler = reference.scheduler::<SchedulerExponential<f32>>(100, 0.1);
+            scheduler.run(&mut |eta| sgd(&mut drawing, eta));
+            reference.stress(&drawing)
+        };
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let mut wrapped = FullSgd::new_with_period(&graph, |_| 0.1f32, 1.);
+        let mut plain = FullSgd::new(&graph, |_| 0.1f32);
+        let wrapped_stress = layout_stress(&mut |drawing, eta| {
+            wrapped.shuffle(&mut rng);
+            wrapped.apply(drawing, eta);
+        });
+        let plain_stress = layout_stress(&mut |drawing, eta| {
+            plain.shuffle(&mut rng);
+            plain.apply(drawing, eta);
+        });
+        assert!(wrapped_stress < plain_stress);
+
+        let mut wrapped = SparseSgd::new_with_period_and_rng(&graph, |_| 0.1f32, 4, 1., &mut rng);
+        assert!(wrapped.node_pairs().iter().all(|p| p.2 <= max_distance));
+        let mut plain = SparseSgd::new_with_rng(&graph, |_| 0.1f32, 4, &mut rng);
+        let wrapped_stress = layout_stress(&mut |drawing, eta| {
+            wrapped.shuffle(&mut rng);
+            wrapped.apply(drawing, eta);
+        });
+        let plain_stress = layout_stress(&mut |drawing, eta| {
+            plain.shuffle(&mut rng);
+            plain.apply(drawing, eta);
+        });
+        assert!(wrapped_stress < plain_stress);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wrap_distance_non_positive_period() {
+        let mut graph = Graph::new_undirected();
+        let u = graph.add_node(());
+        let v = graph.add_node(());
+        graph.add_edge(u, v, ());
+        let mut sgd = FullSgd::new(&graph, |_| 1f32);
+        sgd.wrap_distance(0.);
+    }
+}
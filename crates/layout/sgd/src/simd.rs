@@ -0,0 +1,122 @@
+use crate::Sgd;
+#[cfg(feature = "simd")]
+use petgraph_drawing::{Drawing, MetricEuclidean2d};
+use petgraph_drawing::{DrawingEuclidean2d, DrawingIndex};
+#[cfg(feature = "simd")]
+use wide::{f32x4, CmpGt};
+
+/// Same as [`Sgd::apply`] on a 2D Euclidean drawing.
+#[cfg(not(feature = "simd"))]
+pub fn apply_euclidean_2d<A, N>(sgd: &A, drawing: &mut DrawingEuclidean2d<N, f32>, eta: f32)
+where
+    A: Sgd<f32>,
+    N: DrawingIndex,
+{
+    sgd.apply(drawing, eta);
+}
+
+/// Same as [`Sgd::apply`] on a 2D Euclidean drawing, updating up to four node
+/// pairs at a time. A batch only grows while its pairs touch distinct nodes,
+/// so the updates follow the order of the scalar version. On wasm32 this
+/// compiles to simd128 instructions when the target feature is enabled.
+#[cfg(feature = "simd")]
+pub fn apply_euclidean_2d<A, N>(sgd: &A, drawing: &mut DrawingEuclidean2d<N, f32>, eta: f32)
+where
+    A: Sgd<f32>,
+    N: DrawingIndex,
+{
+    let node_pairs = sgd.node_pairs();
+    let mut start = 0;
+    while start < node_pairs.len() {
+        let mut nodes = vec![];
+        let mut end = start;
+        while end < node_pairs.len() && end - start < 4 {
+            let (i, j, ..) = node_pairs[end];
+            if nodes.contains(&i) || nodes.contains(&j) {
+                break;
+            }
+            nodes.push(i);
+            nodes.push(j);
+            end += 1;
+        }
+        apply_batch(&node_pairs[start..end], drawing, eta);
+        start = end;
+    }
+}
+
+#[cfg(feature = "simd")]
+fn apply_batch<N>(
+    node_pairs: &[(usize, usize, f32, f32, f32, f32)],
+    drawing: &mut DrawingEuclidean2d<N, f32>,
+    eta: f32,
+) where
+    N: DrawingIndex,
+{
+    // Unused lanes have coincident endpoints and are masked out below.
+    let mut lanes = [[0.; 8]; 4];
+    for (k, &(i, j, dij, dji, wij, wji)) in node_pairs.iter().enumerate() {
+        let MetricEuclidean2d(xi, yi) = *drawing.raw_entry(i);
+        let MetricEuclidean2d(xj, yj) = *drawing.raw_entry(j);
+        lanes[k] = [xi, yi, xj, yj, dij, dji, wij, wji];
+    }
+    let lane = |l: usize| f32x4::from([lanes[0][l], lanes[1][l], lanes[2][l], lanes[3][l]]);
+    let eta = f32x4::splat(eta);
+    let half = f32x4::splat(0.5);
+    let mu_i = (eta * lane(6)).min(f32x4::ONE);
+    let mu_j = (eta * lane(7)).min(f32x4::ONE);
+    let dx = lane(0) - lane(2);
+    let dy = lane(1) - lane(3);
+    let norm = (dx * dx + dy * dy).sqrt();
+    let mask = norm.cmp_gt(f32x4::ZERO);
+    let r_i = mask.blend(half * (norm - lane(4)) / norm, f32x4::ZERO);
+    let r_j = mask.blend(half * (norm - lane(5)) / norm, f32x4::ZERO);
+    let dxi = (dx * -r_i * mu_i).to_array();
+    let dyi = (dy * -r_i * mu_i).to_array();
+    let dxj = (dx * r_j * mu_j).to_array();
+    let dyj = (dy * r_j * mu_j).to_array();
+    for (k, &(i, j, ..)) in node_pairs.iter().enumerate() {
+        let p = drawing.raw_entry_mut(i);
+        p.0 += dxi[k];
+        p.1 += dyi[k];
+        let p = drawing.raw_entry_mut(j);
+        p.0 += dxj[k];
+        p.1 += dyj[k];
+    }
+}
+
+#[cfg(all(test, feature = "simd"))]
+mod tests {
+    use super::*;
+    use crate::{FullSgd, SparseSgd};
+    use petgraph::{graph::NodeIndex, Graph};
+    use petgraph_drawing::Drawing;
+    use rand::prelude::*;
+
+    #[test]
+    fn test_apply_euclidean_2d_matches_scalar() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..30).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..30 {
+            graph.add_edge(nodes[(i - 1) / 2], nodes[i], ());
+            graph.add_edge(nodes[i - 1], nodes[i], ());
+        }
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut full = FullSgd::new(&graph, |_| 1.);
+        let mut sparse = SparseSgd::new_with_rng(&graph, |_| 1., 5, &mut rng);
+        let mut scalar = DrawingEuclidean2d::<NodeIndex, f32>::initial_placement(&graph);
+        let mut simd = DrawingEuclidean2d::<NodeIndex, f32>::initial_placement(&graph);
+        for t in 0..30 {
+            let eta = 1. / (t + 1) as f32;
+            full.shuffle(&mut rng);
+            full.apply(&mut scalar, eta);
+            apply_euclidean_2d(&full, &mut simd, eta);
+            sparse.shuffle(&mut rng);
+            sparse.apply(&mut scalar, eta);
+            apply_euclidean_2d(&sparse, &mut simd, eta);
+        }
+        for i in 0..scalar.len() {
+            let (p, q) = (scalar.raw_entry(i), simd.raw_entry(i));
+            assert!((p.0 - q.0).abs() < 1e-3 && (p.1 - q.1).abs() < 1e-3);
+        }
+    }
+}
@@ -1,9 +1,10 @@
-use crate::Sgd;
+use crate::{sgd::wrapped_max_distance, Sgd};
 use ndarray::prelude::*;
 use ordered_float::OrderedFloat;
 use petgraph::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeCount, NodeIndexable};
 use petgraph_algorithm_shortest_path::{
-    dijkstra_with_distance_matrix, multi_source_dijkstra, DistanceMatrix, SubDistanceMatrix,
+    dijkstra, dijkstra_with_distance_matrix, multi_source_dijkstra, DistanceMatrix,
+    LandmarkDistanceMatrix, SubDistanceMatrix,
 };
 use petgraph_drawing::{DrawingIndex, DrawingValue};
 use rand::prelude::*;
@@ -11,6 +12,11 @@ use std::collections::{HashMap, HashSet};
 
 pub struct SparseSgd<S> {
     node_pairs: Vec<(usize, usize, S, S, S, S)>,
+    pivot: Vec<usize>,
+    distance: Array2<S>,
+    edge_length: HashMap<(usize, usize), S>,
+    removed: Vec<bool>,
+    max_distance: S,
 }
 
 impl<S> SparseSgd<S> {
@@ -26,6 +32,43 @@ impl<S> SparseSgd<S> {
     }
 
     pub fn new_with_rng<G, F, R>(graph: G, length: F, h: usize, rng: &mut R) -> Self
+    where
+        G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+        G::NodeId: DrawingIndex + Ord,
+        F: FnMut(G::EdgeRef) -> S,
+        R: Rng,
+        S: DrawingValue,
+    {
+        Self::new_with_max_distance(graph, length, h, S::infinity(), rng)
+    }
+
+    /// Builds node pairs for a torus drawing with the given period.
+    /// Pivots are sampled by their wrapped distance, and pivot pairs farther
+    /// apart than the largest wrapped distance are left out.
+    pub fn new_with_period_and_rng<G, F, R>(
+        graph: G,
+        length: F,
+        h: usize,
+        period: S,
+        rng: &mut R,
+    ) -> Self
+    where
+        G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+        G::NodeId: DrawingIndex + Ord,
+        F: FnMut(G::EdgeRef) -> S,
+        R: Rng,
+        S: DrawingValue,
+    {
+        Self::new_with_max_distance(graph, length, h, wrapped_max_distance(period), rng)
+    }
+
+    fn new_with_max_distance<G, F, R>(
+        graph: G,
+        length: F,
+        h: usize,
+        max_distance: S,
+        rng: &mut R,
+    ) -> Self
     where
         G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + NodeCount,
         G::NodeId: DrawingIndex + Ord,
@@ -36,8 +79,8 @@ impl<S> SparseSgd<S> {
         let mut length = length;
         let n = graph.node_count();
         let h = h.min(n);
-        let (pivot, d) = Self::choose_pivot(graph, &mut length, h, rng);
-        Self::new_with_pivot_and_distance_matrix(graph, length, &pivot, &d)
+        let (pivot, d) = max_min_random_sp(graph, &mut length, h, max_distance, rng);
+        Self::build(graph, length, &pivot, &d, max_distance)
     }
 
     pub fn new_with_pivot<G, F>(graph: G, mut length: F, pivot: &[G::NodeId]) -> Self
@@ -52,10 +95,27 @@ impl<S> SparseSgd<S> {
     }
 
     pub fn new_with_pivot_and_distance_matrix<G, F, D>(
+        graph: G,
+        length: F,
+        pivot: &[G::NodeId],
+        distance_matrix: &D,
+    ) -> Self
+    where
+        G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+        G::NodeId: DrawingIndex + Ord,
+        F: FnMut(G::EdgeRef) -> S,
+        D: DistanceMatrix<G::NodeId, S>,
+        S: DrawingValue,
+    {
+        Self::build(graph, length, pivot, distance_matrix, S::infinity())
+    }
+
+    fn build<G, F, D>(
         graph: G,
         mut length: F,
         pivot: &[G::NodeId],
         distance_matrix: &D,
+        max_distance: S,
     ) -> Self
     where
         G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
@@ -71,11 +131,170 @@ impl<S> SparseSgd<S> {
             .collect::<HashMap<_, _>>();
         let n = indices.len();
         let h = pivot.len();
+        let pivot = pivot.iter().map(|u| indices[u]).collect::<Vec<_>>();
+        let mut distance = Array2::from_elem((h, n), S::infinity());
+        for k in 0..h {
+            for i in 0..n {
+                distance[[k, i]] = distance_matrix.get_by_index(k, i);
+            }
+        }
+        let removed = vec![false; n];
+        let node_pairs = Self::build_node_pairs(
+            graph,
+            &mut length,
+            &indices,
+            &pivot,
+            &distance,
+            &removed,
+            max_distance,
+        );
+        SparseSgd {
+            node_pairs,
+            pivot,
+            distance,
+            edge_length: Self::edge_length(graph, &mut length, &indices),
+            removed,
+            max_distance,
+        }
+    }
+
+    /// Uses the landmarks of `distance_matrix` as pivots, reusing its rows
+    /// instead of running Dijkstra again.
+    pub fn new_with_landmark_distance_matrix<G, F>(
+        graph: G,
+        length: F,
+        distance_matrix: &LandmarkDistanceMatrix<G::NodeId, S>,
+    ) -> Self
+    where
+        G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+        G::NodeId: DrawingIndex + Ord,
+        F: FnMut(G::EdgeRef) -> S,
+        S: DrawingValue,
+    {
+        let landmarks = distance_matrix.landmarks();
+        let pivot = landmarks.row_indices().collect::<Vec<_>>();
+        Self::new_with_pivot_and_distance_matrix(graph, length, &pivot, landmarks)
+    }
+
+    /// Refreshes the node pairs after the edges in `changed_edges` were
+    /// added, removed or changed length in `graph`. Only the pivot rows whose
+    /// shortest paths can be affected by a change are recomputed. `graph`
+    /// must have the same nodes as the one this instance was built from.
+    pub fn update_edges<G, F>(
+        &mut self,
+        graph: G,
+        mut length: F,
+        changed_edges: &[(G::NodeId, G::NodeId)],
+    ) where
+        G: IntoEdges + IntoNodeIdentifiers + NodeIndexable,
+        G::NodeId: DrawingIndex + Ord,
+        F: FnMut(G::EdgeRef) -> S,
+        S: DrawingValue,
+    {
+        let nodes = graph.node_identifiers().collect::<Vec<_>>();
+        let indices = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i))
+            .collect::<HashMap<_, _>>();
+        let edge_length = Self::edge_length(graph, &mut length, &indices);
+        let changed_edges = changed_edges
+            .iter()
+            .map(|&(u, v)| {
+                let (i, j) = (indices[&u], indices[&v]);
+                let key = (i.min(j), i.max(j));
+                (
+                    i,
+                    j,
+                    self.edge_length.get(&key).copied(),
+                    edge_length.get(&key).copied(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let tolerance = S::from_f32(1e-5).unwrap();
+        for k in 0..self.pivot.len() {
+            if self.removed[self.pivot[k]] {
+                continue;
+            }
+            let affected = changed_edges.iter().any(|&(i, j, old, new)| {
+                let dki = self.distance[[k, i]];
+                let dkj = self.distance[[k, j]];
+                let shortened = new.is_some_and(|l| dki + l < dkj || dkj + l < dki);
+                let lengthened = match (old, new) {
+                    (Some(old), new) if new.map_or(true, |l| l > old) => {
+                        (dki - dkj).abs() >= old * (S::one() - tolerance)
+                    }
+                    _ => false,
+                };
+                shortened || lengthened
+            });
+            if affected {
+                let d = dijkstra(graph, &mut length, nodes[self.pivot[k]]);
+                for i in 0..nodes.len() {
+                    self.distance[[k, i]] = d.get_by_index(0, i);
+                }
+            }
+        }
+        self.edge_length = edge_length;
+        self.node_pairs = Self::build_node_pairs(
+            graph,
+            &mut length,
+            &indices,
+            &self.pivot,
+            &self.distance,
+            &self.removed,
+            self.max_distance,
+        );
+    }
+
+    fn edge_length<G, F>(
+        graph: G,
+        mut length: F,
+        indices: &HashMap<G::NodeId, usize>,
+    ) -> HashMap<(usize, usize), S>
+    where
+        G: IntoEdges,
+        G::NodeId: DrawingIndex,
+        F: FnMut(G::EdgeRef) -> S,
+        S: DrawingValue,
+    {
+        let mut edge_length = HashMap::<_, S>::new();
+        for edge in graph.edge_references() {
+            let i = indices[&edge.source()];
+            let j = indices[&edge.target()];
+            let l = length(edge);
+            edge_length
+                .entry((i.min(j), i.max(j)))
+                .and_modify(|m| *m = m.min(l))
+                .or_insert(l);
+        }
+        edge_length
+    }
+
+    fn build_node_pairs<G, F>(
+        graph: G,
+        mut length: F,
+        indices: &HashMap<G::NodeId, usize>,
+        pivot: &[usize],
+        distance: &Array2<S>,
+        removed: &[bool],
+        max_distance: S,
+    ) -> Vec<(usize, usize, S, S, S, S)>
+    where
+        G: IntoEdges,
+        G::NodeId: DrawingIndex,
+        F: FnMut(G::EdgeRef) -> S,
+        S: DrawingValue,
+    {
+        let (h, n) = distance.dim();
         let mut node_pairs = vec![];
         let mut edges = HashSet::new();
         for edge in graph.edge_references() {
             let i = indices[&edge.source()];
             let j = indices[&edge.target()];
+            if removed[i] || removed[j] {
+                continue;
+            }
             let dij = length(edge);
             let wij = S::one() / (dij * dij);
             node_pairs.push((i, j, dij, dij, wij, wij));
@@ -83,39 +302,42 @@ impl<S> SparseSgd<S> {
             edges.insert((j, i));
         }
 
-        let r = (0..n)
-            .map(|j| {
-                (0..h)
-                    .min_by_key(|&i| OrderedFloat(distance_matrix.get_by_index(i, j)))
-                    .unwrap()
-            })
-            .collect::<Vec<_>>();
+        let active = (0..h).filter(|&k| !removed[pivot[k]]).collect::<Vec<_>>();
+        if active.is_empty() {
+            return node_pairs;
+        }
         let mut r_nodes = vec![vec![]; h];
-        for j in 0..n {
-            r_nodes[r[j]].push(j);
+        for j in (0..n).filter(|&j| !removed[j]) {
+            let r = active
+                .iter()
+                .copied()
+                .min_by_key(|&k| OrderedFloat(distance[[k, j]]))
+                .unwrap();
+            r_nodes[r].push(j);
         }
 
-        for (k, &u) in pivot.iter().enumerate() {
-            let p = indices[&u];
+        for &k in active.iter() {
+            let p = pivot[k];
             for i in 0..n {
-                if edges.contains(&(p, i)) || p == i {
+                if edges.contains(&(p, i)) || p == i || removed[i] {
+                    continue;
+                }
+                let dpi = distance[[k, i]];
+                if dpi > max_distance {
                     continue;
                 }
-                let dpi = distance_matrix.get_by_index(k, i);
                 let wpi = S::one() / (dpi * dpi);
                 let spi = S::from_usize(
                     r_nodes[k]
                         .iter()
-                        .filter(|&&j| {
-                            S::from_usize(2).unwrap() * distance_matrix.get_by_index(k, j) <= dpi
-                        })
+                        .filter(|&&j| S::from_usize(2).unwrap() * distance[[k, j]] <= dpi)
                         .count(),
                 )
                 .unwrap();
                 node_pairs.push((p, i, dpi, dpi, spi * wpi, S::zero()));
             }
         }
-        SparseSgd { node_pairs }
+        node_pairs
     }
 
     pub fn choose_pivot<G, F, R>(
@@ -131,7 +353,7 @@ impl<S> SparseSgd<S> {
         R: Rng,
         S: DrawingValue,
     {
-        max_min_random_sp(graph, length, h, rng)
+        max_min_random_sp(graph, length, h, S::infinity(), rng)
     }
 }
 
@@ -143,12 +365,29 @@ impl<S> Sgd<S> for SparseSgd<S> {
     fn node_pairs_mut(&mut self) -> &mut Vec<(usize, usize, S, S, S, S)> {
         &mut self.node_pairs
     }
+
+    fn remove_nodes(&mut self, nodes: &HashSet<usize>) {
+        for &i in nodes.iter() {
+            if let Some(r) = self.removed.get_mut(i) {
+                *r = true;
+            }
+        }
+        self.retain_node_pairs(|i, j| !nodes.contains(&i) && !nodes.contains(&j));
+    }
+
+    fn retain_nodes(&mut self, nodes: &HashSet<usize>) {
+        for (i, r) in self.removed.iter_mut().enumerate() {
+            *r = *r || !nodes.contains(&i);
+        }
+        self.retain_node_pairs(|i, j| nodes.contains(&i) && nodes.contains(&j));
+    }
 }
 
 fn max_min_random_sp<G, F, R, S>(
     graph: G,
     length: F,
     h: usize,
+    max_distance: S,
     rng: &mut R,
 ) -> (Vec<G::NodeId>, SubDistanceMatrix<G::NodeId, S>)
 where
@@ -174,7 +413,9 @@ where
     let mut min_d = Array1::from_elem(n, S::infinity());
     for k in 1..h {
         for j in 0..n {
-            min_d[j] = min_d[j].min(distance_matrix.get_by_index(k - 1, j));
+            min_d[j] = min_d[j]
+                .min(distance_matrix.get_by_index(k - 1, j))
+                .min(max_distance);
         }
         pivot.push(nodes[proportional_sampling(&min_d, rng)]);
         distance_matrix.push(pivot[k]);
@@ -206,3 +447,158 @@ where
     }
     panic!("unreachable");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_update_edges() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..20).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..20 {
+            graph.add_edge(nodes[i - 1], nodes[i], ());
+        }
+        let pivot = [nodes[0], nodes[7], nodes[15]];
+        let sorted = |sgd: &SparseSgd<f32>| {
+            let mut node_pairs = sgd.node_pairs().clone();
+            node_pairs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            node_pairs
+        };
+
+        let mut sgd = SparseSgd::new_with_pivot(&graph, |_| 1., &pivot);
+        graph.add_edge(nodes[2], nodes[12], ());
+        sgd.update_edges(&graph, |_| 1., &[(nodes[2], nodes[12])]);
+        let expected = SparseSgd::new_with_pivot(&graph, |_| 1., &pivot);
+        assert_eq!(sorted(&sgd), sorted(&expected));
+
+        let e = graph.find_edge(nodes[2], nodes[12]).unwrap();
+        graph.remove_edge(e);
+        sgd.update_edges(&graph, |_| 1., &[(nodes[2], nodes[12])]);
+        let expected = SparseSgd::new_with_pivot(&graph, |_| 1., &pivot);
+        assert_eq!(sorted(&sgd), sorted(&expected));
+    }
+
+    #[test]
+    fn test_new_with_landmark_distance_matrix() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..20).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..20 {
+            graph.add_edge(nodes[(i - 1) / 2], nodes[i], ());
+        }
+        let pivot = [nodes[0], nodes[7], nodes[15]];
+        let d = LandmarkDistanceMatrix::new(&graph, |_| 1., &pivot);
+        let sgd = SparseSgd::<f32>::new_with_landmark_distance_matrix(&graph, |_| 1., &d);
+        let expected = SparseSgd::new_with_pivot(&graph, |_| 1., &pivot);
+        assert_eq!(sgd.node_pairs(), expected.node_pairs());
+    }
+
+    #[test]
+    fn test_update_edges_length() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..20).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..20 {
+            graph.add_edge(nodes[i - 1], nodes[i], 1.);
+        }
+        graph.add_edge(nodes[2], nodes[12], 2.);
+        let pivot = [nodes[0], nodes[7], nodes[15]];
+        let sorted = |sgd: &SparseSgd<f32>| {
+            let mut node_pairs = sgd.node_pairs().clone();
+            node_pairs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            node_pairs
+        };
+
+        let mut sgd = SparseSgd::new_with_pivot(&graph, |e| *e.weight(), &pivot);
+        for l in [5., 1.5, 20.] {
+            let e = graph.find_edge(nodes[2], nodes[12]).unwrap();
+            graph[e] = l;
+            sgd.update_edges(&graph, |e| *e.weight(), &[(nodes[2], nodes[12])]);
+            let expected = SparseSgd::new_with_pivot(&graph, |e| *e.weight(), &pivot);
+            assert_eq!(sorted(&sgd), sorted(&expected));
+        }
+    }
+
+    #[test]
+    fn test_update_edges_after_remove_nodes() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..20).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..20 {
+            graph.add_edge(nodes[i - 1], nodes[i], ());
+        }
+        let pivot = [nodes[0], nodes[7], nodes[15]];
+        let mut sgd = SparseSgd::new_with_pivot(&graph, |_| 1., &pivot);
+        let removed = HashSet::from([3, 7]);
+        sgd.remove_nodes(&removed);
+        graph.add_edge(nodes[2], nodes[12], ());
+        sgd.update_edges(&graph, |_| 1., &[(nodes[2], nodes[12])]);
+        assert!(!sgd.node_pairs().is_empty());
+        for &(i, j, ..) in sgd.node_pairs() {
+            assert!(!removed.contains(&i) && !removed.contains(&j));
+        }
+        assert!(sgd.node_pairs().iter().any(|&(i, j, ..)| (i, j) == (2, 12)));
+
+        sgd.retain_nodes(&(10..20).collect());
+        sgd.update_edges(&graph, |_| 1., &[(nodes[2], nodes[12])]);
+        for &(i, j, ..) in sgd.node_pairs() {
+            assert!((10..20).contains(&i) && (10..20).contains(&j));
+        }
+        assert!(sgd.node_pairs().iter().any(|&(i, ..)| i == 15));
+    }
+
+    #[test]
+    fn test_remove_nodes_with_pivot() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..20).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..20 {
+            graph.add_edge(nodes[i - 1], nodes[i], ());
+        }
+        let pivot = [nodes[0], nodes[7], nodes[15]];
+        let mut sgd = SparseSgd::<f32>::new_with_pivot(&graph, |_| 1., &pivot);
+        let removed = HashSet::from([7, 8]);
+        let expected = sgd
+            .node_pairs()
+            .iter()
+            .filter(|&&(i, j, ..)| !removed.contains(&i) && !removed.contains(&j))
+            .cloned()
+            .collect::<Vec<_>>();
+        sgd.remove_nodes(&removed);
+        assert_eq!(sgd.node_pairs(), &expected);
+
+        // Rebuilding the node pairs must not bring back the removed pivot.
+        sgd.update_edges(&graph, |_| 1., &[]);
+        for &(i, j, ..) in sgd.node_pairs() {
+            assert!(!removed.contains(&i) && !removed.contains(&j));
+        }
+        for i in (0..20).filter(|i| !removed.contains(i)) {
+            for p in [0, 15].into_iter().filter(|&p| p != i) {
+                assert!(sgd
+                    .node_pairs()
+                    .iter()
+                    .any(|&(s, t, ..)| (s, t) == (p, i) || (s, t) == (i, p)));
+            }
+        }
+
+        sgd.retain_nodes(&(0..10).collect());
+        sgd.update_edges(&graph, |_| 1., &[]);
+        for &(i, j, ..) in sgd.node_pairs() {
+            assert!(i < 10 && j < 10 && !removed.contains(&i) && !removed.contains(&j));
+        }
+        assert!(sgd.node_pairs().iter().all(|&(i, ..)| i != 15));
+    }
+
+    #[test]
+    fn test_seeded_pivot_selection() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..50).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 1..50 {
+            graph.add_edge(nodes[(i - 1) / 2], nodes[i], ());
+        }
+        let build = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            SparseSgd::<f32>::new_with_rng(&graph, |_| 1., 5, &mut rng)
+        };
+        assert_eq!(build(7).pivot, build(7).pivot);
+        assert_eq!(build(7).node_pairs(), build(7).node_pairs());
+    }
+}
@@ -0,0 +1,21 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_drawing::Drawing;
+//! use petgraph_layout_spectral::SpectralLayout;
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 0..4 {
+//!     graph.add_edge(nodes[i], nodes[(i + 1) % 4], ());
+//! }
+//! let drawing = SpectralLayout::new(&graph).run_2d();
+//! assert_eq!(drawing.len(), 4);
+//! for &u in &nodes {
+//!     assert!(drawing.x(u).unwrap().is_finite() && drawing.y(u).unwrap().is_finite());
+//! }
+//! ```
+
+mod spectral_layout;
+
+pub use petgraph_algorithm_laplacian::{Convergence, LaplacianStructure};
+pub use spectral_layout::SpectralLayout;
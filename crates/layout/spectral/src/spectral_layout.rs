@@ -0,0 +1,87 @@
+use ndarray::prelude::*;
+use petgraph::visit::{IntoEdgeReferences, IntoNodeIdentifiers};
+use petgraph_algorithm_laplacian::{spectral_embedding, Convergence, LaplacianStructure};
+use petgraph_drawing::{Drawing, DrawingEuclidean, DrawingEuclidean2d, DrawingIndex};
+
+pub struct SpectralLayout<N> {
+    pub normalized: bool,
+    pub iterations: usize,
+    pub eps: f32,
+    indices: Vec<N>,
+    laplacian: LaplacianStructure,
+}
+
+impl<N> SpectralLayout<N>
+where
+    N: DrawingIndex,
+{
+    pub fn new<G>(graph: G) -> Self
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers,
+        G::NodeId: DrawingIndex + Into<N>,
+    {
+        SpectralLayout::new_with_weight(graph, |_| 1.)
+    }
+
+    /// Uses `weight` as the edge weights of the Laplacian. Edges with a
+    /// non-positive or non-finite weight are ignored, as if they were absent.
+    pub fn new_with_weight<G, F>(graph: G, weight: F) -> Self
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers,
+        G::NodeId: DrawingIndex + Into<N>,
+        F: FnMut(G::EdgeRef) -> f32,
+    {
+        Self {
+            normalized: false,
+            iterations: 1000,
+            eps: 1e-4,
+            indices: graph.node_identifiers().map(|u| u.into()).collect(),
+            laplacian: LaplacianStructure::new_with_weight(graph, weight),
+        }
+    }
+
+    pub fn embedding(&self, d: usize) -> Array2<f32> {
+        self.embedding_with_convergence(d).0
+    }
+
+    pub fn embedding_with_convergence(&self, d: usize) -> (Array2<f32>, Convergence) {
+        spectral_embedding(
+            &self.laplacian,
+            d,
+            self.normalized,
+            self.iterations,
+            self.eps,
+        )
+    }
+
+    pub fn run_2d(&self) -> DrawingEuclidean2d<N, f32>
+    where
+        N: Copy,
+    {
+        let x = self.embedding(2);
+        let mut drawing = DrawingEuclidean2d::from_node_indices(&self.indices);
+        for (i, &u) in self.indices.iter().enumerate() {
+            if let Some(p) = drawing.position_mut(u) {
+                p.0 = x[[i, 0]];
+                p.1 = x[[i, 1]];
+            }
+        }
+        drawing
+    }
+
+    pub fn run(&self, d: usize) -> DrawingEuclidean<N, f32>
+    where
+        N: Copy,
+    {
+        let x = self.embedding(d);
+        let mut drawing = DrawingEuclidean::from_node_indices(&self.indices, d);
+        for (i, &u) in self.indices.iter().enumerate() {
+            if let Some(p) = drawing.position_mut(u) {
+                for j in 0..d {
+                    p.0[j] = x[[i, j]];
+                }
+            }
+        }
+        drawing
+    }
+}
@@ -0,0 +1,38 @@
+use ndarray::prelude::*;
+use petgraph::prelude::*;
+use petgraph_layout_spectral::LaplacianStructure;
+
+#[test]
+fn test_weighted_laplacian_dot() {
+    let mut graph = Graph::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge(a, b, 2.);
+    graph.add_edge(b, c, 3.);
+    let laplacian = LaplacianStructure::new_with_weight(&graph, |e| *e.weight());
+    assert_eq!(laplacian.len(), 3);
+    assert_eq!(laplacian.degree(), &array![2., 5., 3.]);
+    assert_eq!(laplacian.neighbors(1), &[(0, 2.), (2, 3.)]);
+
+    let x = array![1., 2., 4.];
+    assert_eq!(laplacian.dot(&x), array![-2., -4., 6.]);
+    let y = laplacian.normalized_dot(&Array1::ones(3));
+    assert!(y.iter().all(|y| y.is_finite()));
+    assert!(laplacian.dot(&Array1::ones(3)).iter().all(|&y| y == 0.));
+}
+
+#[test]
+fn test_laplacian_skips_non_positive_weights() {
+    let mut graph = Graph::new_undirected();
+    let a = graph.add_node(());
+    let b = graph.add_node(());
+    let c = graph.add_node(());
+    graph.add_edge(a, b, 2.);
+    graph.add_edge(b, c, 0.);
+    graph.add_edge(a, c, -1.);
+    graph.add_edge(a, c, f32::NAN);
+    let laplacian = LaplacianStructure::new_with_weight(&graph, |e| *e.weight());
+    assert_eq!(laplacian.degree(), &array![2., 2., 0.]);
+    assert_eq!(laplacian.neighbors(2), &[]);
+}
@@ -0,0 +1,50 @@
+use egraph_dataset::dataset_1138_bus;
+use petgraph::prelude::*;
+use petgraph_layout_spectral::SpectralLayout;
+
+#[test]
+fn test_spectral_layout_2d() {
+    let graph: UnGraph<(), ()> = dataset_1138_bus();
+    let spectral = SpectralLayout::new(&graph);
+    let drawing = spectral.run_2d();
+    for u in graph.node_indices() {
+        assert!(drawing.x(u).unwrap().is_finite());
+        assert!(drawing.y(u).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_spectral_layout_normalized_3d() {
+    let graph: UnGraph<(), ()> = dataset_1138_bus();
+    let mut spectral = SpectralLayout::new(&graph);
+    spectral.normalized = true;
+    let drawing = spectral.run(3);
+    for u in graph.node_indices() {
+        assert!(drawing.get(u, 0).unwrap().is_finite());
+        assert!(drawing.get(u, 1).unwrap().is_finite());
+        assert!(drawing.get(u, 2).unwrap().is_finite());
+    }
+}
+
+#[test]
+fn test_spectral_layout_convergence() {
+    let graph: UnGraph<(), ()> = dataset_1138_bus();
+    let mut spectral = SpectralLayout::<NodeIndex>::new(&graph);
+    spectral.iterations = 3;
+    let (_, convergence) = spectral.embedding_with_convergence(2);
+    assert_eq!(convergence.iterations, vec![3, 3]);
+    assert!(!convergence.converged(spectral.eps));
+
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for i in 0..6 {
+        graph.add_edge(nodes[i], nodes[(i + 1) % 6], ());
+    }
+    let spectral = SpectralLayout::<NodeIndex>::new(&graph);
+    let (x, convergence) = spectral.embedding_with_convergence(2);
+    assert_eq!(x.shape(), &[6, 2]);
+    assert!(convergence.converged(spectral.eps));
+    for &e in convergence.eigenvalues.iter() {
+        assert!((e - 1.).abs() < 1e-3);
+    }
+}
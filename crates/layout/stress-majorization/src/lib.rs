@@ -1,7 +1,29 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_drawing::{Drawing, DrawingEuclidean2d};
+//! use petgraph_layout_stress_majorization::StressMajorization;
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 1..4 {
+//!     graph.add_edge(nodes[i - 1], nodes[i], ());
+//! }
+//! let mut drawing = DrawingEuclidean2d::initial_placement(&graph);
+//! let mut stress_majorization = StressMajorization::new(&graph, &drawing, |_| 1.);
+//! stress_majorization.run(&mut drawing);
+//! assert_eq!(drawing.len(), 4);
+//! for &u in &nodes {
+//!     assert!(drawing.x(u).unwrap().is_finite() && drawing.y(u).unwrap().is_finite());
+//! }
+//! ```
+
 use ndarray::prelude::*;
 use petgraph::visit::{IntoEdges, IntoNodeIdentifiers, NodeCount};
-use petgraph_algorithm_shortest_path::{all_sources_dijkstra, DistanceMatrix, FullDistanceMatrix};
+use petgraph_algorithm_shortest_path::{
+    all_sources_shortest_path, DistanceMatrix, FullDistanceMatrix,
+};
 use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex};
+use std::collections::HashMap;
 
 fn line_search(a: &Array2<f32>, dx: &Array1<f32>, d: &Array1<f32>) -> f32 {
     let n = dx.len();
@@ -104,7 +126,7 @@ impl StressMajorization {
         G::NodeId: DrawingIndex + Ord,
         F: FnMut(G::EdgeRef) -> f32,
     {
-        let d = all_sources_dijkstra(graph, length);
+        let d = all_sources_shortest_path(graph, length);
         StressMajorization::new_with_distance_matrix(drawing, &d)
     }
 
@@ -149,6 +171,55 @@ impl StressMajorization {
         sm
     }
 
+    pub fn new_with_warm_start<N>(
+        drawing: &mut DrawingEuclidean2d<N, f32>,
+        distance_matrix: &FullDistanceMatrix<N, f32>,
+    ) -> StressMajorization
+    where
+        N: DrawingIndex,
+    {
+        let mut sm = StressMajorization::new_with_distance_matrix(drawing, distance_matrix);
+        sm.fit_scale(drawing);
+        sm
+    }
+
+    pub fn fit_scale<N>(&mut self, drawing: &mut DrawingEuclidean2d<N, f32>) -> f32
+    where
+        N: DrawingIndex,
+    {
+        let n = drawing.len();
+        let mut numerator = 0.;
+        let mut denominator = 0.;
+        for j in 1..n {
+            for i in 0..j {
+                let wij = self.w[[i, j]];
+                let dij = self.d[[i, j]];
+                if !wij.is_finite() || !dij.is_finite() {
+                    continue;
+                }
+                let dx = drawing.raw_entry(i).0 - drawing.raw_entry(j).0;
+                let dy = drawing.raw_entry(i).1 - drawing.raw_entry(j).1;
+                let norm = (dx * dx + dy * dy).sqrt();
+                numerator += wij * dij * norm;
+                denominator += wij * norm * norm;
+            }
+        }
+        if denominator <= 0. {
+            return 1.;
+        }
+        let scale = numerator / denominator;
+        for i in 0..n {
+            drawing.raw_entry_mut(i).0 *= scale;
+            drawing.raw_entry_mut(i).1 *= scale;
+        }
+        for i in 0..n - 1 {
+            self.x_x[i] = drawing.raw_entry(i).0 - drawing.raw_entry(n - 1).0;
+            self.x_y[i] = drawing.raw_entry(i).1 - drawing.raw_entry(n - 1).1;
+        }
+        self.stress = stress(&self.x_x, &self.x_y, &self.w, &self.d);
+        scale
+    }
+
     pub fn apply<N>(&mut self, drawing: &mut DrawingEuclidean2d<N, f32>) -> f32
     where
         N: DrawingIndex,
@@ -224,6 +295,10 @@ impl StressMajorization {
         diff
     }
 
+    pub fn stress(&self) -> f32 {
+        self.stress
+    }
+
     pub fn run<N>(&mut self, coordinates: &mut DrawingEuclidean2d<N, f32>)
     where
         N: DrawingIndex,
@@ -267,6 +342,24 @@ impl StressMajorization {
         }
         self.stress = stress(&self.x_x, &self.x_y, &self.w, &self.d);
     }
+
+    pub fn update_cluster_weight<N>(
+        &mut self,
+        drawing: &DrawingEuclidean2d<N, f32>,
+        communities: &HashMap<N, usize>,
+        intra_cluster_weight: f32,
+        inter_cluster_weight: f32,
+    ) where
+        N: DrawingIndex,
+    {
+        let community = (0..drawing.len())
+            .map(|i| communities.get(drawing.node_id(i)).copied())
+            .collect::<Vec<_>>();
+        self.update_weight(|i, j, _, wij| match (community[i], community[j]) {
+            (Some(ci), Some(cj)) if ci == cj => wij * intra_cluster_weight,
+            _ => wij * inter_cluster_weight,
+        });
+    }
 }
 
 #[test]
@@ -310,3 +403,63 @@ fn test_stress_majorization() {
         println!("{:?}", coordinates.position(u));
     }
 }
+
+#[test]
+fn test_stress_majorization_warm_start() {
+    use petgraph::Graph;
+
+    let n = 10;
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for i in 1..n {
+        graph.add_edge(nodes[i - 1], nodes[i], ());
+    }
+    let mut coordinates = DrawingEuclidean2d::initial_placement(&graph);
+    let mut stress_majorization = StressMajorization::new(&graph, &coordinates, &mut |_| 1.);
+    stress_majorization.run(&mut coordinates);
+
+    let distance = all_sources_shortest_path(&graph, &mut |_| 3.);
+    let mut cold = DrawingEuclidean2d::new(&graph);
+    let mut warm = DrawingEuclidean2d::new(&graph);
+    for &u in &nodes {
+        cold.set_x(u, coordinates.x(u).unwrap());
+        cold.set_y(u, coordinates.y(u).unwrap());
+        warm.set_x(u, coordinates.x(u).unwrap());
+        warm.set_y(u, coordinates.y(u).unwrap());
+    }
+    let cold_stress = StressMajorization::new_with_distance_matrix(&cold, &distance).stress;
+    let mut stress_majorization = StressMajorization::new_with_warm_start(&mut warm, &distance);
+    assert!(stress_majorization.stress < cold_stress);
+    assert!(stress_majorization.apply(&mut warm) < 1e-2);
+}
+
+#[test]
+fn test_stress_majorization_cluster_weight() {
+    use petgraph::Graph;
+
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..8).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for k in 0..2 {
+        for j in 1..4 {
+            for i in 0..j {
+                graph.add_edge(nodes[4 * k + i], nodes[4 * k + j], ());
+            }
+        }
+    }
+    graph.add_edge(nodes[0], nodes[4], ());
+    let communities = nodes
+        .iter()
+        .map(|&u| (u, u.index() / 4))
+        .collect::<HashMap<_, _>>();
+    let mut coordinates = DrawingEuclidean2d::initial_placement(&graph);
+    let mut stress_majorization = StressMajorization::new(&graph, &coordinates, |_| 1.);
+    let w01 = stress_majorization.w[[0, 1]];
+    let w04 = stress_majorization.w[[0, 4]];
+    stress_majorization.update_cluster_weight(&coordinates, &communities, 10., 0.1);
+    assert!((stress_majorization.w[[0, 1]] - 10. * w01).abs() < 1e-6);
+    assert!((stress_majorization.w[[4, 0]] - 0.1 * w04).abs() < 1e-6);
+    stress_majorization.run(&mut coordinates);
+    for &u in &nodes {
+        assert!(coordinates.x(u).unwrap().is_finite());
+    }
+}
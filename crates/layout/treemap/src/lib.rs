@@ -0,0 +1,280 @@
+//! ```
+//! use petgraph_layout_treemap::{squarify, Rectangle};
+//!
+//! let values = [6., 6., 4., 3., 2., 2., 1.0f64];
+//! let rectangles = squarify(&values, Rectangle::new(0., 0., 6., 4.));
+//! assert_eq!(rectangles.len(), values.len());
+//! for (r, &v) in rectangles.iter().zip(values.iter()) {
+//!     assert!((r.area() - v).abs() < 1e-9);
+//! }
+//! ```
+use petgraph_drawing::{DrawingIndex, DrawingValue};
+use std::{cmp::Ordering, collections::HashMap};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Rectangle<S> {
+    pub x: S,
+    pub y: S,
+    pub width: S,
+    pub height: S,
+}
+
+impl<S> Rectangle<S>
+where
+    S: DrawingValue,
+{
+    pub fn new(x: S, y: S, width: S, height: S) -> Rectangle<S> {
+        Rectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn area(&self) -> S {
+        self.width * self.height
+    }
+
+    pub fn center(&self) -> (S, S) {
+        let two = S::one() + S::one();
+        (self.x + self.width / two, self.y + self.height / two)
+    }
+
+    pub fn contains(&self, x: S, y: S) -> bool {
+        self.x <= x && x <= self.x + self.width && self.y <= y && y <= self.y + self.height
+    }
+}
+
+fn worst<S>(sum: S, min: S, max: S, w: S) -> S
+where
+    S: DrawingValue,
+{
+    let s2 = sum * sum;
+    let w2 = w * w;
+    (w2 * max / s2).max(s2 / (w2 * min))
+}
+
+fn layout_row<S>(areas: &[S], row: &[usize], rect: &mut Rectangle<S>, result: &mut [Rectangle<S>])
+where
+    S: DrawingValue,
+{
+    let sum = row.iter().fold(S::zero(), |s, &i| s + areas[i]);
+    if rect.width >= rect.height {
+        let width = if rect.height > S::zero() {
+            sum / rect.height
+        } else {
+            S::zero()
+        };
+        let mut y = rect.y;
+        for &i in row {
+            let height = if width > S::zero() {
+                areas[i] / width
+            } else {
+                S::zero()
+            };
+            result[i] = Rectangle::new(rect.x, y, width, height);
+            y += height;
+        }
+        rect.x += width;
+        rect.width = (rect.width - width).max(S::zero());
+    } else {
+        let height = if rect.width > S::zero() {
+            sum / rect.width
+        } else {
+            S::zero()
+        };
+        let mut x = rect.x;
+        for &i in row {
+            let width = if height > S::zero() {
+                areas[i] / height
+            } else {
+                S::zero()
+            };
+            result[i] = Rectangle::new(x, rect.y, width, height);
+            x += width;
+        }
+        rect.y += height;
+        rect.height = (rect.height - height).max(S::zero());
+    }
+}
+
+pub fn squarify<S>(values: &[S], rect: Rectangle<S>) -> Vec<Rectangle<S>>
+where
+    S: DrawingValue + Default,
+{
+    let n = values.len();
+    let mut result = vec![Rectangle::new(rect.x, rect.y, S::zero(), S::zero()); n];
+    let total = values.iter().fold(S::zero(), |s, &v| s + v.max(S::zero()));
+    if total <= S::zero() {
+        return result;
+    }
+    let scale = rect.area() / total;
+    let areas = values
+        .iter()
+        .map(|&v| v.max(S::zero()) * scale)
+        .collect::<Vec<_>>();
+    let mut order = (0..n).filter(|&i| areas[i] > S::zero()).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| areas[j].partial_cmp(&areas[i]).unwrap_or(Ordering::Equal));
+
+    let mut rect = rect;
+    let mut row = vec![];
+    let mut row_sum = S::zero();
+    let mut row_min = S::infinity();
+    let mut row_max = S::zero();
+    for i in order {
+        let a = areas[i];
+        let w = rect.width.min(rect.height);
+        if !row.is_empty()
+            && worst(row_sum + a, row_min.min(a), row_max.max(a), w)
+                > worst(row_sum, row_min, row_max, w)
+        {
+            layout_row(&areas, &row, &mut rect, &mut result);
+            row.clear();
+            row_sum = S::zero();
+            row_min = S::infinity();
+            row_max = S::zero();
+        }
+        row.push(i);
+        row_sum += a;
+        row_min = row_min.min(a);
+        row_max = row_max.max(a);
+    }
+    if !row.is_empty() {
+        layout_row(&areas, &row, &mut rect, &mut result);
+    }
+    result
+}
+
+/// Squarifies the clusters of a flat clustering into `rect`, sizing each
+/// cluster by the total weight of its nodes. Nested clusters are handled by
+/// [`hierarchical_cluster_treemap`].
+pub fn cluster_treemap<N, S, F>(
+    clustering: &HashMap<N, usize>,
+    weight: F,
+    rect: Rectangle<S>,
+) -> HashMap<usize, Rectangle<S>>
+where
+    N: DrawingIndex + Copy,
+    S: DrawingValue + Default,
+    F: FnMut(N) -> S,
+{
+    hierarchical_cluster_treemap(clustering, &HashMap::new(), weight, rect)
+}
+
+/// Squarifies a cluster hierarchy into `rect`. `clustering` assigns each node
+/// to a leaf cluster and `parents` maps a cluster to its enclosing cluster;
+/// clusters without a parent are laid out directly in `rect`. Each cluster is
+/// sized by the total weight of the nodes below it and its children are
+/// squarified into its rectangle. `parents` must describe a forest.
+pub fn hierarchical_cluster_treemap<N, S, F>(
+    clustering: &HashMap<N, usize>,
+    parents: &HashMap<usize, usize>,
+    weight: F,
+    rect: Rectangle<S>,
+) -> HashMap<usize, Rectangle<S>>
+where
+    N: DrawingIndex + Copy,
+    S: DrawingValue + Default,
+    F: FnMut(N) -> S,
+{
+    let mut weight = weight;
+    let mut cluster_weights = HashMap::new();
+    for (&c, &p) in parents.iter() {
+        cluster_weights.entry(c).or_insert(S::zero());
+        cluster_weights.entry(p).or_insert(S::zero());
+    }
+    for (&u, &c) in clustering.iter() {
+        let w = weight(u);
+        let mut c = Some(c);
+        while let Some(d) = c {
+            *cluster_weights.entry(d).or_insert(S::zero()) += w;
+            c = parents.get(&d).copied();
+        }
+    }
+    let mut children = HashMap::<Option<usize>, Vec<usize>>::new();
+    for &c in cluster_weights.keys() {
+        children
+            .entry(parents.get(&c).copied())
+            .or_default()
+            .push(c);
+    }
+    for clusters in children.values_mut() {
+        clusters.sort();
+    }
+
+    let mut result = HashMap::new();
+    let mut stack = vec![(None, rect)];
+    while let Some((parent, rect)) = stack.pop() {
+        if let Some(clusters) = children.get(&parent) {
+            let values = clusters
+                .iter()
+                .map(|c| cluster_weights[c])
+                .collect::<Vec<_>>();
+            for (&c, r) in clusters.iter().zip(squarify(&values, rect)) {
+                result.insert(c, r);
+                stack.push((Some(c), r));
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_squarify() {
+        let values = [6., 6., 4., 3., 2., 2., 1.0f64];
+        let rect = Rectangle::new(0., 0., 6., 4.);
+        let rectangles = squarify(&values, rect);
+        for (r, &v) in rectangles.iter().zip(values.iter()) {
+            assert!((r.area() - v).abs() < 1e-9);
+            assert!(r.x >= 0. && r.x + r.width <= 6. + 1e-9);
+            assert!(r.y >= 0. && r.y + r.height <= 4. + 1e-9);
+        }
+    }
+
+    fn assert_inside(inner: &Rectangle<f64>, outer: &Rectangle<f64>) {
+        assert!(inner.x >= outer.x - 1e-9);
+        assert!(inner.y >= outer.y - 1e-9);
+        assert!(inner.x + inner.width <= outer.x + outer.width + 1e-9);
+        assert!(inner.y + inner.height <= outer.y + outer.height + 1e-9);
+    }
+
+    #[test]
+    fn test_cluster_treemap() {
+        let clustering = [(0, 0), (1, 0), (2, 0), (3, 1), (4, 2), (5, 2)]
+            .into_iter()
+            .collect::<HashMap<usize, usize>>();
+        let rect = Rectangle::new(0., 0., 6., 4.);
+        let rectangles = cluster_treemap(&clustering, |u| (u + 1) as f64, rect);
+        assert_eq!(rectangles.len(), 3);
+        for (c, area) in [(0, 6.), (1, 4.), (2, 11.)] {
+            assert!((rectangles[&c].area() - area * 24. / 21.).abs() < 1e-9);
+            assert_inside(&rectangles[&c], &rect);
+        }
+    }
+
+    #[test]
+    fn test_hierarchical_cluster_treemap() {
+        // Leaf clusters 0, 1 and 2 belong to cluster 3; leaf cluster 4 is a root.
+        let clustering = [(0, 0), (1, 0), (2, 1), (3, 2), (4, 4), (5, 4)]
+            .into_iter()
+            .collect::<HashMap<usize, usize>>();
+        let parents = [(0, 3), (1, 3), (2, 3)].into_iter().collect();
+        let rect = Rectangle::new(0., 0., 6., 4.0f64);
+        let rectangles = hierarchical_cluster_treemap(&clustering, &parents, |_| 1., rect);
+        assert_eq!(rectangles.len(), 5);
+        for (c, area) in [(0, 8.), (1, 4.), (2, 4.), (3, 16.), (4, 8.)] {
+            assert!((rectangles[&c].area() - area).abs() < 1e-9);
+        }
+        for c in [3, 4] {
+            assert_inside(&rectangles[&c], &rect);
+        }
+        for c in [0, 1, 2] {
+            assert_inside(&rectangles[&c], &rectangles[&3]);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::graph::{GraphType, IndexType, PyGraphAdapter};
+use petgraph::graph::NodeIndex;
+use petgraph_algorithm_centrality::{
+    betweenness_centrality, closeness_centrality, eigenvector_centrality, pagerank,
+};
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+fn to_index_map(centrality: HashMap<NodeIndex<IndexType>, f32>) -> HashMap<usize, f32> {
+    centrality
+        .into_iter()
+        .map(|(u, c)| (u.index(), c))
+        .collect()
+}
+
+#[pyfunction]
+#[pyo3(name = "betweenness_centrality")]
+fn py_betweenness_centrality(graph: &PyGraphAdapter) -> HashMap<usize, f32> {
+    to_index_map(match graph.graph() {
+        GraphType::Graph(g) => betweenness_centrality(g),
+        GraphType::DiGraph(g) => betweenness_centrality(g),
+    })
+}
+
+#[pyfunction]
+#[pyo3(name = "closeness_centrality")]
+fn py_closeness_centrality(graph: &PyGraphAdapter) -> HashMap<usize, f32> {
+    to_index_map(match graph.graph() {
+        GraphType::Graph(g) => closeness_centrality(g),
+        GraphType::DiGraph(g) => closeness_centrality(g),
+    })
+}
+
+#[pyfunction]
+#[pyo3(name = "pagerank")]
+fn py_pagerank(graph: &PyGraphAdapter, damping: f32, iterations: usize) -> HashMap<usize, f32> {
+    to_index_map(match graph.graph() {
+        GraphType::Graph(g) => pagerank(g, damping, iterations),
+        GraphType::DiGraph(g) => pagerank(g, damping, iterations),
+    })
+}
+
+#[pyfunction]
+#[pyo3(name = "eigenvector_centrality")]
+fn py_eigenvector_centrality(graph: &PyGraphAdapter, iterations: usize) -> HashMap<usize, f32> {
+    to_index_map(match graph.graph() {
+        GraphType::Graph(g) => eigenvector_centrality(g, iterations),
+        GraphType::DiGraph(g) => eigenvector_centrality(g, iterations),
+    })
+}
+
+pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(py_betweenness_centrality, m)?)?;
+    m.add_function(wrap_pyfunction!(py_closeness_centrality, m)?)?;
+    m.add_function(wrap_pyfunction!(py_pagerank, m)?)?;
+    m.add_function(wrap_pyfunction!(py_eigenvector_centrality, m)?)?;
+    Ok(())
+}
@@ -1,7 +1,9 @@
+mod centrality;
 mod shortest_path;
 use pyo3::prelude::*;
 
 pub fn register(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
+    centrality::register(py, m)?;
     shortest_path::register(py, m)?;
     Ok(())
 }
@@ -2,9 +2,12 @@ use crate::{
     distance_matrix::PyDistanceMatrix,
     graph::{GraphType, PyGraphAdapter},
 };
-use petgraph::visit::EdgeRef;
-use petgraph_algorithm_shortest_path::{all_sources_bfs, all_sources_dijkstra, warshall_floyd};
+use petgraph::{graph::node_index, visit::EdgeRef};
+use petgraph_algorithm_shortest_path::{
+    all_sources_bfs, all_sources_dijkstra, dijkstra_with_targets, warshall_floyd,
+};
 use pyo3::prelude::*;
+use std::collections::HashMap;
 
 #[pyfunction]
 #[pyo3(name = "all_sources_bfs")]
@@ -44,9 +47,31 @@ fn py_warshall_floyd(graph: &PyGraphAdapter, f: &Bound<PyAny>) -> PyDistanceMatr
     PyDistanceMatrix::new_with_full_distance_matrix(distance_matrix)
 }
 
+#[pyfunction]
+#[pyo3(name = "dijkstra_with_targets")]
+fn py_dijkstra_with_targets(
+    graph: &PyGraphAdapter,
+    source: usize,
+    targets: Vec<usize>,
+    f: &Bound<PyAny>,
+) -> HashMap<usize, f32> {
+    let source = node_index(source);
+    let targets = targets.into_iter().map(node_index).collect::<Vec<_>>();
+    let distance = match graph.graph() {
+        GraphType::Graph(g) => dijkstra_with_targets(g, source, &targets, |e| {
+            f.call1((e.id().index(),)).unwrap().extract().unwrap()
+        }),
+        GraphType::DiGraph(g) => dijkstra_with_targets(g, source, &targets, |e| {
+            f.call1((e.id().index(),)).unwrap().extract().unwrap()
+        }),
+    };
+    distance.into_iter().map(|(u, d)| (u.index(), d)).collect()
+}
+
 pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_all_sources_bfs, m)?)?;
     m.add_function(wrap_pyfunction!(py_all_sources_dijkstra, m)?)?;
     m.add_function(wrap_pyfunction!(py_warshall_floyd, m)?)?;
+    m.add_function(wrap_pyfunction!(py_dijkstra_with_targets, m)?)?;
     Ok(())
 }
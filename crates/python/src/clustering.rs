@@ -0,0 +1,32 @@
+use crate::graph::{GraphType, PyGraphAdapter};
+use petgraph_clustering::{CommunityDetection, LabelPropagation};
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+#[pyfunction]
+#[pyo3(name = "label_propagation")]
+fn py_label_propagation(
+    graph: &PyGraphAdapter,
+    max_iterations: usize,
+    synchronous: bool,
+    seed: u64,
+) -> HashMap<usize, usize> {
+    let label_propagation = LabelPropagation {
+        max_iterations,
+        synchronous,
+        seed,
+    };
+    let communities = match graph.graph() {
+        GraphType::Graph(g) => label_propagation.detect_communities(g),
+        GraphType::DiGraph(g) => label_propagation.detect_communities(g),
+    };
+    communities
+        .into_iter()
+        .map(|(u, c)| (u.index(), c))
+        .collect()
+}
+
+pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(py_label_propagation, m)?)?;
+    Ok(())
+}
@@ -1,4 +1,8 @@
-use crate::graph::{GraphType, IndexType, PyGraphAdapter};
+use crate::{
+    drawing::array_to_numpy,
+    graph::{GraphType, IndexType, PyGraphAdapter},
+};
+use ndarray::Array2;
 use petgraph::{graph::NodeIndex, stable_graph::node_index};
 use petgraph_algorithm_shortest_path::{DistanceMatrix, FullDistanceMatrix, SubDistanceMatrix};
 use pyo3::prelude::*;
@@ -8,6 +12,14 @@ pub enum DistanceMatrixType {
     Sub(SubDistanceMatrix<NodeIndex<IndexType>, f32>),
 }
 
+fn distance_matrix_array<D: DistanceMatrix<NodeIndex<IndexType>, f32>>(
+    distance_matrix: &D,
+) -> Array2<f32> {
+    Array2::from_shape_fn(distance_matrix.shape(), |(i, j)| {
+        distance_matrix.get_by_index(i, j)
+    })
+}
+
 #[pyclass]
 #[pyo3(name = "DistanceMatrix")]
 pub struct PyDistanceMatrix {
@@ -73,6 +85,14 @@ impl PyDistanceMatrix {
             }
         }
     }
+
+    pub fn to_numpy(&self, py: Python<'_>) -> PyObject {
+        let array = match self.distance_matrix() {
+            DistanceMatrixType::Full(distance_matrix) => distance_matrix_array(distance_matrix),
+            DistanceMatrixType::Sub(distance_matrix) => distance_matrix_array(distance_matrix),
+        };
+        array_to_numpy(py, array)
+    }
 }
 
 pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
@@ -1,6 +1,11 @@
-use crate::{drawing::PyDrawing, graph::NodeId};
+use crate::{
+    drawing::{array_to_numpy, check_coords_shape, PyDrawing},
+    graph::NodeId,
+};
+use ndarray::Array2;
+use numpy::PyReadonlyArray2;
 use petgraph::graph::node_index;
-use petgraph_drawing::DrawingEuclidean;
+use petgraph_drawing::{Drawing, DrawingEuclidean};
 use pyo3::prelude::*;
 
 #[pyclass(extends=PyDrawing)]
@@ -34,4 +39,21 @@ impl PyDrawingEuclidean {
         let u = node_index(u);
         self.drawing.set(u, d, value);
     }
+
+    pub fn coords(&self, py: Python<'_>) -> PyObject {
+        let coords =
+            Array2::from_shape_fn((self.drawing.len(), self.drawing.dimension()), |(i, k)| {
+                self.drawing.raw_entry(i).0[k]
+            });
+        array_to_numpy(py, coords)
+    }
+
+    pub fn set_coords(&mut self, coords: PyReadonlyArray2<f32>) -> PyResult<()> {
+        let coords = coords.as_array();
+        check_coords_shape(&coords, self.drawing.len(), self.drawing.dimension())?;
+        for (i, p) in coords.outer_iter().enumerate() {
+            self.drawing.raw_entry_mut(i).0 = p.to_vec();
+        }
+        Ok(())
+    }
 }
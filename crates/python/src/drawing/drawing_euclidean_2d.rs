@@ -1,10 +1,44 @@
 use crate::{
-    drawing::PyDrawing,
-    graph::{GraphType, NodeId, PyGraphAdapter},
+    drawing::{array_to_numpy, check_coords_shape, PyDrawing},
+    graph::{Edge, GraphType, IndexType, Node, NodeId, PyGraphAdapter},
+};
+use ndarray::Array2;
+use numpy::PyReadonlyArray2;
+use petgraph::{
+    graph::{node_index, Graph},
+    EdgeType,
 };
-use petgraph::graph::node_index;
 use petgraph_drawing::{Drawing, DrawingEuclidean2d};
-use pyo3::prelude::*;
+use pyo3::{prelude::*, types::PyDict};
+
+fn drawing_from_pos<Ty: EdgeType>(
+    graph: &Graph<Node, Edge, Ty, IndexType>,
+    pos: &Bound<PyDict>,
+) -> PyResult<DrawingEuclidean2d<NodeId, f32>> {
+    let mut drawing = DrawingEuclidean2d::initial_placement(graph);
+    for u in graph.node_indices() {
+        if let Some(p) = pos.get_item(&graph[u])? {
+            let (x, y) = p.extract::<(f32, f32)>()?;
+            drawing.set_x(u, x);
+            drawing.set_y(u, y);
+        }
+    }
+    Ok(drawing)
+}
+
+fn drawing_to_pos<'py, Ty: EdgeType>(
+    py: Python<'py>,
+    graph: &Graph<Node, Edge, Ty, IndexType>,
+    drawing: &DrawingEuclidean2d<NodeId, f32>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let pos = PyDict::new_bound(py);
+    for u in graph.node_indices() {
+        if let (Some(x), Some(y)) = (drawing.x(u), drawing.y(u)) {
+            pos.set_item(&graph[u], (x, y))?;
+        }
+    }
+    Ok(pos)
+}
 
 #[pyclass(extends=PyDrawing)]
 #[pyo3(name = "DrawingEuclidean2d")]
@@ -78,4 +112,46 @@ impl PyDrawingEuclidean2d {
             GraphType::DiGraph(native_graph) => DrawingEuclidean2d::initial_placement(native_graph),
         })
     }
+
+    #[staticmethod]
+    pub fn from_pos(graph: &PyGraphAdapter, pos: &Bound<PyDict>) -> PyResult<PyObject> {
+        Ok(PyDrawing::new_drawing_euclidean_2d(match graph.graph() {
+            GraphType::Graph(native_graph) => drawing_from_pos(native_graph, pos)?,
+            GraphType::DiGraph(native_graph) => drawing_from_pos(native_graph, pos)?,
+        }))
+    }
+
+    pub fn to_pos<'py>(
+        &self,
+        py: Python<'py>,
+        graph: &PyGraphAdapter,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        match graph.graph() {
+            GraphType::Graph(native_graph) => drawing_to_pos(py, native_graph, &self.drawing),
+            GraphType::DiGraph(native_graph) => drawing_to_pos(py, native_graph, &self.drawing),
+        }
+    }
+
+    pub fn coords(&self, py: Python<'_>) -> PyObject {
+        let coords = Array2::from_shape_fn((self.drawing.len(), 2), |(i, k)| {
+            let u = *self.drawing.node_id(i);
+            if k == 0 {
+                self.drawing.x(u).unwrap()
+            } else {
+                self.drawing.y(u).unwrap()
+            }
+        });
+        array_to_numpy(py, coords)
+    }
+
+    pub fn set_coords(&mut self, coords: PyReadonlyArray2<f32>) -> PyResult<()> {
+        let coords = coords.as_array();
+        check_coords_shape(&coords, self.drawing.len(), 2)?;
+        for (i, p) in coords.outer_iter().enumerate() {
+            let u = *self.drawing.node_id(i);
+            self.drawing.set_x(u, p[0]);
+            self.drawing.set_y(u, p[1]);
+        }
+        Ok(())
+    }
 }
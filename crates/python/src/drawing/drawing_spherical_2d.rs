@@ -1,7 +1,9 @@
 use crate::{
-    drawing::PyDrawing,
+    drawing::{array_to_numpy, check_coords_shape, PyDrawing},
     graph::{GraphType, NodeId, PyGraphAdapter},
 };
+use ndarray::Array2;
+use numpy::PyReadonlyArray2;
 use petgraph::graph::node_index;
 use petgraph_drawing::{Drawing, DrawingSpherical2d};
 use pyo3::prelude::*;
@@ -59,4 +61,89 @@ impl PyDrawingSpherical2d {
             GraphType::DiGraph(native_graph) => DrawingSpherical2d::initial_placement(native_graph),
         })
     }
+
+    pub fn coords(&self, py: Python<'_>) -> PyObject {
+        let coords = Array2::from_shape_fn((self.drawing.len(), 2), |(i, k)| {
+            let u = *self.drawing.node_id(i);
+            if k == 0 {
+                self.drawing.lon(u).unwrap()
+            } else {
+                self.drawing.lat(u).unwrap()
+            }
+        });
+        array_to_numpy(py, coords)
+    }
+
+    pub fn edge_arc(
+        &self,
+        py: Python<'_>,
+        u: usize,
+        v: usize,
+        segments: usize,
+    ) -> Option<PyObject> {
+        let u = node_index(u);
+        let v = node_index(v);
+        self.drawing.edge_arc(u, v, segments).map(|arc| {
+            let arc =
+                Array2::from_shape_fn(
+                    (arc.len(), 2),
+                    |(i, k)| {
+                        if k == 0 {
+                            arc[i].0
+                        } else {
+                            arc[i].1
+                        }
+                    },
+                );
+            array_to_numpy(py, arc)
+        })
+    }
+
+    pub fn geographic_coords(&self, py: Python<'_>) -> PyObject {
+        let coords = Array2::from_shape_fn((self.drawing.len(), 2), |(i, k)| {
+            let (lon, lat) = self.drawing.geographic(*self.drawing.node_id(i)).unwrap();
+            if k == 0 {
+                lon
+            } else {
+                lat
+            }
+        });
+        array_to_numpy(py, coords)
+    }
+
+    pub fn edge_arc_geographic(
+        &self,
+        py: Python<'_>,
+        u: usize,
+        v: usize,
+        segments: usize,
+    ) -> Option<PyObject> {
+        let u = node_index(u);
+        let v = node_index(v);
+        self.drawing.edge_arc_geographic(u, v, segments).map(|arc| {
+            let arc =
+                Array2::from_shape_fn(
+                    (arc.len(), 2),
+                    |(i, k)| {
+                        if k == 0 {
+                            arc[i].0
+                        } else {
+                            arc[i].1
+                        }
+                    },
+                );
+            array_to_numpy(py, arc)
+        })
+    }
+
+    pub fn set_coords(&mut self, coords: PyReadonlyArray2<f32>) -> PyResult<()> {
+        let coords = coords.as_array();
+        check_coords_shape(&coords, self.drawing.len(), 2)?;
+        for (i, p) in coords.outer_iter().enumerate() {
+            let u = *self.drawing.node_id(i);
+            self.drawing.set_lon(u, p[0]);
+            self.drawing.set_lat(u, p[1]);
+        }
+        Ok(())
+    }
 }
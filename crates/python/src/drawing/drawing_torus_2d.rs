@@ -1,7 +1,9 @@
 use crate::{
-    drawing::PyDrawing,
+    drawing::{array_to_numpy, check_coords_shape, PyDrawing},
     graph::{GraphType, NodeId, PyGraphAdapter},
 };
+use ndarray::Array2;
+use numpy::PyReadonlyArray2;
 use petgraph::graph::node_index;
 use petgraph_drawing::{Drawing, DrawingTorus2d};
 use pyo3::prelude::*;
@@ -70,4 +72,27 @@ impl PyDrawingTorus2d {
             GraphType::DiGraph(native_graph) => DrawingTorus2d::initial_placement(native_graph),
         })
     }
+
+    pub fn coords(&self, py: Python<'_>) -> PyObject {
+        let coords = Array2::from_shape_fn((self.drawing.len(), 2), |(i, k)| {
+            let u = *self.drawing.node_id(i);
+            if k == 0 {
+                self.drawing.x(u).unwrap()
+            } else {
+                self.drawing.y(u).unwrap()
+            }
+        });
+        array_to_numpy(py, coords)
+    }
+
+    pub fn set_coords(&mut self, coords: PyReadonlyArray2<f32>) -> PyResult<()> {
+        let coords = coords.as_array();
+        check_coords_shape(&coords, self.drawing.len(), 2)?;
+        for (i, p) in coords.outer_iter().enumerate() {
+            let u = *self.drawing.node_id(i);
+            self.drawing.set_x(u, p[0]);
+            self.drawing.set_y(u, p[1]);
+        }
+        Ok(())
+    }
 }
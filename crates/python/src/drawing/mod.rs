@@ -12,7 +12,23 @@ pub use drawing_hyperbolic_2d::*;
 pub use drawing_spherical_2d::*;
 pub use drawing_torus_2d::*;
 
-use pyo3::prelude::*;
+use ndarray::{Array2, ArrayView2};
+use numpy::PyArray2;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+pub fn array_to_numpy(py: Python<'_>, array: Array2<f32>) -> PyObject {
+    PyArray2::from_owned_array_bound(py, array).into_py(py)
+}
+
+pub fn check_coords_shape(coords: &ArrayView2<f32>, n: usize, d: usize) -> PyResult<()> {
+    if coords.shape() != [n, d] {
+        return Err(PyValueError::new_err(format!(
+            "coords must have shape ({}, {})",
+            n, d
+        )));
+    }
+    Ok(())
+}
 
 pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PyDrawing>()?;
@@ -0,0 +1,30 @@
+use crate::{
+    drawing::PyDrawingEuclidean2d,
+    graph::{GraphType, PyGraphAdapter},
+};
+use petgraph_edge_bundling_fdeb::{fdeb, EdgeBundlingOptions};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::collections::HashMap;
+
+#[pyfunction]
+#[pyo3(name = "fdeb")]
+fn py_fdeb(
+    graph: &PyGraphAdapter,
+    drawing: &PyDrawingEuclidean2d,
+) -> PyResult<HashMap<usize, Vec<(f32, f32)>>> {
+    let options = EdgeBundlingOptions::<f32>::new();
+    let bends = match graph.graph() {
+        GraphType::Graph(native_graph) => fdeb(native_graph, drawing.drawing(), &options),
+        GraphType::DiGraph(native_graph) => fdeb(native_graph, drawing.drawing(), &options),
+    }
+    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    Ok(bends
+        .into_iter()
+        .map(|(e, lines)| (e.index(), lines))
+        .collect())
+}
+
+pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(py_fdeb, m)?)?;
+    Ok(())
+}
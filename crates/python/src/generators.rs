@@ -0,0 +1,78 @@
+use crate::{graph::PyGraph, rng::PyRng};
+use petgraph::graph::UnGraph;
+use petgraph_generators::{
+    barabasi_albert, complete_graph, erdos_renyi, grid_graph, torus_graph, tree_graph,
+    watts_strogatz,
+};
+use pyo3::prelude::*;
+
+fn to_py_graph(py: Python<'_>, graph: UnGraph<(), ()>) -> PyResult<Py<PyGraph>> {
+    PyGraph::new_from_graph(
+        py,
+        graph.map(|u, _| u.index().into_py(py), |_, _| py.None()),
+    )
+}
+
+#[pyfunction]
+#[pyo3(name = "complete_graph")]
+fn py_complete_graph(py: Python<'_>, n: usize) -> PyResult<Py<PyGraph>> {
+    to_py_graph(py, complete_graph(n))
+}
+
+#[pyfunction]
+#[pyo3(name = "grid_graph")]
+fn py_grid_graph(py: Python<'_>, rows: usize, cols: usize) -> PyResult<Py<PyGraph>> {
+    to_py_graph(py, grid_graph(rows, cols))
+}
+
+#[pyfunction]
+#[pyo3(name = "torus_graph")]
+fn py_torus_graph(py: Python<'_>, rows: usize, cols: usize) -> PyResult<Py<PyGraph>> {
+    to_py_graph(py, torus_graph(rows, cols))
+}
+
+#[pyfunction]
+#[pyo3(name = "tree_graph")]
+fn py_tree_graph(py: Python<'_>, branching: usize, height: usize) -> PyResult<Py<PyGraph>> {
+    to_py_graph(py, tree_graph(branching, height))
+}
+
+#[pyfunction]
+#[pyo3(name = "erdos_renyi")]
+fn py_erdos_renyi(py: Python<'_>, n: usize, p: f64, rng: &mut PyRng) -> PyResult<Py<PyGraph>> {
+    to_py_graph(py, erdos_renyi(n, p, rng.get_mut()))
+}
+
+#[pyfunction]
+#[pyo3(name = "watts_strogatz")]
+fn py_watts_strogatz(
+    py: Python<'_>,
+    n: usize,
+    k: usize,
+    beta: f64,
+    rng: &mut PyRng,
+) -> PyResult<Py<PyGraph>> {
+    to_py_graph(py, watts_strogatz(n, k, beta, rng.get_mut()))
+}
+
+#[pyfunction]
+#[pyo3(name = "barabasi_albert")]
+fn py_barabasi_albert(
+    py: Python<'_>,
+    n: usize,
+    m: usize,
+    rng: &mut PyRng,
+) -> PyResult<Py<PyGraph>> {
+    to_py_graph(py, barabasi_albert(n, m, rng.get_mut()))
+}
+
+pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(py_complete_graph, m)?)?;
+    m.add_function(wrap_pyfunction!(py_grid_graph, m)?)?;
+    m.add_function(wrap_pyfunction!(py_torus_graph, m)?)?;
+    m.add_function(wrap_pyfunction!(py_tree_graph, m)?)?;
+    m.add_function(wrap_pyfunction!(py_erdos_renyi, m)?)?;
+    m.add_function(wrap_pyfunction!(py_watts_strogatz, m)?)?;
+    m.add_function(wrap_pyfunction!(py_barabasi_albert, m)?)?;
+    Ok(())
+}
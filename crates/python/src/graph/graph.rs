@@ -4,7 +4,7 @@ use petgraph::{
     prelude::*,
     EdgeType,
 };
-use pyo3::{exceptions::PyValueError, prelude::*};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyDict};
 
 pub fn graph_node_count<Ty: EdgeType>(graph: &Graph<Node, Edge, Ty, IndexType>) -> usize {
     graph.node_count()
@@ -209,10 +209,74 @@ pub fn graph_filter_map<Ty: EdgeType>(
     )
 }
 
+pub fn graph_from_networkx<Ty: EdgeType>(
+    nx_graph: &Bound<PyAny>,
+) -> PyResult<Graph<Node, Edge, Ty, IndexType>> {
+    let py = nx_graph.py();
+    let mut graph = Graph::with_capacity(0, 0);
+    let indices = PyDict::new_bound(py);
+    for u in nx_graph.getattr("nodes")?.iter()? {
+        let u = u?;
+        let a = graph.add_node(u.clone().unbind());
+        indices.set_item(u, a.index())?;
+    }
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item("data", true)?;
+    for item in nx_graph.call_method("edges", (), Some(&kwargs))?.iter()? {
+        let (u, v, data) = item?.extract::<(Bound<PyAny>, Bound<PyAny>, Bound<PyAny>)>()?;
+        let a = indices.as_any().get_item(u)?.extract::<usize>()?;
+        let b = indices.as_any().get_item(v)?.extract::<usize>()?;
+        graph.add_edge(node_index(a), node_index(b), data.unbind());
+    }
+    Ok(graph)
+}
+
+pub fn graph_to_networkx<Ty: EdgeType>(
+    py: Python<'_>,
+    graph: &Graph<Node, Edge, Ty, IndexType>,
+) -> PyResult<PyObject> {
+    let nx = py.import_bound("networkx")?;
+    let nx_graph = nx
+        .getattr(if Ty::is_directed() {
+            "DiGraph"
+        } else {
+            "Graph"
+        })?
+        .call0()?;
+    for u in graph.node_indices() {
+        nx_graph.call_method1("add_node", (&graph[u],))?;
+    }
+    for e in graph.edge_indices() {
+        let (a, b) = graph.edge_endpoints(e).unwrap();
+        let data = graph[e].bind(py);
+        nx_graph.call_method(
+            "add_edge",
+            (&graph[a], &graph[b]),
+            data.downcast::<PyDict>().ok(),
+        )?;
+    }
+    Ok(nx_graph.unbind())
+}
+
 #[pyclass(extends = PyGraphAdapter)]
 #[pyo3(name = "Graph")]
 pub struct PyGraph;
 
+impl PyGraph {
+    pub fn new_from_graph(
+        py: Python<'_>,
+        graph: Graph<Node, Edge, Undirected, IndexType>,
+    ) -> PyResult<Py<PyGraph>> {
+        Py::new(
+            py,
+            PyClassInitializer::from(PyGraphAdapter {
+                graph: GraphType::Graph(graph),
+            })
+            .add_subclass(Self),
+        )
+    }
+}
+
 #[pymethods]
 impl PyGraph {
     #[new]
@@ -230,6 +294,21 @@ impl PyGraph {
 #[pyo3(name = "DiGraph")]
 pub struct PyDiGraph;
 
+impl PyDiGraph {
+    pub fn new_from_graph(
+        py: Python<'_>,
+        graph: Graph<Node, Edge, Directed, IndexType>,
+    ) -> PyResult<Py<PyDiGraph>> {
+        Py::new(
+            py,
+            PyClassInitializer::from(PyGraphAdapter {
+                graph: GraphType::DiGraph(graph),
+            })
+            .add_subclass(Self),
+        )
+    }
+}
+
 #[pymethods]
 impl PyDiGraph {
     #[new]
@@ -1,6 +1,8 @@
 mod graph;
 
 use graph::*;
+
+pub use graph::PyGraph;
 use petgraph::prelude::*;
 use pyo3::prelude::*;
 
@@ -158,6 +160,22 @@ impl PyGraphAdapter {
         }
     }
 
+    #[staticmethod]
+    pub fn from_networkx(py: Python<'_>, nx_graph: &Bound<PyAny>) -> PyResult<PyObject> {
+        if nx_graph.call_method0("is_directed")?.extract::<bool>()? {
+            Ok(PyDiGraph::new_from_graph(py, graph_from_networkx(nx_graph)?)?.into_py(py))
+        } else {
+            Ok(PyGraph::new_from_graph(py, graph_from_networkx(nx_graph)?)?.into_py(py))
+        }
+    }
+
+    pub fn to_networkx(&self, py: Python<'_>) -> PyResult<PyObject> {
+        match self.graph() {
+            GraphType::Graph(native_graph) => graph_to_networkx(py, native_graph),
+            GraphType::DiGraph(native_graph) => graph_to_networkx(py, native_graph),
+        }
+    }
+
     pub fn map(&self, node_map: &Bound<PyAny>, edge_map: &Bound<PyAny>) -> Self {
         Self {
             graph: match self.graph() {
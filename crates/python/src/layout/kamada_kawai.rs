@@ -1,5 +1,8 @@
 use crate::{
-    drawing::PyDrawingEuclidean2d,
+    drawing::{
+        DrawingType, PyDrawing, PyDrawingEuclidean, PyDrawingEuclidean2d, PyDrawingSpherical2d,
+        PyDrawingTorus2d,
+    },
     graph::{GraphType, PyGraphAdapter},
 };
 use petgraph::visit::EdgeRef;
@@ -26,16 +29,121 @@ impl PyKamadaKawai {
         }
     }
 
-    fn select_node(&self, drawing: &PyDrawingEuclidean2d) -> Option<usize> {
-        self.kamada_kawai.select_node(drawing.drawing())
+    fn select_node(&self, drawing: &Bound<PyDrawing>) -> Option<usize> {
+        let drawing_type = drawing.borrow().drawing_type();
+        Python::with_gil(|py| match drawing_type {
+            DrawingType::Euclidean2d => {
+                let drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingEuclidean2d>(py)
+                    .unwrap()
+                    .borrow();
+                self.kamada_kawai.select_node(drawing.drawing())
+            }
+            DrawingType::Euclidean => {
+                let drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingEuclidean>(py)
+                    .unwrap()
+                    .borrow();
+                self.kamada_kawai.select_node(drawing.drawing())
+            }
+            DrawingType::Spherical2d => {
+                let drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingSpherical2d>(py)
+                    .unwrap()
+                    .borrow();
+                self.kamada_kawai.select_node(drawing.drawing())
+            }
+            DrawingType::Torus2d => {
+                let drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingTorus2d>(py)
+                    .unwrap()
+                    .borrow();
+                self.kamada_kawai.select_node(drawing.drawing())
+            }
+            _ => unimplemented!(),
+        })
     }
 
-    fn apply_to_node(&self, m: usize, drawing: &mut PyDrawingEuclidean2d) {
-        self.kamada_kawai.apply_to_node(m, drawing.drawing_mut())
+    fn apply_to_node(&self, m: usize, drawing: &Bound<PyDrawing>) {
+        let drawing_type = drawing.borrow().drawing_type();
+        Python::with_gil(|py| match drawing_type {
+            DrawingType::Euclidean2d => {
+                let mut drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingEuclidean2d>(py)
+                    .unwrap()
+                    .borrow_mut();
+                self.kamada_kawai.apply_to_node(m, drawing.drawing_mut())
+            }
+            DrawingType::Euclidean => {
+                let mut drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingEuclidean>(py)
+                    .unwrap()
+                    .borrow_mut();
+                self.kamada_kawai.apply_to_node(m, drawing.drawing_mut())
+            }
+            DrawingType::Spherical2d => {
+                let mut drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingSpherical2d>(py)
+                    .unwrap()
+                    .borrow_mut();
+                self.kamada_kawai.apply_to_node(m, drawing.drawing_mut())
+            }
+            DrawingType::Torus2d => {
+                let mut drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingTorus2d>(py)
+                    .unwrap()
+                    .borrow_mut();
+                self.kamada_kawai.apply_to_node(m, drawing.drawing_mut())
+            }
+            _ => unimplemented!(),
+        })
     }
 
-    fn run(&self, drawing: &mut PyDrawingEuclidean2d) {
-        self.kamada_kawai.run(drawing.drawing_mut())
+    fn run(&self, drawing: &Bound<PyDrawing>) {
+        let drawing_type = drawing.borrow().drawing_type();
+        Python::with_gil(|py| match drawing_type {
+            DrawingType::Euclidean2d => {
+                let mut drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingEuclidean2d>(py)
+                    .unwrap()
+                    .borrow_mut();
+                self.kamada_kawai.run(drawing.drawing_mut())
+            }
+            DrawingType::Euclidean => {
+                let mut drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingEuclidean>(py)
+                    .unwrap()
+                    .borrow_mut();
+                self.kamada_kawai.run(drawing.drawing_mut())
+            }
+            DrawingType::Spherical2d => {
+                let mut drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingSpherical2d>(py)
+                    .unwrap()
+                    .borrow_mut();
+                self.kamada_kawai.run(drawing.drawing_mut())
+            }
+            DrawingType::Torus2d => {
+                let mut drawing = drawing
+                    .into_py(py)
+                    .downcast_bound::<PyDrawingTorus2d>(py)
+                    .unwrap()
+                    .borrow_mut();
+                self.kamada_kawai.run(drawing.drawing_mut())
+            }
+            _ => unimplemented!(),
+        })
     }
 
     #[getter]
@@ -47,6 +155,16 @@ impl PyKamadaKawai {
     fn set_eps(&mut self, value: f32) {
         self.kamada_kawai.eps = value;
     }
+
+    #[getter]
+    fn min_distance(&self) -> Option<f32> {
+        self.kamada_kawai.min_distance
+    }
+
+    #[setter]
+    fn set_min_distance(&mut self, value: Option<f32>) {
+        self.kamada_kawai.min_distance = value;
+    }
 }
 
 pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
@@ -1,6 +1,6 @@
 use crate::{
     distance_matrix::{DistanceMatrixType, PyDistanceMatrix},
-    drawing::PyDrawing,
+    drawing::{array_to_numpy, PyDrawing},
     graph::{GraphType, PyGraphAdapter},
 };
 use petgraph::{graph::node_index, stable_graph::NodeIndex, visit::EdgeRef};
@@ -41,6 +41,10 @@ impl PyClassicalMds {
         PyDrawing::new_drawing_euclidean(self.mds.run(d))
     }
 
+    fn embedding(&self, py: Python<'_>, d: usize) -> PyObject {
+        array_to_numpy(py, self.mds.embedding(d))
+    }
+
     fn run_2d(&self) -> PyObject {
         PyDrawing::new_drawing_euclidean_2d(self.mds.run_2d())
     }
@@ -97,6 +101,10 @@ impl PyPivotMds {
         PyDrawing::new_drawing_euclidean(self.mds.run(d))
     }
 
+    fn embedding(&self, py: Python<'_>, d: usize) -> PyObject {
+        array_to_numpy(py, self.mds.embedding(d))
+    }
+
     fn run_2d(&self) -> PyObject {
         PyDrawing::new_drawing_euclidean_2d(self.mds.run_2d())
     }
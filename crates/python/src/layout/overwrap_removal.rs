@@ -1,5 +1,5 @@
-use petgraph_layout_overwrap_removal::OverwrapRemoval;
-use pyo3::prelude::*;
+use petgraph_layout_overwrap_removal::{OverwrapRemoval, Prism};
+use pyo3::{exceptions::PyValueError, prelude::*};
 
 use crate::{
     drawing::{
@@ -80,7 +80,63 @@ impl PyOverwrapRemoval {
     }
 }
 
+#[pyclass]
+#[pyo3(name = "Prism")]
+struct PyPrism {
+    prism: Prism,
+}
+
+#[pymethods]
+impl PyPrism {
+    #[new]
+    fn new(graph: &PyGraphAdapter, f: &Bound<PyAny>) -> PyResult<PyPrism> {
+        match graph.graph() {
+            GraphType::Graph(native_graph) => Ok(PyPrism {
+                prism: Prism::new(native_graph, |u| {
+                    f.call1((u.index(),)).unwrap().extract().unwrap()
+                }),
+            }),
+            _ => Err(PyValueError::new_err("Prism requires an undirected Graph")),
+        }
+    }
+
+    fn apply(&self, drawing: &mut PyDrawingEuclidean2d) {
+        self.prism.apply(drawing.drawing_mut());
+    }
+
+    #[getter]
+    fn get_iterations(&self) -> usize {
+        self.prism.iterations
+    }
+
+    #[setter]
+    fn set_iterations(&mut self, value: usize) {
+        self.prism.iterations = value;
+    }
+
+    #[getter]
+    fn get_max_scale(&self) -> f32 {
+        self.prism.max_scale
+    }
+
+    #[setter]
+    fn set_max_scale(&mut self, value: f32) {
+        self.prism.max_scale = value;
+    }
+
+    #[getter]
+    fn get_min_distance(&self) -> f32 {
+        self.prism.min_distance
+    }
+
+    #[setter]
+    fn set_min_distance(&mut self, value: f32) {
+        self.prism.min_distance = value;
+    }
+}
+
 pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PyOverwrapRemoval>()?;
+    m.add_class::<PyPrism>()?;
     Ok(())
 }
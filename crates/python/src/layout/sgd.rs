@@ -7,12 +7,188 @@ use crate::{
     graph::{GraphType, PyGraphAdapter},
     rng::PyRng,
 };
-use petgraph::visit::{EdgeRef, IntoNodeIdentifiers};
+use petgraph::{
+    graph::node_index,
+    visit::{EdgeRef, IntoNodeIdentifiers},
+};
 use petgraph_layout_sgd::{
-    DistanceAdjustedSgd, FullSgd, Scheduler, SchedulerConstant, SchedulerExponential,
-    SchedulerLinear, SchedulerQuadratic, SchedulerReciprocal, Sgd, SparseSgd,
+    ConstrainedSgd, Constraint, DistanceAdjustedSgd, FullSgd, Scheduler, SchedulerConstant,
+    SchedulerExponential, SchedulerLinear, SchedulerQuadratic, SchedulerReciprocal, Sgd, SparseSgd,
 };
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+fn sgd_apply<A: Sgd<f32>>(sgd: &A, drawing: &Bound<PyDrawing>, eta: f32) {
+    let drawing_type = drawing.borrow().drawing_type();
+    Python::with_gil(|py| match drawing_type {
+        DrawingType::Euclidean2d => {
+            let mut drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingEuclidean2d>(py)
+                .unwrap()
+                .borrow_mut();
+            sgd.apply(drawing.drawing_mut(), eta)
+        }
+        DrawingType::Euclidean => {
+            let mut drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingEuclidean>(py)
+                .unwrap()
+                .borrow_mut();
+            sgd.apply(drawing.drawing_mut(), eta)
+        }
+        DrawingType::Hyperbolic2d => {
+            let mut drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingHyperbolic2d>(py)
+                .unwrap()
+                .borrow_mut();
+            sgd.apply(drawing.drawing_mut(), eta)
+        }
+        DrawingType::Spherical2d => {
+            let mut drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingSpherical2d>(py)
+                .unwrap()
+                .borrow_mut();
+            sgd.apply(drawing.drawing_mut(), eta)
+        }
+        DrawingType::Torus2d => {
+            let mut drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingTorus2d>(py)
+                .unwrap()
+                .borrow_mut();
+            sgd.apply(drawing.drawing_mut(), eta)
+        }
+    })
+}
+
+fn sgd_stress<A: Sgd<f32>>(sgd: &A, drawing: &Bound<PyDrawing>) -> f32 {
+    let drawing_type = drawing.borrow().drawing_type();
+    Python::with_gil(|py| match drawing_type {
+        DrawingType::Euclidean2d => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingEuclidean2d>(py)
+                .unwrap()
+                .borrow();
+            sgd.stress(drawing.drawing())
+        }
+        DrawingType::Euclidean => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingEuclidean>(py)
+                .unwrap()
+                .borrow();
+            sgd.stress(drawing.drawing())
+        }
+        DrawingType::Hyperbolic2d => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingHyperbolic2d>(py)
+                .unwrap()
+                .borrow();
+            sgd.stress(drawing.drawing())
+        }
+        DrawingType::Spherical2d => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingSpherical2d>(py)
+                .unwrap()
+                .borrow();
+            sgd.stress(drawing.drawing())
+        }
+        DrawingType::Torus2d => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingTorus2d>(py)
+                .unwrap()
+                .borrow();
+            sgd.stress(drawing.drawing())
+        }
+    })
+}
+
+fn constrained_sgd_apply<A: Sgd<f32>>(
+    sgd: &ConstrainedSgd<A, f32>,
+    drawing: &Bound<PyDrawing>,
+    eta: f32,
+) {
+    let drawing_type = drawing.borrow().drawing_type();
+    Python::with_gil(|py| match drawing_type {
+        DrawingType::Euclidean2d => {
+            let mut drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingEuclidean2d>(py)
+                .unwrap()
+                .borrow_mut();
+            sgd.apply(drawing.drawing_mut(), eta)
+        }
+        DrawingType::Euclidean => {
+            let mut drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingEuclidean>(py)
+                .unwrap()
+                .borrow_mut();
+            sgd.apply(drawing.drawing_mut(), eta)
+        }
+        DrawingType::Hyperbolic2d => {
+            let mut drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingHyperbolic2d>(py)
+                .unwrap()
+                .borrow_mut();
+            sgd.apply(drawing.drawing_mut(), eta)
+        }
+        DrawingType::Spherical2d => {
+            let mut drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingSpherical2d>(py)
+                .unwrap()
+                .borrow_mut();
+            sgd.apply(drawing.drawing_mut(), eta)
+        }
+        DrawingType::Torus2d => {
+            let mut drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingTorus2d>(py)
+                .unwrap()
+                .borrow_mut();
+            sgd.apply(drawing.drawing_mut(), eta)
+        }
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sgd_run<A: Sgd<f32>>(
+    sgd: &mut A,
+    drawing: &Bound<PyDrawing>,
+    rng: &mut PyRng,
+    scheduler: &str,
+    t_max: usize,
+    epsilon: f32,
+    interval: usize,
+    callback: &Bound<PyAny>,
+) -> PyResult<()> {
+    let mut scheduler = sgd
+        .scheduler_by_name(scheduler, t_max, epsilon)
+        .ok_or_else(|| PyValueError::new_err("unknown scheduler"))?;
+    let mut iteration = 0;
+    let mut result = Ok(());
+    while result.is_ok() && !scheduler.is_finished() {
+        scheduler.step(&mut |eta| {
+            sgd.shuffle(rng.get_mut());
+            sgd_apply(sgd, drawing, eta);
+            iteration += 1;
+            if interval > 0 && iteration % interval == 0 {
+                result = callback
+                    .call1((iteration, sgd_stress(sgd, drawing)))
+                    .map(|_| ());
+            }
+        });
+    }
+    result
+}
 #[pyclass]
 #[pyo3(name = "SchedulerConstant")]
 struct PySchedulerConstant {
@@ -36,6 +212,18 @@ impl PySchedulerConstant {
     pub fn is_finished(&self) -> bool {
         self.scheduler.is_finished()
     }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<f32> {
+        let mut result = None;
+        if !self.scheduler.is_finished() {
+            self.scheduler.step(&mut |eta| result = Some(eta));
+        }
+        result
+    }
 }
 
 #[pyclass]
@@ -61,6 +249,18 @@ impl PySchedulerLinear {
     pub fn is_finished(&self) -> bool {
         self.scheduler.is_finished()
     }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<f32> {
+        let mut result = None;
+        if !self.scheduler.is_finished() {
+            self.scheduler.step(&mut |eta| result = Some(eta));
+        }
+        result
+    }
 }
 
 #[pyclass]
@@ -86,6 +286,18 @@ impl PySchedulerQuadratic {
     pub fn is_finished(&self) -> bool {
         self.scheduler.is_finished()
     }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<f32> {
+        let mut result = None;
+        if !self.scheduler.is_finished() {
+            self.scheduler.step(&mut |eta| result = Some(eta));
+        }
+        result
+    }
 }
 
 #[pyclass]
@@ -111,6 +323,18 @@ impl PySchedulerExponential {
     pub fn is_finished(&self) -> bool {
         self.scheduler.is_finished()
     }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<f32> {
+        let mut result = None;
+        if !self.scheduler.is_finished() {
+            self.scheduler.step(&mut |eta| result = Some(eta));
+        }
+        result
+    }
 }
 
 #[pyclass]
@@ -136,6 +360,18 @@ impl PySchedulerReciprocal {
     pub fn is_finished(&self) -> bool {
         self.scheduler.is_finished()
     }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self) -> Option<f32> {
+        let mut result = None;
+        if !self.scheduler.is_finished() {
+            self.scheduler.step(&mut |eta| result = Some(eta));
+        }
+        result
+    }
 }
 
 #[pyclass]
@@ -213,54 +449,62 @@ impl PySparseSgd {
         }
     }
 
+    fn update_edges(
+        &mut self,
+        graph: &PyGraphAdapter,
+        f: &Bound<PyAny>,
+        changed_edges: Vec<(usize, usize)>,
+    ) -> PyResult<()> {
+        match graph.graph() {
+            GraphType::Graph(native_graph) => {
+                let changed_edges = changed_edges
+                    .into_iter()
+                    .map(|(u, v)| (node_index(u), node_index(v)))
+                    .collect::<Vec<_>>();
+                self.sgd.update_edges(
+                    native_graph,
+                    |e| f.call1((e.id().index(),)).unwrap().extract().unwrap(),
+                    &changed_edges,
+                );
+                Ok(())
+            }
+            _ => Err(PyValueError::new_err("unsupported graph type")),
+        }
+    }
+
     fn shuffle(&mut self, rng: &mut PyRng) {
         self.sgd.shuffle(rng.get_mut())
     }
 
     fn apply(&self, drawing: &Bound<PyDrawing>, eta: f32) {
-        let drawing_type = drawing.borrow().drawing_type();
-        Python::with_gil(|py| match drawing_type {
-            DrawingType::Euclidean2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingEuclidean2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Euclidean => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingEuclidean>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Hyperbolic2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingHyperbolic2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Spherical2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingSpherical2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Torus2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingTorus2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-        })
+        sgd_apply(&self.sgd, drawing, eta)
+    }
+
+    fn stress(&self, drawing: &Bound<PyDrawing>) -> f32 {
+        sgd_stress(&self.sgd, drawing)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn run(
+        &mut self,
+        drawing: &Bound<PyDrawing>,
+        rng: &mut PyRng,
+        scheduler: &str,
+        t_max: usize,
+        epsilon: f32,
+        interval: usize,
+        callback: &Bound<PyAny>,
+    ) -> PyResult<()> {
+        sgd_run(
+            &mut self.sgd,
+            drawing,
+            rng,
+            scheduler,
+            t_max,
+            epsilon,
+            interval,
+            callback,
+        )
     }
 
     pub fn scheduler(&self, t_max: usize, epsilon: f32) -> PySchedulerExponential {
@@ -302,6 +546,10 @@ impl PySparseSgd {
             .update_distance(|i, j, dij, wij| f.call1((i, j, dij, wij)).unwrap().extract().unwrap())
     }
 
+    pub fn wrap_distance(&mut self, period: f32) {
+        self.sgd.wrap_distance(period)
+    }
+
     pub fn update_weight(&mut self, f: &Bound<PyAny>) {
         self.sgd
             .update_weight(|i, j, dij, wij| f.call1((i, j, dij, wij)).unwrap().extract().unwrap())
@@ -367,49 +615,34 @@ impl PyFullSgd {
     }
 
     fn apply(&self, drawing: &Bound<PyDrawing>, eta: f32) {
-        let drawing_type = drawing.borrow().drawing_type();
-        Python::with_gil(|py| match drawing_type {
-            DrawingType::Euclidean2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingEuclidean2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Euclidean => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingEuclidean>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Hyperbolic2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingHyperbolic2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Spherical2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingSpherical2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Torus2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingTorus2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-        })
+        sgd_apply(&self.sgd, drawing, eta)
+    }
+
+    fn stress(&self, drawing: &Bound<PyDrawing>) -> f32 {
+        sgd_stress(&self.sgd, drawing)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn run(
+        &mut self,
+        drawing: &Bound<PyDrawing>,
+        rng: &mut PyRng,
+        scheduler: &str,
+        t_max: usize,
+        epsilon: f32,
+        interval: usize,
+        callback: &Bound<PyAny>,
+    ) -> PyResult<()> {
+        sgd_run(
+            &mut self.sgd,
+            drawing,
+            rng,
+            scheduler,
+            t_max,
+            epsilon,
+            interval,
+            callback,
+        )
     }
 
     pub fn scheduler(&self, t_max: usize, epsilon: f32) -> PySchedulerExponential {
@@ -451,6 +684,10 @@ impl PyFullSgd {
             .update_distance(|i, j, dij, wij| f.call1((i, j, dij, wij)).unwrap().extract().unwrap())
     }
 
+    pub fn wrap_distance(&mut self, period: f32) {
+        self.sgd.wrap_distance(period)
+    }
+
     pub fn update_weight(&mut self, f: &Bound<PyAny>) {
         self.sgd
             .update_weight(|i, j, dij, wij| f.call1((i, j, dij, wij)).unwrap().extract().unwrap())
@@ -502,49 +739,7 @@ impl PyDistanceAdjustedSparseSgd {
     }
 
     fn apply(&self, drawing: &Bound<PyDrawing>, eta: f32) {
-        let drawing_type = drawing.borrow().drawing_type();
-        Python::with_gil(|py| match drawing_type {
-            DrawingType::Euclidean2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingEuclidean2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Euclidean => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingEuclidean>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Hyperbolic2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingHyperbolic2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Spherical2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingSpherical2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Torus2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingTorus2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-        })
+        sgd_apply(&self.sgd, drawing, eta)
     }
 
     pub fn apply_with_distance_adjustment(&mut self, drawing: &Bound<PyDrawing>, eta: f32) {
@@ -698,49 +893,7 @@ impl PyDistanceAdjustedFullSgd {
     }
 
     fn apply(&self, drawing: &Bound<PyDrawing>, eta: f32) {
-        let drawing_type = drawing.borrow().drawing_type();
-        Python::with_gil(|py| match drawing_type {
-            DrawingType::Euclidean2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingEuclidean2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Euclidean => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingEuclidean>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Hyperbolic2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingHyperbolic2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Spherical2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingSpherical2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-            DrawingType::Torus2d => {
-                let mut drawing = drawing
-                    .into_py(py)
-                    .downcast_bound::<PyDrawingTorus2d>(py)
-                    .unwrap()
-                    .borrow_mut();
-                self.sgd.apply(drawing.drawing_mut(), eta)
-            }
-        })
+        sgd_apply(&self.sgd, drawing, eta)
     }
 
     pub fn apply_with_distance_adjustment(&mut self, drawing: &Bound<PyDrawing>, eta: f32) {
@@ -859,6 +1012,66 @@ impl PyDistanceAdjustedFullSgd {
     }
 }
 
+#[pyclass]
+#[pyo3(name = "ConstrainedSparseSgd")]
+struct PyConstrainedSparseSgd {
+    sgd: ConstrainedSgd<SparseSgd<f32>, f32>,
+}
+
+#[pymethods]
+impl PyConstrainedSparseSgd {
+    #[new]
+    fn new(graph: &PyGraphAdapter, f: &Bound<PyAny>, h: usize, rng: &mut PyRng) -> Self {
+        Self {
+            sgd: ConstrainedSgd::new(match graph.graph() {
+                GraphType::Graph(native_graph) => SparseSgd::new_with_rng(
+                    native_graph,
+                    |e| f.call1((e.id().index(),)).unwrap().extract().unwrap(),
+                    h,
+                    rng.get_mut(),
+                ),
+                _ => panic!("unsupported graph type"),
+            }),
+        }
+    }
+
+    #[pyo3(signature = (i, fixed_x=true, fixed_y=true))]
+    pub fn set_fixed(&mut self, i: usize, fixed_x: bool, fixed_y: bool) {
+        match (fixed_x, fixed_y) {
+            (true, true) => self.sgd.set_fixed(i, Constraint::FixedBoth),
+            (true, false) => self.sgd.set_fixed(i, Constraint::FixedX),
+            (false, true) => self.sgd.set_fixed(i, Constraint::FixedY),
+            (false, false) => self.sgd.unset_fixed(i),
+        }
+    }
+
+    pub fn unset_fixed(&mut self, i: usize) {
+        self.sgd.unset_fixed(i)
+    }
+
+    pub fn clear_fixed(&mut self) {
+        self.sgd.clear_fixed()
+    }
+
+    fn shuffle(&mut self, rng: &mut PyRng) {
+        self.sgd.shuffle(rng.get_mut())
+    }
+
+    fn apply(&self, drawing: &Bound<PyDrawing>, eta: f32) {
+        constrained_sgd_apply(&self.sgd, drawing, eta)
+    }
+
+    fn stress(&self, drawing: &Bound<PyDrawing>) -> f32 {
+        sgd_stress(self.sgd.sgd(), drawing)
+    }
+
+    pub fn scheduler(&self, t_max: usize, epsilon: f32) -> PySchedulerExponential {
+        PySchedulerExponential {
+            scheduler: self.sgd.scheduler(t_max, epsilon),
+        }
+    }
+}
+
 pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PySchedulerConstant>()?;
     m.add_class::<PySchedulerLinear>()?;
@@ -869,5 +1082,6 @@ pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PySparseSgd>()?;
     m.add_class::<PyDistanceAdjustedFullSgd>()?;
     m.add_class::<PyDistanceAdjustedSparseSgd>()?;
+    m.add_class::<PyConstrainedSparseSgd>()?;
     Ok(())
 }
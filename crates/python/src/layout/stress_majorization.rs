@@ -50,6 +50,27 @@ impl PyStressMajorization {
         }
     }
 
+    #[classmethod]
+    fn with_warm_start(
+        _cls: &Bound<PyType>,
+        drawing: &mut PyDrawingEuclidean2d,
+        distance_matrix: &PyDistanceMatrix,
+    ) -> PyStressMajorization {
+        match distance_matrix.distance_matrix() {
+            DistanceMatrixType::Full(distance_matrix) => PyStressMajorization {
+                stress_majorization: StressMajorization::new_with_warm_start(
+                    drawing.drawing_mut(),
+                    distance_matrix,
+                ),
+            },
+            _ => unimplemented!(),
+        }
+    }
+
+    fn fit_scale(&mut self, drawing: &mut PyDrawingEuclidean2d) -> f32 {
+        self.stress_majorization.fit_scale(drawing.drawing_mut())
+    }
+
     fn apply(&mut self, drawing: &mut PyDrawingEuclidean2d) -> f32 {
         self.stress_majorization.apply(drawing.drawing_mut())
     }
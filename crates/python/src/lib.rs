@@ -1,8 +1,11 @@
 use pyo3::prelude::*;
 
 mod algorithm;
+mod clustering;
 mod distance_matrix;
 mod drawing;
+mod edge_bundling;
+mod generators;
 mod graph;
 mod layout;
 mod quality_metrics;
@@ -14,8 +17,11 @@ fn egraph(py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     drawing::register(py, m)?;
     distance_matrix::register(py, m)?;
     rng::register(py, m)?;
+    generators::register(py, m)?;
     layout::register(py, m)?;
     algorithm::register(py, m)?;
+    clustering::register(py, m)?;
+    edge_bundling::register(py, m)?;
     quality_metrics::register(py, m)?;
     Ok(())
 }
@@ -1,15 +1,27 @@
 use crate::{
     distance_matrix::{DistanceMatrixType, PyDistanceMatrix},
-    drawing::{DrawingType, PyDrawing, PyDrawingEuclidean2d, PyDrawingTorus2d},
+    drawing::{
+        DrawingType, PyDrawing, PyDrawingEuclidean, PyDrawingEuclidean2d, PyDrawingHyperbolic2d,
+        PyDrawingSpherical2d, PyDrawingTorus2d,
+    },
     graph::{GraphType, PyGraphAdapter},
 };
 use petgraph_quality_metrics::{
     angular_resolution, aspect_ratio, crossing_angle, crossing_angle_with_crossing_edges,
     crossing_edges, crossing_edges_torus, crossing_number, crossing_number_with_crossing_edges,
-    gabriel_graph_property, ideal_edge_lengths, neighborhood_preservation, node_resolution, stress,
-    CrossingEdges,
+    edge_length_ratio, edge_length_uniformity, gabriel_graph_property, ideal_edge_lengths,
+    neighborhood_preservation, node_resolution, stress, symmetry, CrossingEdges,
+    QualityMetricsError,
 };
-use pyo3::prelude::*;
+use pyo3::{exceptions::PyValueError, prelude::*};
+use std::fmt::Debug;
+
+fn value_error<N>(e: QualityMetricsError<N>) -> PyErr
+where
+    N: Debug,
+{
+    PyValueError::new_err(e.to_string())
+}
 
 #[pyclass]
 #[pyo3(name = "CrossingEdges")]
@@ -19,7 +31,10 @@ pub struct PyCrossingEdges {
 
 #[pyfunction]
 #[pyo3(name = "crossing_edges")]
-fn py_crossing_edges(graph: &PyGraphAdapter, drawing: &Bound<PyDrawing>) -> PyCrossingEdges {
+fn py_crossing_edges(
+    graph: &PyGraphAdapter,
+    drawing: &Bound<PyDrawing>,
+) -> PyResult<PyCrossingEdges> {
     Python::with_gil(|py| {
         let drawing_type = drawing.borrow().drawing_type();
         let crossing_edges = match drawing_type {
@@ -31,10 +46,10 @@ fn py_crossing_edges(graph: &PyGraphAdapter, drawing: &Bound<PyDrawing>) -> PyCr
                     .borrow_mut();
                 match graph.graph() {
                     GraphType::Graph(native_graph) => {
-                        crossing_edges(native_graph, drawing.drawing())
+                        crossing_edges(native_graph, drawing.drawing()).map_err(value_error)?
                     }
                     GraphType::DiGraph(native_graph) => {
-                        crossing_edges(native_graph, drawing.drawing())
+                        crossing_edges(native_graph, drawing.drawing()).map_err(value_error)?
                     }
                 }
             }
@@ -47,25 +62,28 @@ fn py_crossing_edges(graph: &PyGraphAdapter, drawing: &Bound<PyDrawing>) -> PyCr
                 match graph.graph() {
                     GraphType::Graph(native_graph) => {
                         crossing_edges_torus(native_graph, drawing.drawing())
+                            .map_err(value_error)?
                     }
                     GraphType::DiGraph(native_graph) => {
                         crossing_edges_torus(native_graph, drawing.drawing())
+                            .map_err(value_error)?
                     }
                 }
             }
             _ => unimplemented!(),
         };
-        PyCrossingEdges { crossing_edges }
+        Ok(PyCrossingEdges { crossing_edges })
     })
 }
 
 #[pyfunction]
 #[pyo3(name = "angular_resolution")]
-fn py_angular_resolution(graph: &PyGraphAdapter, drawing: &PyDrawingEuclidean2d) -> f32 {
+fn py_angular_resolution(graph: &PyGraphAdapter, drawing: &PyDrawingEuclidean2d) -> PyResult<f32> {
     match graph.graph() {
         GraphType::Graph(native_graph) => angular_resolution(native_graph, drawing.drawing()),
         GraphType::DiGraph(native_graph) => angular_resolution(native_graph, drawing.drawing()),
     }
+    .map_err(value_error)
 }
 
 #[pyfunction]
@@ -76,11 +94,12 @@ fn py_aspect_ratio(drawing: &PyDrawingEuclidean2d) -> f32 {
 
 #[pyfunction]
 #[pyo3(name = "crossing_angle")]
-fn py_crossing_angle(graph: &PyGraphAdapter, drawing: &PyDrawingEuclidean2d) -> f32 {
+fn py_crossing_angle(graph: &PyGraphAdapter, drawing: &PyDrawingEuclidean2d) -> PyResult<f32> {
     match graph.graph() {
         GraphType::Graph(native_graph) => crossing_angle(native_graph, drawing.drawing()),
         GraphType::DiGraph(native_graph) => crossing_angle(native_graph, drawing.drawing()),
     }
+    .map_err(value_error)
 }
 
 #[pyfunction]
@@ -91,11 +110,12 @@ fn py_crossing_angle_with_crossing_edges(crossing_edges: &PyCrossingEdges) -> f3
 
 #[pyfunction]
 #[pyo3(name = "crossing_number")]
-fn py_crossing_number(graph: &PyGraphAdapter, drawing: &PyDrawingEuclidean2d) -> f32 {
+fn py_crossing_number(graph: &PyGraphAdapter, drawing: &PyDrawingEuclidean2d) -> PyResult<f32> {
     match graph.graph() {
         GraphType::Graph(native_graph) => crossing_number(native_graph, drawing.drawing()),
         GraphType::DiGraph(native_graph) => crossing_number(native_graph, drawing.drawing()),
     }
+    .map_err(value_error)
 }
 
 #[pyfunction]
@@ -104,13 +124,139 @@ fn py_crossing_number_with_crossing_edges(crossing_edges: &PyCrossingEdges) -> f
     crossing_number_with_crossing_edges(&crossing_edges.crossing_edges)
 }
 
+#[pyfunction]
+#[pyo3(name = "edge_length_ratio")]
+fn py_edge_length_ratio(graph: &PyGraphAdapter, drawing: &Bound<PyDrawing>) -> f32 {
+    let drawing_type = drawing.borrow().drawing_type();
+    Python::with_gil(|py| match drawing_type {
+        DrawingType::Euclidean2d => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingEuclidean2d>(py)
+                .unwrap()
+                .borrow();
+            match graph.graph() {
+                GraphType::Graph(native_graph) => {
+                    edge_length_ratio(native_graph, drawing.drawing())
+                }
+                GraphType::DiGraph(native_graph) => {
+                    edge_length_ratio(native_graph, drawing.drawing())
+                }
+            }
+        }
+        DrawingType::Euclidean => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingEuclidean>(py)
+                .unwrap()
+                .borrow();
+            match graph.graph() {
+                GraphType::Graph(native_graph) => {
+                    edge_length_ratio(native_graph, drawing.drawing())
+                }
+                GraphType::DiGraph(native_graph) => {
+                    edge_length_ratio(native_graph, drawing.drawing())
+                }
+            }
+        }
+        DrawingType::Hyperbolic2d => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingHyperbolic2d>(py)
+                .unwrap()
+                .borrow();
+            match graph.graph() {
+                GraphType::Graph(native_graph) => {
+                    edge_length_ratio(native_graph, drawing.drawing())
+                }
+                GraphType::DiGraph(native_graph) => {
+                    edge_length_ratio(native_graph, drawing.drawing())
+                }
+            }
+        }
+        DrawingType::Spherical2d => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingSpherical2d>(py)
+                .unwrap()
+                .borrow();
+            match graph.graph() {
+                GraphType::Graph(native_graph) => {
+                    edge_length_ratio(native_graph, drawing.drawing())
+                }
+                GraphType::DiGraph(native_graph) => {
+                    edge_length_ratio(native_graph, drawing.drawing())
+                }
+            }
+        }
+        DrawingType::Torus2d => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingTorus2d>(py)
+                .unwrap()
+                .borrow();
+            match graph.graph() {
+                GraphType::Graph(native_graph) => {
+                    edge_length_ratio(native_graph, drawing.drawing())
+                }
+                GraphType::DiGraph(native_graph) => {
+                    edge_length_ratio(native_graph, drawing.drawing())
+                }
+            }
+        }
+    })
+}
+
+#[pyfunction]
+#[pyo3(name = "edge_length_uniformity")]
+fn py_edge_length_uniformity(graph: &PyGraphAdapter, drawing: &Bound<PyDrawing>) -> f32 {
+    let drawing_type = drawing.borrow().drawing_type();
+    Python::with_gil(|py| match drawing_type {
+        DrawingType::Euclidean2d => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingEuclidean2d>(py)
+                .unwrap()
+                .borrow();
+            match graph.graph() {
+                GraphType::Graph(native_graph) => {
+                    edge_length_uniformity(native_graph, drawing.drawing())
+                }
+                GraphType::DiGraph(native_graph) => {
+                    edge_length_uniformity(native_graph, drawing.drawing())
+                }
+            }
+        }
+        DrawingType::Torus2d => {
+            let drawing = drawing
+                .into_py(py)
+                .downcast_bound::<PyDrawingTorus2d>(py)
+                .unwrap()
+                .borrow();
+            match graph.graph() {
+                GraphType::Graph(native_graph) => {
+                    edge_length_uniformity(native_graph, drawing.drawing())
+                }
+                GraphType::DiGraph(native_graph) => {
+                    edge_length_uniformity(native_graph, drawing.drawing())
+                }
+            }
+        }
+        _ => unimplemented!(),
+    })
+}
+
 #[pyfunction]
 #[pyo3(name = "gabriel_graph_property")]
-fn py_gabriel_graph_property(graph: &PyGraphAdapter, drawing: &PyDrawingEuclidean2d) -> f32 {
+fn py_gabriel_graph_property(
+    graph: &PyGraphAdapter,
+    drawing: &PyDrawingEuclidean2d,
+) -> PyResult<f32> {
     match graph.graph() {
         GraphType::Graph(native_graph) => gabriel_graph_property(native_graph, drawing.drawing()),
         GraphType::DiGraph(native_graph) => gabriel_graph_property(native_graph, drawing.drawing()),
     }
+    .map_err(value_error)
 }
 
 #[pyfunction]
@@ -119,7 +265,7 @@ fn py_ideal_edge_lengths(
     graph: &PyGraphAdapter,
     drawing: &Bound<PyDrawing>,
     distance_matrix: &PyDistanceMatrix,
-) -> f32 {
+) -> PyResult<f32> {
     Python::with_gil(|py| {
         let drawing_type = drawing.borrow().drawing_type();
         match drawing_type {
@@ -133,9 +279,11 @@ fn py_ideal_edge_lengths(
                     DistanceMatrixType::Full(d) => match graph.graph() {
                         GraphType::Graph(native_graph) => {
                             ideal_edge_lengths(native_graph, drawing.drawing(), d)
+                                .map_err(value_error)
                         }
                         GraphType::DiGraph(native_graph) => {
                             ideal_edge_lengths(native_graph, drawing.drawing(), d)
+                                .map_err(value_error)
                         }
                     },
                     _ => panic!("unsupported distance matrix type"),
@@ -151,9 +299,11 @@ fn py_ideal_edge_lengths(
                     DistanceMatrixType::Full(d) => match graph.graph() {
                         GraphType::Graph(native_graph) => {
                             ideal_edge_lengths(native_graph, drawing.drawing(), d)
+                                .map_err(value_error)
                         }
                         GraphType::DiGraph(native_graph) => {
                             ideal_edge_lengths(native_graph, drawing.drawing(), d)
+                                .map_err(value_error)
                         }
                     },
                     _ => panic!("unsupported distance matrix type"),
@@ -204,6 +354,15 @@ fn py_node_resolution(drawing: &Bound<PyDrawing>) -> f32 {
     })
 }
 
+#[pyfunction]
+#[pyo3(name = "symmetry")]
+fn py_symmetry(graph: &PyGraphAdapter, drawing: &PyDrawingEuclidean2d) -> f32 {
+    match graph.graph() {
+        GraphType::Graph(native_graph) => symmetry(native_graph, drawing.drawing()),
+        GraphType::DiGraph(native_graph) => symmetry(native_graph, drawing.drawing()),
+    }
+}
+
 #[pyfunction]
 #[pyo3(name = "stress")]
 fn py_stress(drawing: &Bound<PyDrawing>, distance_matrix: &PyDistanceMatrix) -> f32 {
@@ -242,10 +401,13 @@ pub fn register(_py: Python<'_>, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_crossing_edges, m)?)?;
     m.add_function(wrap_pyfunction!(py_crossing_number, m)?)?;
     m.add_function(wrap_pyfunction!(py_crossing_number_with_crossing_edges, m)?)?;
+    m.add_function(wrap_pyfunction!(py_edge_length_ratio, m)?)?;
+    m.add_function(wrap_pyfunction!(py_edge_length_uniformity, m)?)?;
     m.add_function(wrap_pyfunction!(py_gabriel_graph_property, m)?)?;
     m.add_function(wrap_pyfunction!(py_ideal_edge_lengths, m)?)?;
     m.add_function(wrap_pyfunction!(py_neighborhood_preservation, m)?)?;
     m.add_function(wrap_pyfunction!(py_node_resolution, m)?)?;
     m.add_function(wrap_pyfunction!(py_stress, m)?)?;
+    m.add_function(wrap_pyfunction!(py_symmetry, m)?)?;
     Ok(())
 }
@@ -0,0 +1,41 @@
+import egraph as eg
+import networkx as nx
+import unittest
+
+
+def create_graph(nx_graph):
+    graph = eg.Graph()
+    indices = {}
+    for u in nx_graph.nodes:
+        indices[u] = graph.add_node(u)
+    for u, v in nx_graph.edges:
+        graph.add_edge(indices[u], indices[v], (u, v))
+    return indices, graph
+
+
+class TestCentrality(unittest.TestCase):
+    @classmethod
+    def setUpClass(cls):
+        cls._nx_graph = nx.les_miserables_graph()
+        cls._indices, cls._graph = create_graph(cls._nx_graph)
+
+    def check(self, expected, actual):
+        for u, i in self._indices.items():
+            self.assertAlmostEqual(
+                expected[u], actual[i], delta=1e-3 * max(1, expected[u]))
+
+    def test_betweenness_centrality(self):
+        expected = nx.betweenness_centrality(self._nx_graph, normalized=False)
+        self.check(expected, eg.betweenness_centrality(self._graph))
+
+    def test_closeness_centrality(self):
+        expected = nx.closeness_centrality(self._nx_graph)
+        self.check(expected, eg.closeness_centrality(self._graph))
+
+    def test_pagerank(self):
+        expected = nx.pagerank(self._nx_graph, alpha=0.85, weight=None)
+        self.check(expected, eg.pagerank(self._graph, 0.85, 100))
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,27 @@
+import egraph as eg
+import networkx as nx
+import unittest
+
+
+def create_graph(nx_graph):
+    graph = eg.Graph()
+    indices = {}
+    for u in nx_graph.nodes:
+        indices[u] = graph.add_node(u)
+    for u, v in nx_graph.edges:
+        graph.add_edge(indices[u], indices[v], (u, v))
+    return indices, graph
+
+
+class TestClustering(unittest.TestCase):
+    def test_label_propagation(self):
+        _, graph = create_graph(nx.karate_club_graph())
+        communities = eg.label_propagation(graph, 100, False, 0)
+        self.assertEqual(len(communities), graph.node_count())
+        self.assertEqual(communities, eg.label_propagation(graph, 100, False, 0))
+        communities = eg.label_propagation(graph, 10, True, 0)
+        self.assertEqual(len(communities), graph.node_count())
+
+
+if __name__ == '__main__':
+    unittest.main()
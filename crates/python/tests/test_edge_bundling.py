@@ -0,0 +1,34 @@
+import egraph as eg
+import networkx as nx
+import unittest
+
+
+class TestEdgeBundling(unittest.TestCase):
+    def test_fdeb(self):
+        nx_graph = nx.les_miserables_graph()
+        graph = eg.Graph()
+        indices = {}
+        for u in nx_graph.nodes:
+            indices[u] = graph.add_node(u)
+        for u, v in nx_graph.edges:
+            graph.add_edge(indices[u], indices[v], (u, v))
+        drawing = eg.DrawingEuclidean2d.initial_placement(graph)
+        bends = eg.fdeb(graph, drawing)
+        self.assertEqual(len(bends), graph.edge_count())
+        for e in graph.edge_indices():
+            u, v = graph.edge_endpoints(e)
+            points = bends[e]
+            self.assertEqual(points[0], (drawing.x(u), drawing.y(u)))
+            self.assertEqual(points[-1], (drawing.x(v), drawing.y(v)))
+
+    def test_fdeb_missing_position(self):
+        graph = eg.Graph()
+        u = graph.add_node(0)
+        drawing = eg.DrawingEuclidean2d.initial_placement(graph)
+        graph.add_edge(u, graph.add_node(1), None)
+        with self.assertRaises(ValueError):
+            eg.fdeb(graph, drawing)
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,27 @@
+import egraph as eg
+import unittest
+
+
+class TestGenerators(unittest.TestCase):
+    def test_deterministic_generators(self):
+        graph = eg.complete_graph(5)
+        self.assertEqual((graph.node_count(), graph.edge_count()), (5, 10))
+        graph = eg.grid_graph(3, 4)
+        self.assertEqual((graph.node_count(), graph.edge_count()), (12, 17))
+        graph = eg.torus_graph(3, 4)
+        self.assertEqual((graph.node_count(), graph.edge_count()), (12, 24))
+        graph = eg.tree_graph(2, 3)
+        self.assertEqual((graph.node_count(), graph.edge_count()), (15, 14))
+
+    def test_random_generators(self):
+        rng = eg.Rng.seed_from(0)
+        graph = eg.erdos_renyi(20, 1., rng)
+        self.assertEqual(graph.edge_count(), 190)
+        graph = eg.watts_strogatz(20, 4, 0.3, rng)
+        self.assertEqual((graph.node_count(), graph.edge_count()), (20, 40))
+        graph = eg.barabasi_albert(30, 2, rng)
+        self.assertEqual((graph.node_count(), graph.edge_count()), (30, 56))
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -74,6 +74,15 @@ class TestQualityMetrics(unittest.TestCase):
             drawing = mds.run(3)
             check_drawing_3d(graph, drawing)
 
+    def test_embedding(self):
+        for graph in self._graphs:
+            n = len(graph.node_indices())
+            x = eg.ClassicalMds(graph, lambda _: 30).embedding(5)
+            self.assertEqual(x.shape, (n, 5))
+            pivot = graph.node_indices()[:10]
+            x = eg.PivotMds(graph, lambda _: 30, pivot).embedding(5)
+            self.assertEqual(x.shape, (n, 5))
+
 
 if __name__ == '__main__':
     unittest.main()
@@ -0,0 +1,35 @@
+import egraph as eg
+import networkx as nx
+import unittest
+
+
+class TestNetworkX(unittest.TestCase):
+    def test_graph_round_trip(self):
+        for nx_graph in [nx.les_miserables_graph(), nx.gn_graph(50, seed=0)]:
+            graph = eg.Graph.from_networkx(nx_graph)
+            self.assertEqual(graph.node_count(), nx_graph.number_of_nodes())
+            self.assertEqual(graph.edge_count(), nx_graph.number_of_edges())
+            result = graph.to_networkx()
+            self.assertEqual(result.is_directed(), nx_graph.is_directed())
+            self.assertEqual(set(result.nodes), set(nx_graph.nodes))
+            self.assertEqual(set(result.edges), set(nx_graph.edges))
+
+    def test_edge_data(self):
+        nx_graph = nx.les_miserables_graph()
+        result = eg.Graph.from_networkx(nx_graph).to_networkx()
+        for u, v, data in nx_graph.edges(data=True):
+            self.assertEqual(result.edges[u, v], data)
+
+    def test_pos(self):
+        nx_graph = nx.les_miserables_graph()
+        graph = eg.Graph.from_networkx(nx_graph)
+        pos = {u: (float(i), float(2 * i)) for i, u in enumerate(nx_graph.nodes)}
+        drawing = eg.DrawingEuclidean2d.from_pos(graph, pos)
+        for u in graph.node_indices():
+            self.assertEqual(
+                (drawing.x(u), drawing.y(u)), pos[graph.node_weight(u)])
+        self.assertEqual(drawing.to_pos(graph), pos)
+
+
+if __name__ == '__main__':
+    unittest.main()
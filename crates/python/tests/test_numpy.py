@@ -0,0 +1,49 @@
+import egraph as eg
+import networkx as nx
+import numpy as np
+import unittest
+
+
+class TestNumpy(unittest.TestCase):
+    @classmethod
+    def setUpClass(cls):
+        cls._graph = eg.Graph.from_networkx(nx.les_miserables_graph())
+
+    def test_drawing_euclidean_2d_coords(self):
+        drawing = eg.DrawingEuclidean2d.initial_placement(self._graph)
+        coords = drawing.coords()
+        self.assertEqual(coords.shape, (self._graph.node_count(), 2))
+        for u in self._graph.node_indices():
+            self.assertEqual(coords[u, 0], drawing.x(u))
+            self.assertEqual(coords[u, 1], drawing.y(u))
+        drawing.set_coords(coords * 2)
+        for u in self._graph.node_indices():
+            self.assertAlmostEqual(drawing.x(u), 2 * coords[u, 0], places=4)
+        with self.assertRaises(ValueError):
+            drawing.set_coords(np.zeros((2, 2), dtype=np.float32))
+
+    def test_drawing_euclidean_coords(self):
+        drawing = eg.ClassicalMds(self._graph, lambda _: 30).run(3)
+        coords = drawing.coords()
+        self.assertEqual(coords.shape, (self._graph.node_count(), 3))
+        drawing.set_coords(np.ones(coords.shape, dtype=np.float32))
+        self.assertEqual(drawing.get(0, 2), 1.)
+
+    def test_empty_drawing_coords(self):
+        graph = eg.Graph()
+        coords = eg.DrawingEuclidean2d.initial_placement(graph).coords()
+        self.assertEqual(coords.shape, (0, 2))
+        self.assertEqual(coords.dtype, np.float32)
+
+    def test_distance_matrix_to_numpy(self):
+        d = eg.all_sources_bfs(self._graph, 1.)
+        matrix = d.to_numpy()
+        n = self._graph.node_count()
+        self.assertEqual(matrix.shape, (n, n))
+        for u in range(n):
+            for v in range(n):
+                self.assertEqual(matrix[u, v], d.get(u, v))
+
+
+if __name__ == '__main__':
+    unittest.main()
@@ -0,0 +1,38 @@
+import egraph as eg
+import math
+import networkx as nx
+import unittest
+
+
+def create_graph(nx_graph):
+    graph = eg.Graph()
+    indices = {}
+    for u in nx_graph.nodes:
+        indices[u] = graph.add_node(u)
+    for u, v in nx_graph.edges:
+        graph.add_edge(indices[u], indices[v], (u, v))
+    return indices, graph
+
+
+class TestOverwrapRemoval(unittest.TestCase):
+    def test_prism(self):
+        _, graph = create_graph(nx.les_miserables_graph())
+        drawing = eg.DrawingEuclidean2d.initial_placement(graph)
+        prism = eg.Prism(graph, lambda _: 10)
+        prism.apply(drawing)
+        for u in graph.node_indices():
+            for v in graph.node_indices():
+                if u < v:
+                    d = math.hypot(drawing.x(u) - drawing.x(v),
+                                   drawing.y(u) - drawing.y(v))
+                    self.assertGreater(d, 19.9)
+
+    def test_prism_digraph(self):
+        graph = eg.DiGraph()
+        graph.add_node(0)
+        with self.assertRaises(ValueError):
+            eg.Prism(graph, lambda _: 10)
+
+
+if __name__ == '__main__':
+    unittest.main()
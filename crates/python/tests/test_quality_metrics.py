@@ -92,6 +92,26 @@ class TestQualityMetrics(unittest.TestCase):
             assert (math.isfinite(
                 eg.crossing_number_with_crossing_edges(crossings)))
 
+    def test_edge_length_ratio(self):
+        for (graph, drawing, _) in self._graphs:
+            assert (eg.edge_length_ratio(graph, drawing) >= 1)
+        for (graph, drawing, _) in self._torus_graphs:
+            assert (eg.edge_length_ratio(graph, drawing) >= 1)
+        for (graph, _, _) in self._graphs:
+            drawings = [
+                eg.ClassicalMds(graph, lambda _: 30).run(3),
+                eg.DrawingHyperbolic2d.initial_placement(graph),
+                eg.DrawingSpherical2d.initial_placement(graph),
+            ]
+            for drawing in drawings:
+                assert (eg.edge_length_ratio(graph, drawing) >= 1)
+
+    def test_edge_length_uniformity(self):
+        for (graph, drawing, _) in self._graphs:
+            assert (eg.edge_length_uniformity(graph, drawing) >= 0)
+        for (graph, drawing, _) in self._torus_graphs:
+            assert (eg.edge_length_uniformity(graph, drawing) >= 0)
+
     def test_gabriel_graph_property(self):
         for (graph, drawing, _) in self._graphs:
             assert (math.isfinite(eg.gabriel_graph_property(graph, drawing)))
@@ -104,6 +124,20 @@ class TestQualityMetrics(unittest.TestCase):
             assert (math.isfinite(eg.ideal_edge_lengths(
                 graph, drawing, distance_matrix)))
 
+    def test_missing_position(self):
+        graph = eg.Graph()
+        u = graph.add_node(0)
+        v = graph.add_node(1)
+        drawing = eg.DrawingEuclidean2d.initial_placement(graph)
+        graph.add_edge(u, graph.add_node(2), None)
+        graph.add_edge(u, v, None)
+        with self.assertRaises(ValueError):
+            eg.crossing_number(graph, drawing)
+        with self.assertRaises(ValueError):
+            eg.angular_resolution(graph, drawing)
+        with self.assertRaises(ValueError):
+            eg.gabriel_graph_property(graph, drawing)
+
     def test_neighborhood_preservation(self):
         for (graph, drawing, _) in self._graphs:
             assert (math.isfinite(eg.neighborhood_preservation(graph, drawing)))
@@ -120,6 +154,10 @@ class TestQualityMetrics(unittest.TestCase):
         for (_, drawing, _) in self._torus_graphs:
             assert (math.isfinite(eg.stress(drawing, distance_matrix)))
 
+    def test_symmetry(self):
+        for (graph, drawing, _) in self._graphs:
+            assert (0 <= eg.symmetry(graph, drawing) <= 1)
+
 
 if __name__ == '__main__':
     unittest.main()
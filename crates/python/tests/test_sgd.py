@@ -70,6 +70,20 @@ class TestSgd(unittest.TestCase):
                 scheduler.run(step)
                 check_drawing_2d(graph, drawing)
 
+    def test_sparse_sgd_update_edges(self):
+        graph = eg.Graph()
+        nodes = [graph.add_node(i) for i in range(20)]
+        for i in range(1, 20):
+            graph.add_edge(nodes[i - 1], nodes[i], None)
+        pivot = [nodes[0], nodes[7], nodes[15]]
+        sgd = eg.SparseSgd.new_with_pivot(graph, lambda _: 1, pivot)
+        graph.add_edge(nodes[2], nodes[12], None)
+        sgd.update_edges(graph, lambda _: 1, [(nodes[2], nodes[12])])
+        expected = eg.SparseSgd.new_with_pivot(graph, lambda _: 1, pivot)
+        drawing = eg.DrawingEuclidean2d.initial_placement(graph)
+        self.assertAlmostEqual(sgd.stress(drawing), expected.stress(drawing),
+                               places=3)
+
     def test_distance_adjusted_full_sgd(self):
         for graph in self._graphs:
             for scheduler_accessor in self._schedulers:
@@ -162,6 +176,79 @@ class TestSgd(unittest.TestCase):
                 scheduler.run(step)
                 check_drawing_3d(graph, drawing)
 
+    def test_full_sgd_spherical(self):
+        for graph in self._graphs:
+            drawing = eg.DrawingSpherical2d.initial_placement(graph)
+            rng = eg.Rng.seed_from(0)
+            sgd = eg.FullSgd(graph, lambda _: 0.1)
+            scheduler = sgd.scheduler(15, 0.1)
+
+            def step(eta):
+                sgd.shuffle(rng)
+                sgd.apply(drawing, eta)
+
+            scheduler.run(step)
+            for u in graph.node_indices():
+                assert math.isfinite(drawing.lon(u))
+                assert math.isfinite(drawing.lat(u))
+            for e in graph.edge_indices():
+                u, v = graph.edge_endpoints(e)
+                arc = drawing.edge_arc(u, v, 8)
+                self.assertEqual(arc.shape, (9, 2))
+                arc = drawing.edge_arc_geographic(u, v, 8)
+                self.assertEqual(arc.shape, (9, 2))
+                self.assertTrue(((-180 <= arc[:, 0]) & (arc[:, 0] <= 180)).all())
+                self.assertTrue(((-90 <= arc[:, 1]) & (arc[:, 1] <= 90)).all())
+            coords = drawing.geographic_coords()
+            self.assertEqual(coords.shape, (graph.node_count(), 2))
+
+    def test_scheduler_iterator(self):
+        for graph in self._graphs:
+            for scheduler_accessor in self._schedulers:
+                drawing = eg.DrawingEuclidean2d.initial_placement(graph)
+                rng = eg.Rng.seed_from(0)
+                sgd = eg.FullSgd(graph, lambda _: 30)
+                etas = []
+                for eta in scheduler_accessor(sgd)(15, 0.1):
+                    sgd.shuffle(rng)
+                    sgd.apply(drawing, eta)
+                    etas.append(eta)
+                self.assertEqual(len(etas), 15)
+                check_drawing_2d(graph, drawing)
+
+    def test_run_with_callback(self):
+        for graph in self._graphs:
+            drawing = eg.DrawingEuclidean2d.initial_placement(graph)
+            rng = eg.Rng.seed_from(0)
+            sgd = eg.SparseSgd(graph, lambda _: 30, 50, rng)
+            progress = []
+            sgd.run(drawing, rng, "exponential", 30, 0.1, 10,
+                    lambda i, stress: progress.append((i, stress)))
+            self.assertEqual([i for i, _ in progress], [10, 20, 30])
+            self.assertAlmostEqual(
+                progress[-1][1], sgd.stress(drawing), places=2)
+            check_drawing_2d(graph, drawing)
+
+    def test_constrained_sparse_sgd(self):
+        for graph in self._graphs:
+            drawing = eg.DrawingEuclidean2d.initial_placement(graph)
+            rng = eg.Rng.seed_from(0)
+            sgd = eg.ConstrainedSparseSgd(graph, lambda _: 30, 50, rng)
+            sgd.set_fixed(0)
+            sgd.set_fixed(1, fixed_x=True, fixed_y=False)
+            x0, y0 = drawing.x(0), drawing.y(0)
+            x1 = drawing.x(1)
+            scheduler = sgd.scheduler(15, 0.1)
+
+            def step(eta):
+                sgd.shuffle(rng)
+                sgd.apply(drawing, eta)
+
+            scheduler.run(step)
+            check_drawing_2d(graph, drawing)
+            self.assertEqual((drawing.x(0), drawing.y(0)), (x0, y0))
+            self.assertEqual(drawing.x(1), x1)
+
 
 if __name__ == "__main__":
     unittest.main()
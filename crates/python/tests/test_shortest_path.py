@@ -59,6 +59,20 @@ class TestShortestPath(unittest.TestCase):
         for nx_graph, graph in self._digraphs:
             self.check(nx_graph, eg.warshall_floyd(graph, lambda _: 1))
 
+    def test_dijkstra_with_targets(self):
+        for nx_graph, graph in self._graphs + self._digraphs:
+            nodes = list(nx_graph.nodes)
+            expected = {
+                nodes.index(v): d
+                for v, d in nx.single_source_shortest_path_length(
+                    nx_graph, nodes[0]).items()
+            }
+            targets = sorted(expected)[-2:]
+            actual = eg.dijkstra_with_targets(graph, 0, targets, lambda _: 1)
+            self.assertEqual(sorted(actual), targets)
+            for v in targets:
+                self.assertEqual(actual[v], expected[v])
+
 
 if __name__ == '__main__':
     unittest.main()
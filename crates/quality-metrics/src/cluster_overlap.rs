@@ -0,0 +1,94 @@
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex, MetricEuclidean2d};
+use std::collections::HashMap;
+
+fn union_area(boxes: &[(f32, f32, f32, f32)]) -> f32 {
+    let mut xs = boxes
+        .iter()
+        .flat_map(|&(x0, _, x1, _)| [x0, x1])
+        .collect::<Vec<_>>();
+    xs.sort_by(|a, b| a.total_cmp(b));
+    xs.dedup();
+    let mut area = 0.;
+    for w in xs.windows(2) {
+        let mut intervals = boxes
+            .iter()
+            .filter(|&&(x0, _, x1, _)| x0 <= w[0] && w[1] <= x1)
+            .map(|&(_, y0, _, y1)| (y0, y1))
+            .collect::<Vec<_>>();
+        intervals.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut covered = 0.;
+        let mut end = f32::NEG_INFINITY;
+        for (y0, y1) in intervals {
+            if y1 > end {
+                covered += y1 - y0.max(end);
+                end = y1;
+            }
+        }
+        area += (w[1] - w[0]) * covered;
+    }
+    area
+}
+
+/// Share of the community bounding box area that lies under more than one
+/// box, `(sum of box areas - area of their union) / sum of box areas`. The
+/// value is 0 when the boxes are disjoint and approaches 1 as they pile up.
+pub fn cluster_overlap<N>(
+    drawing: &DrawingEuclidean2d<N, f32>,
+    communities: &HashMap<N, usize>,
+) -> f32
+where
+    N: Copy + DrawingIndex,
+{
+    let mut boxes = HashMap::new();
+    for i in 0..drawing.len() {
+        if let Some(&c) = communities.get(drawing.node_id(i)) {
+            let MetricEuclidean2d(x, y) = *drawing.raw_entry(i);
+            let b = boxes.entry(c).or_insert((x, y, x, y));
+            b.0 = b.0.min(x);
+            b.1 = b.1.min(y);
+            b.2 = b.2.max(x);
+            b.3 = b.3.max(y);
+        }
+    }
+    let boxes = boxes.into_values().collect::<Vec<_>>();
+    let total_area = boxes
+        .iter()
+        .map(|&(x0, y0, x1, y1)| (x1 - x0) * (y1 - y0))
+        .sum::<f32>();
+    if total_area == 0. {
+        return 0.;
+    }
+    (total_area - union_area(&boxes)) / total_area
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_cluster_overlap() {
+        let mut graph = Graph::<(), ()>::new();
+        let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut drawing = DrawingEuclidean2d::new(&graph);
+        let mut communities = HashMap::new();
+        let mut place = |boxes: &[(f32, f32, f32, f32)]| {
+            for (c, &(x0, y0, x1, y1)) in boxes.iter().enumerate() {
+                drawing.set_x(nodes[2 * c], x0);
+                drawing.set_y(nodes[2 * c], y0);
+                drawing.set_x(nodes[2 * c + 1], x1);
+                drawing.set_y(nodes[2 * c + 1], y1);
+                communities.insert(nodes[2 * c], c);
+                communities.insert(nodes[2 * c + 1], c);
+            }
+            cluster_overlap(&drawing, &communities)
+        };
+        let disjoint = [(0., 0., 1., 1.), (2., 0., 3., 1.), (0., 2., 1., 3.)];
+        assert_eq!(place(&disjoint), 0.);
+        let half = [(0., 0., 2., 2.), (1., 0., 3., 2.), (5., 5., 6., 6.)];
+        assert!((place(&half) - 2. / 9.).abs() < 1e-6);
+        let stacked = [(0., 0., 2., 2.), (0., 0., 2., 2.), (0., 0., 2., 2.)];
+        assert!((place(&stacked) - 2. / 3.).abs() < 1e-6);
+        assert!(place(&stacked) <= 1.);
+    }
+}
@@ -0,0 +1,107 @@
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph_drawing::{DrawingEuclidean2d, DrawingIndex};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+fn polyline_length(points: &[(f32, f32)]) -> f32 {
+    points
+        .windows(2)
+        .map(|w| (w[1].0 - w[0].0).hypot(w[1].1 - w[0].1))
+        .sum()
+}
+
+fn edge_lengths<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+    bundled_edges: &HashMap<G::EdgeId, Vec<(f32, f32)>>,
+) -> Vec<(f32, f32)>
+where
+    G: IntoEdgeReferences,
+    G::NodeId: DrawingIndex + Copy,
+    G::EdgeId: Eq + Hash,
+{
+    graph
+        .edge_references()
+        .filter_map(|e| {
+            let (x1, y1) = (drawing.x(e.source())?, drawing.y(e.source())?);
+            let (x2, y2) = (drawing.x(e.target())?, drawing.y(e.target())?);
+            let straight = (x2 - x1).hypot(y2 - y1);
+            let bundled = match bundled_edges.get(&e.id()) {
+                Some(points) => polyline_length(points),
+                None => straight,
+            };
+            Some((straight, bundled))
+        })
+        .collect()
+}
+
+pub fn ink_ratio<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+    bundled_edges: &HashMap<G::EdgeId, Vec<(f32, f32)>>,
+) -> f32
+where
+    G: IntoEdgeReferences,
+    G::NodeId: DrawingIndex + Copy,
+    G::EdgeId: Eq + Hash,
+{
+    let (straight, bundled) = edge_lengths(graph, drawing, bundled_edges)
+        .into_iter()
+        .fold((0., 0.), |(s, b), (ls, lb)| (s + ls, b + lb));
+    if straight > 0. {
+        bundled / straight
+    } else {
+        1.
+    }
+}
+
+pub fn mean_edge_distortion<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+    bundled_edges: &HashMap<G::EdgeId, Vec<(f32, f32)>>,
+) -> f32
+where
+    G: IntoEdgeReferences,
+    G::NodeId: DrawingIndex + Copy,
+    G::EdgeId: Eq + Hash,
+{
+    let ratios = edge_lengths(graph, drawing, bundled_edges)
+        .into_iter()
+        .filter(|&(straight, _)| straight > 0.)
+        .map(|(straight, bundled)| bundled / straight)
+        .collect::<Vec<_>>();
+    if ratios.is_empty() {
+        1.
+    } else {
+        ratios.iter().sum::<f32>() / ratios.len() as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_bundling_metrics() {
+        let mut graph = Graph::new_undirected();
+        let a = graph.add_node(());
+        let b = graph.add_node(());
+        let c = graph.add_node(());
+        let e1 = graph.add_edge(a, b, ());
+        graph.add_edge(b, c, ());
+        let mut drawing = DrawingEuclidean2d::new(&graph);
+        for (u, x, y) in [(a, 0., 0.), (b, 6., 0.), (c, 6., 2.)] {
+            drawing.set_x(u, x);
+            drawing.set_y(u, y);
+        }
+
+        let mut bundled_edges = HashMap::new();
+        assert_eq!(ink_ratio(&graph, &drawing, &bundled_edges), 1.);
+        assert_eq!(mean_edge_distortion(&graph, &drawing, &bundled_edges), 1.);
+
+        bundled_edges.insert(e1, vec![(0., 0.), (3., 4.), (6., 0.)]);
+        assert!((ink_ratio(&graph, &drawing, &bundled_edges) - 12. / 8.).abs() < 1e-6);
+        assert!((mean_edge_distortion(&graph, &drawing, &bundled_edges) - 4. / 3.).abs() < 1e-6);
+    }
+}
@@ -1,6 +1,8 @@
-use crate::edge_angle::edge_angle;
+use crate::{edge_angle::edge_angle, QualityMetricsError};
 use petgraph::visit::{EdgeRef, IntoEdgeReferences};
-use petgraph_drawing::{DrawingEuclidean2d, DrawingIndex, DrawingTorus2d, MetricEuclidean2d};
+use petgraph_drawing::{
+    Drawing, DrawingEuclidean2d, DrawingIndex, DrawingTorus2d, MetricEuclidean2d,
+};
 use std::f32::consts::PI;
 
 fn cross(x11: f32, y11: f32, x12: f32, y12: f32, x21: f32, y21: f32, x22: f32, y22: f32) -> bool {
@@ -19,7 +21,10 @@ fn cross(x11: f32, y11: f32, x12: f32, y12: f32, x21: f32, y21: f32, x22: f32, y
 
 pub type CrossingEdges = Vec<(f32, f32, f32, f32, f32, f32, f32, f32)>;
 
-pub fn crossing_edges<G>(graph: G, drawing: &DrawingEuclidean2d<G::NodeId, f32>) -> CrossingEdges
+pub fn crossing_edges<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+) -> Result<CrossingEdges, QualityMetricsError<G::NodeId>>
 where
     G: IntoEdgeReferences,
     G::NodeId: DrawingIndex,
@@ -28,7 +33,11 @@ where
     for e in graph.edge_references() {
         let u = e.source();
         let v = e.target();
-        for &(p, q) in drawing.edge_segments(u, v).unwrap().iter() {
+        drawing.try_position(u)?;
+        let segments = drawing
+            .edge_segments(u, v)
+            .ok_or(QualityMetricsError::MissingPosition(v))?;
+        for &(p, q) in segments.iter() {
             let MetricEuclidean2d(x1, y1) = p;
             let MetricEuclidean2d(x2, y2) = q;
             edges.push((u, v, x1, y1, x2, y2));
@@ -54,10 +63,13 @@ where
             }
         }
     }
-    crossing_edges
+    Ok(crossing_edges)
 }
 
-pub fn crossing_edges_torus<G>(graph: G, drawing: &DrawingTorus2d<G::NodeId, f32>) -> CrossingEdges
+pub fn crossing_edges_torus<G>(
+    graph: G,
+    drawing: &DrawingTorus2d<G::NodeId, f32>,
+) -> Result<CrossingEdges, QualityMetricsError<G::NodeId>>
 where
     G: IntoEdgeReferences,
     G::NodeId: DrawingIndex,
@@ -66,7 +78,11 @@ where
     for e in graph.edge_references() {
         let u = e.source();
         let v = e.target();
-        for &(p, q) in drawing.edge_segments(u, v).unwrap().iter() {
+        drawing.try_position(u)?;
+        let segments = drawing
+            .edge_segments(u, v)
+            .ok_or(QualityMetricsError::MissingPosition(v))?;
+        for &(p, q) in segments.iter() {
             edges.push((u, v, p.0 .0, p.1 .0, q.0 .0, q.1 .0));
         }
     }
@@ -90,29 +106,53 @@ where
             }
         }
     }
-    crossing_edges
+    Ok(crossing_edges)
 }
 
-pub fn crossing_number<G>(graph: G, drawing: &DrawingEuclidean2d<G::NodeId, f32>) -> f32
+pub fn crossing_number<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+) -> Result<f32, QualityMetricsError<G::NodeId>>
 where
     G: IntoEdgeReferences,
     G::NodeId: DrawingIndex,
 {
-    let crossing_edges = crossing_edges(graph, drawing);
-    crossing_number_with_crossing_edges(&crossing_edges)
+    let crossing_edges = crossing_edges(graph, drawing)?;
+    Ok(crossing_number_with_crossing_edges(&crossing_edges))
 }
 
 pub fn crossing_number_with_crossing_edges(crossing_edges: &CrossingEdges) -> f32 {
     crossing_edges.len() as f32
 }
 
-pub fn crossing_angle<G>(graph: G, drawing: &DrawingEuclidean2d<G::NodeId, f32>) -> f32
+pub fn crossing_points_with_crossing_edges(crossing_edges: &CrossingEdges) -> Vec<(f32, f32)> {
+    crossing_edges
+        .iter()
+        .map(|&(x11, y11, x12, y12, x21, y21, x22, y22)| {
+            let dx1 = x12 - x11;
+            let dy1 = y12 - y11;
+            let dx2 = x22 - x21;
+            let dy2 = y22 - y21;
+            let d = dx1 * dy2 - dy1 * dx2;
+            if d == 0. {
+                return ((x11 + x12) / 2., (y11 + y12) / 2.);
+            }
+            let t = ((x21 - x11) * dy2 - (y21 - y11) * dx2) / d;
+            (x11 + t * dx1, y11 + t * dy1)
+        })
+        .collect()
+}
+
+pub fn crossing_angle<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+) -> Result<f32, QualityMetricsError<G::NodeId>>
 where
     G: IntoEdgeReferences,
     G::NodeId: DrawingIndex,
 {
-    let crossing_edges = crossing_edges(graph, drawing);
-    crossing_angle_with_crossing_edges(&crossing_edges)
+    let crossing_edges = crossing_edges(graph, drawing)?;
+    Ok(crossing_angle_with_crossing_edges(&crossing_edges))
 }
 
 pub fn crossing_angle_with_crossing_edges(crossing_edges: &CrossingEdges) -> f32 {
@@ -125,3 +165,62 @@ pub fn crossing_angle_with_crossing_edges(crossing_edges: &CrossingEdges) -> f32
     }
     s
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_crossing_points() {
+        let crossing_edges = vec![
+            (0., 0., 2., 2., 0., 2., 2., 0.),
+            (0., 1., 4., 1., 1., 0., 1., 4.),
+        ];
+        let points = crossing_points_with_crossing_edges(&crossing_edges);
+        assert_eq!(points, vec![(1., 1.), (1., 1.)]);
+    }
+
+    proptest! {
+        #[test]
+        fn test_cross_symmetric(
+            p in proptest::array::uniform8(-1e3f32..1e3)
+        ) {
+            let [x11, y11, x12, y12, x21, y21, x22, y22] = p;
+            prop_assert_eq!(
+                cross(x11, y11, x12, y12, x21, y21, x22, y22),
+                cross(x21, y21, x22, y22, x11, y11, x12, y12)
+            );
+        }
+
+        #[test]
+        fn test_crossing_edges_partial_drawing(
+            coordinates in proptest::collection::vec((-1e3f32..1e3, -1e3f32..1e3), 1..20),
+            edges in proptest::collection::vec((0usize..20, 0usize..20), 0..40)
+        ) {
+            let n = coordinates.len();
+            let mut graph = Graph::new_undirected();
+            let nodes = (0..20).map(|_| graph.add_node(())).collect::<Vec<_>>();
+            for &(u, v) in &edges {
+                graph.add_edge(nodes[u], nodes[v], ());
+            }
+            let mut drawing = DrawingEuclidean2d::from_node_indices(&nodes[..n]);
+            for (i, &(x, y)) in coordinates.iter().enumerate() {
+                drawing.set_x(nodes[i], x);
+                drawing.set_y(nodes[i], y);
+            }
+            let m = edges.len();
+            match crossing_edges(&graph, &drawing) {
+                Ok(crossings) => {
+                    prop_assert!(edges.iter().all(|&(u, v)| u < n && v < n));
+                    prop_assert!(crossings.len() <= m * m);
+                }
+                Err(QualityMetricsError::MissingPosition(u)) => {
+                    prop_assert!(u.index() >= n);
+                }
+                Err(e) => prop_assert!(false, "unexpected error {:?}", e),
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph_drawing::{Delta, Drawing, DrawingIndex, DrawingValue, Metric};
+
+pub fn edge_length_uniformity<G, Diff, D, N, M, S>(graph: G, drawing: &D) -> S
+where
+    G: IntoEdgeReferences<NodeId = N>,
+    D: Drawing<Item = M, Index = N>,
+    Diff: Delta<S = S>,
+    N: Copy + DrawingIndex,
+    M: Copy + Metric<D = Diff>,
+    S: DrawingValue,
+{
+    let mut lengths = vec![];
+    for e in graph.edge_references() {
+        let u = e.source();
+        let v = e.target();
+        if u == v || drawing.position(u).is_none() || drawing.position(v).is_none() {
+            continue;
+        }
+        lengths.push(drawing.delta(drawing.index(u), drawing.index(v)).norm());
+    }
+    if lengths.is_empty() {
+        return S::zero();
+    }
+    let m = S::from_usize(lengths.len()).unwrap();
+    let mean = lengths.iter().fold(S::zero(), |s, &l| s + l) / m;
+    if mean == S::zero() {
+        return S::zero();
+    }
+    let variance = lengths
+        .iter()
+        .fold(S::zero(), |s, &l| s + (l - mean) * (l - mean))
+        / m;
+    variance.sqrt() / mean
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+    use petgraph_drawing::{DrawingEuclidean2d, DrawingTorus2d};
+
+    #[test]
+    fn test_edge_length_uniformity() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..3).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        graph.add_edge(nodes[0], nodes[1], ());
+        graph.add_edge(nodes[1], nodes[2], ());
+        graph.add_edge(nodes[2], nodes[2], ());
+        let mut drawing = DrawingEuclidean2d::<_, f32>::new(&graph);
+        for (&u, (x, y)) in nodes.iter().zip([(0., 0.), (1., 0.), (1., 3.)]) {
+            drawing.set_x(u, x);
+            drawing.set_y(u, y);
+        }
+        assert!((edge_length_uniformity(&graph, &drawing) - 0.5).abs() < 1e-6);
+
+        for (&u, (x, y)) in nodes.iter().zip([(0., 0.), (2., 0.), (2., 2.)]) {
+            drawing.set_x(u, x);
+            drawing.set_y(u, y);
+        }
+        assert!(edge_length_uniformity(&graph, &drawing).abs() < 1e-6);
+
+        let mut drawing = DrawingTorus2d::<_, f32>::new(&graph);
+        for (&u, (x, y)) in nodes.iter().zip([(0.1, 0.5), (0.9, 0.5), (0.9, 0.1)]) {
+            drawing.set_x(u, x);
+            drawing.set_y(u, y);
+        }
+        assert!((edge_length_uniformity(&graph, &drawing) - 1. / 3.).abs() < 1e-5);
+
+        let graph = Graph::<(), ()>::new();
+        let drawing = DrawingEuclidean2d::<_, f32>::new(&graph);
+        assert_eq!(edge_length_uniformity(&graph, &drawing), 0.);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::QualityMetric;
+use petgraph_drawing::MissingPosition;
+use std::fmt;
+
+/// Error returned when a metric cannot be computed from its inputs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QualityMetricsError<N> {
+    /// The node has no position in the drawing.
+    MissingPosition(N),
+    /// The distance matrix has no entry for the pair of nodes.
+    MissingDistance(N, N),
+    /// The distance between the pair of distinct nodes is zero, so the
+    /// relative error of their edge length is undefined.
+    ZeroDistance(N, N),
+    /// The metric is not defined for the kind of drawing it was requested on.
+    UnsupportedMetric(QualityMetric),
+}
+
+impl<N> From<MissingPosition<N>> for QualityMetricsError<N> {
+    fn from(MissingPosition(u): MissingPosition<N>) -> Self {
+        QualityMetricsError::MissingPosition(u)
+    }
+}
+
+impl<N> fmt::Display for QualityMetricsError<N>
+where
+    N: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QualityMetricsError::MissingPosition(u) => {
+                write!(f, "node {:?} has no position in the drawing", u)
+            }
+            QualityMetricsError::MissingDistance(u, v) => {
+                write!(f, "no distance between nodes {:?} and {:?}", u, v)
+            }
+            QualityMetricsError::ZeroDistance(u, v) => {
+                write!(f, "distance between nodes {:?} and {:?} is zero", u, v)
+            }
+            QualityMetricsError::UnsupportedMetric(metric) => {
+                write!(
+                    f,
+                    "metric {} is not supported for this drawing",
+                    metric.name()
+                )
+            }
+        }
+    }
+}
+
+impl<N> std::error::Error for QualityMetricsError<N> where N: fmt::Debug {}
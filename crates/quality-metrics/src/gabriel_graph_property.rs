@@ -1,7 +1,11 @@
+use crate::QualityMetricsError;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences};
 use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex, MetricEuclidean2d};
 
-pub fn gabriel_graph_property<G>(graph: G, drawing: &DrawingEuclidean2d<G::NodeId, f32>) -> f32
+pub fn gabriel_graph_property<G>(
+    graph: G,
+    drawing: &DrawingEuclidean2d<G::NodeId, f32>,
+) -> Result<f32, QualityMetricsError<G::NodeId>>
 where
     G: IntoEdgeReferences,
     G::NodeId: DrawingIndex,
@@ -11,8 +15,8 @@ where
     for e in graph.edge_references() {
         let u = e.source();
         let v = e.target();
-        let MetricEuclidean2d(x1, y1) = drawing.position(u).unwrap();
-        let MetricEuclidean2d(x2, y2) = drawing.position(v).unwrap();
+        let MetricEuclidean2d(x1, y1) = drawing.try_position(u)?;
+        let MetricEuclidean2d(x2, y2) = drawing.try_position(v)?;
         let cx = (x1 + x2) / 2.;
         let cy = (y1 + y2) / 2.;
         let r = (x1 - x2).hypot(y1 - y2) / 2.;
@@ -22,5 +26,5 @@ where
                 .powi(2);
         }
     }
-    s
+    Ok(s)
 }
@@ -1,12 +1,15 @@
+use crate::QualityMetricsError;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences};
 use petgraph_algorithm_shortest_path::{DistanceMatrix, FullDistanceMatrix};
 use petgraph_drawing::{Delta, Drawing, DrawingIndex, DrawingValue, Metric};
 
+/// Sum of the squared relative errors between the edge lengths in `drawing`
+/// and the distances in `d`. Self loops are ignored.
 pub fn ideal_edge_lengths<G, Diff, D, N, M, S>(
     graph: G,
     drawing: &D,
     d: &FullDistanceMatrix<N, S>,
-) -> S
+) -> Result<S, QualityMetricsError<N>>
 where
     G: IntoEdgeReferences<NodeId = N>,
     D: Drawing<Item = M, Index = N>,
@@ -19,9 +22,19 @@ where
     for e in graph.edge_references() {
         let u = e.source();
         let v = e.target();
+        if u == v {
+            continue;
+        }
+        drawing.try_position(u)?;
+        drawing.try_position(v)?;
+        let l = d
+            .get(u, v)
+            .ok_or(QualityMetricsError::MissingDistance(u, v))?;
+        if l == S::zero() {
+            return Err(QualityMetricsError::ZeroDistance(u, v));
+        }
         let delta = drawing.delta(drawing.index(u), drawing.index(v));
-        let l = d.get(u, v).unwrap();
         s += ((delta.norm() - l) / l).powi(2);
     }
-    s
+    Ok(s)
 }
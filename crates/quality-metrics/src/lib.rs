@@ -1,28 +1,64 @@
+//! ```
+//! use petgraph::Graph;
+//! use petgraph_algorithm_shortest_path::warshall_floyd;
+//! use petgraph_drawing::DrawingEuclidean2d;
+//! use petgraph_quality_metrics::{quality_metrics, QualityMetric};
+//!
+//! let mut graph = Graph::new_undirected();
+//! let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+//! for i in 0..4 {
+//!     graph.add_edge(nodes[i], nodes[(i + 1) % 4], ());
+//! }
+//! let drawing = DrawingEuclidean2d::<_, f32>::initial_placement(&graph);
+//! let d = warshall_floyd(&graph, &mut |_| 1.);
+//! let metrics = quality_metrics(&graph, &drawing, &d).unwrap();
+//! assert_eq!(metrics.len(), QualityMetric::all().len());
+//! for (_, value) in metrics {
+//!     assert!(value.is_finite());
+//! }
+//! ```
 mod angular_resolution;
 mod aspect_ratio;
+mod cluster_overlap;
 mod edge_angle;
+mod edge_bundling;
 mod edge_crossings;
+mod edge_length_ratio;
+mod edge_length_uniformity;
+mod error;
 mod gabriel_graph_property;
 mod ideal_edge_lengths;
 mod neighborhood_preservation;
 mod node_resolution;
+mod silhouette;
+mod space_utilization;
 mod stress;
+mod symmetry;
 
 use petgraph::visit::{IntoEdgeReferences, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
 use petgraph_algorithm_shortest_path::FullDistanceMatrix;
-use petgraph_drawing::{DrawingEuclidean2d, DrawingIndex};
+use petgraph_drawing::{DrawingEuclidean2d, DrawingIndex, DrawingTorus2d};
 
 pub use angular_resolution::angular_resolution;
 pub use aspect_ratio::aspect_ratio;
+pub use cluster_overlap::cluster_overlap;
+pub use edge_bundling::{ink_ratio, mean_edge_distortion};
 pub use edge_crossings::{
     crossing_angle, crossing_angle_with_crossing_edges, crossing_edges, crossing_edges_torus,
-    crossing_number, crossing_number_with_crossing_edges, CrossingEdges,
+    crossing_number, crossing_number_with_crossing_edges, crossing_points_with_crossing_edges,
+    CrossingEdges,
 };
+pub use edge_length_ratio::edge_length_ratio;
+pub use edge_length_uniformity::edge_length_uniformity;
+pub use error::QualityMetricsError;
 pub use gabriel_graph_property::gabriel_graph_property;
 pub use ideal_edge_lengths::ideal_edge_lengths;
-pub use neighborhood_preservation::neighborhood_preservation;
+pub use neighborhood_preservation::{neighborhood_preservation, neighborhood_preservation_torus};
 pub use node_resolution::node_resolution;
+pub use silhouette::silhouette;
+pub use space_utilization::{convex_hull_area_ratio, density_entropy, normalized_area};
 pub use stress::stress;
+pub use symmetry::symmetry;
 
 #[derive(Clone, Copy)]
 pub enum Sense {
@@ -30,7 +66,7 @@ pub enum Sense {
     Minimize,
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum QualityMetric {
     Stress,
     IdealEdgeLengths,
@@ -41,6 +77,9 @@ pub enum QualityMetric {
     AngularResolution,
     NodeResolution,
     GabrielGraphProperty,
+    EdgeLengthRatio,
+    EdgeLengthUniformity,
+    Symmetry,
 }
 
 impl QualityMetric {
@@ -55,9 +94,54 @@ impl QualityMetric {
             QualityMetric::AngularResolution => "angular-resolution".into(),
             QualityMetric::NodeResolution => "node-resolution".into(),
             QualityMetric::GabrielGraphProperty => "gabriel-graph-property".into(),
+            QualityMetric::EdgeLengthRatio => "edge-length-ratio".into(),
+            QualityMetric::EdgeLengthUniformity => "edge-length-uniformity".into(),
+            QualityMetric::Symmetry => "symmetry".into(),
         }
     }
 
+    pub fn from_name(name: &str) -> Option<QualityMetric> {
+        QualityMetric::all()
+            .into_iter()
+            .chain(QualityMetric::opt_in())
+            .find(|metric| metric.name() == name)
+    }
+
+    pub fn all() -> Vec<QualityMetric> {
+        vec![
+            QualityMetric::Stress,
+            QualityMetric::IdealEdgeLengths,
+            QualityMetric::NeighborhoodPreservation,
+            QualityMetric::CrossingNumber,
+            QualityMetric::CrossingAngle,
+            QualityMetric::AspectRatio,
+            QualityMetric::AngularResolution,
+            QualityMetric::NodeResolution,
+            QualityMetric::GabrielGraphProperty,
+        ]
+    }
+
+    /// Metrics that are not part of [`QualityMetric::all`] and are only
+    /// computed when requested explicitly as targets.
+    pub fn opt_in() -> Vec<QualityMetric> {
+        vec![
+            QualityMetric::EdgeLengthRatio,
+            QualityMetric::EdgeLengthUniformity,
+            QualityMetric::Symmetry,
+        ]
+    }
+
+    pub fn torus() -> Vec<QualityMetric> {
+        vec![
+            QualityMetric::Stress,
+            QualityMetric::IdealEdgeLengths,
+            QualityMetric::NeighborhoodPreservation,
+            QualityMetric::CrossingNumber,
+            QualityMetric::CrossingAngle,
+            QualityMetric::NodeResolution,
+        ]
+    }
+
     pub fn sense(&self) -> Sense {
         match self {
             QualityMetric::NeighborhoodPreservation => Sense::Maximize,
@@ -65,36 +149,24 @@ impl QualityMetric {
             QualityMetric::AspectRatio => Sense::Maximize,
             QualityMetric::AngularResolution => Sense::Maximize,
             QualityMetric::NodeResolution => Sense::Maximize,
+            QualityMetric::Symmetry => Sense::Maximize,
             _ => Sense::Minimize,
         }
     }
 }
 
+pub type QualityMetrics = Vec<(QualityMetric, f32)>;
+
 pub fn quality_metrics<G>(
     graph: G,
     drawing: &DrawingEuclidean2d<G::NodeId, f32>,
     d: &FullDistanceMatrix<G::NodeId, f32>,
-) -> Vec<(QualityMetric, f32)>
+) -> Result<QualityMetrics, QualityMetricsError<G::NodeId>>
 where
     G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
     G::NodeId: DrawingIndex,
 {
-    quality_metrics_with_targets(
-        graph,
-        drawing,
-        d,
-        &vec![
-            QualityMetric::Stress,
-            QualityMetric::IdealEdgeLengths,
-            QualityMetric::NeighborhoodPreservation,
-            QualityMetric::CrossingNumber,
-            QualityMetric::CrossingAngle,
-            QualityMetric::AspectRatio,
-            QualityMetric::AngularResolution,
-            QualityMetric::NodeResolution,
-            QualityMetric::GabrielGraphProperty,
-        ],
-    )
+    quality_metrics_with_targets(graph, drawing, d, &QualityMetric::all())
 }
 
 pub fn quality_metrics_with_targets<G>(
@@ -102,18 +174,18 @@ pub fn quality_metrics_with_targets<G>(
     drawing: &DrawingEuclidean2d<G::NodeId, f32>,
     d: &FullDistanceMatrix<G::NodeId, f32>,
     targets: &[QualityMetric],
-) -> Vec<(QualityMetric, f32)>
+) -> Result<QualityMetrics, QualityMetricsError<G::NodeId>>
 where
     G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
     G::NodeId: DrawingIndex,
 {
-    let crossing_edges = crossing_edges(graph, drawing);
+    let crossing_edges = crossing_edges(graph, drawing)?;
     targets
         .iter()
         .map(|&t| {
             let v = match t {
                 QualityMetric::Stress => stress(drawing, d),
-                QualityMetric::IdealEdgeLengths => ideal_edge_lengths(graph, drawing, d),
+                QualityMetric::IdealEdgeLengths => ideal_edge_lengths(graph, drawing, d)?,
                 QualityMetric::NeighborhoodPreservation => {
                     neighborhood_preservation(graph, drawing)
                 }
@@ -122,11 +194,178 @@ where
                 }
                 QualityMetric::CrossingAngle => crossing_angle_with_crossing_edges(&crossing_edges),
                 QualityMetric::AspectRatio => aspect_ratio(drawing),
-                QualityMetric::AngularResolution => angular_resolution(graph, drawing),
+                QualityMetric::AngularResolution => angular_resolution(graph, drawing)?,
+                QualityMetric::NodeResolution => node_resolution(drawing),
+                QualityMetric::GabrielGraphProperty => gabriel_graph_property(graph, drawing)?,
+                QualityMetric::EdgeLengthRatio => edge_length_ratio(graph, drawing),
+                QualityMetric::EdgeLengthUniformity => edge_length_uniformity(graph, drawing),
+                QualityMetric::Symmetry => symmetry(graph, drawing),
+            };
+            Ok((t, v))
+        })
+        .collect()
+}
+
+pub fn quality_metrics_batch<G>(
+    graph: G,
+    drawings: &[DrawingEuclidean2d<G::NodeId, f32>],
+    d: &FullDistanceMatrix<G::NodeId, f32>,
+    targets: &[QualityMetric],
+) -> Result<Vec<QualityMetrics>, QualityMetricsError<G::NodeId>>
+where
+    G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: DrawingIndex,
+{
+    drawings
+        .iter()
+        .map(|drawing| quality_metrics_with_targets(graph, drawing, d, targets))
+        .collect()
+}
+
+pub fn quality_metrics_torus<G>(
+    graph: G,
+    drawing: &DrawingTorus2d<G::NodeId, f32>,
+    d: &FullDistanceMatrix<G::NodeId, f32>,
+) -> Result<QualityMetrics, QualityMetricsError<G::NodeId>>
+where
+    G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: DrawingIndex,
+{
+    quality_metrics_torus_with_targets(graph, drawing, d, &QualityMetric::torus())
+}
+
+pub fn quality_metrics_torus_with_targets<G>(
+    graph: G,
+    drawing: &DrawingTorus2d<G::NodeId, f32>,
+    d: &FullDistanceMatrix<G::NodeId, f32>,
+    targets: &[QualityMetric],
+) -> Result<QualityMetrics, QualityMetricsError<G::NodeId>>
+where
+    G: IntoEdgeReferences + IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: DrawingIndex,
+{
+    let crossing_edges = crossing_edges_torus(graph, drawing)?;
+    targets
+        .iter()
+        .map(|&t| {
+            let v = match t {
+                QualityMetric::Stress => stress(drawing, d),
+                QualityMetric::IdealEdgeLengths => ideal_edge_lengths(graph, drawing, d)?,
+                QualityMetric::NeighborhoodPreservation => {
+                    neighborhood_preservation_torus(graph, drawing)
+                }
+                QualityMetric::CrossingNumber => {
+                    crossing_number_with_crossing_edges(&crossing_edges)
+                }
+                QualityMetric::CrossingAngle => crossing_angle_with_crossing_edges(&crossing_edges),
                 QualityMetric::NodeResolution => node_resolution(drawing),
-                QualityMetric::GabrielGraphProperty => gabriel_graph_property(graph, drawing),
+                QualityMetric::EdgeLengthRatio => edge_length_ratio(graph, drawing),
+                QualityMetric::EdgeLengthUniformity => edge_length_uniformity(graph, drawing),
+                _ => return Err(QualityMetricsError::UnsupportedMetric(t)),
             };
-            (t, v)
+            Ok((t, v))
         })
-        .collect::<Vec<_>>()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+    use petgraph_algorithm_shortest_path::all_sources_bfs;
+
+    #[test]
+    fn test_opt_in_metrics() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..4 {
+            graph.add_edge(nodes[i], nodes[(i + 1) % 4], ());
+        }
+        let drawing = DrawingEuclidean2d::initial_placement(&graph);
+        let d = all_sources_bfs(&graph, 1.);
+        let metrics = quality_metrics(&graph, &drawing, &d).unwrap();
+        assert_eq!(metrics.len(), QualityMetric::all().len());
+        for (metric, _) in metrics {
+            assert!(!QualityMetric::opt_in().contains(&metric));
+        }
+        for metric in QualityMetric::opt_in() {
+            assert!(QualityMetric::from_name(&metric.name()).is_some());
+        }
+        let metrics =
+            quality_metrics_with_targets(&graph, &drawing, &d, &QualityMetric::opt_in()).unwrap();
+        assert_eq!(metrics.len(), 3);
+    }
+
+    #[test]
+    fn test_missing_position() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..4 {
+            graph.add_edge(nodes[i], nodes[(i + 1) % 4], ());
+        }
+        let d = all_sources_bfs(&graph, 1.);
+        let drawing = DrawingEuclidean2d::from_node_indices(&nodes[..3]);
+        for targets in [
+            vec![QualityMetric::IdealEdgeLengths],
+            vec![QualityMetric::AngularResolution],
+            vec![QualityMetric::GabrielGraphProperty],
+            QualityMetric::all(),
+        ] {
+            assert_eq!(
+                quality_metrics_with_targets(&graph, &drawing, &d, &targets).err(),
+                Some(QualityMetricsError::MissingPosition(nodes[3]))
+            );
+        }
+
+        let drawing = DrawingEuclidean2d::from_node_indices(&nodes);
+        let d = all_sources_bfs(&graph, 0.);
+        assert_eq!(
+            ideal_edge_lengths(&graph, &drawing, &d).unwrap_err(),
+            QualityMetricsError::ZeroDistance(nodes[0], nodes[1])
+        );
+    }
+
+    #[test]
+    fn test_quality_metrics_torus() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..6 {
+            graph.add_edge(nodes[i], nodes[(i + 1) % 6], ());
+        }
+        let mut drawing = DrawingTorus2d::new(&graph);
+        for (i, &u) in nodes.iter().enumerate() {
+            drawing.set_x(u, i as f32 / 6.);
+            drawing.set_y(u, 0.5);
+        }
+        let d = all_sources_bfs(&graph, 1. / 6.);
+        let metrics = quality_metrics_torus(&graph, &drawing, &d).unwrap();
+        assert_eq!(metrics.len(), QualityMetric::torus().len());
+        for (metric, value) in metrics {
+            match metric {
+                QualityMetric::Stress => assert!(value.abs() < 1e-4),
+                QualityMetric::NeighborhoodPreservation => assert!((value - 1.).abs() < 1e-4),
+                _ => assert!(value.is_finite()),
+            }
+        }
+        let metrics = quality_metrics_torus_with_targets(
+            &graph,
+            &drawing,
+            &d,
+            &[QualityMetric::EdgeLengthRatio, QualityMetric::Stress],
+        )
+        .unwrap();
+        assert_eq!(metrics.len(), 2);
+        assert_eq!(
+            quality_metrics_torus_with_targets(
+                &graph,
+                &drawing,
+                &d,
+                &[QualityMetric::Stress, QualityMetric::AspectRatio],
+            )
+            .err(),
+            Some(QualityMetricsError::UnsupportedMetric(
+                QualityMetric::AspectRatio
+            ))
+        );
+    }
 }
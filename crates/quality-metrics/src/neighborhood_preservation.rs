@@ -1,7 +1,7 @@
 use linfa_nn::{distance::L2Dist, BallTree, NearestNeighbour};
 use ndarray::prelude::*;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNeighbors, NodeIndexable};
-use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex};
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex, DrawingTorus2d};
 use std::collections::HashSet;
 
 pub fn neighborhood_preservation<G>(graph: G, drawing: &DrawingEuclidean2d<G::NodeId, f32>) -> f32
@@ -49,3 +49,91 @@ where
 
     cap as f32 / cup as f32
 }
+
+pub fn neighborhood_preservation_torus<G>(graph: G, drawing: &DrawingTorus2d<G::NodeId, f32>) -> f32
+where
+    G: IntoEdgeReferences + IntoNeighbors + NodeIndexable,
+    G::NodeId: DrawingIndex,
+{
+    let mut graph_edges = HashSet::new();
+    for e in graph.edge_references() {
+        let u = e.source();
+        let v = e.target();
+        graph_edges.insert((graph.to_index(u), graph.to_index(v)));
+        graph_edges.insert((graph.to_index(v), graph.to_index(u)));
+    }
+
+    // Points are tiled over the 3x3 neighbouring copies of the unit torus so
+    // that the nearest copy of each node is at its torus distance. Copies of
+    // the same node can show up more than once, so the query grows until it
+    // has found enough distinct nodes.
+    let n = drawing.len();
+    let mut points = Array2::zeros((9 * n, 2));
+    for k in 0..9 {
+        let dx = (k % 3) as f32 - 1.;
+        let dy = (k / 3) as f32 - 1.;
+        for i in 0..n {
+            points[[k * n + i, 0]] = drawing.raw_entry(i).0 .0 + dx;
+            points[[k * n + i, 1]] = drawing.raw_entry(i).1 .0 + dy;
+        }
+    }
+    let nn = BallTree::new().from_batch(&points, L2Dist).unwrap();
+
+    let mut cap = 0;
+    let mut cup = graph_edges.len();
+    for i in 0..n {
+        let u = *drawing.node_id(i);
+        let d = graph.neighbors(u).count().min(n - 1);
+        let query = points.row(4 * n + i);
+        let mut k = d + 1;
+        let nearest = loop {
+            let mut nearest = vec![];
+            for (_, j) in nn.k_nearest(query, k).unwrap() {
+                let j = j % n;
+                if j != i && !nearest.contains(&j) {
+                    nearest.push(j);
+                }
+            }
+            if nearest.len() >= d || k >= 9 * n {
+                break nearest;
+            }
+            k = (2 * k).min(9 * n);
+        };
+        for &j in nearest.iter().take(d) {
+            let v = *drawing.node_id(j);
+            if graph_edges.contains(&(graph.to_index(u), graph.to_index(v))) {
+                cap += 1;
+            } else {
+                cup += 1;
+            }
+        }
+    }
+
+    cap as f32 / cup as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_neighborhood_preservation_torus() {
+        let n = 10;
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..n).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..n {
+            graph.add_edge(nodes[i], nodes[(i + 1) % n], ());
+        }
+        let mut drawing = DrawingTorus2d::new(&graph);
+        for (i, &u) in nodes.iter().enumerate() {
+            drawing.set_x(u, (i as f32 + 0.5) / n as f32);
+            drawing.set_y(u, 0.5);
+        }
+        assert!((neighborhood_preservation_torus(&graph, &drawing) - 1.).abs() < 1e-6);
+
+        drawing.set_x(nodes[0], 0.5);
+        drawing.set_y(nodes[0], 0.);
+        assert!(neighborhood_preservation_torus(&graph, &drawing) < 1.);
+    }
+}
@@ -0,0 +1,76 @@
+use petgraph_drawing::{Delta, Drawing, DrawingIndex, DrawingValue, Metric};
+use std::collections::HashMap;
+
+pub fn silhouette<Diff, D, N, M, S>(drawing: &D, communities: &HashMap<N, usize>) -> S
+where
+    D: Drawing<Item = M, Index = N>,
+    Diff: Delta<S = S>,
+    N: Copy + DrawingIndex,
+    M: Copy + Metric<D = Diff>,
+    S: DrawingValue,
+{
+    let nodes = (0..drawing.len())
+        .filter_map(|i| communities.get(drawing.node_id(i)).map(|&c| (i, c)))
+        .collect::<Vec<_>>();
+    let mut sizes = HashMap::new();
+    for &(_, c) in nodes.iter() {
+        *sizes.entry(c).or_insert(0usize) += 1;
+    }
+    if nodes.is_empty() || sizes.len() < 2 {
+        return S::zero();
+    }
+
+    let mut s = S::zero();
+    for &(i, ci) in nodes.iter() {
+        if sizes[&ci] == 1 {
+            continue;
+        }
+        let mut distance_sum = HashMap::new();
+        for &(j, cj) in nodes.iter() {
+            if i != j {
+                *distance_sum.entry(cj).or_insert(S::zero()) += drawing.delta(i, j).norm();
+            }
+        }
+        let a = distance_sum[&ci] / S::from_usize(sizes[&ci] - 1).unwrap();
+        let b = distance_sum
+            .iter()
+            .filter(|&(&c, _)| c != ci)
+            .map(|(c, &d)| d / S::from_usize(sizes[c]).unwrap())
+            .fold(S::infinity(), |x, y| x.min(y));
+        let m = a.max(b);
+        if m > S::zero() {
+            s += (b - a) / m;
+        }
+    }
+    s / S::from_usize(nodes.len()).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::prelude::*;
+    use petgraph_drawing::DrawingEuclidean2d;
+
+    #[test]
+    fn test_silhouette() {
+        let mut graph = Graph::<(), ()>::new();
+        let nodes = (0..6).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::new(&graph);
+        for (i, &u) in nodes.iter().enumerate() {
+            drawing.set_x(u, (i / 3) as f32 * 100. + (i % 3) as f32);
+            drawing.set_y(u, 0.);
+        }
+        let communities = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i / 3))
+            .collect::<HashMap<_, _>>();
+        assert!(silhouette(&drawing, &communities) > 0.9);
+        let communities = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &u)| (u, i % 2))
+            .collect::<HashMap<_, _>>();
+        assert!(silhouette(&drawing, &communities) < 0.);
+    }
+}
@@ -0,0 +1,158 @@
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex};
+
+fn bounding_box<N>(drawing: &DrawingEuclidean2d<N, f32>) -> (f32, f32, f32, f32)
+where
+    N: DrawingIndex,
+{
+    let mut left = f32::INFINITY;
+    let mut right = f32::NEG_INFINITY;
+    let mut top = f32::INFINITY;
+    let mut bottom = f32::NEG_INFINITY;
+    for i in 0..drawing.len() {
+        let p = drawing.raw_entry(i);
+        left = left.min(p.0);
+        right = right.max(p.0);
+        top = top.min(p.1);
+        bottom = bottom.max(p.1);
+    }
+    (left, top, right, bottom)
+}
+
+fn cross(o: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+fn half_hull<'a>(points: impl Iterator<Item = &'a (f32, f32)>) -> Vec<(f32, f32)> {
+    let mut hull: Vec<(f32, f32)> = vec![];
+    for &p in points {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0. {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    hull.pop();
+    hull
+}
+
+fn convex_hull(mut points: Vec<(f32, f32)>) -> Vec<(f32, f32)> {
+    points.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+    let mut hull = half_hull(points.iter());
+    hull.extend(half_hull(points.iter().rev()));
+    hull
+}
+
+pub fn normalized_area<G>(graph: G, drawing: &DrawingEuclidean2d<G::NodeId, f32>) -> f32
+where
+    G: IntoEdgeReferences,
+    G::NodeId: DrawingIndex + Copy,
+{
+    let (total, count) = graph
+        .edge_references()
+        .filter(|e| e.source() != e.target())
+        .filter_map(|e| {
+            let dx = drawing.x(e.source())? - drawing.x(e.target())?;
+            let dy = drawing.y(e.source())? - drawing.y(e.target())?;
+            Some(dx.hypot(dy))
+        })
+        .fold((0., 0), |(t, c), l| (t + l, c + 1));
+    if drawing.len() == 0 || count == 0 || total == 0. {
+        return 0.;
+    }
+    let mean = total / count as f32;
+    let (left, top, right, bottom) = bounding_box(drawing);
+    (right - left) * (bottom - top) / (mean * mean)
+}
+
+pub fn convex_hull_area_ratio<N>(drawing: &DrawingEuclidean2d<N, f32>) -> f32
+where
+    N: DrawingIndex,
+{
+    let (left, top, right, bottom) = bounding_box(drawing);
+    let box_area = (right - left) * (bottom - top);
+    if drawing.len() == 0 || box_area <= 0. {
+        return 0.;
+    }
+    let hull = convex_hull(
+        (0..drawing.len())
+            .map(|i| {
+                let p = drawing.raw_entry(i);
+                (p.0, p.1)
+            })
+            .collect(),
+    );
+    let hull_area = (0..hull.len())
+        .map(|i| {
+            let (x1, y1) = hull[i];
+            let (x2, y2) = hull[(i + 1) % hull.len()];
+            x1 * y2 - x2 * y1
+        })
+        .sum::<f32>()
+        .abs()
+        / 2.;
+    hull_area / box_area
+}
+
+pub fn density_entropy<N>(drawing: &DrawingEuclidean2d<N, f32>, grid_size: usize) -> f32
+where
+    N: DrawingIndex,
+{
+    let n = drawing.len();
+    if n == 0 || grid_size <= 1 {
+        return 0.;
+    }
+    let (left, top, right, bottom) = bounding_box(drawing);
+    let cell = |x: f32, min: f32, max: f32| {
+        if max > min {
+            (((x - min) / (max - min) * grid_size as f32) as usize).min(grid_size - 1)
+        } else {
+            0
+        }
+    };
+    let mut counts = vec![0usize; grid_size * grid_size];
+    for i in 0..n {
+        let p = drawing.raw_entry(i);
+        counts[cell(p.1, top, bottom) * grid_size + cell(p.0, left, right)] += 1;
+    }
+    let entropy = counts
+        .into_iter()
+        .filter(|&c| c > 0)
+        .map(|c| {
+            let p = c as f32 / n as f32;
+            -p * p.ln()
+        })
+        .sum::<f32>();
+    entropy / ((grid_size * grid_size) as f32).ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::Graph;
+
+    #[test]
+    fn test_space_utilization() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..4 {
+            graph.add_edge(nodes[i], nodes[(i + 1) % 4], ());
+        }
+        let mut drawing = DrawingEuclidean2d::new(&graph);
+        for (&u, (x, y)) in nodes.iter().zip([(0., 0.), (2., 0.), (2., 2.), (0., 2.)]) {
+            drawing.set_x(u, x);
+            drawing.set_y(u, y);
+        }
+        assert!((normalized_area(&graph, &drawing) - 1.).abs() < 1e-6);
+        assert!((convex_hull_area_ratio(&drawing) - 1.).abs() < 1e-6);
+        assert!((density_entropy(&drawing, 2) - 1.).abs() < 1e-6);
+
+        drawing.set_x(nodes[2], 1.);
+        drawing.set_y(nodes[2], 1.);
+        assert!((convex_hull_area_ratio(&drawing) - 0.5).abs() < 1e-6);
+        assert!(density_entropy(&drawing, 4) < 1.);
+    }
+}
@@ -0,0 +1,86 @@
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph_drawing::{Drawing, DrawingEuclidean2d, DrawingIndex, MetricEuclidean2d};
+
+pub fn symmetry<G>(graph: G, drawing: &DrawingEuclidean2d<G::NodeId, f32>) -> f32
+where
+    G: IntoEdgeReferences,
+    G::NodeId: DrawingIndex,
+{
+    let n = drawing.len();
+    if n == 0 {
+        return 1.;
+    }
+    let points = (0..n)
+        .map(|i| {
+            let MetricEuclidean2d(x, y) = *drawing.raw_entry(i);
+            (x, y)
+        })
+        .collect::<Vec<_>>();
+    let cx = points.iter().map(|p| p.0).sum::<f32>() / n as f32;
+    let cy = points.iter().map(|p| p.1).sum::<f32>() / n as f32;
+
+    let mut length_sum = 0.;
+    let mut m = 0;
+    for e in graph.edge_references() {
+        if let Some((&p, &q)) = drawing
+            .position(e.source())
+            .zip(drawing.position(e.target()))
+        {
+            length_sum += (p.0 - q.0).hypot(p.1 - q.1);
+            m += 1;
+        }
+    }
+    let tolerance = if m > 0 && length_sum > 0. {
+        0.25 * length_sum / m as f32
+    } else {
+        1.
+    };
+
+    let steps = 36;
+    let mut best = 0.;
+    for k in 0..steps {
+        let theta = std::f32::consts::PI * k as f32 / steps as f32;
+        let (s, c) = (2. * theta).sin_cos();
+        let matched = points
+            .iter()
+            .filter(|&&(x, y)| {
+                let dx = x - cx;
+                let dy = y - cy;
+                let rx = cx + c * dx + s * dy;
+                let ry = cy + s * dx - c * dy;
+                points
+                    .iter()
+                    .any(|&(qx, qy)| (rx - qx).hypot(ry - qy) <= tolerance)
+            })
+            .count();
+        best = f32::max(best, matched as f32 / n as f32);
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use petgraph::prelude::*;
+
+    #[test]
+    fn test_symmetry() {
+        let mut graph = Graph::new_undirected();
+        let nodes = (0..4).map(|_| graph.add_node(())).collect::<Vec<_>>();
+        for i in 0..4 {
+            graph.add_edge(nodes[i], nodes[(i + 1) % 4], ());
+        }
+        let mut drawing = DrawingEuclidean2d::<NodeIndex, f32>::new(&graph);
+        for (&u, &(x, y)) in nodes
+            .iter()
+            .zip([(0., 0.), (1., 0.), (1., 1.), (0., 1.)].iter())
+        {
+            drawing.set_x(u, x);
+            drawing.set_y(u, y);
+        }
+        assert_eq!(symmetry(&graph, &drawing), 1.);
+        drawing.set_x(nodes[2], 3.);
+        drawing.set_y(nodes[2], 0.5);
+        assert!(symmetry(&graph, &drawing) < 1.);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::graph::JsGraph;
+use petgraph::graph::NodeIndex;
+use petgraph_algorithm_centrality::{
+    betweenness_centrality, closeness_centrality, eigenvector_centrality, pagerank,
+};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+fn centrality_to_value(centrality: HashMap<NodeIndex, f32>) -> JsValue {
+    let centrality = centrality
+        .into_iter()
+        .map(|(u, c)| (u.index(), c))
+        .collect::<HashMap<_, _>>();
+    serde_wasm_bindgen::to_value(&centrality).unwrap()
+}
+
+#[wasm_bindgen(js_name = betweennessCentrality)]
+pub fn js_betweenness_centrality(graph: &JsGraph) -> JsValue {
+    centrality_to_value(betweenness_centrality(graph.graph()))
+}
+
+#[wasm_bindgen(js_name = closenessCentrality)]
+pub fn js_closeness_centrality(graph: &JsGraph) -> JsValue {
+    centrality_to_value(closeness_centrality(graph.graph()))
+}
+
+#[wasm_bindgen(js_name = pagerank)]
+pub fn js_pagerank(graph: &JsGraph, damping: f32, iterations: usize) -> JsValue {
+    centrality_to_value(pagerank(graph.graph(), damping, iterations))
+}
+
+#[wasm_bindgen(js_name = eigenvectorCentrality)]
+pub fn js_eigenvector_centrality(graph: &JsGraph, iterations: usize) -> JsValue {
+    centrality_to_value(eigenvector_centrality(graph.graph(), iterations))
+}
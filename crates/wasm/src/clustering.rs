@@ -1,5 +1,6 @@
 use crate::graph::JsGraph;
 use js_sys::{Array, Function};
+use petgraph_clustering::{CommunityDetection, LabelPropagation};
 use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
@@ -56,3 +57,23 @@ pub fn js_coarsen(
     result.push(&serde_wasm_bindgen::to_value(&group_ids).unwrap());
     Ok(result.into())
 }
+
+#[wasm_bindgen(js_name = labelPropagation)]
+pub fn js_label_propagation(
+    graph: &JsGraph,
+    max_iterations: usize,
+    synchronous: bool,
+    seed: u64,
+) -> JsValue {
+    let label_propagation = LabelPropagation {
+        max_iterations,
+        synchronous,
+        seed,
+    };
+    let communities = label_propagation
+        .detect_communities(graph.graph())
+        .into_iter()
+        .map(|(u, c)| (u.index(), c))
+        .collect::<HashMap<_, _>>();
+    serde_wasm_bindgen::to_value(&communities).unwrap()
+}
@@ -39,4 +39,29 @@ impl JsDrawingEuclidean {
     pub fn len(&self) -> usize {
         self.drawing.len()
     }
+
+    pub fn dimension(&self) -> usize {
+        self.drawing.dimension()
+    }
+
+    pub fn coords(&self) -> Vec<f32> {
+        (0..self.drawing.len())
+            .flat_map(|i| self.drawing.raw_entry(i).0.iter().copied())
+            .collect()
+    }
+
+    #[wasm_bindgen(js_name = setCoords)]
+    pub fn set_coords(&mut self, coords: &[f32]) -> Result<(), JsValue> {
+        let d = self.drawing.dimension();
+        if coords.len() != d * self.drawing.len() {
+            return Err("coords length must be dimension times the number of nodes".into());
+        }
+        if d == 0 {
+            return Ok(());
+        }
+        for (i, p) in coords.chunks(d).enumerate() {
+            self.drawing.raw_entry_mut(i).0.copy_from_slice(p);
+        }
+        Ok(())
+    }
 }
@@ -90,4 +90,27 @@ impl JsDrawingEuclidean2d {
     pub fn initial_placement(graph: &JsGraph) -> Self {
         Self::new(DrawingEuclidean2d::initial_placement(graph.graph()))
     }
+
+    pub fn coords(&self) -> Vec<f32> {
+        let mut coords = Vec::with_capacity(2 * self.drawing.len());
+        for i in 0..self.drawing.len() {
+            let u = *self.drawing.node_id(i);
+            coords.push(self.drawing.x(u).unwrap());
+            coords.push(self.drawing.y(u).unwrap());
+        }
+        coords
+    }
+
+    #[wasm_bindgen(js_name = setCoords)]
+    pub fn set_coords(&mut self, coords: &[f32]) -> Result<(), JsValue> {
+        if coords.len() != 2 * self.drawing.len() {
+            return Err("coords length must be twice the number of nodes".into());
+        }
+        for i in 0..self.drawing.len() {
+            let u = *self.drawing.node_id(i);
+            self.drawing.set_x(u, coords[2 * i]);
+            self.drawing.set_y(u, coords[2 * i + 1]);
+        }
+        Ok(())
+    }
 }
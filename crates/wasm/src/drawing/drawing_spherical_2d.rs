@@ -56,4 +56,55 @@ impl JsDrawingSpherical2d {
     pub fn initial_placement(graph: &JsGraph) -> Self {
         Self::new(DrawingSpherical2d::initial_placement(graph.graph()))
     }
+
+    pub fn coords(&self) -> Vec<f32> {
+        let mut coords = Vec::with_capacity(2 * self.drawing.len());
+        for i in 0..self.drawing.len() {
+            let u = *self.drawing.node_id(i);
+            coords.push(self.drawing.lon(u).unwrap());
+            coords.push(self.drawing.lat(u).unwrap());
+        }
+        coords
+    }
+
+    #[wasm_bindgen(js_name = edgeArc)]
+    pub fn edge_arc(&self, u: usize, v: usize, segments: usize) -> Option<Vec<f32>> {
+        let u = node_index(u);
+        let v = node_index(v);
+        self.drawing
+            .edge_arc(u, v, segments)
+            .map(|arc| arc.into_iter().flat_map(|p| [p.0, p.1]).collect())
+    }
+
+    #[wasm_bindgen(js_name = geographicCoords)]
+    pub fn geographic_coords(&self) -> Vec<f32> {
+        (0..self.drawing.len())
+            .flat_map(|i| {
+                let (lon, lat) = self.drawing.geographic(*self.drawing.node_id(i)).unwrap();
+                [lon, lat]
+            })
+            .collect()
+    }
+
+    #[wasm_bindgen(js_name = edgeArcGeographic)]
+    pub fn edge_arc_geographic(&self, u: usize, v: usize, segments: usize) -> Option<Vec<f32>> {
+        let u = node_index(u);
+        let v = node_index(v);
+        self.drawing
+            .edge_arc_geographic(u, v, segments)
+            .map(|arc| arc.into_iter().flat_map(|(lon, lat)| [lon, lat]).collect())
+    }
+
+    #[wasm_bindgen(js_name = setCoords)]
+    pub fn set_coords(&mut self, coords: &[f32]) -> Result<(), JsValue> {
+        if coords.len() != 2 * self.drawing.len() {
+            return Err("coords length must be twice the number of nodes".into());
+        }
+        for i in 0..self.drawing.len() {
+            let u = *self.drawing.node_id(i);
+            self.drawing.set_lon(u, coords[2 * i]);
+            self.drawing.set_lat(u, coords[2 * i + 1]);
+        }
+        Ok(())
+    }
 }
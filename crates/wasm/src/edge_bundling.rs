@@ -4,11 +4,12 @@ use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen(js_name = fdeb)]
-pub fn js_fdeb(graph: &JsGraph, drawing: JsDrawingEuclidean2d) -> JsValue {
+pub fn js_fdeb(graph: &JsGraph, drawing: JsDrawingEuclidean2d) -> Result<JsValue, JsValue> {
     let options = EdgeBundlingOptions::<f32>::new();
     let bends = fdeb(graph.graph(), drawing.drawing(), &options)
+        .map_err(|e| e.to_string())?
         .into_iter()
         .map(|(e, lines)| (e.index(), lines))
         .collect::<HashMap<_, _>>();
-    serde_wasm_bindgen::to_value(&bends).unwrap()
+    Ok(serde_wasm_bindgen::to_value(&bends).unwrap())
 }
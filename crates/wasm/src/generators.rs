@@ -0,0 +1,49 @@
+use crate::{graph::JsGraph, rng::JsRng};
+use petgraph::graph::UnGraph;
+use petgraph_generators::{
+    barabasi_albert, complete_graph, erdos_renyi, grid_graph, torus_graph, tree_graph,
+    watts_strogatz,
+};
+use wasm_bindgen::prelude::*;
+
+fn to_js_graph(graph: UnGraph<(), ()>) -> JsGraph {
+    JsGraph::new_from_graph(graph.map(
+        |u, _| JsValue::from_f64(u.index() as f64),
+        |_, _| JsValue::null(),
+    ))
+}
+
+#[wasm_bindgen(js_name = completeGraph)]
+pub fn js_complete_graph(n: usize) -> JsGraph {
+    to_js_graph(complete_graph(n))
+}
+
+#[wasm_bindgen(js_name = gridGraph)]
+pub fn js_grid_graph(rows: usize, cols: usize) -> JsGraph {
+    to_js_graph(grid_graph(rows, cols))
+}
+
+#[wasm_bindgen(js_name = torusGraph)]
+pub fn js_torus_graph(rows: usize, cols: usize) -> JsGraph {
+    to_js_graph(torus_graph(rows, cols))
+}
+
+#[wasm_bindgen(js_name = treeGraph)]
+pub fn js_tree_graph(branching: usize, height: usize) -> JsGraph {
+    to_js_graph(tree_graph(branching, height))
+}
+
+#[wasm_bindgen(js_name = erdosRenyi)]
+pub fn js_erdos_renyi(n: usize, p: f64, rng: &mut JsRng) -> JsGraph {
+    to_js_graph(erdos_renyi(n, p, rng.get_mut()))
+}
+
+#[wasm_bindgen(js_name = wattsStrogatz)]
+pub fn js_watts_strogatz(n: usize, k: usize, beta: f64, rng: &mut JsRng) -> JsGraph {
+    to_js_graph(watts_strogatz(n, k, beta, rng.get_mut()))
+}
+
+#[wasm_bindgen(js_name = barabasiAlbert)]
+pub fn js_barabasi_albert(n: usize, m: usize, rng: &mut JsRng) -> JsGraph {
+    to_js_graph(barabasi_albert(n, m, rng.get_mut()))
+}
@@ -3,3 +3,33 @@ pub mod mds;
 pub mod overwrap_removal;
 pub mod sgd;
 pub mod stress_majorization;
+
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+#[derive(Serialize)]
+pub(crate) struct LayoutProgress {
+    pub iteration: usize,
+    pub progress: f32,
+    pub stress: f32,
+    pub finished: bool,
+}
+
+impl LayoutProgress {
+    pub(crate) fn to_value(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(self).unwrap()
+    }
+}
+
+/// Maps a residual that decays from `initial` toward `target` onto [0, 1] on
+/// a log scale. Used by runners that stop on a tolerance instead of a fixed
+/// number of iterations.
+pub(crate) fn residual_progress(initial: f32, current: f32, target: f32) -> f32 {
+    if current <= target {
+        1.
+    } else if initial <= current || initial <= target || target <= 0. {
+        0.
+    } else {
+        ((initial / current).ln() / (initial / target).ln()).clamp(0., 1.)
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{drawing::JsDrawingEuclidean2d, graph::JsGraph};
+use crate::{
+    drawing::JsDrawingEuclidean2d,
+    graph::JsGraph,
+    layout::{residual_progress, LayoutProgress},
+};
 use js_sys::{Function, Reflect};
 use petgraph::visit::EdgeRef;
 use petgraph_layout_kamada_kawai::KamadaKawai;
@@ -8,6 +12,9 @@ use wasm_bindgen::prelude::*;
 #[wasm_bindgen(js_name = KamadaKawai)]
 pub struct JsKamadaKawai {
     kamada_kawai: KamadaKawai<f32>,
+    iteration: usize,
+    initial_gradient: Option<f32>,
+    progress: f32,
 }
 
 #[wasm_bindgen(js_class = KamadaKawai)]
@@ -24,6 +31,9 @@ impl JsKamadaKawai {
         }
         Ok(JsKamadaKawai {
             kamada_kawai: KamadaKawai::new(graph.graph(), |e| distance[&e.id()]),
+            iteration: 0,
+            initial_gradient: None,
+            progress: 0.,
         })
     }
 
@@ -41,6 +51,29 @@ impl JsKamadaKawai {
         self.kamada_kawai.run(drawing.drawing_mut())
     }
 
+    pub fn step(&mut self, drawing: &mut JsDrawingEuclidean2d, iterations: usize) -> JsValue {
+        let mut finished = false;
+        for _ in 0..iterations {
+            let (m, gradient) = self.kamada_kawai.max_gradient(drawing.drawing());
+            let initial = *self.initial_gradient.get_or_insert(gradient);
+            let eps = self.kamada_kawai.eps;
+            self.progress = self.progress.max(residual_progress(initial, gradient, eps));
+            if gradient < eps {
+                finished = true;
+                break;
+            }
+            self.kamada_kawai.apply_to_node(m, drawing.drawing_mut());
+            self.iteration += 1;
+        }
+        LayoutProgress {
+            iteration: self.iteration,
+            progress: self.progress,
+            stress: self.kamada_kawai.energy(drawing.drawing()),
+            finished,
+        }
+        .to_value()
+    }
+
     #[wasm_bindgen(getter)]
     pub fn eps(&self) -> f32 {
         self.kamada_kawai.eps
@@ -1,4 +1,5 @@
 use js_sys::Function;
+use petgraph_drawing::{Delta, Drawing, Metric};
 use petgraph_layout_overwrap_removal::OverwrapRemoval;
 use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
@@ -9,11 +10,47 @@ use crate::{
         JsDrawingTorus2d,
     },
     graph::JsGraph,
+    layout::LayoutProgress,
 };
 
 #[wasm_bindgen(js_name = OverwrapRemoval)]
 pub struct JsOverwrapRemoval {
     overwrap_removal: OverwrapRemoval<f32>,
+    iteration: usize,
+    initial_overlap: Option<f32>,
+}
+
+impl JsOverwrapRemoval {
+    fn step<DR, M, D>(&mut self, drawing: &mut DR, iterations: usize) -> JsValue
+    where
+        DR: Drawing<Item = M>,
+        M: Metric<D = D>,
+        D: Delta<S = f32>,
+    {
+        let tolerance = self.overwrap_removal.min_distance;
+        let mut overlap = self.overwrap_removal.overlap(drawing);
+        let initial = *self.initial_overlap.get_or_insert(overlap);
+        for _ in 0..iterations {
+            if overlap < tolerance {
+                break;
+            }
+            self.overwrap_removal.apply(drawing);
+            self.iteration += 1;
+            overlap = self.overwrap_removal.overlap(drawing);
+        }
+        let finished = overlap < tolerance;
+        LayoutProgress {
+            iteration: self.iteration,
+            progress: if finished || initial <= 0. {
+                1.
+            } else {
+                (1. - overlap / initial).clamp(0., 1.)
+            },
+            stress: overlap,
+            finished,
+        }
+        .to_value()
+    }
 }
 
 #[wasm_bindgen(js_class = OverwrapRemoval)]
@@ -31,6 +68,8 @@ impl JsOverwrapRemoval {
         }
         JsOverwrapRemoval {
             overwrap_removal: OverwrapRemoval::new(graph.graph(), |u| radius_map[&u]),
+            iteration: 0,
+            initial_overlap: None,
         }
     }
 
@@ -59,6 +98,51 @@ impl JsOverwrapRemoval {
         self.overwrap_removal.apply(drawing.drawing_mut());
     }
 
+    #[wasm_bindgen(js_name = "stepWithDrawingEuclidean2d")]
+    pub fn step_with_drawing_euclidean_2d(
+        &mut self,
+        drawing: &mut JsDrawingEuclidean2d,
+        iterations: usize,
+    ) -> JsValue {
+        self.step(drawing.drawing_mut(), iterations)
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingEuclidean")]
+    pub fn step_with_drawing_euclidean(
+        &mut self,
+        drawing: &mut JsDrawingEuclidean,
+        iterations: usize,
+    ) -> JsValue {
+        self.step(drawing.drawing_mut(), iterations)
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingHyperbolic2d")]
+    pub fn step_with_drawing_hyperbolic_2d(
+        &mut self,
+        drawing: &mut JsDrawingHyperbolic2d,
+        iterations: usize,
+    ) -> JsValue {
+        self.step(drawing.drawing_mut(), iterations)
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingSpherical2d")]
+    pub fn step_with_drawing_spherical_2d(
+        &mut self,
+        drawing: &mut JsDrawingSpherical2d,
+        iterations: usize,
+    ) -> JsValue {
+        self.step(drawing.drawing_mut(), iterations)
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingTorus2d")]
+    pub fn step_with_drawing_torus_2d(
+        &mut self,
+        drawing: &mut JsDrawingTorus2d,
+        iterations: usize,
+    ) -> JsValue {
+        self.step(drawing.drawing_mut(), iterations)
+    }
+
     #[wasm_bindgen(getter)]
     pub fn get_strength(&self) -> f32 {
         self.overwrap_removal.strength
@@ -4,17 +4,84 @@ use crate::{
         JsDrawingTorus2d,
     },
     graph::JsGraph,
+    layout::LayoutProgress,
     rng::JsRng,
 };
 use js_sys::{Array, Function};
-use petgraph::visit::EdgeRef;
+use petgraph::{graph::NodeIndex, visit::EdgeRef};
+use petgraph_drawing::{Delta, Drawing, Metric};
 use petgraph_layout_sgd::{
-    DistanceAdjustedSgd, FullSgd, Scheduler, SchedulerConstant, SchedulerExponential,
-    SchedulerLinear, SchedulerQuadratic, SchedulerReciprocal, Sgd, SparseSgd,
+    apply_euclidean_2d, ConstrainedSgd, Constraint, DistanceAdjustedSgd, FullSgd, Scheduler,
+    SchedulerConstant, SchedulerExponential, SchedulerLinear, SchedulerQuadratic,
+    SchedulerReciprocal, Sgd, SparseSgd,
 };
 use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
+struct SgdRunState {
+    scheduler: Box<dyn Scheduler<f32>>,
+    t_max: usize,
+    iteration: usize,
+}
+
+fn sgd_start_run<A: Sgd<f32>>(
+    sgd: &A,
+    scheduler: &str,
+    t_max: usize,
+    epsilon: f32,
+) -> Result<SgdRunState, JsValue> {
+    let scheduler = sgd
+        .scheduler_by_name(scheduler, t_max, epsilon)
+        .ok_or_else(|| JsValue::from(format!("unknown scheduler: {}", scheduler)))?;
+    Ok(SgdRunState {
+        scheduler,
+        t_max,
+        iteration: 0,
+    })
+}
+
+fn sgd_step<A, Diff, D, M, F>(
+    sgd: &mut A,
+    state: &mut Option<SgdRunState>,
+    drawing: &mut D,
+    rng: &mut JsRng,
+    iterations: usize,
+    mut apply: F,
+) -> Result<JsValue, JsValue>
+where
+    A: Sgd<f32>,
+    D: Drawing<Item = M>,
+    Diff: Delta<S = f32>,
+    M: Metric<D = Diff>,
+    F: FnMut(&mut A, &mut D, f32),
+{
+    let state = state
+        .as_mut()
+        .ok_or_else(|| JsValue::from("startRun must be called before step"))?;
+    for _ in 0..iterations {
+        if state.scheduler.is_finished() {
+            break;
+        }
+        state.scheduler.step(&mut |eta| {
+            sgd.shuffle(rng.get_mut());
+            apply(sgd, drawing, eta);
+        });
+        state.iteration += 1;
+    }
+    let finished = state.scheduler.is_finished();
+    Ok(LayoutProgress {
+        iteration: state.iteration,
+        progress: if finished {
+            1.
+        } else {
+            state.iteration as f32 / state.t_max as f32
+        },
+        stress: sgd.stress(drawing),
+        finished,
+    }
+    .to_value())
+}
+
 #[wasm_bindgen(js_name = "SchedulerConstant")]
 pub struct JsSchedulerConstant {
     scheduler: SchedulerConstant<f32>,
@@ -143,6 +210,7 @@ impl JsSchedulerReciprocal {
 #[wasm_bindgen(js_name = "FullSgd")]
 pub struct JsFullSgd {
     sgd: FullSgd<f32>,
+    run_state: Option<SgdRunState>,
 }
 
 #[wasm_bindgen(js_class = "FullSgd")]
@@ -160,6 +228,7 @@ impl JsFullSgd {
         }
         JsFullSgd {
             sgd: FullSgd::new(graph.graph(), |e| length_map[&e.id()]),
+            run_state: None,
         }
     }
 
@@ -169,7 +238,7 @@ impl JsFullSgd {
 
     #[wasm_bindgen(js_name = "applyWithDrawingEuclidean2d")]
     pub fn apply_with_drawing_euclidean_2d(&self, drawing: &mut JsDrawingEuclidean2d, eta: f32) {
-        self.sgd.apply(drawing.drawing_mut(), eta);
+        apply_euclidean_2d(&self.sgd, drawing.drawing_mut(), eta);
     }
 
     #[wasm_bindgen(js_name = "applyWithDrawingEuclidean")]
@@ -192,6 +261,102 @@ impl JsFullSgd {
         self.sgd.apply(drawing.drawing_mut(), eta);
     }
 
+    #[wasm_bindgen(js_name = "startRun")]
+    pub fn start_run(
+        &mut self,
+        scheduler: &str,
+        t_max: usize,
+        epsilon: f32,
+    ) -> Result<(), JsValue> {
+        self.run_state = Some(sgd_start_run(&self.sgd, scheduler, t_max, epsilon)?);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingEuclidean2d")]
+    pub fn step_with_drawing_euclidean_2d(
+        &mut self,
+        drawing: &mut JsDrawingEuclidean2d,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| apply_euclidean_2d(sgd, drawing, eta),
+        )
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingEuclidean")]
+    pub fn step_with_drawing_euclidean(
+        &mut self,
+        drawing: &mut JsDrawingEuclidean,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| sgd.apply(drawing, eta),
+        )
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingHyperbolic2d")]
+    pub fn step_with_drawing_hyperbolic_2d(
+        &mut self,
+        drawing: &mut JsDrawingHyperbolic2d,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| sgd.apply(drawing, eta),
+        )
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingSpherical2d")]
+    pub fn step_with_drawing_spherical_2d(
+        &mut self,
+        drawing: &mut JsDrawingSpherical2d,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| sgd.apply(drawing, eta),
+        )
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingTorus2d")]
+    pub fn step_with_drawing_torus_2d(
+        &mut self,
+        drawing: &mut JsDrawingTorus2d,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| sgd.apply(drawing, eta),
+        )
+    }
+
     pub fn scheduler(&self, t_max: usize, epsilon: f32) -> JsSchedulerExponential {
         self.scheduler_exponential(t_max, epsilon)
     }
@@ -247,6 +412,11 @@ impl JsFullSgd {
         })
     }
 
+    #[wasm_bindgen(js_name = "wrapDistance")]
+    pub fn wrap_distance(&mut self, period: f32) {
+        self.sgd.wrap_distance(period)
+    }
+
     #[wasm_bindgen(js_name = "updateWeight")]
     pub fn update_weight(&mut self, weight: &Function) {
         self.sgd.update_weight(|i, j, d, w| {
@@ -267,6 +437,7 @@ impl JsFullSgd {
 #[wasm_bindgen(js_name = "SparseSgd")]
 pub struct JsSparseSgd {
     sgd: SparseSgd<f32>,
+    run_state: Option<SgdRunState>,
 }
 
 #[wasm_bindgen(js_class = "SparseSgd")]
@@ -284,6 +455,7 @@ impl JsSparseSgd {
         }
         JsSparseSgd {
             sgd: SparseSgd::new_with_rng(graph.graph(), |e| length_map[&e.id()], h, rng.get_mut()),
+            run_state: None,
         }
     }
 
@@ -291,9 +463,35 @@ impl JsSparseSgd {
         self.sgd.shuffle(rng.get_mut());
     }
 
+    #[wasm_bindgen(js_name = "updateEdges")]
+    pub fn update_edges(
+        &mut self,
+        graph: &JsGraph,
+        length: &Function,
+        sources: Vec<usize>,
+        targets: Vec<usize>,
+    ) {
+        let mut length_map = HashMap::new();
+        for e in graph.graph().edge_indices() {
+            let c = length
+                .call1(&JsValue::null(), &JsValue::from_f64(e.index() as f64))
+                .unwrap()
+                .as_f64()
+                .unwrap() as f32;
+            length_map.insert(e, c);
+        }
+        let changed_edges = sources
+            .into_iter()
+            .zip(targets)
+            .map(|(u, v)| (NodeIndex::new(u), NodeIndex::new(v)))
+            .collect::<Vec<_>>();
+        self.sgd
+            .update_edges(graph.graph(), |e| length_map[&e.id()], &changed_edges);
+    }
+
     #[wasm_bindgen(js_name = "applyWithDrawingEuclidean2d")]
     pub fn apply_with_drawing_euclidean_2d(&self, drawing: &mut JsDrawingEuclidean2d, eta: f32) {
-        self.sgd.apply(drawing.drawing_mut(), eta);
+        apply_euclidean_2d(&self.sgd, drawing.drawing_mut(), eta);
     }
 
     #[wasm_bindgen(js_name = "applyWithDrawingEuclidean")]
@@ -316,6 +514,102 @@ impl JsSparseSgd {
         self.sgd.apply(drawing.drawing_mut(), eta);
     }
 
+    #[wasm_bindgen(js_name = "startRun")]
+    pub fn start_run(
+        &mut self,
+        scheduler: &str,
+        t_max: usize,
+        epsilon: f32,
+    ) -> Result<(), JsValue> {
+        self.run_state = Some(sgd_start_run(&self.sgd, scheduler, t_max, epsilon)?);
+        Ok(())
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingEuclidean2d")]
+    pub fn step_with_drawing_euclidean_2d(
+        &mut self,
+        drawing: &mut JsDrawingEuclidean2d,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| apply_euclidean_2d(sgd, drawing, eta),
+        )
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingEuclidean")]
+    pub fn step_with_drawing_euclidean(
+        &mut self,
+        drawing: &mut JsDrawingEuclidean,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| sgd.apply(drawing, eta),
+        )
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingHyperbolic2d")]
+    pub fn step_with_drawing_hyperbolic_2d(
+        &mut self,
+        drawing: &mut JsDrawingHyperbolic2d,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| sgd.apply(drawing, eta),
+        )
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingSpherical2d")]
+    pub fn step_with_drawing_spherical_2d(
+        &mut self,
+        drawing: &mut JsDrawingSpherical2d,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| sgd.apply(drawing, eta),
+        )
+    }
+
+    #[wasm_bindgen(js_name = "stepWithDrawingTorus2d")]
+    pub fn step_with_drawing_torus_2d(
+        &mut self,
+        drawing: &mut JsDrawingTorus2d,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| sgd.apply(drawing, eta),
+        )
+    }
+
     pub fn scheduler(&self, t_max: usize, epsilon: f32) -> JsSchedulerExponential {
         self.scheduler_exponential(t_max, epsilon)
     }
@@ -371,6 +665,11 @@ impl JsSparseSgd {
         })
     }
 
+    #[wasm_bindgen(js_name = "wrapDistance")]
+    pub fn wrap_distance(&mut self, period: f32) {
+        self.sgd.wrap_distance(period)
+    }
+
     #[wasm_bindgen(js_name = "updateWeight")]
     pub fn update_weight(&mut self, weight: &Function) {
         self.sgd.update_weight(|i, j, d, w| {
@@ -391,6 +690,7 @@ impl JsSparseSgd {
 #[wasm_bindgen(js_name = "DistanceAdjustedFullSgd")]
 pub struct JsDistanceAdjustedFullSgd {
     sgd: DistanceAdjustedSgd<FullSgd<f32>, f32>,
+    run_state: Option<SgdRunState>,
 }
 
 #[wasm_bindgen(js_class = "DistanceAdjustedFullSgd")]
@@ -408,6 +708,7 @@ impl JsDistanceAdjustedFullSgd {
         }
         Self {
             sgd: DistanceAdjustedSgd::new(FullSgd::new(graph.graph(), |e| length_map[&e.id()])),
+            run_state: None,
         }
     }
 
@@ -424,6 +725,33 @@ impl JsDistanceAdjustedFullSgd {
         self.sgd.apply(drawing.drawing_mut(), eta);
     }
 
+    #[wasm_bindgen(js_name = "startRun")]
+    pub fn start_run(
+        &mut self,
+        scheduler: &str,
+        t_max: usize,
+        epsilon: f32,
+    ) -> Result<(), JsValue> {
+        self.run_state = Some(sgd_start_run(&self.sgd, scheduler, t_max, epsilon)?);
+        Ok(())
+    }
+
+    pub fn step(
+        &mut self,
+        drawing: &mut JsDrawingEuclidean2d,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| sgd.apply_with_distance_adjustment(drawing, eta),
+        )
+    }
+
     pub fn scheduler(&self, t_max: usize, epsilon: f32) -> JsSchedulerExponential {
         JsSchedulerExponential {
             scheduler: self.sgd.scheduler(t_max, epsilon),
@@ -486,6 +814,7 @@ impl JsDistanceAdjustedFullSgd {
 #[wasm_bindgen(js_name = "DistanceAdjustedSparseSgd")]
 pub struct JsDistanceAdjustedSparseSgd {
     sgd: DistanceAdjustedSgd<SparseSgd<f32>, f32>,
+    run_state: Option<SgdRunState>,
 }
 
 #[wasm_bindgen(js_class = "DistanceAdjustedSparseSgd")]
@@ -508,6 +837,7 @@ impl JsDistanceAdjustedSparseSgd {
                 h,
                 rng.get_mut(),
             )),
+            run_state: None,
         }
     }
 
@@ -524,6 +854,33 @@ impl JsDistanceAdjustedSparseSgd {
         self.sgd.apply(drawing.drawing_mut(), eta);
     }
 
+    #[wasm_bindgen(js_name = "startRun")]
+    pub fn start_run(
+        &mut self,
+        scheduler: &str,
+        t_max: usize,
+        epsilon: f32,
+    ) -> Result<(), JsValue> {
+        self.run_state = Some(sgd_start_run(&self.sgd, scheduler, t_max, epsilon)?);
+        Ok(())
+    }
+
+    pub fn step(
+        &mut self,
+        drawing: &mut JsDrawingEuclidean2d,
+        rng: &mut JsRng,
+        iterations: usize,
+    ) -> Result<JsValue, JsValue> {
+        sgd_step(
+            &mut self.sgd,
+            &mut self.run_state,
+            drawing.drawing_mut(),
+            rng,
+            iterations,
+            |sgd, drawing, eta| sgd.apply_with_distance_adjustment(drawing, eta),
+        )
+    }
+
     pub fn scheduler(&self, t_max: usize, epsilon: f32) -> JsSchedulerExponential {
         JsSchedulerExponential {
             scheduler: self.sgd.scheduler(t_max, epsilon),
@@ -582,3 +939,92 @@ impl JsDistanceAdjustedSparseSgd {
         self.sgd.minimum_distance = value;
     }
 }
+
+#[wasm_bindgen(js_name = "ConstrainedSparseSgd")]
+pub struct JsConstrainedSparseSgd {
+    sgd: ConstrainedSgd<SparseSgd<f32>, f32>,
+}
+
+#[wasm_bindgen(js_class = "ConstrainedSparseSgd")]
+impl JsConstrainedSparseSgd {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        graph: &JsGraph,
+        length: &Function,
+        h: usize,
+        rng: &mut JsRng,
+    ) -> JsConstrainedSparseSgd {
+        let mut length_map = HashMap::new();
+        for e in graph.graph().edge_indices() {
+            let c = length
+                .call1(&JsValue::null(), &JsValue::from_f64(e.index() as f64))
+                .unwrap()
+                .as_f64()
+                .unwrap() as f32;
+            length_map.insert(e, c);
+        }
+        JsConstrainedSparseSgd {
+            sgd: ConstrainedSgd::new(SparseSgd::new_with_rng(
+                graph.graph(),
+                |e| length_map[&e.id()],
+                h,
+                rng.get_mut(),
+            )),
+        }
+    }
+
+    #[wasm_bindgen(js_name = "setFixed")]
+    pub fn set_fixed(&mut self, i: usize, fixed_x: bool, fixed_y: bool) {
+        match (fixed_x, fixed_y) {
+            (true, true) => self.sgd.set_fixed(i, Constraint::FixedBoth),
+            (true, false) => self.sgd.set_fixed(i, Constraint::FixedX),
+            (false, true) => self.sgd.set_fixed(i, Constraint::FixedY),
+            (false, false) => self.sgd.unset_fixed(i),
+        }
+    }
+
+    #[wasm_bindgen(js_name = "unsetFixed")]
+    pub fn unset_fixed(&mut self, i: usize) {
+        self.sgd.unset_fixed(i);
+    }
+
+    #[wasm_bindgen(js_name = "clearFixed")]
+    pub fn clear_fixed(&mut self) {
+        self.sgd.clear_fixed();
+    }
+
+    pub fn shuffle(&mut self, rng: &mut JsRng) {
+        self.sgd.shuffle(rng.get_mut());
+    }
+
+    #[wasm_bindgen(js_name = "applyWithDrawingEuclidean2d")]
+    pub fn apply_with_drawing_euclidean_2d(&self, drawing: &mut JsDrawingEuclidean2d, eta: f32) {
+        self.sgd.apply(drawing.drawing_mut(), eta);
+    }
+
+    #[wasm_bindgen(js_name = "applyWithDrawingEuclidean")]
+    pub fn apply_with_drawing_euclidean(&self, drawing: &mut JsDrawingEuclidean, eta: f32) {
+        self.sgd.apply(drawing.drawing_mut(), eta);
+    }
+
+    #[wasm_bindgen(js_name = "applyWithDrawingHyperbolic2d")]
+    pub fn apply_with_drawing_hyperbolic_2d(&self, drawing: &mut JsDrawingHyperbolic2d, eta: f32) {
+        self.sgd.apply(drawing.drawing_mut(), eta);
+    }
+
+    #[wasm_bindgen(js_name = "applyWithDrawingSpherical2d")]
+    pub fn apply_with_drawing_spherical_2d(&self, drawing: &mut JsDrawingSpherical2d, eta: f32) {
+        self.sgd.apply(drawing.drawing_mut(), eta);
+    }
+
+    #[wasm_bindgen(js_name = "applyWithDrawingTorus2d")]
+    pub fn apply_with_drawing_torus_2d(&self, drawing: &mut JsDrawingTorus2d, eta: f32) {
+        self.sgd.apply(drawing.drawing_mut(), eta);
+    }
+
+    pub fn scheduler(&self, t_max: usize, epsilon: f32) -> JsSchedulerExponential {
+        JsSchedulerExponential {
+            scheduler: self.sgd.scheduler(t_max, epsilon),
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{drawing::JsDrawingEuclidean2d, graph::JsGraph};
+use crate::{
+    drawing::JsDrawingEuclidean2d,
+    graph::JsGraph,
+    layout::{residual_progress, LayoutProgress},
+};
 use js_sys::{Function, Reflect};
 use petgraph::visit::EdgeRef;
 use petgraph_layout_stress_majorization::StressMajorization;
@@ -8,6 +12,11 @@ use wasm_bindgen::prelude::*;
 #[wasm_bindgen(js_name = StressMajorization)]
 pub struct JsStressMajorization {
     stress_majorization: StressMajorization,
+    iteration: usize,
+    epsilon: f32,
+    initial_diff: Option<f32>,
+    progress: f32,
+    finished: bool,
 }
 
 #[wasm_bindgen(js_class = StressMajorization)]
@@ -31,6 +40,11 @@ impl JsStressMajorization {
             stress_majorization: StressMajorization::new(graph.graph(), drawing.drawing(), |e| {
                 distance[&e.id()]
             }),
+            iteration: 0,
+            epsilon: 1e-4,
+            initial_diff: None,
+            progress: 0.,
+            finished: false,
         })
     }
 
@@ -41,4 +55,36 @@ impl JsStressMajorization {
     pub fn run(&mut self, drawing: &mut JsDrawingEuclidean2d) {
         self.stress_majorization.run(drawing.drawing_mut());
     }
+
+    pub fn step(&mut self, drawing: &mut JsDrawingEuclidean2d, iterations: usize) -> JsValue {
+        for _ in 0..iterations {
+            if self.finished {
+                break;
+            }
+            let diff = self.stress_majorization.apply(drawing.drawing_mut());
+            self.iteration += 1;
+            self.finished = diff < self.epsilon;
+            let initial = *self.initial_diff.get_or_insert(diff);
+            self.progress = self
+                .progress
+                .max(residual_progress(initial, diff, self.epsilon));
+        }
+        LayoutProgress {
+            iteration: self.iteration,
+            progress: self.progress,
+            stress: self.stress_majorization.stress(),
+            finished: self.finished,
+        }
+        .to_value()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn epsilon(&self) -> f32 {
+        self.epsilon
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_epsilon(&mut self, value: f32) {
+        self.epsilon = value;
+    }
 }
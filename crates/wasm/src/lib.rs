@@ -2,9 +2,11 @@
 // extern crate serde_derive;
 
 // pub mod algorithm;
+pub mod centrality;
 pub mod clustering;
 pub mod drawing;
 pub mod edge_bundling;
+pub mod generators;
 pub mod graph;
 // pub mod grouping;
 pub mod layout;
@@ -5,10 +5,35 @@ use crate::{
 use petgraph_algorithm_shortest_path::warshall_floyd;
 use petgraph_quality_metrics::{
     crossing_edges, crossing_edges_torus, crossing_number_with_crossing_edges,
-    neighborhood_preservation, stress,
+    crossing_points_with_crossing_edges, neighborhood_preservation, stress, CrossingEdges,
 };
+use serde::Serialize;
 use wasm_bindgen::prelude::*;
 
+#[derive(Serialize)]
+struct Crossing {
+    edge1: (f32, f32, f32, f32),
+    edge2: (f32, f32, f32, f32),
+    x: f32,
+    y: f32,
+}
+
+fn crossings_to_value(crossing_edges: &CrossingEdges) -> JsValue {
+    let crossings = crossing_edges
+        .iter()
+        .zip(crossing_points_with_crossing_edges(crossing_edges))
+        .map(
+            |(&(x11, y11, x12, y12, x21, y21, x22, y22), (x, y))| Crossing {
+                edge1: (x11, y11, x12, y12),
+                edge2: (x21, y21, x22, y22),
+                x,
+                y,
+            },
+        )
+        .collect::<Vec<_>>();
+    serde_wasm_bindgen::to_value(&crossings).unwrap()
+}
+
 #[wasm_bindgen(js_name = stress)]
 pub fn js_stress(graph: &JsGraph, drawing: &JsDrawingEuclidean2d) -> f32 {
     let distance = warshall_floyd(graph.graph(), &mut |_| 1.0);
@@ -16,18 +41,38 @@ pub fn js_stress(graph: &JsGraph, drawing: &JsDrawingEuclidean2d) -> f32 {
 }
 
 #[wasm_bindgen(js_name = crossingNumber)]
-pub fn js_crossing_number(graph: &JsGraph, drawing: &JsDrawingEuclidean2d) -> f32 {
-    let crossings = crossing_edges(graph.graph(), drawing.drawing());
-    crossing_number_with_crossing_edges(&crossings)
+pub fn js_crossing_number(graph: &JsGraph, drawing: &JsDrawingEuclidean2d) -> Result<f32, JsValue> {
+    let crossings = crossing_edges(graph.graph(), drawing.drawing()).map_err(|e| e.to_string())?;
+    Ok(crossing_number_with_crossing_edges(&crossings))
 }
 
 #[wasm_bindgen(js_name = crossingNumberWithDrawingTorus2d)]
 pub fn js_crossing_number_with_drawing_torus_2d(
     graph: &JsGraph,
     drawing: &JsDrawingTorus2d,
-) -> f32 {
-    let crossings = crossing_edges_torus(graph.graph(), drawing.drawing());
-    crossing_number_with_crossing_edges(&crossings)
+) -> Result<f32, JsValue> {
+    let crossings =
+        crossing_edges_torus(graph.graph(), drawing.drawing()).map_err(|e| e.to_string())?;
+    Ok(crossing_number_with_crossing_edges(&crossings))
+}
+
+#[wasm_bindgen(js_name = crossingEdges)]
+pub fn js_crossing_edges(
+    graph: &JsGraph,
+    drawing: &JsDrawingEuclidean2d,
+) -> Result<JsValue, JsValue> {
+    let crossings = crossing_edges(graph.graph(), drawing.drawing()).map_err(|e| e.to_string())?;
+    Ok(crossings_to_value(&crossings))
+}
+
+#[wasm_bindgen(js_name = crossingEdgesWithDrawingTorus2d)]
+pub fn js_crossing_edges_with_drawing_torus_2d(
+    graph: &JsGraph,
+    drawing: &JsDrawingTorus2d,
+) -> Result<JsValue, JsValue> {
+    let crossings =
+        crossing_edges_torus(graph.graph(), drawing.drawing()).map_err(|e| e.to_string())?;
+    Ok(crossings_to_value(&crossings))
 }
 
 #[wasm_bindgen(js_name = neighborhoodPreservation)]
@@ -88,12 +88,41 @@ exports.testSparseSgd = function (data) {
   checkResult(graph, drawing);
 };
 
+exports.testConstrainedSparseSgd = function (data) {
+  const rng = eg.Rng.seedFrom(0n);
+  const graph = constructGraph(data);
+  const drawing = eg.DrawingEuclidean2d.initialPlacement(graph);
+  const sgd = new eg.ConstrainedSparseSgd(graph, () => 100, 50, rng);
+  sgd.setFixed(0, true, true);
+  const x = drawing.x(0);
+  const y = drawing.y(0);
+  const scheduler = sgd.scheduler(15, 0.1);
+  scheduler.run((eta) => {
+    sgd.shuffle(rng);
+    sgd.applyWithDrawingEuclidean2d(drawing, eta);
+  });
+  checkResult(graph, drawing);
+  assert.strictEqual(drawing.x(0), x);
+  assert.strictEqual(drawing.y(0), y);
+};
+
 exports.testCrossingNumber = function (data) {
   const graph = constructGraph(data);
   const drawing = eg.DrawingEuclidean2d.initialPlacement(graph);
   eg.crossingNumber(graph, drawing);
 };
 
+exports.testCrossingEdges = function (data) {
+  const graph = constructGraph(data);
+  const drawing = eg.DrawingEuclidean2d.initialPlacement(graph);
+  const crossings = eg.crossingEdges(graph, drawing);
+  assert.strictEqual(crossings.length, eg.crossingNumber(graph, drawing));
+  const u = graph.addNode({});
+  graph.addEdge(0, u, {});
+  assert.throws(() => eg.crossingEdges(graph, drawing));
+  assert.throws(() => eg.fdeb(graph, drawing));
+};
+
 exports.testNeighborhoodPreservation = function (data) {
   const graph = constructGraph(data);
   const drawing = eg.DrawingEuclidean2d.initialPlacement(graph);
@@ -105,3 +134,117 @@ exports.testStress = function (data) {
   const drawing = eg.DrawingEuclidean2d.initialPlacement(graph);
   eg.stress(graph, drawing);
 };
+
+exports.testPagerank = function (data) {
+  const graph = constructGraph(data);
+  const rank = eg.pagerank(graph, 0.85, 100);
+  let total = 0;
+  for (const u of graph.nodeIndices()) {
+    assert(Number.isFinite(rank.get(u)));
+    total += rank.get(u);
+  }
+  assert(Math.abs(total - 1) < 1e-3);
+};
+
+exports.testDrawingCoords = function (data) {
+  const graph = constructGraph(data);
+  const drawing = eg.DrawingEuclidean2d.initialPlacement(graph);
+  const coords = drawing.coords();
+  assert(coords instanceof Float32Array);
+  assert.strictEqual(coords.length, 2 * graph.nodeCount());
+  for (const u of graph.nodeIndices()) {
+    assert.strictEqual(coords[2 * u], drawing.x(u));
+    assert.strictEqual(coords[2 * u + 1], drawing.y(u));
+  }
+  drawing.setCoords(coords.map((v) => 2 * v));
+  for (const u of graph.nodeIndices()) {
+    assert.strictEqual(drawing.x(u), 2 * coords[2 * u]);
+  }
+  assert.throws(() => drawing.setCoords(new Float32Array(1)));
+};
+
+exports.testLayoutStep = function (data) {
+  const graph = constructGraph(data);
+  const rng = eg.Rng.seedFrom(0n);
+  const drawing = eg.DrawingEuclidean2d.initialPlacement(graph);
+  const sgd = new eg.FullSgd(graph, () => 30);
+  assert.throws(() => sgd.stepWithDrawingEuclidean2d(drawing, rng, 1));
+  sgd.startRun("exponential", 15, 0.1);
+  let result = sgd.stepWithDrawingEuclidean2d(drawing, rng, 10);
+  assert.strictEqual(result.iteration, 10);
+  assert(!result.finished);
+  assert(Number.isFinite(result.stress));
+  result = sgd.stepWithDrawingEuclidean2d(drawing, rng, 10);
+  assert.strictEqual(result.iteration, 15);
+  assert.strictEqual(result.progress, 1);
+  assert(result.finished);
+
+  const kamadaKawai = new eg.KamadaKawai(graph, () => ({ distance: 30 }));
+  result = kamadaKawai.step(drawing, 5);
+  assert(result.iteration <= 5);
+  assert(Number.isFinite(result.stress));
+  let progress = result.progress;
+  assert(progress >= 0 && progress <= 1);
+  result = kamadaKawai.step(drawing, 50);
+  assert(result.progress >= progress);
+  assert(result.finished || result.progress < 1);
+
+  const stressMajorization = new eg.StressMajorization(graph, drawing, () => ({
+    distance: 30,
+  }));
+  result = stressMajorization.step(drawing, 1);
+  progress = result.progress;
+  assert(progress >= 0 && progress <= 1);
+  result = stressMajorization.step(drawing, 1000);
+  assert(result.finished);
+  assert.strictEqual(result.progress, 1);
+
+  const overwrapRemoval = new eg.OverwrapRemoval(graph, () => 10);
+  result = overwrapRemoval.stepWithDrawingEuclidean2d(drawing, 1);
+  assert(result.progress >= 0 && result.progress <= 1);
+  result = overwrapRemoval.stepWithDrawingEuclidean2d(drawing, 1000);
+  assert(result.finished);
+  assert.strictEqual(result.progress, 1);
+  assert(result.stress < 1e-3);
+};
+
+exports.testSphericalSgd = function (data) {
+  const rng = eg.Rng.seedFrom(0n);
+  const graph = constructGraph(data);
+  const drawing = eg.DrawingSpherical2d.initialPlacement(graph);
+  const sgd = new eg.FullSgd(graph, () => 0.1);
+  const scheduler = sgd.scheduler(15, 0.1);
+  scheduler.run((eta) => {
+    sgd.shuffle(rng);
+    sgd.applyWithDrawingSpherical2d(drawing, eta);
+  });
+  for (const u of graph.nodeIndices()) {
+    assert(Number.isFinite(drawing.lon(u)));
+    assert(Number.isFinite(drawing.lat(u)));
+  }
+  for (const e of graph.edgeIndices()) {
+    const [source, target] = graph.edgeEndpoints(e);
+    const arc = drawing.edgeArc(source, target, 8);
+    assert.strictEqual(arc.length, 18);
+    assert(arc.every((v) => Number.isFinite(v)));
+    const geographic = drawing.edgeArcGeographic(source, target, 8);
+    assert.strictEqual(geographic.length, 18);
+    for (let i = 0; i < geographic.length; i += 2) {
+      assert(-180 <= geographic[i] && geographic[i] <= 180);
+      assert(-90 <= geographic[i + 1] && geographic[i + 1] <= 90);
+    }
+  }
+  assert.strictEqual(drawing.geographicCoords().length, 2 * drawing.len());
+};
+
+exports.testLabelPropagation = function (data) {
+  const graph = constructGraph(data);
+  const communities = eg.labelPropagation(graph, 100, false, 42n);
+  const again = eg.labelPropagation(graph, 100, false, 42n);
+  for (const u of graph.nodeIndices()) {
+    assert(Number.isInteger(communities.get(u)));
+    assert.strictEqual(communities.get(u), again.get(u));
+  }
+  const synchronous = eg.labelPropagation(graph, 10, true, 42n);
+  assert.strictEqual(synchronous.size, graph.nodeCount());
+};
@@ -22,12 +22,26 @@ extern "C" {
     fn test_full_sgd(data: JsValue);
     #[wasm_bindgen(js_name = "testSparseSgd")]
     fn test_sparse_sgd(data: JsValue);
+    #[wasm_bindgen(js_name = "testConstrainedSparseSgd")]
+    fn test_constrained_sparse_sgd(data: JsValue);
     #[wasm_bindgen(js_name = "testCrossingNumber")]
     fn test_crossing_number(data: JsValue);
+    #[wasm_bindgen(js_name = "testCrossingEdges")]
+    fn test_crossing_edges(data: JsValue);
     #[wasm_bindgen(js_name = "testNeighborhoodPreservation")]
     fn test_neighborhood_preservation(data: JsValue);
     #[wasm_bindgen(js_name = "testStress")]
     fn test_stress(data: JsValue);
+    #[wasm_bindgen(js_name = "testPagerank")]
+    fn test_pagerank(data: JsValue);
+    #[wasm_bindgen(js_name = "testDrawingCoords")]
+    fn test_drawing_coords(data: JsValue);
+    #[wasm_bindgen(js_name = "testLayoutStep")]
+    fn test_layout_step(data: JsValue);
+    #[wasm_bindgen(js_name = "testSphericalSgd")]
+    fn test_spherical_sgd(data: JsValue);
+    #[wasm_bindgen(js_name = "testLabelPropagation")]
+    fn test_label_propagation(data: JsValue);
 }
 
 #[wasm_bindgen_test]
@@ -72,12 +86,24 @@ pub fn sparse_sgd() {
     test_sparse_sgd(data);
 }
 
+#[wasm_bindgen_test]
+pub fn constrained_sparse_sgd() {
+    let data = example_data();
+    test_constrained_sparse_sgd(data);
+}
+
 #[wasm_bindgen_test]
 pub fn crossing_number() {
     let data = example_data();
     test_crossing_number(data);
 }
 
+#[wasm_bindgen_test]
+pub fn crossing_edges() {
+    let data = example_data();
+    test_crossing_edges(data);
+}
+
 #[wasm_bindgen_test]
 pub fn neighborhood_preservation() {
     let data = example_data();
@@ -89,3 +115,33 @@ pub fn stress() {
     let data = example_data();
     test_stress(data);
 }
+
+#[wasm_bindgen_test]
+pub fn pagerank() {
+    let data = example_data();
+    test_pagerank(data);
+}
+
+#[wasm_bindgen_test]
+pub fn drawing_coords() {
+    let data = example_data();
+    test_drawing_coords(data);
+}
+
+#[wasm_bindgen_test]
+pub fn layout_step() {
+    let data = example_data();
+    test_layout_step(data);
+}
+
+#[wasm_bindgen_test]
+pub fn spherical_sgd() {
+    let data = example_data();
+    test_spherical_sgd(data);
+}
+
+#[wasm_bindgen_test]
+pub fn label_propagation() {
+    let data = example_data();
+    test_label_propagation(data);
+}
@@ -0,0 +1,26 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use petgraph::Graph;
+use petgraph_drawing::{DrawingEuclidean2d, DrawingTorus2d};
+use petgraph_quality_metrics::{crossing_edges, crossing_edges_torus};
+
+fuzz_target!(|input: (Vec<(f32, f32)>, Vec<(u8, u8)>)| {
+    let (coordinates, edges) = input;
+    let mut graph = Graph::new_undirected();
+    let nodes = (0..256).map(|_| graph.add_node(())).collect::<Vec<_>>();
+    for &(u, v) in &edges {
+        graph.add_edge(nodes[u as usize], nodes[v as usize], ());
+    }
+    let n = coordinates.len().min(nodes.len());
+    let mut drawing = DrawingEuclidean2d::from_node_indices(&nodes[..n]);
+    let mut drawing_torus = DrawingTorus2d::from_node_indices(&nodes[..n]);
+    for (i, &(x, y)) in coordinates.iter().take(n).enumerate() {
+        drawing.set_x(nodes[i], x);
+        drawing.set_y(nodes[i], y);
+        drawing_torus.set_x(nodes[i], x);
+        drawing_torus.set_y(nodes[i], y);
+    }
+    let _ = crossing_edges(&graph, &drawing);
+    let _ = crossing_edges_torus(&graph, &drawing_torus);
+});
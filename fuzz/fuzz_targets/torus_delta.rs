@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use petgraph_drawing::{Delta, MetricTorus2d, TorusValue};
+
+fuzz_target!(|input: (f32, f32, f32, f32)| {
+    let (x1, y1, x2, y2) = input;
+    let p = MetricTorus2d(TorusValue::new(x1), TorusValue::new(y1));
+    let q = MetricTorus2d(TorusValue::new(x2), TorusValue::new(y2));
+    let d = &p - &q;
+    if [x1, y1, x2, y2].iter().all(|x| x.is_finite()) {
+        assert!(d.norm() <= 0.75);
+    }
+});